@@ -60,11 +60,11 @@ export class AMM {
     }
 
     applySell(token_amount: bigint): SellResult {
+        const sell_price = this.getSellPrice(token_amount);
+
         this.virtualTokenReserves = this.virtualTokenReserves + token_amount;
         this.realTokenReserves = this.realTokenReserves + token_amount;
 
-        const sell_price = this.getSellPrice(token_amount);
-
         this.virtualSolReserves = this.virtualSolReserves - sell_price;
         this.realSolReserves = this.realSolReserves - sell_price;
 
@@ -75,9 +75,11 @@ export class AMM {
     }
 
     getSellPrice(tokens: bigint): bigint {
-        const scaling_factor = this.initialVirtualTokenReserves;
-        const token_sell_proportion = (tokens * scaling_factor) / this.virtualTokenReserves;
-        const sol_received = (this.virtualSolReserves * token_sell_proportion) / scaling_factor;
+        const productOfReserves = this.virtualSolReserves * this.virtualTokenReserves;
+        const newVirtualTokenReserves = this.virtualTokenReserves + tokens;
+        // round the remaining reserves up so the pool never pays out more than the invariant allows
+        const newVirtualSolReserves = (productOfReserves + newVirtualTokenReserves - 1n) / newVirtualTokenReserves;
+        const sol_received = this.virtualSolReserves - newVirtualSolReserves;
         return sol_received < this.realSolReserves ? sol_received : this.realSolReserves;
     }
 }
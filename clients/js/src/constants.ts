@@ -28,6 +28,7 @@ export const SIMPLE_DEFAULT_BONDING_CURVE_PRESET:CreateBondingCurveInstructionAr
     uri: "https://www.simpleBondingCurve.com",
     vestingTerms: none(),
     startTime: none(),
+    graduationDeadline: none(),
     tokenTotalSupply: DEFAULT_TOKEN_SUPPLY,
 
     solLaunchThreshold: 300 *LAMPORTS_PER_SOL,
@@ -38,8 +39,18 @@ export const SIMPLE_DEFAULT_BONDING_CURVE_PRESET:CreateBondingCurveInstructionAr
 
     allocation: INIT_ALLOCATIONS_PCS,
 
+    presaleTerms: none(),
+    brandkitVestingTerms: none(),
+    launchProtection: none(),
+    feeSchedule: none(),
+    allowlist: none(),
+    curveKind: none(),
+    completionPolicy: none(),
 }
 
+// minimum notice for scheduled fee, cap, amm and status changes, matches Global::SETTINGS_TIMELOCK_SECONDS
+export const SETTINGS_TIMELOCK_SECONDS = 24 * 60 * 60;
+
 export const INIT_DEFAULTS={
     tradeFeeBps: 100,
     launchFeeLamports: 0.5*LAMPORTS_PER_SOL,
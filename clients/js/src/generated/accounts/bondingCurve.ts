@@ -6,20 +6,20 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import { Account, Context, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
-import { Serializer, array, bool, i64, mapSerializer, publicKey as publicKeySerializer, string, struct, u128, u64, u8 } from '@metaplex-foundation/umi/serializers';
-import { AllocationData, AllocationDataArgs, VestingTerms, VestingTermsArgs, getAllocationDataSerializer, getVestingTermsSerializer } from '../types';
+import { Account, Context, Option, OptionOrNullable, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
+import { Serializer, array, bool, i64, mapSerializer, option, publicKey as publicKeySerializer, string, struct, u128, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { AllocationData, AllocationDataArgs, Allowlist, AllowlistArgs, CompletionPolicy, CompletionPolicyArgs, CurveKind, CurveKindArgs, FeeSchedule, FeeScheduleArgs, LaunchProtection, LaunchProtectionArgs, VestingTerms, VestingTermsArgs, getAllocationDataSerializer, getAllowlistSerializer, getCompletionPolicySerializer, getCurveKindSerializer, getFeeScheduleSerializer, getLaunchProtectionSerializer, getVestingTermsSerializer } from '../types';
 
   
   export type BondingCurve = Account<BondingCurveAccountData>;
 
-  export type BondingCurveAccountData = { discriminator: Array<number>; mint: PublicKey; creator: PublicKey; platformAuthority: PublicKey; brandAuthority: PublicKey; virtualTokenMultiplierBps: bigint; virtualSolReserves: bigint; virtualTokenReserves: bigint; initialVirtualTokenReserves: bigint; realSolReserves: bigint; realTokenReserves: bigint; tokenTotalSupply: bigint; creatorVestedSupply: bigint; presaleSupply: bigint; bondingSupply: bigint; cexSupply: bigint; launchBrandkitSupply: bigint; lifetimeBrandkitSupply: bigint; platformSupply: bigint; solLaunchThreshold: bigint; startTime: bigint; complete: boolean; vestingTerms: VestingTerms; allocation: AllocationData; bump: number;  };
+  export type BondingCurveAccountData = { discriminator: Array<number>; mint: PublicKey; creator: PublicKey; platformAuthority: PublicKey; brandAuthority: PublicKey; virtualTokenMultiplierBps: bigint; virtualSolReserves: bigint; virtualTokenReserves: bigint; initialVirtualTokenReserves: bigint; realSolReserves: bigint; realTokenReserves: bigint; tokenTotalSupply: bigint; creatorVestedSupply: bigint; presaleSupply: bigint; bondingSupply: bigint; cexSupply: bigint; launchBrandkitSupply: bigint; lifetimeBrandkitSupply: bigint; platformSupply: bigint; solLaunchThreshold: bigint; presaleSolRaised: bigint; startTime: bigint; complete: boolean; graduated: boolean; traded: boolean; completionTime: bigint; graduationDeadline: Option<bigint>; failed: boolean; refundTokenSupply: bigint; launchFeeLamports: bigint; launchProtection: LaunchProtection; feeSchedule: Option<FeeSchedule>; allowlist: Option<Allowlist>; curveKind: CurveKind; completionPolicy: CompletionPolicy; vestingTerms: VestingTerms; allocation: AllocationData; bump: number;  };
 
-export type BondingCurveAccountDataArgs = { mint: PublicKey; creator: PublicKey; platformAuthority: PublicKey; brandAuthority: PublicKey; virtualTokenMultiplierBps: number | bigint; virtualSolReserves: number | bigint; virtualTokenReserves: number | bigint; initialVirtualTokenReserves: number | bigint; realSolReserves: number | bigint; realTokenReserves: number | bigint; tokenTotalSupply: number | bigint; creatorVestedSupply: number | bigint; presaleSupply: number | bigint; bondingSupply: number | bigint; cexSupply: number | bigint; launchBrandkitSupply: number | bigint; lifetimeBrandkitSupply: number | bigint; platformSupply: number | bigint; solLaunchThreshold: number | bigint; startTime: number | bigint; complete: boolean; vestingTerms: VestingTermsArgs; allocation: AllocationDataArgs; bump: number;  };
+export type BondingCurveAccountDataArgs = { mint: PublicKey; creator: PublicKey; platformAuthority: PublicKey; brandAuthority: PublicKey; virtualTokenMultiplierBps: number | bigint; virtualSolReserves: number | bigint; virtualTokenReserves: number | bigint; initialVirtualTokenReserves: number | bigint; realSolReserves: number | bigint; realTokenReserves: number | bigint; tokenTotalSupply: number | bigint; creatorVestedSupply: number | bigint; presaleSupply: number | bigint; bondingSupply: number | bigint; cexSupply: number | bigint; launchBrandkitSupply: number | bigint; lifetimeBrandkitSupply: number | bigint; platformSupply: number | bigint; solLaunchThreshold: number | bigint; presaleSolRaised: number | bigint; startTime: number | bigint; complete: boolean; graduated: boolean; traded: boolean; completionTime: number | bigint; graduationDeadline: OptionOrNullable<number | bigint>; failed: boolean; refundTokenSupply: number | bigint; launchFeeLamports: number | bigint; launchProtection: LaunchProtectionArgs; feeSchedule: OptionOrNullable<FeeScheduleArgs>; allowlist: OptionOrNullable<AllowlistArgs>; curveKind: CurveKindArgs; completionPolicy: CompletionPolicyArgs; vestingTerms: VestingTermsArgs; allocation: AllocationDataArgs; bump: number;  };
 
 
   export function getBondingCurveAccountDataSerializer(): Serializer<BondingCurveAccountDataArgs, BondingCurveAccountData> {
-  return mapSerializer<BondingCurveAccountDataArgs, any, BondingCurveAccountData>(struct<BondingCurveAccountData>([['discriminator', array(u8(), { size: 8 })], ['mint', publicKeySerializer()], ['creator', publicKeySerializer()], ['platformAuthority', publicKeySerializer()], ['brandAuthority', publicKeySerializer()], ['virtualTokenMultiplierBps', u64()], ['virtualSolReserves', u64()], ['virtualTokenReserves', u128()], ['initialVirtualTokenReserves', u128()], ['realSolReserves', u64()], ['realTokenReserves', u64()], ['tokenTotalSupply', u64()], ['creatorVestedSupply', u64()], ['presaleSupply', u64()], ['bondingSupply', u64()], ['cexSupply', u64()], ['launchBrandkitSupply', u64()], ['lifetimeBrandkitSupply', u64()], ['platformSupply', u64()], ['solLaunchThreshold', u64()], ['startTime', i64()], ['complete', bool()], ['vestingTerms', getVestingTermsSerializer()], ['allocation', getAllocationDataSerializer()], ['bump', u8()]], { description: 'BondingCurveAccountData' }), (value) => ({ ...value, discriminator: [23, 183, 248, 55, 96, 216, 172, 96] }) ) as Serializer<BondingCurveAccountDataArgs, BondingCurveAccountData>;
+  return mapSerializer<BondingCurveAccountDataArgs, any, BondingCurveAccountData>(struct<BondingCurveAccountData>([['discriminator', array(u8(), { size: 8 })], ['mint', publicKeySerializer()], ['creator', publicKeySerializer()], ['platformAuthority', publicKeySerializer()], ['brandAuthority', publicKeySerializer()], ['virtualTokenMultiplierBps', u64()], ['virtualSolReserves', u64()], ['virtualTokenReserves', u128()], ['initialVirtualTokenReserves', u128()], ['realSolReserves', u64()], ['realTokenReserves', u64()], ['tokenTotalSupply', u64()], ['creatorVestedSupply', u64()], ['presaleSupply', u64()], ['bondingSupply', u64()], ['cexSupply', u64()], ['launchBrandkitSupply', u64()], ['lifetimeBrandkitSupply', u64()], ['platformSupply', u64()], ['solLaunchThreshold', u64()], ['presaleSolRaised', u64()], ['startTime', i64()], ['complete', bool()], ['graduated', bool()], ['traded', bool()], ['completionTime', i64()], ['graduationDeadline', option(i64())], ['failed', bool()], ['refundTokenSupply', u64()], ['launchFeeLamports', u64()], ['launchProtection', getLaunchProtectionSerializer()], ['feeSchedule', option(getFeeScheduleSerializer())], ['allowlist', option(getAllowlistSerializer())], ['curveKind', getCurveKindSerializer()], ['completionPolicy', getCompletionPolicySerializer()], ['vestingTerms', getVestingTermsSerializer()], ['allocation', getAllocationDataSerializer()], ['bump', u8()]], { description: 'BondingCurveAccountData' }), (value) => ({ ...value, discriminator: [23, 183, 248, 55, 96, 216, 172, 96] }) ) as Serializer<BondingCurveAccountDataArgs, BondingCurveAccountData>;
 }
 
 
@@ -74,15 +74,11 @@ export async function safeFetchAllBondingCurve(
 export function getBondingCurveGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
   const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
   return gpaBuilder(context, programId)
-    .registerFields<{ 'discriminator': Array<number>, 'mint': PublicKey, 'creator': PublicKey, 'platformAuthority': PublicKey, 'brandAuthority': PublicKey, 'virtualTokenMultiplierBps': number | bigint, 'virtualSolReserves': number | bigint, 'virtualTokenReserves': number | bigint, 'initialVirtualTokenReserves': number | bigint, 'realSolReserves': number | bigint, 'realTokenReserves': number | bigint, 'tokenTotalSupply': number | bigint, 'creatorVestedSupply': number | bigint, 'presaleSupply': number | bigint, 'bondingSupply': number | bigint, 'cexSupply': number | bigint, 'launchBrandkitSupply': number | bigint, 'lifetimeBrandkitSupply': number | bigint, 'platformSupply': number | bigint, 'solLaunchThreshold': number | bigint, 'startTime': number | bigint, 'complete': boolean, 'vestingTerms': VestingTermsArgs, 'allocation': AllocationDataArgs, 'bump': number }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'mint': [8, publicKeySerializer()], 'creator': [40, publicKeySerializer()], 'platformAuthority': [72, publicKeySerializer()], 'brandAuthority': [104, publicKeySerializer()], 'virtualTokenMultiplierBps': [136, u64()], 'virtualSolReserves': [144, u64()], 'virtualTokenReserves': [152, u128()], 'initialVirtualTokenReserves': [168, u128()], 'realSolReserves': [184, u64()], 'realTokenReserves': [192, u64()], 'tokenTotalSupply': [200, u64()], 'creatorVestedSupply': [208, u64()], 'presaleSupply': [216, u64()], 'bondingSupply': [224, u64()], 'cexSupply': [232, u64()], 'launchBrandkitSupply': [240, u64()], 'lifetimeBrandkitSupply': [248, u64()], 'platformSupply': [256, u64()], 'solLaunchThreshold': [264, u64()], 'startTime': [272, i64()], 'complete': [280, bool()], 'vestingTerms': [281, getVestingTermsSerializer()], 'allocation': [297, getAllocationDataSerializer()], 'bump': [353, u8()] })
+    .registerFields<{ 'discriminator': Array<number>, 'mint': PublicKey, 'creator': PublicKey, 'platformAuthority': PublicKey, 'brandAuthority': PublicKey, 'virtualTokenMultiplierBps': number | bigint, 'virtualSolReserves': number | bigint, 'virtualTokenReserves': number | bigint, 'initialVirtualTokenReserves': number | bigint, 'realSolReserves': number | bigint, 'realTokenReserves': number | bigint, 'tokenTotalSupply': number | bigint, 'creatorVestedSupply': number | bigint, 'presaleSupply': number | bigint, 'bondingSupply': number | bigint, 'cexSupply': number | bigint, 'launchBrandkitSupply': number | bigint, 'lifetimeBrandkitSupply': number | bigint, 'platformSupply': number | bigint, 'solLaunchThreshold': number | bigint, 'presaleSolRaised': number | bigint, 'startTime': number | bigint, 'complete': boolean, 'graduated': boolean, 'traded': boolean, 'completionTime': number | bigint, 'graduationDeadline': OptionOrNullable<number | bigint>, 'failed': boolean, 'refundTokenSupply': number | bigint, 'launchFeeLamports': number | bigint, 'launchProtection': LaunchProtectionArgs, 'feeSchedule': OptionOrNullable<FeeScheduleArgs>, 'allowlist': OptionOrNullable<AllowlistArgs>, 'curveKind': CurveKindArgs, 'completionPolicy': CompletionPolicyArgs, 'vestingTerms': VestingTermsArgs, 'allocation': AllocationDataArgs, 'bump': number }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'mint': [8, publicKeySerializer()], 'creator': [40, publicKeySerializer()], 'platformAuthority': [72, publicKeySerializer()], 'brandAuthority': [104, publicKeySerializer()], 'virtualTokenMultiplierBps': [136, u64()], 'virtualSolReserves': [144, u64()], 'virtualTokenReserves': [152, u128()], 'initialVirtualTokenReserves': [168, u128()], 'realSolReserves': [184, u64()], 'realTokenReserves': [192, u64()], 'tokenTotalSupply': [200, u64()], 'creatorVestedSupply': [208, u64()], 'presaleSupply': [216, u64()], 'bondingSupply': [224, u64()], 'cexSupply': [232, u64()], 'launchBrandkitSupply': [240, u64()], 'lifetimeBrandkitSupply': [248, u64()], 'platformSupply': [256, u64()], 'solLaunchThreshold': [264, u64()], 'presaleSolRaised': [272, u64()], 'startTime': [280, i64()], 'complete': [288, bool()], 'graduated': [289, bool()], 'traded': [290, bool()], 'completionTime': [291, i64()], 'graduationDeadline': [299, option(i64())], 'failed': [null, bool()], 'refundTokenSupply': [null, u64()], 'launchFeeLamports': [null, u64()], 'launchProtection': [null, getLaunchProtectionSerializer()], 'feeSchedule': [null, option(getFeeScheduleSerializer())], 'allowlist': [null, option(getAllowlistSerializer())], 'curveKind': [null, getCurveKindSerializer()], 'completionPolicy': [null, getCompletionPolicySerializer()], 'vestingTerms': [null, getVestingTermsSerializer()], 'allocation': [null, getAllocationDataSerializer()], 'bump': [null, u8()] })
     .deserializeUsing<BondingCurve>((account) => deserializeBondingCurve(account))      .whereField('discriminator', [23, 183, 248, 55, 96, 216, 172, 96])
     ;
 }
 
-export function getBondingCurveSize(): number {
-  return 354;
-}
-
 export function findBondingCurvePda(
   context: Pick<Context, 'eddsa' | 'programs'>,
       seeds: {
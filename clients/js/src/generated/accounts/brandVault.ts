@@ -8,17 +8,18 @@
 
 import { Account, Context, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
 import { Serializer, array, mapSerializer, publicKey as publicKeySerializer, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { VestingTerms, VestingTermsArgs, getVestingTermsSerializer } from '../types';
 
   
   export type BrandVault = Account<BrandVaultAccountData>;
 
-  export type BrandVaultAccountData = { discriminator: Array<number>; launchBrandkitSupply: bigint; lifetimeBrandkitSupply: bigint; initialVestedSupply: bigint;  };
+  export type BrandVaultAccountData = { discriminator: Array<number>; launchBrandkitSupply: bigint; lifetimeBrandkitSupply: bigint; initialVestedSupply: bigint; lifetimeVestingTerms: VestingTerms; launchBrandkitClaimed: bigint; lifetimeBrandkitClaimed: bigint;  };
 
-export type BrandVaultAccountDataArgs = { launchBrandkitSupply: number | bigint; lifetimeBrandkitSupply: number | bigint; initialVestedSupply: number | bigint;  };
+export type BrandVaultAccountDataArgs = { launchBrandkitSupply: number | bigint; lifetimeBrandkitSupply: number | bigint; initialVestedSupply: number | bigint; lifetimeVestingTerms: VestingTermsArgs; launchBrandkitClaimed: number | bigint; lifetimeBrandkitClaimed: number | bigint;  };
 
 
   export function getBrandVaultAccountDataSerializer(): Serializer<BrandVaultAccountDataArgs, BrandVaultAccountData> {
-  return mapSerializer<BrandVaultAccountDataArgs, any, BrandVaultAccountData>(struct<BrandVaultAccountData>([['discriminator', array(u8(), { size: 8 })], ['launchBrandkitSupply', u64()], ['lifetimeBrandkitSupply', u64()], ['initialVestedSupply', u64()]], { description: 'BrandVaultAccountData' }), (value) => ({ ...value, discriminator: [151, 78, 229, 240, 28, 131, 251, 218] }) ) as Serializer<BrandVaultAccountDataArgs, BrandVaultAccountData>;
+  return mapSerializer<BrandVaultAccountDataArgs, any, BrandVaultAccountData>(struct<BrandVaultAccountData>([['discriminator', array(u8(), { size: 8 })], ['launchBrandkitSupply', u64()], ['lifetimeBrandkitSupply', u64()], ['initialVestedSupply', u64()], ['lifetimeVestingTerms', getVestingTermsSerializer()], ['launchBrandkitClaimed', u64()], ['lifetimeBrandkitClaimed', u64()]], { description: 'BrandVaultAccountData' }), (value) => ({ ...value, discriminator: [151, 78, 229, 240, 28, 131, 251, 218] }) ) as Serializer<BrandVaultAccountDataArgs, BrandVaultAccountData>;
 }
 
 
@@ -73,15 +74,11 @@ export async function safeFetchAllBrandVault(
 export function getBrandVaultGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
   const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
   return gpaBuilder(context, programId)
-    .registerFields<{ 'discriminator': Array<number>, 'launchBrandkitSupply': number | bigint, 'lifetimeBrandkitSupply': number | bigint, 'initialVestedSupply': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'launchBrandkitSupply': [8, u64()], 'lifetimeBrandkitSupply': [16, u64()], 'initialVestedSupply': [24, u64()] })
+    .registerFields<{ 'discriminator': Array<number>, 'launchBrandkitSupply': number | bigint, 'lifetimeBrandkitSupply': number | bigint, 'initialVestedSupply': number | bigint, 'lifetimeVestingTerms': VestingTermsArgs, 'launchBrandkitClaimed': number | bigint, 'lifetimeBrandkitClaimed': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'launchBrandkitSupply': [8, u64()], 'lifetimeBrandkitSupply': [16, u64()], 'initialVestedSupply': [24, u64()], 'lifetimeVestingTerms': [32, getVestingTermsSerializer()], 'launchBrandkitClaimed': [null, u64()], 'lifetimeBrandkitClaimed': [null, u64()] })
     .deserializeUsing<BrandVault>((account) => deserializeBrandVault(account))      .whereField('discriminator', [151, 78, 229, 240, 28, 131, 251, 218])
     ;
 }
 
-export function getBrandVaultSize(): number {
-  return 32;
-}
-
 export function findBrandVaultPda(
   context: Pick<Context, 'eddsa' | 'programs'>,
       seeds: {
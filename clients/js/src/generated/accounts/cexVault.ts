@@ -0,0 +1,110 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Account, Context, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
+import { Serializer, array, i64, mapSerializer, publicKey as publicKeySerializer, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { VestingTerms, VestingTermsArgs, getVestingTermsSerializer } from '../types';
+
+  
+  export type CexVault = Account<CexVaultAccountData>;
+
+  export type CexVaultAccountData = { discriminator: Array<number>; initialVestedSupply: bigint; vestingTerms: VestingTerms; tokensReleased: bigint; lastRelease: bigint;  };
+
+export type CexVaultAccountDataArgs = { initialVestedSupply: number | bigint; vestingTerms: VestingTermsArgs; tokensReleased: number | bigint; lastRelease: number | bigint;  };
+
+
+  export function getCexVaultAccountDataSerializer(): Serializer<CexVaultAccountDataArgs, CexVaultAccountData> {
+  return mapSerializer<CexVaultAccountDataArgs, any, CexVaultAccountData>(struct<CexVaultAccountData>([['discriminator', array(u8(), { size: 8 })], ['initialVestedSupply', u64()], ['vestingTerms', getVestingTermsSerializer()], ['tokensReleased', u64()], ['lastRelease', i64()]], { description: 'CexVaultAccountData' }), (value) => ({ ...value, discriminator: [231, 157, 240, 148, 202, 132, 222, 166] }) ) as Serializer<CexVaultAccountDataArgs, CexVaultAccountData>;
+}
+
+
+export function deserializeCexVault(rawAccount: RpcAccount): CexVault {
+  return deserializeAccount(rawAccount, getCexVaultAccountDataSerializer());
+}
+
+export async function fetchCexVault(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions,
+): Promise<CexVault> {
+  const maybeAccount = await context.rpc.getAccount(toPublicKey(publicKey, false), options);
+  assertAccountExists(maybeAccount, 'CexVault');
+  return deserializeCexVault(maybeAccount);
+}
+
+export async function safeFetchCexVault(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions,
+): Promise<CexVault | null> {
+  const maybeAccount = await context.rpc.getAccount(toPublicKey(publicKey, false), options);
+  return maybeAccount.exists
+    ? deserializeCexVault(maybeAccount)
+    : null;
+}
+
+export async function fetchAllCexVault(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions,
+): Promise<CexVault[]> {
+  const maybeAccounts = await context.rpc.getAccounts(publicKeys.map(key => toPublicKey(key, false)), options);
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'CexVault');
+    return deserializeCexVault(maybeAccount);
+  });
+}
+
+export async function safeFetchAllCexVault(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions,
+): Promise<CexVault[]> {
+  const maybeAccounts = await context.rpc.getAccounts(publicKeys.map(key => toPublicKey(key, false)), options);
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeCexVault(maybeAccount as RpcAccount));
+}
+
+export function getCexVaultGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+  return gpaBuilder(context, programId)
+    .registerFields<{ 'discriminator': Array<number>, 'initialVestedSupply': number | bigint, 'vestingTerms': VestingTermsArgs, 'tokensReleased': number | bigint, 'lastRelease': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'initialVestedSupply': [8, u64()], 'vestingTerms': [16, getVestingTermsSerializer()], 'tokensReleased': [null, u64()], 'lastRelease': [null, i64()] })
+    .deserializeUsing<CexVault>((account) => deserializeCexVault(account))      .whereField('discriminator', [231, 157, 240, 148, 202, 132, 222, 166])
+    ;
+}
+
+export function findCexVaultPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+      seeds: {
+                                      /** The mint of the bonding curve tkn */
+          mint: PublicKey;
+                  }
+  ): Pda {
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+  return context.eddsa.findPda(programId, [
+                  string({ size: 'variable' }).serialize("cex-vault"),
+                        publicKeySerializer().serialize(seeds.mint),
+            ]);
+}
+
+export async function fetchCexVaultFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+      seeds: Parameters<typeof findCexVaultPda>[1],
+    options?: RpcGetAccountOptions,
+): Promise<CexVault> {
+  return fetchCexVault(context, findCexVaultPda(context, seeds), options);
+}
+
+export async function safeFetchCexVaultFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+      seeds: Parameters<typeof findCexVaultPda>[1],
+    options?: RpcGetAccountOptions,
+): Promise<CexVault | null> {
+  return safeFetchCexVault(context, findCexVaultPda(context, seeds), options);
+}
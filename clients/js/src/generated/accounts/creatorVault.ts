@@ -12,13 +12,13 @@ import { Serializer, array, i64, mapSerializer, publicKey as publicKeySerializer
   
   export type CreatorVault = Account<CreatorVaultAccountData>;
 
-  export type CreatorVaultAccountData = { discriminator: Array<number>; initialVestedSupply: bigint; lastDistribution: bigint;  };
+  export type CreatorVaultAccountData = { discriminator: Array<number>; initialVestedSupply: bigint; lastDistribution: bigint; claimedAmount: bigint;  };
 
-export type CreatorVaultAccountDataArgs = { initialVestedSupply: number | bigint; lastDistribution: number | bigint;  };
+export type CreatorVaultAccountDataArgs = { initialVestedSupply: number | bigint; lastDistribution: number | bigint; claimedAmount: number | bigint;  };
 
 
   export function getCreatorVaultAccountDataSerializer(): Serializer<CreatorVaultAccountDataArgs, CreatorVaultAccountData> {
-  return mapSerializer<CreatorVaultAccountDataArgs, any, CreatorVaultAccountData>(struct<CreatorVaultAccountData>([['discriminator', array(u8(), { size: 8 })], ['initialVestedSupply', u64()], ['lastDistribution', i64()]], { description: 'CreatorVaultAccountData' }), (value) => ({ ...value, discriminator: [200, 135, 38, 98, 35, 236, 238, 12] }) ) as Serializer<CreatorVaultAccountDataArgs, CreatorVaultAccountData>;
+  return mapSerializer<CreatorVaultAccountDataArgs, any, CreatorVaultAccountData>(struct<CreatorVaultAccountData>([['discriminator', array(u8(), { size: 8 })], ['initialVestedSupply', u64()], ['lastDistribution', i64()], ['claimedAmount', u64()]], { description: 'CreatorVaultAccountData' }), (value) => ({ ...value, discriminator: [200, 135, 38, 98, 35, 236, 238, 12] }) ) as Serializer<CreatorVaultAccountDataArgs, CreatorVaultAccountData>;
 }
 
 
@@ -73,13 +73,13 @@ export async function safeFetchAllCreatorVault(
 export function getCreatorVaultGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
   const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
   return gpaBuilder(context, programId)
-    .registerFields<{ 'discriminator': Array<number>, 'initialVestedSupply': number | bigint, 'lastDistribution': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'initialVestedSupply': [8, u64()], 'lastDistribution': [16, i64()] })
+    .registerFields<{ 'discriminator': Array<number>, 'initialVestedSupply': number | bigint, 'lastDistribution': number | bigint, 'claimedAmount': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'initialVestedSupply': [8, u64()], 'lastDistribution': [16, i64()], 'claimedAmount': [24, u64()] })
     .deserializeUsing<CreatorVault>((account) => deserializeCreatorVault(account))      .whereField('discriminator', [200, 135, 38, 98, 35, 236, 238, 12])
     ;
 }
 
 export function getCreatorVaultSize(): number {
-  return 24;
+  return 32;
 }
 
 export function findCreatorVaultPda(
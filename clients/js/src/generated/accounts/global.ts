@@ -6,20 +6,20 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import { Account, Context, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
-import { Serializer, array, bool, mapSerializer, publicKey as publicKeySerializer, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
-import { ProgramStatus, ProgramStatusArgs, getProgramStatusSerializer } from '../types';
+import { Account, Context, Option, OptionOrNullable, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
+import { Serializer, array, bool, i64, mapSerializer, option, publicKey as publicKeySerializer, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { GlobalSettingsInput, GlobalSettingsInputArgs, ProgramStatus, ProgramStatusArgs, SettingsCaps, SettingsCapsArgs, VestingTerms, VestingTermsArgs, getGlobalSettingsInputSerializer, getProgramStatusSerializer, getSettingsCapsSerializer, getVestingTermsSerializer } from '../types';
 
   
   export type Global = Account<GlobalAccountData>;
 
-  export type GlobalAccountData = { discriminator: Array<number>; status: ProgramStatus; initialized: boolean; globalAuthority: PublicKey; withdrawAuthority: PublicKey; tradeFeeBps: bigint; launchFeeLamports: bigint; createdMintDecimals: number;  };
+  export type GlobalAccountData = { discriminator: Array<number>; status: ProgramStatus; initialized: boolean; globalAuthority: PublicKey; withdrawAuthority: PublicKey; pendingGlobalAuthority: Option<PublicKey>; pendingWithdrawAuthority: Option<PublicKey>; tradeFeeBps: bigint; launchFeeLamports: bigint; launchFeeRefundBps: bigint; createdMintDecimals: number; maxLaunchLeadTime: bigint; ammProgram: PublicKey; platformVestingTerms: VestingTerms; cexVestingTerms: VestingTerms; caps: SettingsCaps; pendingSettings: Option<GlobalSettingsInput>; pendingSettingsEffectiveTime: bigint;  };
 
-export type GlobalAccountDataArgs = { status: ProgramStatusArgs; initialized: boolean; globalAuthority: PublicKey; withdrawAuthority: PublicKey; tradeFeeBps: number | bigint; launchFeeLamports: number | bigint; createdMintDecimals: number;  };
+export type GlobalAccountDataArgs = { status: ProgramStatusArgs; initialized: boolean; globalAuthority: PublicKey; withdrawAuthority: PublicKey; pendingGlobalAuthority: OptionOrNullable<PublicKey>; pendingWithdrawAuthority: OptionOrNullable<PublicKey>; tradeFeeBps: number | bigint; launchFeeLamports: number | bigint; launchFeeRefundBps: number | bigint; createdMintDecimals: number; maxLaunchLeadTime: number | bigint; ammProgram: PublicKey; platformVestingTerms: VestingTermsArgs; cexVestingTerms: VestingTermsArgs; caps: SettingsCapsArgs; pendingSettings: OptionOrNullable<GlobalSettingsInputArgs>; pendingSettingsEffectiveTime: number | bigint;  };
 
 
   export function getGlobalAccountDataSerializer(): Serializer<GlobalAccountDataArgs, GlobalAccountData> {
-  return mapSerializer<GlobalAccountDataArgs, any, GlobalAccountData>(struct<GlobalAccountData>([['discriminator', array(u8(), { size: 8 })], ['status', getProgramStatusSerializer()], ['initialized', bool()], ['globalAuthority', publicKeySerializer()], ['withdrawAuthority', publicKeySerializer()], ['tradeFeeBps', u64()], ['launchFeeLamports', u64()], ['createdMintDecimals', u8()]], { description: 'GlobalAccountData' }), (value) => ({ ...value, discriminator: [167, 232, 232, 177, 200, 108, 114, 127] }) ) as Serializer<GlobalAccountDataArgs, GlobalAccountData>;
+  return mapSerializer<GlobalAccountDataArgs, any, GlobalAccountData>(struct<GlobalAccountData>([['discriminator', array(u8(), { size: 8 })], ['status', getProgramStatusSerializer()], ['initialized', bool()], ['globalAuthority', publicKeySerializer()], ['withdrawAuthority', publicKeySerializer()], ['pendingGlobalAuthority', option(publicKeySerializer())], ['pendingWithdrawAuthority', option(publicKeySerializer())], ['tradeFeeBps', u64()], ['launchFeeLamports', u64()], ['launchFeeRefundBps', u64()], ['createdMintDecimals', u8()], ['maxLaunchLeadTime', i64()], ['ammProgram', publicKeySerializer()], ['platformVestingTerms', getVestingTermsSerializer()], ['cexVestingTerms', getVestingTermsSerializer()], ['caps', getSettingsCapsSerializer()], ['pendingSettings', option(getGlobalSettingsInputSerializer())], ['pendingSettingsEffectiveTime', i64()]], { description: 'GlobalAccountData' }), (value) => ({ ...value, discriminator: [167, 232, 232, 177, 200, 108, 114, 127] }) ) as Serializer<GlobalAccountDataArgs, GlobalAccountData>;
 }
 
 
@@ -74,15 +74,11 @@ export async function safeFetchAllGlobal(
 export function getGlobalGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
   const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
   return gpaBuilder(context, programId)
-    .registerFields<{ 'discriminator': Array<number>, 'status': ProgramStatusArgs, 'initialized': boolean, 'globalAuthority': PublicKey, 'withdrawAuthority': PublicKey, 'tradeFeeBps': number | bigint, 'launchFeeLamports': number | bigint, 'createdMintDecimals': number }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'status': [8, getProgramStatusSerializer()], 'initialized': [9, bool()], 'globalAuthority': [10, publicKeySerializer()], 'withdrawAuthority': [42, publicKeySerializer()], 'tradeFeeBps': [74, u64()], 'launchFeeLamports': [82, u64()], 'createdMintDecimals': [90, u8()] })
+    .registerFields<{ 'discriminator': Array<number>, 'status': ProgramStatusArgs, 'initialized': boolean, 'globalAuthority': PublicKey, 'withdrawAuthority': PublicKey, 'pendingGlobalAuthority': OptionOrNullable<PublicKey>, 'pendingWithdrawAuthority': OptionOrNullable<PublicKey>, 'tradeFeeBps': number | bigint, 'launchFeeLamports': number | bigint, 'launchFeeRefundBps': number | bigint, 'createdMintDecimals': number, 'maxLaunchLeadTime': number | bigint, 'ammProgram': PublicKey, 'platformVestingTerms': VestingTermsArgs, 'cexVestingTerms': VestingTermsArgs, 'caps': SettingsCapsArgs, 'pendingSettings': OptionOrNullable<GlobalSettingsInputArgs>, 'pendingSettingsEffectiveTime': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'status': [8, getProgramStatusSerializer()], 'initialized': [9, bool()], 'globalAuthority': [10, publicKeySerializer()], 'withdrawAuthority': [42, publicKeySerializer()], 'pendingGlobalAuthority': [74, option(publicKeySerializer())], 'pendingWithdrawAuthority': [null, option(publicKeySerializer())], 'tradeFeeBps': [null, u64()], 'launchFeeLamports': [null, u64()], 'launchFeeRefundBps': [null, u64()], 'createdMintDecimals': [null, u8()], 'maxLaunchLeadTime': [null, i64()], 'ammProgram': [null, publicKeySerializer()], 'platformVestingTerms': [null, getVestingTermsSerializer()], 'cexVestingTerms': [null, getVestingTermsSerializer()], 'caps': [null, getSettingsCapsSerializer()], 'pendingSettings': [null, option(getGlobalSettingsInputSerializer())], 'pendingSettingsEffectiveTime': [null, i64()] })
     .deserializeUsing<Global>((account) => deserializeGlobal(account))      .whereField('discriminator', [167, 232, 232, 177, 200, 108, 114, 127])
     ;
 }
 
-export function getGlobalSize(): number {
-  return 91;
-}
-
 export function findGlobalPda(
   context: Pick<Context, 'eddsa' | 'programs'>,
   ): Pda {
@@ -8,7 +8,10 @@
 
 export * from './bondingCurve';
 export * from './brandVault';
+export * from './cexVault';
 export * from './creatorVault';
 export * from './global';
 export * from './platformVault';
+export * from './presalePosition';
 export * from './presaleVault';
+export * from './userPosition';
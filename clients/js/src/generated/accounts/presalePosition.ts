@@ -0,0 +1,116 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Account, Context, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
+import { Serializer, array, bool, mapSerializer, publicKey as publicKeySerializer, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+
+  
+  export type PresalePosition = Account<PresalePositionAccountData>;
+
+  export type PresalePositionAccountData = { discriminator: Array<number>; contributor: PublicKey; mint: PublicKey; contributed: bigint; claimed: boolean; bump: number;  };
+
+export type PresalePositionAccountDataArgs = { contributor: PublicKey; mint: PublicKey; contributed: number | bigint; claimed: boolean; bump: number;  };
+
+
+  export function getPresalePositionAccountDataSerializer(): Serializer<PresalePositionAccountDataArgs, PresalePositionAccountData> {
+  return mapSerializer<PresalePositionAccountDataArgs, any, PresalePositionAccountData>(struct<PresalePositionAccountData>([['discriminator', array(u8(), { size: 8 })], ['contributor', publicKeySerializer()], ['mint', publicKeySerializer()], ['contributed', u64()], ['claimed', bool()], ['bump', u8()]], { description: 'PresalePositionAccountData' }), (value) => ({ ...value, discriminator: [129, 228, 114, 229, 85, 40, 191, 7] }) ) as Serializer<PresalePositionAccountDataArgs, PresalePositionAccountData>;
+}
+
+
+export function deserializePresalePosition(rawAccount: RpcAccount): PresalePosition {
+  return deserializeAccount(rawAccount, getPresalePositionAccountDataSerializer());
+}
+
+export async function fetchPresalePosition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions,
+): Promise<PresalePosition> {
+  const maybeAccount = await context.rpc.getAccount(toPublicKey(publicKey, false), options);
+  assertAccountExists(maybeAccount, 'PresalePosition');
+  return deserializePresalePosition(maybeAccount);
+}
+
+export async function safeFetchPresalePosition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions,
+): Promise<PresalePosition | null> {
+  const maybeAccount = await context.rpc.getAccount(toPublicKey(publicKey, false), options);
+  return maybeAccount.exists
+    ? deserializePresalePosition(maybeAccount)
+    : null;
+}
+
+export async function fetchAllPresalePosition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions,
+): Promise<PresalePosition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(publicKeys.map(key => toPublicKey(key, false)), options);
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'PresalePosition');
+    return deserializePresalePosition(maybeAccount);
+  });
+}
+
+export async function safeFetchAllPresalePosition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions,
+): Promise<PresalePosition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(publicKeys.map(key => toPublicKey(key, false)), options);
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializePresalePosition(maybeAccount as RpcAccount));
+}
+
+export function getPresalePositionGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+  return gpaBuilder(context, programId)
+    .registerFields<{ 'discriminator': Array<number>, 'contributor': PublicKey, 'mint': PublicKey, 'contributed': number | bigint, 'claimed': boolean, 'bump': number }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'contributor': [8, publicKeySerializer()], 'mint': [40, publicKeySerializer()], 'contributed': [72, u64()], 'claimed': [80, bool()], 'bump': [81, u8()] })
+    .deserializeUsing<PresalePosition>((account) => deserializePresalePosition(account))      .whereField('discriminator', [129, 228, 114, 229, 85, 40, 191, 7])
+    ;
+}
+
+export function getPresalePositionSize(): number {
+  return 82;
+}
+
+export function findPresalePositionPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+      seeds: {
+                                      /** The mint of the bonding curve tkn */
+          mint: PublicKey;
+                            /** The wallet contributing to the presale */
+          contributor: PublicKey;
+                  }
+  ): Pda {
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+  return context.eddsa.findPda(programId, [
+                  string({ size: 'variable' }).serialize("presale-position"),
+                        publicKeySerializer().serialize(seeds.mint),
+                        publicKeySerializer().serialize(seeds.contributor),
+            ]);
+}
+
+export async function fetchPresalePositionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+      seeds: Parameters<typeof findPresalePositionPda>[1],
+    options?: RpcGetAccountOptions,
+): Promise<PresalePosition> {
+  return fetchPresalePosition(context, findPresalePositionPda(context, seeds), options);
+}
+
+export async function safeFetchPresalePositionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+      seeds: Parameters<typeof findPresalePositionPda>[1],
+    options?: RpcGetAccountOptions,
+): Promise<PresalePosition | null> {
+  return safeFetchPresalePosition(context, findPresalePositionPda(context, seeds), options);
+}
@@ -8,17 +8,18 @@
 
 import { Account, Context, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
 import { Serializer, array, mapSerializer, publicKey as publicKeySerializer, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { PresaleTerms, PresaleTermsArgs, getPresaleTermsSerializer } from '../types';
 
   
   export type PresaleVault = Account<PresaleVaultAccountData>;
 
-  export type PresaleVaultAccountData = { discriminator: Array<number>; initialVestedSupply: bigint;  };
+  export type PresaleVaultAccountData = { discriminator: Array<number>; initialVestedSupply: bigint; presaleTerms: PresaleTerms; totalClaimed: bigint;  };
 
-export type PresaleVaultAccountDataArgs = { initialVestedSupply: number | bigint;  };
+export type PresaleVaultAccountDataArgs = { initialVestedSupply: number | bigint; presaleTerms: PresaleTermsArgs; totalClaimed: number | bigint;  };
 
 
   export function getPresaleVaultAccountDataSerializer(): Serializer<PresaleVaultAccountDataArgs, PresaleVaultAccountData> {
-  return mapSerializer<PresaleVaultAccountDataArgs, any, PresaleVaultAccountData>(struct<PresaleVaultAccountData>([['discriminator', array(u8(), { size: 8 })], ['initialVestedSupply', u64()]], { description: 'PresaleVaultAccountData' }), (value) => ({ ...value, discriminator: [102, 155, 208, 196, 54, 144, 19, 203] }) ) as Serializer<PresaleVaultAccountDataArgs, PresaleVaultAccountData>;
+  return mapSerializer<PresaleVaultAccountDataArgs, any, PresaleVaultAccountData>(struct<PresaleVaultAccountData>([['discriminator', array(u8(), { size: 8 })], ['initialVestedSupply', u64()], ['presaleTerms', getPresaleTermsSerializer()], ['totalClaimed', u64()]], { description: 'PresaleVaultAccountData' }), (value) => ({ ...value, discriminator: [102, 155, 208, 196, 54, 144, 19, 203] }) ) as Serializer<PresaleVaultAccountDataArgs, PresaleVaultAccountData>;
 }
 
 
@@ -73,13 +74,13 @@ export async function safeFetchAllPresaleVault(
 export function getPresaleVaultGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
   const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
   return gpaBuilder(context, programId)
-    .registerFields<{ 'discriminator': Array<number>, 'initialVestedSupply': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'initialVestedSupply': [8, u64()] })
+    .registerFields<{ 'discriminator': Array<number>, 'initialVestedSupply': number | bigint, 'presaleTerms': PresaleTermsArgs, 'totalClaimed': number | bigint }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'initialVestedSupply': [8, u64()], 'presaleTerms': [16, getPresaleTermsSerializer()], 'totalClaimed': [48, u64()] })
     .deserializeUsing<PresaleVault>((account) => deserializePresaleVault(account))      .whereField('discriminator', [102, 155, 208, 196, 54, 144, 19, 203])
     ;
 }
 
 export function getPresaleVaultSize(): number {
-  return 16;
+  return 56;
 }
 
 export function findPresaleVaultPda(
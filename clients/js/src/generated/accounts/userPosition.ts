@@ -0,0 +1,116 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Account, Context, Pda, PublicKey, RpcAccount, RpcGetAccountOptions, RpcGetAccountsOptions, assertAccountExists, deserializeAccount, gpaBuilder, publicKey as toPublicKey } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, publicKey as publicKeySerializer, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+
+  
+  export type UserPosition = Account<UserPositionAccountData>;
+
+  export type UserPositionAccountData = { discriminator: Array<number>; user: PublicKey; mint: PublicKey; protectedBuyLamports: bigint; allowlistBuyLamports: bigint; bump: number;  };
+
+export type UserPositionAccountDataArgs = { user: PublicKey; mint: PublicKey; protectedBuyLamports: number | bigint; allowlistBuyLamports: number | bigint; bump: number;  };
+
+
+  export function getUserPositionAccountDataSerializer(): Serializer<UserPositionAccountDataArgs, UserPositionAccountData> {
+  return mapSerializer<UserPositionAccountDataArgs, any, UserPositionAccountData>(struct<UserPositionAccountData>([['discriminator', array(u8(), { size: 8 })], ['user', publicKeySerializer()], ['mint', publicKeySerializer()], ['protectedBuyLamports', u64()], ['allowlistBuyLamports', u64()], ['bump', u8()]], { description: 'UserPositionAccountData' }), (value) => ({ ...value, discriminator: [251, 248, 209, 245, 83, 234, 17, 27] }) ) as Serializer<UserPositionAccountDataArgs, UserPositionAccountData>;
+}
+
+
+export function deserializeUserPosition(rawAccount: RpcAccount): UserPosition {
+  return deserializeAccount(rawAccount, getUserPositionAccountDataSerializer());
+}
+
+export async function fetchUserPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions,
+): Promise<UserPosition> {
+  const maybeAccount = await context.rpc.getAccount(toPublicKey(publicKey, false), options);
+  assertAccountExists(maybeAccount, 'UserPosition');
+  return deserializeUserPosition(maybeAccount);
+}
+
+export async function safeFetchUserPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions,
+): Promise<UserPosition | null> {
+  const maybeAccount = await context.rpc.getAccount(toPublicKey(publicKey, false), options);
+  return maybeAccount.exists
+    ? deserializeUserPosition(maybeAccount)
+    : null;
+}
+
+export async function fetchAllUserPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions,
+): Promise<UserPosition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(publicKeys.map(key => toPublicKey(key, false)), options);
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'UserPosition');
+    return deserializeUserPosition(maybeAccount);
+  });
+}
+
+export async function safeFetchAllUserPosition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions,
+): Promise<UserPosition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(publicKeys.map(key => toPublicKey(key, false)), options);
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeUserPosition(maybeAccount as RpcAccount));
+}
+
+export function getUserPositionGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+  return gpaBuilder(context, programId)
+    .registerFields<{ 'discriminator': Array<number>, 'user': PublicKey, 'mint': PublicKey, 'protectedBuyLamports': number | bigint, 'allowlistBuyLamports': number | bigint, 'bump': number }>({ 'discriminator': [0, array(u8(), { size: 8 })], 'user': [8, publicKeySerializer()], 'mint': [40, publicKeySerializer()], 'protectedBuyLamports': [72, u64()], 'allowlistBuyLamports': [80, u64()], 'bump': [88, u8()] })
+    .deserializeUsing<UserPosition>((account) => deserializeUserPosition(account))      .whereField('discriminator', [251, 248, 209, 245, 83, 234, 17, 27])
+    ;
+}
+
+export function getUserPositionSize(): number {
+  return 89;
+}
+
+export function findUserPositionPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+      seeds: {
+                                      /** The mint of the bonding curve tkn */
+          mint: PublicKey;
+                            /** The wallet trading on the bonding curve */
+          user: PublicKey;
+                  }
+  ): Pda {
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+  return context.eddsa.findPda(programId, [
+                  string({ size: 'variable' }).serialize("user-position"),
+                        publicKeySerializer().serialize(seeds.mint),
+                        publicKeySerializer().serialize(seeds.user),
+            ]);
+}
+
+export async function fetchUserPositionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+      seeds: Parameters<typeof findUserPositionPda>[1],
+    options?: RpcGetAccountOptions,
+): Promise<UserPosition> {
+  return fetchUserPosition(context, findUserPositionPda(context, seeds), options);
+}
+
+export async function safeFetchUserPositionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+      seeds: Parameters<typeof findUserPositionPda>[1],
+    options?: RpcGetAccountOptions,
+): Promise<UserPosition | null> {
+  return safeFetchUserPosition(context, findUserPositionPda(context, seeds), options);
+}
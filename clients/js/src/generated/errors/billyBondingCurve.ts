@@ -337,6 +337,760 @@ export class NoFeesToWithdrawError extends ProgramError {
 codeToErrorMap.set(0x1788, NoFeesToWithdrawError);
 nameToErrorMap.set('NoFeesToWithdraw', NoFeesToWithdrawError);
 
+/** BondingCurveGraduated: Bonding Curve Already Graduated */
+export class BondingCurveGraduatedError extends ProgramError {
+  override readonly name: string = 'BondingCurveGraduated';
+
+  readonly code: number = 0x1789; // 6025
+  
+  constructor(program: Program, cause?: Error) {
+    super('Bonding Curve Already Graduated', program, cause);
+  }
+}
+codeToErrorMap.set(0x1789, BondingCurveGraduatedError);
+nameToErrorMap.set('BondingCurveGraduated', BondingCurveGraduatedError);
+
+/** AmmProgramNotSet: AMM program not set on Global */
+export class AmmProgramNotSetError extends ProgramError {
+  override readonly name: string = 'AmmProgramNotSet';
+
+  readonly code: number = 0x178a; // 6026
+  
+  constructor(program: Program, cause?: Error) {
+    super('AMM program not set on Global', program, cause);
+  }
+}
+codeToErrorMap.set(0x178a, AmmProgramNotSetError);
+nameToErrorMap.set('AmmProgramNotSet', AmmProgramNotSetError);
+
+/** InvalidAmmProgram: Invalid AMM program */
+export class InvalidAmmProgramError extends ProgramError {
+  override readonly name: string = 'InvalidAmmProgram';
+
+  readonly code: number = 0x178b; // 6027
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid AMM program', program, cause);
+  }
+}
+codeToErrorMap.set(0x178b, InvalidAmmProgramError);
+nameToErrorMap.set('InvalidAmmProgram', InvalidAmmProgramError);
+
+/** GraduationFailed: Graduation Failed */
+export class GraduationFailedError extends ProgramError {
+  override readonly name: string = 'GraduationFailed';
+
+  readonly code: number = 0x178c; // 6028
+  
+  constructor(program: Program, cause?: Error) {
+    super('Graduation Failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x178c, GraduationFailedError);
+nameToErrorMap.set('GraduationFailed', GraduationFailedError);
+
+/** InvalidLpTokenAccount: Invalid LP token account */
+export class InvalidLpTokenAccountError extends ProgramError {
+  override readonly name: string = 'InvalidLpTokenAccount';
+
+  readonly code: number = 0x178d; // 6029
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid LP token account', program, cause);
+  }
+}
+codeToErrorMap.set(0x178d, InvalidLpTokenAccountError);
+nameToErrorMap.set('InvalidLpTokenAccount', InvalidLpTokenAccountError);
+
+/** InvalidPresaleTerms: Invalid presale terms */
+export class InvalidPresaleTermsError extends ProgramError {
+  override readonly name: string = 'InvalidPresaleTerms';
+
+  readonly code: number = 0x178e; // 6030
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid presale terms', program, cause);
+  }
+}
+codeToErrorMap.set(0x178e, InvalidPresaleTermsError);
+nameToErrorMap.set('InvalidPresaleTerms', InvalidPresaleTermsError);
+
+/** PresaleNotOpen: Presale is not open */
+export class PresaleNotOpenError extends ProgramError {
+  override readonly name: string = 'PresaleNotOpen';
+
+  readonly code: number = 0x178f; // 6031
+  
+  constructor(program: Program, cause?: Error) {
+    super('Presale is not open', program, cause);
+  }
+}
+codeToErrorMap.set(0x178f, PresaleNotOpenError);
+nameToErrorMap.set('PresaleNotOpen', PresaleNotOpenError);
+
+/** PresaleWalletCapExceeded: Presale contribution exceeds the per wallet cap */
+export class PresaleWalletCapExceededError extends ProgramError {
+  override readonly name: string = 'PresaleWalletCapExceeded';
+
+  readonly code: number = 0x1790; // 6032
+  
+  constructor(program: Program, cause?: Error) {
+    super('Presale contribution exceeds the per wallet cap', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, PresaleWalletCapExceededError);
+nameToErrorMap.set('PresaleWalletCapExceeded', PresaleWalletCapExceededError);
+
+/** PresaleAlreadyClaimed: Presale tokens already claimed */
+export class PresaleAlreadyClaimedError extends ProgramError {
+  override readonly name: string = 'PresaleAlreadyClaimed';
+
+  readonly code: number = 0x1791; // 6033
+  
+  constructor(program: Program, cause?: Error) {
+    super('Presale tokens already claimed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, PresaleAlreadyClaimedError);
+nameToErrorMap.set('PresaleAlreadyClaimed', PresaleAlreadyClaimedError);
+
+/** NoPresaleTokens: No presale tokens to claim */
+export class NoPresaleTokensError extends ProgramError {
+  override readonly name: string = 'NoPresaleTokens';
+
+  readonly code: number = 0x1792; // 6034
+  
+  constructor(program: Program, cause?: Error) {
+    super('No presale tokens to claim', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, NoPresaleTokensError);
+nameToErrorMap.set('NoPresaleTokens', NoPresaleTokensError);
+
+/** InvalidBrandAuthority: Invalid Brand Authority */
+export class InvalidBrandAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidBrandAuthority';
+
+  readonly code: number = 0x1793; // 6035
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Brand Authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x1793, InvalidBrandAuthorityError);
+nameToErrorMap.set('InvalidBrandAuthority', InvalidBrandAuthorityError);
+
+/** NoBrandkitTokens: No brandkit tokens to claim */
+export class NoBrandkitTokensError extends ProgramError {
+  override readonly name: string = 'NoBrandkitTokens';
+
+  readonly code: number = 0x1794; // 6036
+  
+  constructor(program: Program, cause?: Error) {
+    super('No brandkit tokens to claim', program, cause);
+  }
+}
+codeToErrorMap.set(0x1794, NoBrandkitTokensError);
+nameToErrorMap.set('NoBrandkitTokens', NoBrandkitTokensError);
+
+/** NoPlatformTokens: No platform tokens to claim */
+export class NoPlatformTokensError extends ProgramError {
+  override readonly name: string = 'NoPlatformTokens';
+
+  readonly code: number = 0x1795; // 6037
+  
+  constructor(program: Program, cause?: Error) {
+    super('No platform tokens to claim', program, cause);
+  }
+}
+codeToErrorMap.set(0x1795, NoPlatformTokensError);
+nameToErrorMap.set('NoPlatformTokens', NoPlatformTokensError);
+
+/** InvalidPlatformAuthority: Invalid Platform Authority */
+export class InvalidPlatformAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidPlatformAuthority';
+
+  readonly code: number = 0x1796; // 6038
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Platform Authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x1796, InvalidPlatformAuthorityError);
+nameToErrorMap.set('InvalidPlatformAuthority', InvalidPlatformAuthorityError);
+
+/** CexReleaseExceedsVested: Release exceeds vested CEX tokens */
+export class CexReleaseExceedsVestedError extends ProgramError {
+  override readonly name: string = 'CexReleaseExceedsVested';
+
+  readonly code: number = 0x1797; // 6039
+  
+  constructor(program: Program, cause?: Error) {
+    super('Release exceeds vested CEX tokens', program, cause);
+  }
+}
+codeToErrorMap.set(0x1797, CexReleaseExceedsVestedError);
+nameToErrorMap.set('CexReleaseExceedsVested', CexReleaseExceedsVestedError);
+
+/** InvalidSwapMode: Exactly one of exactInAmount and exactOutAmount must be set */
+export class InvalidSwapModeError extends ProgramError {
+  override readonly name: string = 'InvalidSwapMode';
+
+  readonly code: number = 0x1798; // 6040
+  
+  constructor(program: Program, cause?: Error) {
+    super('Exactly one of exactInAmount and exactOutAmount must be set', program, cause);
+  }
+}
+codeToErrorMap.set(0x1798, InvalidSwapModeError);
+nameToErrorMap.set('InvalidSwapMode', InvalidSwapModeError);
+
+/** CreateDisabled: Creating bonding curves is disabled */
+export class CreateDisabledError extends ProgramError {
+  override readonly name: string = 'CreateDisabled';
+
+  readonly code: number = 0x1799; // 6041
+  
+  constructor(program: Program, cause?: Error) {
+    super('Creating bonding curves is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x1799, CreateDisabledError);
+nameToErrorMap.set('CreateDisabled', CreateDisabledError);
+
+/** BuyDisabled: Buying is disabled */
+export class BuyDisabledError extends ProgramError {
+  override readonly name: string = 'BuyDisabled';
+
+  readonly code: number = 0x179a; // 6042
+  
+  constructor(program: Program, cause?: Error) {
+    super('Buying is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x179a, BuyDisabledError);
+nameToErrorMap.set('BuyDisabled', BuyDisabledError);
+
+/** SellDisabled: Selling is disabled */
+export class SellDisabledError extends ProgramError {
+  override readonly name: string = 'SellDisabled';
+
+  readonly code: number = 0x179b; // 6043
+  
+  constructor(program: Program, cause?: Error) {
+    super('Selling is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x179b, SellDisabledError);
+nameToErrorMap.set('SellDisabled', SellDisabledError);
+
+/** LaunchDisabled: Launching is disabled */
+export class LaunchDisabledError extends ProgramError {
+  override readonly name: string = 'LaunchDisabled';
+
+  readonly code: number = 0x179c; // 6044
+  
+  constructor(program: Program, cause?: Error) {
+    super('Launching is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x179c, LaunchDisabledError);
+nameToErrorMap.set('LaunchDisabled', LaunchDisabledError);
+
+/** ClaimDisabled: Claiming is disabled */
+export class ClaimDisabledError extends ProgramError {
+  override readonly name: string = 'ClaimDisabled';
+
+  readonly code: number = 0x179d; // 6045
+  
+  constructor(program: Program, cause?: Error) {
+    super('Claiming is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x179d, ClaimDisabledError);
+nameToErrorMap.set('ClaimDisabled', ClaimDisabledError);
+
+/** FeeWithdrawalDisabled: Fee withdrawal is disabled */
+export class FeeWithdrawalDisabledError extends ProgramError {
+  override readonly name: string = 'FeeWithdrawalDisabled';
+
+  readonly code: number = 0x179e; // 6046
+  
+  constructor(program: Program, cause?: Error) {
+    super('Fee withdrawal is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x179e, FeeWithdrawalDisabledError);
+nameToErrorMap.set('FeeWithdrawalDisabled', FeeWithdrawalDisabledError);
+
+/** InvalidPendingAuthority: Signer is not the pending authority */
+export class InvalidPendingAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidPendingAuthority';
+
+  readonly code: number = 0x179f; // 6047
+  
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the pending authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x179f, InvalidPendingAuthorityError);
+nameToErrorMap.set('InvalidPendingAuthority', InvalidPendingAuthorityError);
+
+/** NoPendingAuthority: No pending authority transfer */
+export class NoPendingAuthorityError extends ProgramError {
+  override readonly name: string = 'NoPendingAuthority';
+
+  readonly code: number = 0x17a0; // 6048
+  
+  constructor(program: Program, cause?: Error) {
+    super('No pending authority transfer', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a0, NoPendingAuthorityError);
+nameToErrorMap.set('NoPendingAuthority', NoPendingAuthorityError);
+
+/** TradeFeeTooHigh: Trade fee exceeds the maximum trade fee */
+export class TradeFeeTooHighError extends ProgramError {
+  override readonly name: string = 'TradeFeeTooHigh';
+
+  readonly code: number = 0x17a1; // 6049
+  
+  constructor(program: Program, cause?: Error) {
+    super('Trade fee exceeds the maximum trade fee', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a1, TradeFeeTooHighError);
+nameToErrorMap.set('TradeFeeTooHigh', TradeFeeTooHighError);
+
+/** LaunchFeeTooHigh: Launch fee exceeds the maximum launch fee */
+export class LaunchFeeTooHighError extends ProgramError {
+  override readonly name: string = 'LaunchFeeTooHigh';
+
+  readonly code: number = 0x17a2; // 6050
+  
+  constructor(program: Program, cause?: Error) {
+    super('Launch fee exceeds the maximum launch fee', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a2, LaunchFeeTooHighError);
+nameToErrorMap.set('LaunchFeeTooHigh', LaunchFeeTooHighError);
+
+/** InvalidMintDecimals: Mint decimals must be between 1 and the maximum mint decimals */
+export class InvalidMintDecimalsError extends ProgramError {
+  override readonly name: string = 'InvalidMintDecimals';
+
+  readonly code: number = 0x17a3; // 6051
+  
+  constructor(program: Program, cause?: Error) {
+    super('Mint decimals must be between 1 and the maximum mint decimals', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a3, InvalidMintDecimalsError);
+nameToErrorMap.set('InvalidMintDecimals', InvalidMintDecimalsError);
+
+/** SettingsChangeRequiresTimelock: Fee and status changes must be scheduled */
+export class SettingsChangeRequiresTimelockError extends ProgramError {
+  override readonly name: string = 'SettingsChangeRequiresTimelock';
+
+  readonly code: number = 0x17a4; // 6052
+  
+  constructor(program: Program, cause?: Error) {
+    super('Fee and status changes must be scheduled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a4, SettingsChangeRequiresTimelockError);
+nameToErrorMap.set('SettingsChangeRequiresTimelock', SettingsChangeRequiresTimelockError);
+
+/** InvalidSettingsEffectiveTime: Effective time is before the settings timelock */
+export class InvalidSettingsEffectiveTimeError extends ProgramError {
+  override readonly name: string = 'InvalidSettingsEffectiveTime';
+
+  readonly code: number = 0x17a5; // 6053
+  
+  constructor(program: Program, cause?: Error) {
+    super('Effective time is before the settings timelock', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a5, InvalidSettingsEffectiveTimeError);
+nameToErrorMap.set('InvalidSettingsEffectiveTime', InvalidSettingsEffectiveTimeError);
+
+/** NoPendingSettings: No pending settings change */
+export class NoPendingSettingsError extends ProgramError {
+  override readonly name: string = 'NoPendingSettings';
+
+  readonly code: number = 0x17a6; // 6054
+  
+  constructor(program: Program, cause?: Error) {
+    super('No pending settings change', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a6, NoPendingSettingsError);
+nameToErrorMap.set('NoPendingSettings', NoPendingSettingsError);
+
+/** SettingsNotYetEffective: Pending settings change is not yet effective */
+export class SettingsNotYetEffectiveError extends ProgramError {
+  override readonly name: string = 'SettingsNotYetEffective';
+
+  readonly code: number = 0x17a7; // 6055
+  
+  constructor(program: Program, cause?: Error) {
+    super('Pending settings change is not yet effective', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a7, SettingsNotYetEffectiveError);
+nameToErrorMap.set('SettingsNotYetEffective', SettingsNotYetEffectiveError);
+
+/** InvalidMintExtensions: Mint carries unsupported extensions */
+export class InvalidMintExtensionsError extends ProgramError {
+  override readonly name: string = 'InvalidMintExtensions';
+
+  readonly code: number = 0x17a8; // 6056
+  
+  constructor(program: Program, cause?: Error) {
+    super('Mint carries unsupported extensions', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a8, InvalidMintExtensionsError);
+nameToErrorMap.set('InvalidMintExtensions', InvalidMintExtensionsError);
+
+/** MissingMetadataAccounts: Metaplex metadata accounts are required for SPL Token mints */
+export class MissingMetadataAccountsError extends ProgramError {
+  override readonly name: string = 'MissingMetadataAccounts';
+
+  readonly code: number = 0x17a9; // 6057
+  
+  constructor(program: Program, cause?: Error) {
+    super('Metaplex metadata accounts are required for SPL Token mints', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a9, MissingMetadataAccountsError);
+nameToErrorMap.set('MissingMetadataAccounts', MissingMetadataAccountsError);
+
+/** NoVestedTokens: No vested tokens to claim */
+export class NoVestedTokensError extends ProgramError {
+  override readonly name: string = 'NoVestedTokens';
+
+  readonly code: number = 0x17aa; // 6058
+  
+  constructor(program: Program, cause?: Error) {
+    super('No vested tokens to claim', program, cause);
+  }
+}
+codeToErrorMap.set(0x17aa, NoVestedTokensError);
+nameToErrorMap.set('NoVestedTokens', NoVestedTokensError);
+
+/** InvalidVestingTerms: Invalid vesting anchor */
+export class InvalidVestingTermsError extends ProgramError {
+  override readonly name: string = 'InvalidVestingTerms';
+
+  readonly code: number = 0x17ab; // 6059
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid vesting anchor', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ab, InvalidVestingTermsError);
+nameToErrorMap.set('InvalidVestingTerms', InvalidVestingTermsError);
+
+/** InvalidGraduationDeadline: Graduation deadline must be after the curve start */
+export class InvalidGraduationDeadlineError extends ProgramError {
+  override readonly name: string = 'InvalidGraduationDeadline';
+
+  readonly code: number = 0x17ac; // 6060
+  
+  constructor(program: Program, cause?: Error) {
+    super('Graduation deadline must be after the curve start', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ac, InvalidGraduationDeadlineError);
+nameToErrorMap.set('InvalidGraduationDeadline', InvalidGraduationDeadlineError);
+
+/** GraduationDeadlinePassed: Graduation deadline has passed */
+export class GraduationDeadlinePassedError extends ProgramError {
+  override readonly name: string = 'GraduationDeadlinePassed';
+
+  readonly code: number = 0x17ad; // 6061
+  
+  constructor(program: Program, cause?: Error) {
+    super('Graduation deadline has passed', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ad, GraduationDeadlinePassedError);
+nameToErrorMap.set('GraduationDeadlinePassed', GraduationDeadlinePassedError);
+
+/** GraduationDeadlineNotReached: Graduation deadline not yet reached */
+export class GraduationDeadlineNotReachedError extends ProgramError {
+  override readonly name: string = 'GraduationDeadlineNotReached';
+
+  readonly code: number = 0x17ae; // 6062
+  
+  constructor(program: Program, cause?: Error) {
+    super('Graduation deadline not yet reached', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ae, GraduationDeadlineNotReachedError);
+nameToErrorMap.set('GraduationDeadlineNotReached', GraduationDeadlineNotReachedError);
+
+/** BondingCurveFailed: Bonding Curve Failed */
+export class BondingCurveFailedError extends ProgramError {
+  override readonly name: string = 'BondingCurveFailed';
+
+  readonly code: number = 0x17af; // 6063
+  
+  constructor(program: Program, cause?: Error) {
+    super('Bonding Curve Failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x17af, BondingCurveFailedError);
+nameToErrorMap.set('BondingCurveFailed', BondingCurveFailedError);
+
+/** BondingCurveNotFailed: Bonding Curve Not Failed */
+export class BondingCurveNotFailedError extends ProgramError {
+  override readonly name: string = 'BondingCurveNotFailed';
+
+  readonly code: number = 0x17b0; // 6064
+  
+  constructor(program: Program, cause?: Error) {
+    super('Bonding Curve Not Failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b0, BondingCurveNotFailedError);
+nameToErrorMap.set('BondingCurveNotFailed', BondingCurveNotFailedError);
+
+/** InvalidLaunchLeadTime: Launch lead time exceeds the maximum launch lead time */
+export class InvalidLaunchLeadTimeError extends ProgramError {
+  override readonly name: string = 'InvalidLaunchLeadTime';
+
+  readonly code: number = 0x17b1; // 6065
+  
+  constructor(program: Program, cause?: Error) {
+    super('Launch lead time exceeds the maximum launch lead time', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b1, InvalidLaunchLeadTimeError);
+nameToErrorMap.set('InvalidLaunchLeadTime', InvalidLaunchLeadTimeError);
+
+/** LaunchAlreadyStarted: Launch has already started */
+export class LaunchAlreadyStartedError extends ProgramError {
+  override readonly name: string = 'LaunchAlreadyStarted';
+
+  readonly code: number = 0x17b2; // 6066
+  
+  constructor(program: Program, cause?: Error) {
+    super('Launch has already started', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b2, LaunchAlreadyStartedError);
+nameToErrorMap.set('LaunchAlreadyStarted', LaunchAlreadyStartedError);
+
+/** LaunchHasContributions: Launch has presale contributions */
+export class LaunchHasContributionsError extends ProgramError {
+  override readonly name: string = 'LaunchHasContributions';
+
+  readonly code: number = 0x17b3; // 6067
+  
+  constructor(program: Program, cause?: Error) {
+    super('Launch has presale contributions', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b3, LaunchHasContributionsError);
+nameToErrorMap.set('LaunchHasContributions', LaunchHasContributionsError);
+
+/** RescheduleDisabled: Rescheduling launches is disabled */
+export class RescheduleDisabledError extends ProgramError {
+  override readonly name: string = 'RescheduleDisabled';
+
+  readonly code: number = 0x17b4; // 6068
+  
+  constructor(program: Program, cause?: Error) {
+    super('Rescheduling launches is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b4, RescheduleDisabledError);
+nameToErrorMap.set('RescheduleDisabled', RescheduleDisabledError);
+
+/** InvalidLaunchProtection: Invalid launch protection settings */
+export class InvalidLaunchProtectionError extends ProgramError {
+  override readonly name: string = 'InvalidLaunchProtection';
+
+  readonly code: number = 0x17b5; // 6069
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid launch protection settings', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b5, InvalidLaunchProtectionError);
+nameToErrorMap.set('InvalidLaunchProtection', InvalidLaunchProtectionError);
+
+/** LaunchProtectionLimitExceeded: Buy exceeds the launch protection limits */
+export class LaunchProtectionLimitExceededError extends ProgramError {
+  override readonly name: string = 'LaunchProtectionLimitExceeded';
+
+  readonly code: number = 0x17b6; // 6070
+  
+  constructor(program: Program, cause?: Error) {
+    super('Buy exceeds the launch protection limits', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b6, LaunchProtectionLimitExceededError);
+nameToErrorMap.set('LaunchProtectionLimitExceeded', LaunchProtectionLimitExceededError);
+
+/** InvalidFeeSchedule: Invalid fee schedule */
+export class InvalidFeeScheduleError extends ProgramError {
+  override readonly name: string = 'InvalidFeeSchedule';
+
+  readonly code: number = 0x17b7; // 6071
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid fee schedule', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b7, InvalidFeeScheduleError);
+nameToErrorMap.set('InvalidFeeSchedule', InvalidFeeScheduleError);
+
+/** InvalidAllowlist: Invalid allowlist */
+export class InvalidAllowlistError extends ProgramError {
+  override readonly name: string = 'InvalidAllowlist';
+
+  readonly code: number = 0x17b8; // 6072
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid allowlist', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b8, InvalidAllowlistError);
+nameToErrorMap.set('InvalidAllowlist', InvalidAllowlistError);
+
+/** NotAllowlisted: Wallet is not allowlisted */
+export class NotAllowlistedError extends ProgramError {
+  override readonly name: string = 'NotAllowlisted';
+
+  readonly code: number = 0x17b9; // 6073
+  
+  constructor(program: Program, cause?: Error) {
+    super('Wallet is not allowlisted', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b9, NotAllowlistedError);
+nameToErrorMap.set('NotAllowlisted', NotAllowlistedError);
+
+/** AllowlistAllowanceExceeded: Buy exceeds the allowlist allowance */
+export class AllowlistAllowanceExceededError extends ProgramError {
+  override readonly name: string = 'AllowlistAllowanceExceeded';
+
+  readonly code: number = 0x17ba; // 6074
+  
+  constructor(program: Program, cause?: Error) {
+    super('Buy exceeds the allowlist allowance', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ba, AllowlistAllowanceExceededError);
+nameToErrorMap.set('AllowlistAllowanceExceeded', AllowlistAllowanceExceededError);
+
+/** InvalidCurveKind: Invalid curve kind */
+export class InvalidCurveKindError extends ProgramError {
+  override readonly name: string = 'InvalidCurveKind';
+
+  readonly code: number = 0x17bb; // 6075
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid curve kind', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bb, InvalidCurveKindError);
+nameToErrorMap.set('InvalidCurveKind', InvalidCurveKindError);
+
+/** NoSurplusLamports: No surplus lamports to skim */
+export class NoSurplusLamportsError extends ProgramError {
+  override readonly name: string = 'NoSurplusLamports';
+
+  readonly code: number = 0x17bc; // 6076
+  
+  constructor(program: Program, cause?: Error) {
+    super('No surplus lamports to skim', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bc, NoSurplusLamportsError);
+nameToErrorMap.set('NoSurplusLamports', NoSurplusLamportsError);
+
+/** InvalidLaunchFeeRefund: Launch fee refund must not exceed 100% */
+export class InvalidLaunchFeeRefundError extends ProgramError {
+  override readonly name: string = 'InvalidLaunchFeeRefund';
+
+  readonly code: number = 0x17bd; // 6077
+  
+  constructor(program: Program, cause?: Error) {
+    super('Launch fee refund must not exceed 100%', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bd, InvalidLaunchFeeRefundError);
+nameToErrorMap.set('InvalidLaunchFeeRefund', InvalidLaunchFeeRefundError);
+
+/** LaunchAlreadyTraded: Launch has already been traded */
+export class LaunchAlreadyTradedError extends ProgramError {
+  override readonly name: string = 'LaunchAlreadyTraded';
+
+  readonly code: number = 0x17be; // 6078
+  
+  constructor(program: Program, cause?: Error) {
+    super('Launch has already been traded', program, cause);
+  }
+}
+codeToErrorMap.set(0x17be, LaunchAlreadyTradedError);
+nameToErrorMap.set('LaunchAlreadyTraded', LaunchAlreadyTradedError);
+
+/** InvalidAmmPool: Pool or LP mint does not belong to the AMM */
+export class InvalidAmmPoolError extends ProgramError {
+  override readonly name: string = 'InvalidAmmPool';
+
+  readonly code: number = 0x17bf; // 6079
+  
+  constructor(program: Program, cause?: Error) {
+    super('Pool or LP mint does not belong to the AMM', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bf, InvalidAmmPoolError);
+nameToErrorMap.set('InvalidAmmPool', InvalidAmmPoolError);
+
+/** PresaleCapExceeded: Contribution exceeds the presale SOL cap */
+export class PresaleCapExceededError extends ProgramError {
+  override readonly name: string = 'PresaleCapExceeded';
+
+  readonly code: number = 0x17c0; // 6080
+  
+  constructor(program: Program, cause?: Error) {
+    super('Contribution exceeds the presale SOL cap', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c0, PresaleCapExceededError);
+nameToErrorMap.set('PresaleCapExceeded', PresaleCapExceededError);
+
+/** InvalidSettingsCaps: Invalid settings caps */
+export class InvalidSettingsCapsError extends ProgramError {
+  override readonly name: string = 'InvalidSettingsCaps';
+
+  readonly code: number = 0x17c1; // 6081
+  
+  constructor(program: Program, cause?: Error) {
+    super('Invalid settings caps', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c1, InvalidSettingsCapsError);
+nameToErrorMap.set('InvalidSettingsCaps', InvalidSettingsCapsError);
+
+/** MissingUserPosition: User position is required for buys during launch protection or the allowlist phase */
+export class MissingUserPositionError extends ProgramError {
+  override readonly name: string = 'MissingUserPosition';
+
+  readonly code: number = 0x17c2; // 6082
+  
+  constructor(program: Program, cause?: Error) {
+    super('User position is required for buys during launch protection or the allowlist phase', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c2, MissingUserPositionError);
+nameToErrorMap.set('MissingUserPosition', MissingUserPositionError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
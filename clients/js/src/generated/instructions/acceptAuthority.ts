@@ -0,0 +1,72 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+import { AuthorityRole, AuthorityRoleArgs, getAuthorityRoleSerializer } from '../types';
+
+// Accounts.
+export type AcceptAuthorityInstructionAccounts = {
+    newAuthority: Signer;
+    global: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type AcceptAuthorityInstructionData = { discriminator: Array<number>; role: AuthorityRole;  };
+
+export type AcceptAuthorityInstructionDataArgs = { role: AuthorityRoleArgs;  };
+
+
+  export function getAcceptAuthorityInstructionDataSerializer(): Serializer<AcceptAuthorityInstructionDataArgs, AcceptAuthorityInstructionData> {
+  return mapSerializer<AcceptAuthorityInstructionDataArgs, any, AcceptAuthorityInstructionData>(struct<AcceptAuthorityInstructionData>([['discriminator', array(u8(), { size: 8 })], ['role', getAuthorityRoleSerializer()]], { description: 'AcceptAuthorityInstructionData' }), (value) => ({ ...value, discriminator: [107, 86, 198, 91, 33, 12, 107, 160] }) ) as Serializer<AcceptAuthorityInstructionDataArgs, AcceptAuthorityInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type AcceptAuthorityInstructionArgs =           AcceptAuthorityInstructionDataArgs
+      ;
+  
+// Instruction.
+export function acceptAuthority(
+  context: Pick<Context, "programs">,
+                        input: AcceptAuthorityInstructionAccounts & AcceptAuthorityInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          newAuthority: { index: 0, isWritable: false as boolean, value: input.newAuthority ?? null },
+          global: { index: 1, isWritable: true as boolean, value: input.global ?? null },
+          eventAuthority: { index: 2, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 3, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: AcceptAuthorityInstructionArgs = { ...input };
+  
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getAcceptAuthorityInstructionDataSerializer().serialize(resolvedArgs as AcceptAuthorityInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
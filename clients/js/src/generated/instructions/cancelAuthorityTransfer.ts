@@ -0,0 +1,77 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+import { AuthorityRole, AuthorityRoleArgs, getAuthorityRoleSerializer } from '../types';
+
+// Accounts.
+export type CancelAuthorityTransferInstructionAccounts = {
+    authority?: Signer;
+    global: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type CancelAuthorityTransferInstructionData = { discriminator: Array<number>; role: AuthorityRole;  };
+
+export type CancelAuthorityTransferInstructionDataArgs = { role: AuthorityRoleArgs;  };
+
+
+  export function getCancelAuthorityTransferInstructionDataSerializer(): Serializer<CancelAuthorityTransferInstructionDataArgs, CancelAuthorityTransferInstructionData> {
+  return mapSerializer<CancelAuthorityTransferInstructionDataArgs, any, CancelAuthorityTransferInstructionData>(struct<CancelAuthorityTransferInstructionData>([['discriminator', array(u8(), { size: 8 })], ['role', getAuthorityRoleSerializer()]], { description: 'CancelAuthorityTransferInstructionData' }), (value) => ({ ...value, discriminator: [94, 131, 125, 184, 183, 24, 125, 229] }) ) as Serializer<CancelAuthorityTransferInstructionDataArgs, CancelAuthorityTransferInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type CancelAuthorityTransferInstructionArgs =           CancelAuthorityTransferInstructionDataArgs
+      ;
+  
+// Instruction.
+export function cancelAuthorityTransfer(
+  context: Pick<Context, "identity" | "programs">,
+                        input: CancelAuthorityTransferInstructionAccounts & CancelAuthorityTransferInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          authority: { index: 0, isWritable: false as boolean, value: input.authority ?? null },
+          global: { index: 1, isWritable: true as boolean, value: input.global ?? null },
+          eventAuthority: { index: 2, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 3, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: CancelAuthorityTransferInstructionArgs = { ...input };
+  
+    // Default values.
+  if (!resolvedAccounts.authority.value) {
+        resolvedAccounts.authority.value = context.identity;
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getCancelAuthorityTransferInstructionDataSerializer().serialize(resolvedArgs as CancelAuthorityTransferInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
@@ -0,0 +1,101 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type CancelLaunchInstructionAccounts = {
+    creator: Signer;
+    global: PublicKey | Pda;
+    withdrawAuthority: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    bondingCurveTokenAccount: PublicKey | Pda;
+    creatorVault: PublicKey | Pda;
+    creatorVaultTokenAccount: PublicKey | Pda;
+    presaleVault: PublicKey | Pda;
+    presaleVaultTokenAccount: PublicKey | Pda;
+    cexVault: PublicKey | Pda;
+    cexVaultTokenAccount: PublicKey | Pda;
+    brandVault: PublicKey | Pda;
+    brandVaultTokenAccount: PublicKey | Pda;
+    platformVault: PublicKey | Pda;
+    platformVaultTokenAccount: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type CancelLaunchInstructionData = { discriminator: Array<number>;  };
+
+export type CancelLaunchInstructionDataArgs = {  };
+
+
+  export function getCancelLaunchInstructionDataSerializer(): Serializer<CancelLaunchInstructionDataArgs, CancelLaunchInstructionData> {
+  return mapSerializer<CancelLaunchInstructionDataArgs, any, CancelLaunchInstructionData>(struct<CancelLaunchInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'CancelLaunchInstructionData' }), (value) => ({ ...value, discriminator: [120, 69, 17, 7, 41, 48, 40, 37] }) ) as Serializer<CancelLaunchInstructionDataArgs, CancelLaunchInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function cancelLaunch(
+  context: Pick<Context, "programs">,
+                        input: CancelLaunchInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          creator: { index: 0, isWritable: true as boolean, value: input.creator ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          withdrawAuthority: { index: 2, isWritable: true as boolean, value: input.withdrawAuthority ?? null },
+          mint: { index: 3, isWritable: true as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 4, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          bondingCurveTokenAccount: { index: 5, isWritable: true as boolean, value: input.bondingCurveTokenAccount ?? null },
+          creatorVault: { index: 6, isWritable: true as boolean, value: input.creatorVault ?? null },
+          creatorVaultTokenAccount: { index: 7, isWritable: true as boolean, value: input.creatorVaultTokenAccount ?? null },
+          presaleVault: { index: 8, isWritable: true as boolean, value: input.presaleVault ?? null },
+          presaleVaultTokenAccount: { index: 9, isWritable: true as boolean, value: input.presaleVaultTokenAccount ?? null },
+          cexVault: { index: 10, isWritable: true as boolean, value: input.cexVault ?? null },
+          cexVaultTokenAccount: { index: 11, isWritable: true as boolean, value: input.cexVaultTokenAccount ?? null },
+          brandVault: { index: 12, isWritable: true as boolean, value: input.brandVault ?? null },
+          brandVaultTokenAccount: { index: 13, isWritable: true as boolean, value: input.brandVaultTokenAccount ?? null },
+          platformVault: { index: 14, isWritable: true as boolean, value: input.platformVault ?? null },
+          platformVaultTokenAccount: { index: 15, isWritable: true as boolean, value: input.platformVaultTokenAccount ?? null },
+          tokenProgram: { index: 16, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          eventAuthority: { index: 17, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 18, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getCancelLaunchInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
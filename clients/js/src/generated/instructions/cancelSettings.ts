@@ -0,0 +1,70 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type CancelSettingsInstructionAccounts = {
+    authority?: Signer;
+    global: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type CancelSettingsInstructionData = { discriminator: Array<number>;  };
+
+export type CancelSettingsInstructionDataArgs = {  };
+
+
+  export function getCancelSettingsInstructionDataSerializer(): Serializer<CancelSettingsInstructionDataArgs, CancelSettingsInstructionData> {
+  return mapSerializer<CancelSettingsInstructionDataArgs, any, CancelSettingsInstructionData>(struct<CancelSettingsInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'CancelSettingsInstructionData' }), (value) => ({ ...value, discriminator: [0, 254, 32, 200, 106, 75, 172, 26] }) ) as Serializer<CancelSettingsInstructionDataArgs, CancelSettingsInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function cancelSettings(
+  context: Pick<Context, "identity" | "programs">,
+                        input: CancelSettingsInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          authority: { index: 0, isWritable: false as boolean, value: input.authority ?? null },
+          global: { index: 1, isWritable: true as boolean, value: input.global ?? null },
+          eventAuthority: { index: 2, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 3, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.authority.value) {
+        resolvedAccounts.authority.value = context.identity;
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getCancelSettingsInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
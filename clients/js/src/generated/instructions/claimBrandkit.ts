@@ -0,0 +1,91 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type ClaimBrandkitInstructionAccounts = {
+    brandAuthority: Signer;
+    brandVault: PublicKey | Pda;
+    brandVaultTokenAccount: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    userTokenAccount: PublicKey | Pda;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    systemProgram?: PublicKey | Pda;
+    associatedTokenProgram: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type ClaimBrandkitInstructionData = { discriminator: Array<number>;  };
+
+export type ClaimBrandkitInstructionDataArgs = {  };
+
+
+  export function getClaimBrandkitInstructionDataSerializer(): Serializer<ClaimBrandkitInstructionDataArgs, ClaimBrandkitInstructionData> {
+  return mapSerializer<ClaimBrandkitInstructionDataArgs, any, ClaimBrandkitInstructionData>(struct<ClaimBrandkitInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'ClaimBrandkitInstructionData' }), (value) => ({ ...value, discriminator: [149, 82, 199, 252, 6, 200, 9, 151] }) ) as Serializer<ClaimBrandkitInstructionDataArgs, ClaimBrandkitInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function claimBrandkit(
+  context: Pick<Context, "programs">,
+                        input: ClaimBrandkitInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          brandAuthority: { index: 0, isWritable: true as boolean, value: input.brandAuthority ?? null },
+          brandVault: { index: 1, isWritable: true as boolean, value: input.brandVault ?? null },
+          brandVaultTokenAccount: { index: 2, isWritable: true as boolean, value: input.brandVaultTokenAccount ?? null },
+          bondingCurve: { index: 3, isWritable: false as boolean, value: input.bondingCurve ?? null },
+          userTokenAccount: { index: 4, isWritable: true as boolean, value: input.userTokenAccount ?? null },
+          global: { index: 5, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 6, isWritable: false as boolean, value: input.mint ?? null },
+          systemProgram: { index: 7, isWritable: false as boolean, value: input.systemProgram ?? null },
+          associatedTokenProgram: { index: 8, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
+          tokenProgram: { index: 9, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          eventAuthority: { index: 10, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 11, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+        resolvedAccounts.systemProgram.value = context.programs.getPublicKey('splSystem', '11111111111111111111111111111111');
+resolvedAccounts.systemProgram.isWritable = false
+      }
+      if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getClaimBrandkitInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
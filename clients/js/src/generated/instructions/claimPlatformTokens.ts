@@ -0,0 +1,94 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type ClaimPlatformTokensInstructionAccounts = {
+    authority?: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    platformVault: PublicKey | Pda;
+    platformVaultTokenAccount: PublicKey | Pda;
+    userTokenAccount: PublicKey | Pda;
+    systemProgram?: PublicKey | Pda;
+    associatedTokenProgram: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type ClaimPlatformTokensInstructionData = { discriminator: Array<number>;  };
+
+export type ClaimPlatformTokensInstructionDataArgs = {  };
+
+
+  export function getClaimPlatformTokensInstructionDataSerializer(): Serializer<ClaimPlatformTokensInstructionDataArgs, ClaimPlatformTokensInstructionData> {
+  return mapSerializer<ClaimPlatformTokensInstructionDataArgs, any, ClaimPlatformTokensInstructionData>(struct<ClaimPlatformTokensInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'ClaimPlatformTokensInstructionData' }), (value) => ({ ...value, discriminator: [175, 85, 117, 145, 192, 171, 101, 104] }) ) as Serializer<ClaimPlatformTokensInstructionDataArgs, ClaimPlatformTokensInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function claimPlatformTokens(
+  context: Pick<Context, "identity" | "programs">,
+                        input: ClaimPlatformTokensInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          authority: { index: 0, isWritable: true as boolean, value: input.authority ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: false as boolean, value: input.bondingCurve ?? null },
+          platformVault: { index: 4, isWritable: true as boolean, value: input.platformVault ?? null },
+          platformVaultTokenAccount: { index: 5, isWritable: true as boolean, value: input.platformVaultTokenAccount ?? null },
+          userTokenAccount: { index: 6, isWritable: true as boolean, value: input.userTokenAccount ?? null },
+          systemProgram: { index: 7, isWritable: false as boolean, value: input.systemProgram ?? null },
+          associatedTokenProgram: { index: 8, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
+          tokenProgram: { index: 9, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          eventAuthority: { index: 10, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 11, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.authority.value) {
+        resolvedAccounts.authority.value = context.identity;
+      }
+      if (!resolvedAccounts.systemProgram.value) {
+        resolvedAccounts.systemProgram.value = context.programs.getPublicKey('splSystem', '11111111111111111111111111111111');
+resolvedAccounts.systemProgram.isWritable = false
+      }
+      if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getClaimPlatformTokensInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
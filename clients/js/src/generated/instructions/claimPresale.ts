@@ -0,0 +1,93 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type ClaimPresaleInstructionAccounts = {
+    contributor: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    presaleVault: PublicKey | Pda;
+    presaleVaultTokenAccount: PublicKey | Pda;
+    presalePosition: PublicKey | Pda;
+    userTokenAccount: PublicKey | Pda;
+    systemProgram?: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    associatedTokenProgram: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type ClaimPresaleInstructionData = { discriminator: Array<number>;  };
+
+export type ClaimPresaleInstructionDataArgs = {  };
+
+
+  export function getClaimPresaleInstructionDataSerializer(): Serializer<ClaimPresaleInstructionDataArgs, ClaimPresaleInstructionData> {
+  return mapSerializer<ClaimPresaleInstructionDataArgs, any, ClaimPresaleInstructionData>(struct<ClaimPresaleInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'ClaimPresaleInstructionData' }), (value) => ({ ...value, discriminator: [82, 240, 122, 5, 109, 66, 86, 190] }) ) as Serializer<ClaimPresaleInstructionDataArgs, ClaimPresaleInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function claimPresale(
+  context: Pick<Context, "programs">,
+                        input: ClaimPresaleInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          contributor: { index: 0, isWritable: true as boolean, value: input.contributor ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: false as boolean, value: input.bondingCurve ?? null },
+          presaleVault: { index: 4, isWritable: true as boolean, value: input.presaleVault ?? null },
+          presaleVaultTokenAccount: { index: 5, isWritable: true as boolean, value: input.presaleVaultTokenAccount ?? null },
+          presalePosition: { index: 6, isWritable: true as boolean, value: input.presalePosition ?? null },
+          userTokenAccount: { index: 7, isWritable: true as boolean, value: input.userTokenAccount ?? null },
+          systemProgram: { index: 8, isWritable: false as boolean, value: input.systemProgram ?? null },
+          tokenProgram: { index: 9, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          associatedTokenProgram: { index: 10, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
+          eventAuthority: { index: 11, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 12, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+        resolvedAccounts.systemProgram.value = context.programs.getPublicKey('splSystem', '11111111111111111111111111111111');
+resolvedAccounts.systemProgram.isWritable = false
+      }
+      if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getClaimPresaleInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
@@ -0,0 +1,87 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type ContributePresaleInstructionAccounts = {
+    contributor: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    presaleVault: PublicKey | Pda;
+    presalePosition: PublicKey | Pda;
+    systemProgram?: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type ContributePresaleInstructionData = { discriminator: Array<number>; solAmount: bigint;  };
+
+export type ContributePresaleInstructionDataArgs = { solAmount: number | bigint;  };
+
+
+  export function getContributePresaleInstructionDataSerializer(): Serializer<ContributePresaleInstructionDataArgs, ContributePresaleInstructionData> {
+  return mapSerializer<ContributePresaleInstructionDataArgs, any, ContributePresaleInstructionData>(struct<ContributePresaleInstructionData>([['discriminator', array(u8(), { size: 8 })], ['solAmount', u64()]], { description: 'ContributePresaleInstructionData' }), (value) => ({ ...value, discriminator: [248, 72, 28, 96, 70, 166, 8, 117] }) ) as Serializer<ContributePresaleInstructionDataArgs, ContributePresaleInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type ContributePresaleInstructionArgs =           ContributePresaleInstructionDataArgs
+      ;
+  
+// Instruction.
+export function contributePresale(
+  context: Pick<Context, "programs">,
+                        input: ContributePresaleInstructionAccounts & ContributePresaleInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          contributor: { index: 0, isWritable: true as boolean, value: input.contributor ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          presaleVault: { index: 4, isWritable: true as boolean, value: input.presaleVault ?? null },
+          presalePosition: { index: 5, isWritable: true as boolean, value: input.presalePosition ?? null },
+          systemProgram: { index: 6, isWritable: false as boolean, value: input.systemProgram ?? null },
+          eventAuthority: { index: 7, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 8, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: ContributePresaleInstructionArgs = { ...input };
+  
+    // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+        resolvedAccounts.systemProgram.value = context.programs.getPublicKey('splSystem', '11111111111111111111111111111111');
+resolvedAccounts.systemProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getContributePresaleInstructionDataSerializer().serialize(resolvedArgs as ContributePresaleInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
@@ -9,7 +9,7 @@
 import { Context, Option, OptionOrNullable, Pda, PublicKey, Signer, TransactionBuilder, publicKey, transactionBuilder } from '@metaplex-foundation/umi';
 import { Serializer, array, i64, mapSerializer, option, string, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
 import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
-import { AllocationDataParams, AllocationDataParamsArgs, VestingTerms, VestingTermsArgs, getAllocationDataParamsSerializer, getVestingTermsSerializer } from '../types';
+import { AllocationDataParams, AllocationDataParamsArgs, Allowlist, AllowlistArgs, CompletionPolicy, CompletionPolicyArgs, CurveKind, CurveKindArgs, FeeSchedule, FeeScheduleArgs, LaunchProtection, LaunchProtectionArgs, PresaleTerms, PresaleTermsArgs, VestingTerms, VestingTermsArgs, getAllocationDataParamsSerializer, getAllowlistSerializer, getCompletionPolicySerializer, getCurveKindSerializer, getFeeScheduleSerializer, getLaunchProtectionSerializer, getPresaleTermsSerializer, getVestingTermsSerializer } from '../types';
 
 // Accounts.
 export type CreateBondingCurveInstructionAccounts = {
@@ -24,10 +24,12 @@ export type CreateBondingCurveInstructionAccounts = {
     brandVaultTokenAccount: PublicKey | Pda;
     platformVault: PublicKey | Pda;
     platformVaultTokenAccount: PublicKey | Pda;
+    cexVault: PublicKey | Pda;
+    cexVaultTokenAccount: PublicKey | Pda;
     bondingCurve: PublicKey | Pda;
     bondingCurveTokenAccount: PublicKey | Pda;
     global: PublicKey | Pda;
-    metadata: PublicKey | Pda;
+    metadata?: PublicKey | Pda;
     systemProgram?: PublicKey | Pda;
     tokenProgram?: PublicKey | Pda;
     associatedTokenProgram: PublicKey | Pda;
@@ -39,13 +41,13 @@ export type CreateBondingCurveInstructionAccounts = {
 };
 
   // Data.
-  export type CreateBondingCurveInstructionData = { discriminator: Array<number>; name: string; symbol: string; uri: string; startTime: Option<bigint>; tokenTotalSupply: bigint; solLaunchThreshold: bigint; virtualTokenMultiplierBps: bigint; virtualSolReserves: bigint; allocation: AllocationDataParams; vestingTerms: Option<VestingTerms>;  };
+  export type CreateBondingCurveInstructionData = { discriminator: Array<number>; name: string; symbol: string; uri: string; startTime: Option<bigint>; graduationDeadline: Option<bigint>; tokenTotalSupply: bigint; solLaunchThreshold: bigint; virtualTokenMultiplierBps: bigint; virtualSolReserves: bigint; allocation: AllocationDataParams; vestingTerms: Option<VestingTerms>; presaleTerms: Option<PresaleTerms>; brandkitVestingTerms: Option<VestingTerms>; launchProtection: Option<LaunchProtection>; feeSchedule: Option<FeeSchedule>; allowlist: Option<Allowlist>; curveKind: Option<CurveKind>; completionPolicy: Option<CompletionPolicy>;  };
 
-export type CreateBondingCurveInstructionDataArgs = { name: string; symbol: string; uri: string; startTime: OptionOrNullable<number | bigint>; tokenTotalSupply: number | bigint; solLaunchThreshold: number | bigint; virtualTokenMultiplierBps: number | bigint; virtualSolReserves: number | bigint; allocation: AllocationDataParamsArgs; vestingTerms: OptionOrNullable<VestingTermsArgs>;  };
+export type CreateBondingCurveInstructionDataArgs = { name: string; symbol: string; uri: string; startTime: OptionOrNullable<number | bigint>; graduationDeadline: OptionOrNullable<number | bigint>; tokenTotalSupply: number | bigint; solLaunchThreshold: number | bigint; virtualTokenMultiplierBps: number | bigint; virtualSolReserves: number | bigint; allocation: AllocationDataParamsArgs; vestingTerms: OptionOrNullable<VestingTermsArgs>; presaleTerms: OptionOrNullable<PresaleTermsArgs>; brandkitVestingTerms: OptionOrNullable<VestingTermsArgs>; launchProtection: OptionOrNullable<LaunchProtectionArgs>; feeSchedule: OptionOrNullable<FeeScheduleArgs>; allowlist: OptionOrNullable<AllowlistArgs>; curveKind: OptionOrNullable<CurveKindArgs>; completionPolicy: OptionOrNullable<CompletionPolicyArgs>;  };
 
 
   export function getCreateBondingCurveInstructionDataSerializer(): Serializer<CreateBondingCurveInstructionDataArgs, CreateBondingCurveInstructionData> {
-  return mapSerializer<CreateBondingCurveInstructionDataArgs, any, CreateBondingCurveInstructionData>(struct<CreateBondingCurveInstructionData>([['discriminator', array(u8(), { size: 8 })], ['name', string()], ['symbol', string()], ['uri', string()], ['startTime', option(i64())], ['tokenTotalSupply', u64()], ['solLaunchThreshold', u64()], ['virtualTokenMultiplierBps', u64()], ['virtualSolReserves', u64()], ['allocation', getAllocationDataParamsSerializer()], ['vestingTerms', option(getVestingTermsSerializer())]], { description: 'CreateBondingCurveInstructionData' }), (value) => ({ ...value, discriminator: [94, 139, 158, 50, 69, 95, 8, 45] }) ) as Serializer<CreateBondingCurveInstructionDataArgs, CreateBondingCurveInstructionData>;
+  return mapSerializer<CreateBondingCurveInstructionDataArgs, any, CreateBondingCurveInstructionData>(struct<CreateBondingCurveInstructionData>([['discriminator', array(u8(), { size: 8 })], ['name', string()], ['symbol', string()], ['uri', string()], ['startTime', option(i64())], ['graduationDeadline', option(i64())], ['tokenTotalSupply', u64()], ['solLaunchThreshold', u64()], ['virtualTokenMultiplierBps', u64()], ['virtualSolReserves', u64()], ['allocation', getAllocationDataParamsSerializer()], ['vestingTerms', option(getVestingTermsSerializer())], ['presaleTerms', option(getPresaleTermsSerializer())], ['brandkitVestingTerms', option(getVestingTermsSerializer())], ['launchProtection', option(getLaunchProtectionSerializer())], ['feeSchedule', option(getFeeScheduleSerializer())], ['allowlist', option(getAllowlistSerializer())], ['curveKind', option(getCurveKindSerializer())], ['completionPolicy', option(getCompletionPolicySerializer())]], { description: 'CreateBondingCurveInstructionData' }), (value) => ({ ...value, discriminator: [94, 139, 158, 50, 69, 95, 8, 45] }) ) as Serializer<CreateBondingCurveInstructionDataArgs, CreateBondingCurveInstructionData>;
 }
 
 
@@ -76,18 +78,20 @@ export function createBondingCurve(
           brandVaultTokenAccount: { index: 8, isWritable: true as boolean, value: input.brandVaultTokenAccount ?? null },
           platformVault: { index: 9, isWritable: true as boolean, value: input.platformVault ?? null },
           platformVaultTokenAccount: { index: 10, isWritable: true as boolean, value: input.platformVaultTokenAccount ?? null },
-          bondingCurve: { index: 11, isWritable: true as boolean, value: input.bondingCurve ?? null },
-          bondingCurveTokenAccount: { index: 12, isWritable: true as boolean, value: input.bondingCurveTokenAccount ?? null },
-          global: { index: 13, isWritable: false as boolean, value: input.global ?? null },
-          metadata: { index: 14, isWritable: true as boolean, value: input.metadata ?? null },
-          systemProgram: { index: 15, isWritable: false as boolean, value: input.systemProgram ?? null },
-          tokenProgram: { index: 16, isWritable: false as boolean, value: input.tokenProgram ?? null },
-          associatedTokenProgram: { index: 17, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
-          tokenMetadataProgram: { index: 18, isWritable: false as boolean, value: input.tokenMetadataProgram ?? null },
-          rent: { index: 19, isWritable: false as boolean, value: input.rent ?? null },
-          clock: { index: 20, isWritable: false as boolean, value: input.clock ?? null },
-          eventAuthority: { index: 21, isWritable: false as boolean, value: input.eventAuthority ?? null },
-          program: { index: 22, isWritable: false as boolean, value: input.program ?? null },
+          cexVault: { index: 11, isWritable: true as boolean, value: input.cexVault ?? null },
+          cexVaultTokenAccount: { index: 12, isWritable: true as boolean, value: input.cexVaultTokenAccount ?? null },
+          bondingCurve: { index: 13, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          bondingCurveTokenAccount: { index: 14, isWritable: true as boolean, value: input.bondingCurveTokenAccount ?? null },
+          global: { index: 15, isWritable: false as boolean, value: input.global ?? null },
+          metadata: { index: 16, isWritable: true as boolean, value: input.metadata ?? null },
+          systemProgram: { index: 17, isWritable: false as boolean, value: input.systemProgram ?? null },
+          tokenProgram: { index: 18, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          associatedTokenProgram: { index: 19, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
+          tokenMetadataProgram: { index: 20, isWritable: false as boolean, value: input.tokenMetadataProgram ?? null },
+          rent: { index: 21, isWritable: false as boolean, value: input.rent ?? null },
+          clock: { index: 22, isWritable: false as boolean, value: input.clock ?? null },
+          eventAuthority: { index: 23, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 24, isWritable: false as boolean, value: input.program ?? null },
       } satisfies ResolvedAccountsWithIndices;
 
       // Arguments.
@@ -0,0 +1,65 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type ExecuteSettingsInstructionAccounts = {
+    executor: Signer;
+    global: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type ExecuteSettingsInstructionData = { discriminator: Array<number>;  };
+
+export type ExecuteSettingsInstructionDataArgs = {  };
+
+
+  export function getExecuteSettingsInstructionDataSerializer(): Serializer<ExecuteSettingsInstructionDataArgs, ExecuteSettingsInstructionData> {
+  return mapSerializer<ExecuteSettingsInstructionDataArgs, any, ExecuteSettingsInstructionData>(struct<ExecuteSettingsInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'ExecuteSettingsInstructionData' }), (value) => ({ ...value, discriminator: [239, 192, 179, 76, 24, 205, 88, 200] }) ) as Serializer<ExecuteSettingsInstructionDataArgs, ExecuteSettingsInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function executeSettings(
+  context: Pick<Context, "programs">,
+                        input: ExecuteSettingsInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          executor: { index: 0, isWritable: false as boolean, value: input.executor ?? null },
+          global: { index: 1, isWritable: true as boolean, value: input.global ?? null },
+          eventAuthority: { index: 2, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 3, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getExecuteSettingsInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
@@ -0,0 +1,100 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type FailCurveInstructionAccounts = {
+    payer?: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    bondingCurveTokenAccount: PublicKey | Pda;
+    presaleVault: PublicKey | Pda;
+    creatorVault: PublicKey | Pda;
+    creatorVaultTokenAccount: PublicKey | Pda;
+    cexVault: PublicKey | Pda;
+    cexVaultTokenAccount: PublicKey | Pda;
+    brandVault: PublicKey | Pda;
+    brandVaultTokenAccount: PublicKey | Pda;
+    platformVault: PublicKey | Pda;
+    platformVaultTokenAccount: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type FailCurveInstructionData = { discriminator: Array<number>;  };
+
+export type FailCurveInstructionDataArgs = {  };
+
+
+  export function getFailCurveInstructionDataSerializer(): Serializer<FailCurveInstructionDataArgs, FailCurveInstructionData> {
+  return mapSerializer<FailCurveInstructionDataArgs, any, FailCurveInstructionData>(struct<FailCurveInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'FailCurveInstructionData' }), (value) => ({ ...value, discriminator: [59, 38, 250, 59, 243, 219, 177, 173] }) ) as Serializer<FailCurveInstructionDataArgs, FailCurveInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function failCurve(
+  context: Pick<Context, "payer" | "programs">,
+                        input: FailCurveInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          payer: { index: 0, isWritable: true as boolean, value: input.payer ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: true as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          bondingCurveTokenAccount: { index: 4, isWritable: true as boolean, value: input.bondingCurveTokenAccount ?? null },
+          presaleVault: { index: 5, isWritable: true as boolean, value: input.presaleVault ?? null },
+          creatorVault: { index: 6, isWritable: false as boolean, value: input.creatorVault ?? null },
+          creatorVaultTokenAccount: { index: 7, isWritable: true as boolean, value: input.creatorVaultTokenAccount ?? null },
+          cexVault: { index: 8, isWritable: false as boolean, value: input.cexVault ?? null },
+          cexVaultTokenAccount: { index: 9, isWritable: true as boolean, value: input.cexVaultTokenAccount ?? null },
+          brandVault: { index: 10, isWritable: false as boolean, value: input.brandVault ?? null },
+          brandVaultTokenAccount: { index: 11, isWritable: true as boolean, value: input.brandVaultTokenAccount ?? null },
+          platformVault: { index: 12, isWritable: false as boolean, value: input.platformVault ?? null },
+          platformVaultTokenAccount: { index: 13, isWritable: true as boolean, value: input.platformVaultTokenAccount ?? null },
+          tokenProgram: { index: 14, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          eventAuthority: { index: 15, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 16, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.payer.value) {
+        resolvedAccounts.payer.value = context.payer;
+      }
+      if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getFailCurveInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
@@ -0,0 +1,104 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type GraduateInstructionAccounts = {
+    payer?: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    bondingCurveTokenAccount: PublicKey | Pda;
+    presaleVault: PublicKey | Pda;
+    liquidityProvider: PublicKey | Pda;
+    liquidityProviderTokenAccount: PublicKey | Pda;
+    pool: PublicKey | Pda;
+    lpMint: PublicKey | Pda;
+    liquidityProviderLpTokenAccount: PublicKey | Pda;
+    ammProgram: PublicKey | Pda;
+    systemProgram?: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    associatedTokenProgram: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type GraduateInstructionData = { discriminator: Array<number>;  };
+
+export type GraduateInstructionDataArgs = {  };
+
+
+  export function getGraduateInstructionDataSerializer(): Serializer<GraduateInstructionDataArgs, GraduateInstructionData> {
+  return mapSerializer<GraduateInstructionDataArgs, any, GraduateInstructionData>(struct<GraduateInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'GraduateInstructionData' }), (value) => ({ ...value, discriminator: [45, 235, 225, 181, 17, 218, 64, 130] }) ) as Serializer<GraduateInstructionDataArgs, GraduateInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function graduate(
+  context: Pick<Context, "payer" | "programs">,
+                        input: GraduateInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          payer: { index: 0, isWritable: true as boolean, value: input.payer ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          bondingCurveTokenAccount: { index: 4, isWritable: true as boolean, value: input.bondingCurveTokenAccount ?? null },
+          presaleVault: { index: 5, isWritable: true as boolean, value: input.presaleVault ?? null },
+          liquidityProvider: { index: 6, isWritable: true as boolean, value: input.liquidityProvider ?? null },
+          liquidityProviderTokenAccount: { index: 7, isWritable: true as boolean, value: input.liquidityProviderTokenAccount ?? null },
+          pool: { index: 8, isWritable: true as boolean, value: input.pool ?? null },
+          lpMint: { index: 9, isWritable: true as boolean, value: input.lpMint ?? null },
+          liquidityProviderLpTokenAccount: { index: 10, isWritable: true as boolean, value: input.liquidityProviderLpTokenAccount ?? null },
+          ammProgram: { index: 11, isWritable: false as boolean, value: input.ammProgram ?? null },
+          systemProgram: { index: 12, isWritable: false as boolean, value: input.systemProgram ?? null },
+          tokenProgram: { index: 13, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          associatedTokenProgram: { index: 14, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
+          eventAuthority: { index: 15, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 16, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.payer.value) {
+        resolvedAccounts.payer.value = context.payer;
+      }
+      if (!resolvedAccounts.systemProgram.value) {
+        resolvedAccounts.systemProgram.value = context.programs.getPublicKey('splSystem', '11111111111111111111111111111111');
+resolvedAccounts.systemProgram.isWritable = false
+      }
+      if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getGraduateInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
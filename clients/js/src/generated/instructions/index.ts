@@ -6,9 +6,26 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './acceptAuthority';
+export * from './cancelAuthorityTransfer';
+export * from './cancelLaunch';
+export * from './cancelSettings';
+export * from './claimBrandkit';
 export * from './claimCreatorVesting';
+export * from './claimPlatformTokens';
+export * from './claimPresale';
+export * from './contributePresale';
 export * from './createBondingCurve';
+export * from './executeSettings';
+export * from './failCurve';
+export * from './graduate';
 export * from './initialize';
+export * from './quote';
+export * from './refund';
+export * from './releaseCexTokens';
+export * from './rescheduleLaunch';
+export * from './scheduleSettings';
 export * from './setParams';
+export * from './skim';
 export * from './swap';
 export * from './withdrawFees';
@@ -0,0 +1,70 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+import { SwapParams, SwapParamsArgs, getSwapParamsSerializer } from '../types';
+
+// Accounts.
+export type QuoteInstructionAccounts = {
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+};
+
+  // Data.
+  export type QuoteInstructionData = { discriminator: Array<number>; params: SwapParams;  };
+
+export type QuoteInstructionDataArgs = { params: SwapParamsArgs;  };
+
+
+  export function getQuoteInstructionDataSerializer(): Serializer<QuoteInstructionDataArgs, QuoteInstructionData> {
+  return mapSerializer<QuoteInstructionDataArgs, any, QuoteInstructionData>(struct<QuoteInstructionData>([['discriminator', array(u8(), { size: 8 })], ['params', getSwapParamsSerializer()]], { description: 'QuoteInstructionData' }), (value) => ({ ...value, discriminator: [149, 42, 109, 247, 134, 146, 213, 123] }) ) as Serializer<QuoteInstructionDataArgs, QuoteInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type QuoteInstructionArgs =           QuoteInstructionDataArgs
+      ;
+  
+// Instruction.
+export function quote(
+  context: Pick<Context, "programs">,
+                        input: QuoteInstructionAccounts & QuoteInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          global: { index: 0, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 1, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 2, isWritable: false as boolean, value: input.bondingCurve ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: QuoteInstructionArgs = { ...input };
+  
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getQuoteInstructionDataSerializer().serialize(resolvedArgs as QuoteInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
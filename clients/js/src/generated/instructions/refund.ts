@@ -0,0 +1,85 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type RefundInstructionAccounts = {
+    user: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    userTokenAccount: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type RefundInstructionData = { discriminator: Array<number>; tokenAmount: bigint;  };
+
+export type RefundInstructionDataArgs = { tokenAmount: number | bigint;  };
+
+
+  export function getRefundInstructionDataSerializer(): Serializer<RefundInstructionDataArgs, RefundInstructionData> {
+  return mapSerializer<RefundInstructionDataArgs, any, RefundInstructionData>(struct<RefundInstructionData>([['discriminator', array(u8(), { size: 8 })], ['tokenAmount', u64()]], { description: 'RefundInstructionData' }), (value) => ({ ...value, discriminator: [2, 96, 183, 251, 63, 208, 46, 46] }) ) as Serializer<RefundInstructionDataArgs, RefundInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type RefundInstructionArgs =           RefundInstructionDataArgs
+      ;
+  
+// Instruction.
+export function refund(
+  context: Pick<Context, "programs">,
+                        input: RefundInstructionAccounts & RefundInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          user: { index: 0, isWritable: true as boolean, value: input.user ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: true as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          userTokenAccount: { index: 4, isWritable: true as boolean, value: input.userTokenAccount ?? null },
+          tokenProgram: { index: 5, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          eventAuthority: { index: 6, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 7, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: RefundInstructionArgs = { ...input };
+  
+    // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getRefundInstructionDataSerializer().serialize(resolvedArgs as RefundInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
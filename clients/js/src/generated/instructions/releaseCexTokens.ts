@@ -0,0 +1,102 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type ReleaseCexTokensInstructionAccounts = {
+    authority?: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    cexVault: PublicKey | Pda;
+    cexVaultTokenAccount: PublicKey | Pda;
+    exchangeWallet: PublicKey | Pda;
+    exchangeTokenAccount: PublicKey | Pda;
+    systemProgram?: PublicKey | Pda;
+    associatedTokenProgram: PublicKey | Pda;
+    tokenProgram?: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type ReleaseCexTokensInstructionData = { discriminator: Array<number>; amount: bigint;  };
+
+export type ReleaseCexTokensInstructionDataArgs = { amount: number | bigint;  };
+
+
+  export function getReleaseCexTokensInstructionDataSerializer(): Serializer<ReleaseCexTokensInstructionDataArgs, ReleaseCexTokensInstructionData> {
+  return mapSerializer<ReleaseCexTokensInstructionDataArgs, any, ReleaseCexTokensInstructionData>(struct<ReleaseCexTokensInstructionData>([['discriminator', array(u8(), { size: 8 })], ['amount', u64()]], { description: 'ReleaseCexTokensInstructionData' }), (value) => ({ ...value, discriminator: [178, 82, 143, 22, 107, 226, 2, 141] }) ) as Serializer<ReleaseCexTokensInstructionDataArgs, ReleaseCexTokensInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type ReleaseCexTokensInstructionArgs =           ReleaseCexTokensInstructionDataArgs
+      ;
+  
+// Instruction.
+export function releaseCexTokens(
+  context: Pick<Context, "identity" | "programs">,
+                        input: ReleaseCexTokensInstructionAccounts & ReleaseCexTokensInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          authority: { index: 0, isWritable: true as boolean, value: input.authority ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: false as boolean, value: input.bondingCurve ?? null },
+          cexVault: { index: 4, isWritable: true as boolean, value: input.cexVault ?? null },
+          cexVaultTokenAccount: { index: 5, isWritable: true as boolean, value: input.cexVaultTokenAccount ?? null },
+          exchangeWallet: { index: 6, isWritable: false as boolean, value: input.exchangeWallet ?? null },
+          exchangeTokenAccount: { index: 7, isWritable: true as boolean, value: input.exchangeTokenAccount ?? null },
+          systemProgram: { index: 8, isWritable: false as boolean, value: input.systemProgram ?? null },
+          associatedTokenProgram: { index: 9, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
+          tokenProgram: { index: 10, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          eventAuthority: { index: 11, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 12, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: ReleaseCexTokensInstructionArgs = { ...input };
+  
+    // Default values.
+  if (!resolvedAccounts.authority.value) {
+        resolvedAccounts.authority.value = context.identity;
+      }
+      if (!resolvedAccounts.systemProgram.value) {
+        resolvedAccounts.systemProgram.value = context.programs.getPublicKey('splSystem', '11111111111111111111111111111111');
+resolvedAccounts.systemProgram.isWritable = false
+      }
+      if (!resolvedAccounts.tokenProgram.value) {
+        resolvedAccounts.tokenProgram.value = context.programs.getPublicKey('splToken', 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+resolvedAccounts.tokenProgram.isWritable = false
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getReleaseCexTokensInstructionDataSerializer().serialize(resolvedArgs as ReleaseCexTokensInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
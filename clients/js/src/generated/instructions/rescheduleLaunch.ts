@@ -0,0 +1,77 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, i64, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type RescheduleLaunchInstructionAccounts = {
+    creator: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    presaleVault: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type RescheduleLaunchInstructionData = { discriminator: Array<number>; startTime: bigint;  };
+
+export type RescheduleLaunchInstructionDataArgs = { startTime: number | bigint;  };
+
+
+  export function getRescheduleLaunchInstructionDataSerializer(): Serializer<RescheduleLaunchInstructionDataArgs, RescheduleLaunchInstructionData> {
+  return mapSerializer<RescheduleLaunchInstructionDataArgs, any, RescheduleLaunchInstructionData>(struct<RescheduleLaunchInstructionData>([['discriminator', array(u8(), { size: 8 })], ['startTime', i64()]], { description: 'RescheduleLaunchInstructionData' }), (value) => ({ ...value, discriminator: [232, 42, 149, 48, 234, 174, 134, 240] }) ) as Serializer<RescheduleLaunchInstructionDataArgs, RescheduleLaunchInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type RescheduleLaunchInstructionArgs =           RescheduleLaunchInstructionDataArgs
+      ;
+  
+// Instruction.
+export function rescheduleLaunch(
+  context: Pick<Context, "programs">,
+                        input: RescheduleLaunchInstructionAccounts & RescheduleLaunchInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          creator: { index: 0, isWritable: false as boolean, value: input.creator ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          presaleVault: { index: 4, isWritable: false as boolean, value: input.presaleVault ?? null },
+          eventAuthority: { index: 5, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 6, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: RescheduleLaunchInstructionArgs = { ...input };
+  
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getRescheduleLaunchInstructionDataSerializer().serialize(resolvedArgs as RescheduleLaunchInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
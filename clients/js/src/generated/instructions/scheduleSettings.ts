@@ -0,0 +1,77 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, i64, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+import { GlobalSettingsInput, GlobalSettingsInputArgs, getGlobalSettingsInputSerializer } from '../types';
+
+// Accounts.
+export type ScheduleSettingsInstructionAccounts = {
+    authority?: Signer;
+    global: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type ScheduleSettingsInstructionData = { discriminator: Array<number>; params: GlobalSettingsInput; effectiveTime: bigint;  };
+
+export type ScheduleSettingsInstructionDataArgs = { params: GlobalSettingsInputArgs; effectiveTime: number | bigint;  };
+
+
+  export function getScheduleSettingsInstructionDataSerializer(): Serializer<ScheduleSettingsInstructionDataArgs, ScheduleSettingsInstructionData> {
+  return mapSerializer<ScheduleSettingsInstructionDataArgs, any, ScheduleSettingsInstructionData>(struct<ScheduleSettingsInstructionData>([['discriminator', array(u8(), { size: 8 })], ['params', getGlobalSettingsInputSerializer()], ['effectiveTime', i64()]], { description: 'ScheduleSettingsInstructionData' }), (value) => ({ ...value, discriminator: [255, 95, 196, 155, 135, 126, 175, 16] }) ) as Serializer<ScheduleSettingsInstructionDataArgs, ScheduleSettingsInstructionData>;
+}
+
+
+
+  
+  // Args.
+      export type ScheduleSettingsInstructionArgs =           ScheduleSettingsInstructionDataArgs
+      ;
+  
+// Instruction.
+export function scheduleSettings(
+  context: Pick<Context, "identity" | "programs">,
+                        input: ScheduleSettingsInstructionAccounts & ScheduleSettingsInstructionArgs,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          authority: { index: 0, isWritable: false as boolean, value: input.authority ?? null },
+          global: { index: 1, isWritable: true as boolean, value: input.global ?? null },
+          eventAuthority: { index: 2, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 3, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+      // Arguments.
+    const resolvedArgs: ScheduleSettingsInstructionArgs = { ...input };
+  
+    // Default values.
+  if (!resolvedAccounts.authority.value) {
+        resolvedAccounts.authority.value = context.identity;
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getScheduleSettingsInstructionDataSerializer().serialize(resolvedArgs as ScheduleSettingsInstructionDataArgs);
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
@@ -0,0 +1,76 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+
+// Accounts.
+export type SkimInstructionAccounts = {
+    authority?: Signer;
+    global: PublicKey | Pda;
+    mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
+    platformVault: PublicKey | Pda;
+    eventAuthority: PublicKey | Pda;
+    program: PublicKey | Pda;
+};
+
+  // Data.
+  export type SkimInstructionData = { discriminator: Array<number>;  };
+
+export type SkimInstructionDataArgs = {  };
+
+
+  export function getSkimInstructionDataSerializer(): Serializer<SkimInstructionDataArgs, SkimInstructionData> {
+  return mapSerializer<SkimInstructionDataArgs, any, SkimInstructionData>(struct<SkimInstructionData>([['discriminator', array(u8(), { size: 8 })]], { description: 'SkimInstructionData' }), (value) => ({ ...value, discriminator: [238, 120, 221, 138, 82, 60, 100, 218] }) ) as Serializer<SkimInstructionDataArgs, SkimInstructionData>;
+}
+
+
+
+
+// Instruction.
+export function skim(
+  context: Pick<Context, "identity" | "programs">,
+                        input: SkimInstructionAccounts,
+      ): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey('billyBondingCurve', '71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP');
+
+  // Accounts.
+  const resolvedAccounts = {
+          authority: { index: 0, isWritable: false as boolean, value: input.authority ?? null },
+          global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
+          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          bondingCurve: { index: 3, isWritable: true as boolean, value: input.bondingCurve ?? null },
+          platformVault: { index: 4, isWritable: true as boolean, value: input.platformVault ?? null },
+          eventAuthority: { index: 5, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 6, isWritable: false as boolean, value: input.program ?? null },
+      } satisfies ResolvedAccountsWithIndices;
+
+  
+    // Default values.
+  if (!resolvedAccounts.authority.value) {
+        resolvedAccounts.authority.value = context.identity;
+      }
+      
+  // Accounts in order.
+      const orderedAccounts: ResolvedAccount[] = Object.values(resolvedAccounts).sort((a,b) => a.index - b.index);
+  
+  
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(orderedAccounts, "programId", programId);
+
+  // Data.
+      const data = getSkimInstructionDataSerializer().serialize({});
+  
+  // Bytes Created On Chain.
+      const bytesCreatedOnChain = 0;
+  
+  return transactionBuilder([{ instruction: { keys, programId, data }, signers, bytesCreatedOnChain }]);
+}
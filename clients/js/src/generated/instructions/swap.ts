@@ -7,8 +7,9 @@
  */
 
 import { Context, Pda, PublicKey, Signer, TransactionBuilder, transactionBuilder } from '@metaplex-foundation/umi';
-import { Serializer, array, bool, mapSerializer, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { Serializer, array, mapSerializer, struct, u8 } from '@metaplex-foundation/umi/serializers';
 import { ResolvedAccount, ResolvedAccountsWithIndices, getAccountMetasAndSigners } from '../shared';
+import { SwapParams, SwapParamsArgs, getSwapParamsSerializer } from '../types';
 
 // Accounts.
 export type SwapInstructionAccounts = {
@@ -18,7 +19,9 @@ export type SwapInstructionAccounts = {
     bondingCurve: PublicKey | Pda;
     bondingCurveTokenAccount: PublicKey | Pda;
     platformVault: PublicKey | Pda;
+    platformVaultTokenAccount: PublicKey | Pda;
     userTokenAccount: PublicKey | Pda;
+    userPosition?: PublicKey | Pda;
     systemProgram?: PublicKey | Pda;
     tokenProgram?: PublicKey | Pda;
     associatedTokenProgram: PublicKey | Pda;
@@ -28,13 +31,13 @@ export type SwapInstructionAccounts = {
 };
 
   // Data.
-  export type SwapInstructionData = { discriminator: Array<number>; baseIn: boolean; exactInAmount: bigint; minOutAmount: bigint;  };
+  export type SwapInstructionData = { discriminator: Array<number>; params: SwapParams;  };
 
-export type SwapInstructionDataArgs = { baseIn: boolean; exactInAmount: number | bigint; minOutAmount: number | bigint;  };
+export type SwapInstructionDataArgs = { params: SwapParamsArgs;  };
 
 
   export function getSwapInstructionDataSerializer(): Serializer<SwapInstructionDataArgs, SwapInstructionData> {
-  return mapSerializer<SwapInstructionDataArgs, any, SwapInstructionData>(struct<SwapInstructionData>([['discriminator', array(u8(), { size: 8 })], ['baseIn', bool()], ['exactInAmount', u64()], ['minOutAmount', u64()]], { description: 'SwapInstructionData' }), (value) => ({ ...value, discriminator: [248, 198, 158, 145, 225, 117, 135, 200] }) ) as Serializer<SwapInstructionDataArgs, SwapInstructionData>;
+  return mapSerializer<SwapInstructionDataArgs, any, SwapInstructionData>(struct<SwapInstructionData>([['discriminator', array(u8(), { size: 8 })], ['params', getSwapParamsSerializer()]], { description: 'SwapInstructionData' }), (value) => ({ ...value, discriminator: [248, 198, 158, 145, 225, 117, 135, 200] }) ) as Serializer<SwapInstructionDataArgs, SwapInstructionData>;
 }
 
 
@@ -56,17 +59,19 @@ export function swap(
   const resolvedAccounts = {
           user: { index: 0, isWritable: true as boolean, value: input.user ?? null },
           global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
-          mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
+          mint: { index: 2, isWritable: true as boolean, value: input.mint ?? null },
           bondingCurve: { index: 3, isWritable: true as boolean, value: input.bondingCurve ?? null },
           bondingCurveTokenAccount: { index: 4, isWritable: true as boolean, value: input.bondingCurveTokenAccount ?? null },
           platformVault: { index: 5, isWritable: true as boolean, value: input.platformVault ?? null },
-          userTokenAccount: { index: 6, isWritable: true as boolean, value: input.userTokenAccount ?? null },
-          systemProgram: { index: 7, isWritable: false as boolean, value: input.systemProgram ?? null },
-          tokenProgram: { index: 8, isWritable: false as boolean, value: input.tokenProgram ?? null },
-          associatedTokenProgram: { index: 9, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
-          clock: { index: 10, isWritable: false as boolean, value: input.clock ?? null },
-          eventAuthority: { index: 11, isWritable: false as boolean, value: input.eventAuthority ?? null },
-          program: { index: 12, isWritable: false as boolean, value: input.program ?? null },
+          platformVaultTokenAccount: { index: 6, isWritable: true as boolean, value: input.platformVaultTokenAccount ?? null },
+          userTokenAccount: { index: 7, isWritable: true as boolean, value: input.userTokenAccount ?? null },
+          userPosition: { index: 8, isWritable: true as boolean, value: input.userPosition ?? null },
+          systemProgram: { index: 9, isWritable: false as boolean, value: input.systemProgram ?? null },
+          tokenProgram: { index: 10, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          associatedTokenProgram: { index: 11, isWritable: false as boolean, value: input.associatedTokenProgram ?? null },
+          clock: { index: 12, isWritable: false as boolean, value: input.clock ?? null },
+          eventAuthority: { index: 13, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 14, isWritable: false as boolean, value: input.program ?? null },
       } satisfies ResolvedAccountsWithIndices;
 
       // Arguments.
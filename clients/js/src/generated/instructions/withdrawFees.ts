@@ -15,6 +15,7 @@ export type WithdrawFeesInstructionAccounts = {
     authority?: Signer;
     global: PublicKey | Pda;
     mint: PublicKey | Pda;
+    bondingCurve: PublicKey | Pda;
     platformVault: PublicKey | Pda;
     systemProgram?: PublicKey | Pda;
     tokenProgram?: PublicKey | Pda;
@@ -49,12 +50,13 @@ export function withdrawFees(
           authority: { index: 0, isWritable: true as boolean, value: input.authority ?? null },
           global: { index: 1, isWritable: false as boolean, value: input.global ?? null },
           mint: { index: 2, isWritable: false as boolean, value: input.mint ?? null },
-          platformVault: { index: 3, isWritable: true as boolean, value: input.platformVault ?? null },
-          systemProgram: { index: 4, isWritable: false as boolean, value: input.systemProgram ?? null },
-          tokenProgram: { index: 5, isWritable: false as boolean, value: input.tokenProgram ?? null },
-          clock: { index: 6, isWritable: false as boolean, value: input.clock ?? null },
-          eventAuthority: { index: 7, isWritable: false as boolean, value: input.eventAuthority ?? null },
-          program: { index: 8, isWritable: false as boolean, value: input.program ?? null },
+          bondingCurve: { index: 3, isWritable: false as boolean, value: input.bondingCurve ?? null },
+          platformVault: { index: 4, isWritable: true as boolean, value: input.platformVault ?? null },
+          systemProgram: { index: 5, isWritable: false as boolean, value: input.systemProgram ?? null },
+          tokenProgram: { index: 6, isWritable: false as boolean, value: input.tokenProgram ?? null },
+          clock: { index: 7, isWritable: false as boolean, value: input.clock ?? null },
+          eventAuthority: { index: 8, isWritable: false as boolean, value: input.eventAuthority ?? null },
+          program: { index: 9, isWritable: false as boolean, value: input.program ?? null },
       } satisfies ResolvedAccountsWithIndices;
 
   
@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, array, i64, struct, u8 } from '@metaplex-foundation/umi/serializers';
+
+
+export type Allowlist = { merkleRoot: Array<number>; endTime: bigint;  };
+
+export type AllowlistArgs = { merkleRoot: Array<number>; endTime: number | bigint;  };
+
+
+export function getAllowlistSerializer(): Serializer<AllowlistArgs, Allowlist> {
+  return struct<Allowlist>([['merkleRoot', array(u8(), { size: 32 })], ['endTime', i64()]], { description: 'Allowlist' }) as Serializer<AllowlistArgs, Allowlist>;
+}
+
+
@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, array, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+
+
+export type AllowlistProof = { allowance: bigint; proof: Array<Array<number>>;  };
+
+export type AllowlistProofArgs = { allowance: number | bigint; proof: Array<Array<number>>;  };
+
+
+export function getAllowlistProofSerializer(): Serializer<AllowlistProofArgs, AllowlistProof> {
+  return struct<AllowlistProof>([['allowance', u64()], ['proof', array(array(u8(), { size: 32 }))]], { description: 'AllowlistProof' }) as Serializer<AllowlistProofArgs, AllowlistProof>;
+}
+
+
@@ -0,0 +1,20 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+
+export enum CompletionPolicy { Burn, GraduationLiquidity, PlatformVault };
+
+export type CompletionPolicyArgs = CompletionPolicy;
+
+export function getCompletionPolicySerializer(): Serializer<CompletionPolicyArgs, CompletionPolicy> {
+  return scalarEnum<CompletionPolicy>(CompletionPolicy, { description: 'CompletionPolicy' }) as Serializer<CompletionPolicyArgs, CompletionPolicy>;
+}
+
+
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { GetDataEnumKind, GetDataEnumKindContent, Serializer, dataEnum, struct, tuple, unit } from '@metaplex-foundation/umi/serializers';
+import { ExponentialCurve, ExponentialCurveArgs, LinearCurve, LinearCurveArgs, PiecewiseLinearCurve, PiecewiseLinearCurveArgs, getExponentialCurveSerializer, getLinearCurveSerializer, getPiecewiseLinearCurveSerializer } from '.';
+
+
+export type CurveKind = { __kind: "ConstantProduct" } | { __kind: "Linear", fields: [LinearCurve];  } | { __kind: "Exponential", fields: [ExponentialCurve];  } | { __kind: "PiecewiseLinear", fields: [PiecewiseLinearCurve];  };
+
+export type CurveKindArgs = { __kind: "ConstantProduct" } | { __kind: "Linear", fields: [LinearCurveArgs];  } | { __kind: "Exponential", fields: [ExponentialCurveArgs];  } | { __kind: "PiecewiseLinear", fields: [PiecewiseLinearCurveArgs];  };
+
+
+export function getCurveKindSerializer(): Serializer<CurveKindArgs, CurveKind> {
+  return dataEnum<CurveKind>([['ConstantProduct', unit()], ['Linear', struct<GetDataEnumKindContent<CurveKind, 'Linear'>>([['fields', tuple([getLinearCurveSerializer()])]])], ['Exponential', struct<GetDataEnumKindContent<CurveKind, 'Exponential'>>([['fields', tuple([getExponentialCurveSerializer()])]])], ['PiecewiseLinear', struct<GetDataEnumKindContent<CurveKind, 'PiecewiseLinear'>>([['fields', tuple([getPiecewiseLinearCurveSerializer()])]])]], { description: 'CurveKind' }) as Serializer<CurveKindArgs, CurveKind>;
+}
+
+// Data Enum Helpers.
+export function curveKind(kind: 'ConstantProduct'): GetDataEnumKind<CurveKindArgs, 'ConstantProduct'>;
+export function curveKind(kind: 'Linear', data: GetDataEnumKindContent<CurveKindArgs, 'Linear'>['fields']): GetDataEnumKind<CurveKindArgs, 'Linear'>;
+export function curveKind(kind: 'Exponential', data: GetDataEnumKindContent<CurveKindArgs, 'Exponential'>['fields']): GetDataEnumKind<CurveKindArgs, 'Exponential'>;
+export function curveKind(kind: 'PiecewiseLinear', data: GetDataEnumKindContent<CurveKindArgs, 'PiecewiseLinear'>['fields']): GetDataEnumKind<CurveKindArgs, 'PiecewiseLinear'>;
+export function curveKind<K extends CurveKindArgs['__kind']>(
+  kind: K,
+  data?: any,
+): Extract<CurveKindArgs, { __kind: K }> {
+  return Array.isArray(data) ? { __kind: kind, fields: data } : { __kind: kind, ...(data ?? {}) };
+}
+export function isCurveKind<K extends CurveKind['__kind']>(
+  kind: K,
+  value: CurveKind,
+): value is CurveKind & { __kind: K } {
+  return value.__kind === kind;
+};
+
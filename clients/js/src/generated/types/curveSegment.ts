@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+
+export type CurveSegment = { supplyBps: bigint; endPrice: bigint;  };
+
+export type CurveSegmentArgs = { supplyBps: number | bigint; endPrice: number | bigint;  };
+
+
+export function getCurveSegmentSerializer(): Serializer<CurveSegmentArgs, CurveSegment> {
+  return struct<CurveSegment>([['supplyBps', u64()], ['endPrice', u64()]], { description: 'CurveSegment' }) as Serializer<CurveSegmentArgs, CurveSegment>;
+}
+
+
@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+
+export type ExponentialCurve = { startPrice: bigint; growthBps: bigint;  };
+
+export type ExponentialCurveArgs = { startPrice: number | bigint; growthBps: number | bigint;  };
+
+
+export function getExponentialCurveSerializer(): Serializer<ExponentialCurveArgs, ExponentialCurve> {
+  return struct<ExponentialCurve>([['startPrice', u64()], ['growthBps', u64()]], { description: 'ExponentialCurve' }) as Serializer<ExponentialCurveArgs, ExponentialCurve>;
+}
+
+
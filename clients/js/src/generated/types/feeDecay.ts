@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { GetDataEnumKind, GetDataEnumKindContent, Serializer, dataEnum, struct, u32, unit } from '@metaplex-foundation/umi/serializers';
+
+
+export type FeeDecay = { __kind: "Linear" } | { __kind: "Stepwise", steps: number;  };
+
+export type FeeDecayArgs = { __kind: "Linear" } | { __kind: "Stepwise", steps: number;  };
+
+
+export function getFeeDecaySerializer(): Serializer<FeeDecayArgs, FeeDecay> {
+  return dataEnum<FeeDecay>([['Linear', unit()], ['Stepwise', struct<GetDataEnumKindContent<FeeDecay, 'Stepwise'>>([['steps', u32()]])]], { description: 'FeeDecay' }) as Serializer<FeeDecayArgs, FeeDecay>;
+}
+
+// Data Enum Helpers.
+export function feeDecay(kind: 'Linear'): GetDataEnumKind<FeeDecayArgs, 'Linear'>;
+export function feeDecay(kind: 'Stepwise', data: GetDataEnumKindContent<FeeDecayArgs, 'Stepwise'>): GetDataEnumKind<FeeDecayArgs, 'Stepwise'>;
+export function feeDecay<K extends FeeDecayArgs['__kind']>(
+  kind: K,
+  data?: any,
+): Extract<FeeDecayArgs, { __kind: K }> {
+  return Array.isArray(data) ? { __kind: kind, fields: data } : { __kind: kind, ...(data ?? {}) };
+}
+export function isFeeDecay<K extends FeeDecay['__kind']>(
+  kind: K,
+  value: FeeDecay,
+): value is FeeDecay & { __kind: K } {
+  return value.__kind === kind;
+};
+
@@ -0,0 +1,22 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, i64, struct, u64 } from '@metaplex-foundation/umi/serializers';
+import { FeeDecay, FeeDecayArgs, getFeeDecaySerializer } from '.';
+
+
+export type FeeSchedule = { initialFeeBps: bigint; floorFeeBps: bigint; duration: bigint; decay: FeeDecay;  };
+
+export type FeeScheduleArgs = { initialFeeBps: number | bigint; floorFeeBps: number | bigint; duration: number | bigint; decay: FeeDecayArgs;  };
+
+
+export function getFeeScheduleSerializer(): Serializer<FeeScheduleArgs, FeeSchedule> {
+  return struct<FeeSchedule>([['initialFeeBps', u64()], ['floorFeeBps', u64()], ['duration', i64()], ['decay', getFeeDecaySerializer()]], { description: 'FeeSchedule' }) as Serializer<FeeScheduleArgs, FeeSchedule>;
+}
+
+
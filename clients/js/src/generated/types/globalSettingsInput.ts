@@ -6,18 +6,18 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
-import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
-import { Serializer, option, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
-import { ProgramStatus, ProgramStatusArgs, getProgramStatusSerializer } from '.';
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import { Serializer, i64, option, publicKey as publicKeySerializer, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import { ProgramStatus, ProgramStatusArgs, SettingsCaps, SettingsCapsArgs, VestingTerms, VestingTermsArgs, getProgramStatusSerializer, getSettingsCapsSerializer, getVestingTermsSerializer } from '.';
 
 
-export type GlobalSettingsInput = { tradeFeeBps: Option<bigint>; createdMintDecimals: Option<number>; launchFeeLamports: Option<bigint>; status: Option<ProgramStatus>;  };
+export type GlobalSettingsInput = { tradeFeeBps: Option<bigint>; createdMintDecimals: Option<number>; launchFeeLamports: Option<bigint>; launchFeeRefundBps: Option<bigint>; maxLaunchLeadTime: Option<bigint>; status: Option<ProgramStatus>; ammProgram: Option<PublicKey>; platformVestingTerms: Option<VestingTerms>; cexVestingTerms: Option<VestingTerms>; caps: Option<SettingsCaps>;  };
 
-export type GlobalSettingsInputArgs = { tradeFeeBps: OptionOrNullable<number | bigint>; createdMintDecimals: OptionOrNullable<number>; launchFeeLamports: OptionOrNullable<number | bigint>; status: OptionOrNullable<ProgramStatusArgs>;  };
+export type GlobalSettingsInputArgs = { tradeFeeBps: OptionOrNullable<number | bigint>; createdMintDecimals: OptionOrNullable<number>; launchFeeLamports: OptionOrNullable<number | bigint>; launchFeeRefundBps: OptionOrNullable<number | bigint>; maxLaunchLeadTime: OptionOrNullable<number | bigint>; status: OptionOrNullable<ProgramStatusArgs>; ammProgram: OptionOrNullable<PublicKey>; platformVestingTerms: OptionOrNullable<VestingTermsArgs>; cexVestingTerms: OptionOrNullable<VestingTermsArgs>; caps: OptionOrNullable<SettingsCapsArgs>;  };
 
 
 export function getGlobalSettingsInputSerializer(): Serializer<GlobalSettingsInputArgs, GlobalSettingsInput> {
-  return struct<GlobalSettingsInput>([['tradeFeeBps', option(u64())], ['createdMintDecimals', option(u8())], ['launchFeeLamports', option(u64())], ['status', option(getProgramStatusSerializer())]], { description: 'GlobalSettingsInput' }) as Serializer<GlobalSettingsInputArgs, GlobalSettingsInput>;
+  return struct<GlobalSettingsInput>([['tradeFeeBps', option(u64())], ['createdMintDecimals', option(u8())], ['launchFeeLamports', option(u64())], ['launchFeeRefundBps', option(u64())], ['maxLaunchLeadTime', option(i64())], ['status', option(getProgramStatusSerializer())], ['ammProgram', option(publicKeySerializer())], ['platformVestingTerms', option(getVestingTermsSerializer())], ['cexVestingTerms', option(getVestingTermsSerializer())], ['caps', option(getSettingsCapsSerializer())]], { description: 'GlobalSettingsInput' }) as Serializer<GlobalSettingsInputArgs, GlobalSettingsInput>;
 }
 
 
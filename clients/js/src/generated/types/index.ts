@@ -8,7 +8,25 @@
 
 export * from './allocationData';
 export * from './allocationDataParams';
+export * from './allowlist';
+export * from './allowlistProof';
+export * from './authorityRole';
+export * from './completionPolicy';
+export * from './curveKind';
+export * from './curveSegment';
+export * from './exponentialCurve';
+export * from './feeDecay';
+export * from './feeSchedule';
 export * from './globalAuthorityInput';
 export * from './globalSettingsInput';
+export * from './initializePoolArgs';
+export * from './launchProtection';
+export * from './linearCurve';
+export * from './piecewiseLinearCurve';
+export * from './presaleTerms';
 export * from './programStatus';
+export * from './settingsCaps';
+export * from './swapParams';
+export * from './swapQuote';
+export * from './vestingAnchor';
 export * from './vestingTerms';
@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+
+export type InitializePoolArgs = { solAmount: bigint; tokenAmount: bigint;  };
+
+export type InitializePoolArgsArgs = { solAmount: number | bigint; tokenAmount: number | bigint;  };
+
+
+export function getInitializePoolArgsSerializer(): Serializer<InitializePoolArgsArgs, InitializePoolArgs> {
+  return struct<InitializePoolArgs>([['solAmount', u64()], ['tokenAmount', u64()]], { description: 'InitializePoolArgs' }) as Serializer<InitializePoolArgsArgs, InitializePoolArgs>;
+}
+
+
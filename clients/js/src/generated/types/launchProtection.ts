@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, i64, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+
+export type LaunchProtection = { window: bigint; maxBuyLamports: bigint; maxWalletLamports: bigint;  };
+
+export type LaunchProtectionArgs = { window: number | bigint; maxBuyLamports: number | bigint; maxWalletLamports: number | bigint;  };
+
+
+export function getLaunchProtectionSerializer(): Serializer<LaunchProtectionArgs, LaunchProtection> {
+  return struct<LaunchProtection>([['window', i64()], ['maxBuyLamports', u64()], ['maxWalletLamports', u64()]], { description: 'LaunchProtection' }) as Serializer<LaunchProtectionArgs, LaunchProtection>;
+}
+
+
@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+
+export type LinearCurve = { startPrice: bigint; endPrice: bigint;  };
+
+export type LinearCurveArgs = { startPrice: number | bigint; endPrice: number | bigint;  };
+
+
+export function getLinearCurveSerializer(): Serializer<LinearCurveArgs, LinearCurve> {
+  return struct<LinearCurve>([['startPrice', u64()], ['endPrice', u64()]], { description: 'LinearCurve' }) as Serializer<LinearCurveArgs, LinearCurve>;
+}
+
+
@@ -0,0 +1,22 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, array, struct, u64 } from '@metaplex-foundation/umi/serializers';
+import { CurveSegment, CurveSegmentArgs, getCurveSegmentSerializer } from '.';
+
+
+export type PiecewiseLinearCurve = { startPrice: bigint; segments: Array<CurveSegment>;  };
+
+export type PiecewiseLinearCurveArgs = { startPrice: number | bigint; segments: Array<CurveSegmentArgs>;  };
+
+
+export function getPiecewiseLinearCurveSerializer(): Serializer<PiecewiseLinearCurveArgs, PiecewiseLinearCurve> {
+  return struct<PiecewiseLinearCurve>([['startPrice', u64()], ['segments', array(getCurveSegmentSerializer())]], { description: 'PiecewiseLinearCurve' }) as Serializer<PiecewiseLinearCurveArgs, PiecewiseLinearCurve>;
+}
+
+
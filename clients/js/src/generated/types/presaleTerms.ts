@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, i64, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+
+export type PresaleTerms = { openTime: bigint; closeTime: bigint; maxContributionPerWallet: bigint; presaleSolCap: bigint;  };
+
+export type PresaleTermsArgs = { openTime: number | bigint; closeTime: number | bigint; maxContributionPerWallet: number | bigint; presaleSolCap: number | bigint;  };
+
+
+export function getPresaleTermsSerializer(): Serializer<PresaleTermsArgs, PresaleTerms> {
+  return struct<PresaleTerms>([['openTime', i64()], ['closeTime', i64()], ['maxContributionPerWallet', u64()], ['presaleSolCap', u64()]], { description: 'PresaleTerms' }) as Serializer<PresaleTermsArgs, PresaleTerms>;
+}
+
+
@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, i64, struct, u64, u8 } from '@metaplex-foundation/umi/serializers';
+
+
+export type SettingsCaps = { maxTradeFeeBps: bigint; maxLaunchFeeLamports: bigint; maxCreatedMintDecimals: number; maxLaunchLeadTime: bigint;  };
+
+export type SettingsCapsArgs = { maxTradeFeeBps: number | bigint; maxLaunchFeeLamports: number | bigint; maxCreatedMintDecimals: number; maxLaunchLeadTime: number | bigint;  };
+
+
+export function getSettingsCapsSerializer(): Serializer<SettingsCapsArgs, SettingsCaps> {
+  return struct<SettingsCaps>([['maxTradeFeeBps', u64()], ['maxLaunchFeeLamports', u64()], ['maxCreatedMintDecimals', u8()], ['maxLaunchLeadTime', i64()]], { description: 'SettingsCaps' }) as Serializer<SettingsCapsArgs, SettingsCaps>;
+}
+
+
@@ -0,0 +1,23 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import { Serializer, bool, option, struct, u64 } from '@metaplex-foundation/umi/serializers';
+import { AllowlistProof, AllowlistProofArgs, getAllowlistProofSerializer } from '.';
+
+
+export type SwapParams = { baseIn: boolean; exactInAmount: bigint; minOutAmount: bigint; exactOutAmount: bigint; maxInAmount: bigint; allowlistProof: Option<AllowlistProof>;  };
+
+export type SwapParamsArgs = { baseIn: boolean; exactInAmount: number | bigint; minOutAmount: number | bigint; exactOutAmount: number | bigint; maxInAmount: number | bigint; allowlistProof: OptionOrNullable<AllowlistProofArgs>;  };
+
+
+export function getSwapParamsSerializer(): Serializer<SwapParamsArgs, SwapParams> {
+  return struct<SwapParams>([['baseIn', bool()], ['exactInAmount', u64()], ['minOutAmount', u64()], ['exactOutAmount', u64()], ['maxInAmount', u64()], ['allowlistProof', option(getAllowlistProofSerializer())]], { description: 'SwapParams' }) as Serializer<SwapParamsArgs, SwapParams>;
+}
+
+
@@ -0,0 +1,21 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, bool, struct, u128, u64 } from '@metaplex-foundation/umi/serializers';
+
+
+export type SwapQuote = { amountIn: bigint; amountOut: bigint; feeLamports: bigint; feeBps: bigint; virtualSolReserves: bigint; virtualTokenReserves: bigint; realSolReserves: bigint; realTokenReserves: bigint; spotPriceBefore: bigint; spotPriceAfter: bigint; completesCurve: boolean;  };
+
+export type SwapQuoteArgs = { amountIn: number | bigint; amountOut: number | bigint; feeLamports: number | bigint; feeBps: number | bigint; virtualSolReserves: number | bigint; virtualTokenReserves: number | bigint; realSolReserves: number | bigint; realTokenReserves: number | bigint; spotPriceBefore: number | bigint; spotPriceAfter: number | bigint; completesCurve: boolean;  };
+
+
+export function getSwapQuoteSerializer(): Serializer<SwapQuoteArgs, SwapQuote> {
+  return struct<SwapQuote>([['amountIn', u64()], ['amountOut', u64()], ['feeLamports', u64()], ['feeBps', u64()], ['virtualSolReserves', u64()], ['virtualTokenReserves', u128()], ['realSolReserves', u64()], ['realTokenReserves', u64()], ['spotPriceBefore', u128()], ['spotPriceAfter', u128()], ['completesCurve', bool()]], { description: 'SwapQuote' }) as Serializer<SwapQuoteArgs, SwapQuote>;
+}
+
+
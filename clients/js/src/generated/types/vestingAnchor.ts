@@ -0,0 +1,37 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { GetDataEnumKind, GetDataEnumKindContent, Serializer, dataEnum, i64, struct, tuple, unit } from '@metaplex-foundation/umi/serializers';
+
+
+export type VestingAnchor = { __kind: "StartTime" } | { __kind: "CompletionTime" } | { __kind: "Timestamp", fields: [bigint];  };
+
+export type VestingAnchorArgs = { __kind: "StartTime" } | { __kind: "CompletionTime" } | { __kind: "Timestamp", fields: [number | bigint];  };
+
+
+export function getVestingAnchorSerializer(): Serializer<VestingAnchorArgs, VestingAnchor> {
+  return dataEnum<VestingAnchor>([['StartTime', unit()], ['CompletionTime', unit()], ['Timestamp', struct<GetDataEnumKindContent<VestingAnchor, 'Timestamp'>>([['fields', tuple([i64()])]])]], { description: 'VestingAnchor' }) as Serializer<VestingAnchorArgs, VestingAnchor>;
+}
+
+// Data Enum Helpers.
+export function vestingAnchor(kind: 'StartTime'): GetDataEnumKind<VestingAnchorArgs, 'StartTime'>;
+export function vestingAnchor(kind: 'CompletionTime'): GetDataEnumKind<VestingAnchorArgs, 'CompletionTime'>;
+export function vestingAnchor(kind: 'Timestamp', data: GetDataEnumKindContent<VestingAnchorArgs, 'Timestamp'>['fields']): GetDataEnumKind<VestingAnchorArgs, 'Timestamp'>;
+export function vestingAnchor<K extends VestingAnchorArgs['__kind']>(
+  kind: K,
+  data?: any,
+): Extract<VestingAnchorArgs, { __kind: K }> {
+  return Array.isArray(data) ? { __kind: kind, fields: data } : { __kind: kind, ...(data ?? {}) };
+}
+export function isVestingAnchor<K extends VestingAnchor['__kind']>(
+  kind: K,
+  value: VestingAnchor,
+): value is VestingAnchor & { __kind: K } {
+  return value.__kind === kind;
+};
+
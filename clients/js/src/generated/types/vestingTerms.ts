@@ -7,15 +7,16 @@
  */
 
 import { Serializer, i64, struct } from '@metaplex-foundation/umi/serializers';
+import { VestingAnchor, VestingAnchorArgs, getVestingAnchorSerializer } from '.';
 
 
-export type VestingTerms = { cliff: bigint; duration: bigint;  };
+export type VestingTerms = { cliff: bigint; duration: bigint; anchor: VestingAnchor;  };
 
-export type VestingTermsArgs = { cliff: number | bigint; duration: number | bigint;  };
+export type VestingTermsArgs = { cliff: number | bigint; duration: number | bigint; anchor: VestingAnchorArgs;  };
 
 
 export function getVestingTermsSerializer(): Serializer<VestingTermsArgs, VestingTerms> {
-  return struct<VestingTerms>([['cliff', i64()], ['duration', i64()]], { description: 'VestingTerms' }) as Serializer<VestingTermsArgs, VestingTerms>;
+  return struct<VestingTerms>([['cliff', i64()], ['duration', i64()], ['anchor', getVestingAnchorSerializer()]], { description: 'VestingTerms' }) as Serializer<VestingTermsArgs, VestingTerms>;
 }
 
 
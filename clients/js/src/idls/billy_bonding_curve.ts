@@ -90,6 +90,161 @@ export type BillyBondingCurve = {
         }
       ]
     },
+    {
+      "name": "scheduleSettings",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "GlobalSettingsInput"
+          }
+        },
+        {
+          "name": "effectiveTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "executeSettings",
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelSettings",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptAuthority",
+      "accounts": [
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelAuthorityTransfer",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
+          }
+        }
+      ]
+    },
     {
       "name": "createBondingCurve",
       "accounts": [
@@ -148,6 +303,16 @@ export type BillyBondingCurve = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "cexVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "bondingCurve",
           "isMut": true,
@@ -166,7 +331,8 @@ export type BillyBondingCurve = {
         {
           "name": "metadata",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "systemProgram",
@@ -186,7 +352,8 @@ export type BillyBondingCurve = {
         {
           "name": "tokenMetadataProgram",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true
         },
         {
           "name": "rent",
@@ -233,7 +400,7 @@ export type BillyBondingCurve = {
         },
         {
           "name": "mint",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -251,11 +418,22 @@ export type BillyBondingCurve = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "userPosition",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -296,6 +474,37 @@ export type BillyBondingCurve = {
         }
       ]
     },
+    {
+      "name": "quote",
+      "accounts": [
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "SwapParams"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "SwapQuote"
+      }
+    },
     {
       "name": "claimCreatorVesting",
       "accounts": [
@@ -373,40 +582,55 @@ export type BillyBondingCurve = {
       "args": []
     },
     {
-      "name": "withdrawFees",
+      "name": "claimBrandkit",
       "accounts": [
         {
-          "name": "authority",
+          "name": "brandAuthority",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "global",
-          "isMut": false,
+          "name": "brandVault",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "brandVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "platformVault",
+          "name": "userTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "clock",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -422,1974 +646,7028 @@ export type BillyBondingCurve = {
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "bondingCurve",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "platformAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "brandAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "virtualTokenMultiplierBps",
-            "type": "u64"
-          },
-          {
+      "name": "contributePresale",
+      "accounts": [
+        {
+          "name": "contributor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presalePosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimPresale",
+      "accounts": [
+        {
+          "name": "contributor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presalePosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawFees",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "skim",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimPlatformTokens",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseCexTokens",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cexVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "exchangeWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "exchangeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "graduate",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liquidityProvider",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liquidityProviderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liquidityProviderLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "failCurve",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "creatorVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "brandVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "brandVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refund",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "rescheduleLaunch",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "startTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "cancelLaunch",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "brandVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "brandVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "platformAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "brandAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "virtualTokenMultiplierBps",
+            "type": "u64"
+          },
+          {
+            "name": "virtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "virtualTokenReserves",
+            "type": "u128"
+          },
+          {
+            "name": "initialVirtualTokenReserves",
+            "type": "u128"
+          },
+          {
+            "name": "realSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "realTokenReserves",
+            "type": "u64"
+          },
+          {
+            "name": "tokenTotalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "creatorVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "presaleSupply",
+            "type": "u64"
+          },
+          {
+            "name": "bondingSupply",
+            "type": "u64"
+          },
+          {
+            "name": "cexSupply",
+            "type": "u64"
+          },
+          {
+            "name": "launchBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "platformSupply",
+            "type": "u64"
+          },
+          {
+            "name": "solLaunchThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "presaleSolRaised",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "complete",
+            "type": "bool"
+          },
+          {
+            "name": "graduated",
+            "type": "bool"
+          },
+          {
+            "name": "traded",
+            "type": "bool"
+          },
+          {
+            "name": "completionTime",
+            "type": "i64"
+          },
+          {
+            "name": "graduationDeadline",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "failed",
+            "type": "bool"
+          },
+          {
+            "name": "refundTokenSupply",
+            "type": "u64"
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "launchProtection",
+            "type": {
+              "defined": "LaunchProtection"
+            }
+          },
+          {
+            "name": "feeSchedule",
+            "type": {
+              "option": {
+                "defined": "FeeSchedule"
+              }
+            }
+          },
+          {
+            "name": "allowlist",
+            "type": {
+              "option": {
+                "defined": "Allowlist"
+              }
+            }
+          },
+          {
+            "name": "curveKind",
+            "type": {
+              "defined": "CurveKind"
+            }
+          },
+          {
+            "name": "completionPolicy",
+            "type": {
+              "defined": "CompletionPolicy"
+            }
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "allocation",
+            "type": {
+              "defined": "AllocationData"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "global",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "status",
+            "type": {
+              "defined": "ProgramStatus"
+            }
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "globalAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "withdrawAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingGlobalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "pendingWithdrawAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "tradeFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "launchFeeRefundBps",
+            "type": "u64"
+          },
+          {
+            "name": "createdMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "maxLaunchLeadTime",
+            "type": "i64"
+          },
+          {
+            "name": "ammProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "platformVestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "cexVestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "caps",
+            "type": {
+              "defined": "SettingsCaps"
+            }
+          },
+          {
+            "name": "pendingSettings",
+            "type": {
+              "option": {
+                "defined": "GlobalSettingsInput"
+              }
+            }
+          },
+          {
+            "name": "pendingSettingsEffectiveTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userPosition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "protectedBuyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistBuyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "presalePosition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "contributed",
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lastDistribution",
+            "type": "i64"
+          },
+          {
+            "name": "claimedAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "presaleVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "presaleTerms",
+            "type": {
+              "defined": "PresaleTerms"
+            }
+          },
+          {
+            "name": "totalClaimed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cexVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "tokensReleased",
+            "type": "u64"
+          },
+          {
+            "name": "lastRelease",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "platformVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lastDistribution",
+            "type": "i64"
+          },
+          {
+            "name": "lastFeeWithdrawal",
+            "type": "i64"
+          },
+          {
+            "name": "feesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "tokensClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "unsoldTokenAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "brandVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "launchBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeVestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "launchBrandkitClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkitClaimed",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "InitializePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "solAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "feeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "virtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "virtualTokenReserves",
+            "type": "u128"
+          },
+          {
+            "name": "realSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "realTokenReserves",
+            "type": "u64"
+          },
+          {
+            "name": "spotPriceBefore",
+            "type": "u128"
+          },
+          {
+            "name": "spotPriceAfter",
+            "type": "u128"
+          },
+          {
+            "name": "completesCurve",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseIn",
+            "type": "bool"
+          },
+          {
+            "name": "exactInAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minOutAmount",
+            "type": "u64"
+          },
+          {
+            "name": "exactOutAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxInAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistProof",
+            "type": {
+              "option": {
+                "defined": "AllowlistProof"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllocationDataParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "cex",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "launchBrandkit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "lifetimeBrandkit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "platform",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "presale",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "poolReserve",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllocationData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "u64"
+          },
+          {
+            "name": "cex",
+            "type": "u64"
+          },
+          {
+            "name": "launchBrandkit",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkit",
+            "type": "u64"
+          },
+          {
+            "name": "platform",
+            "type": "u64"
+          },
+          {
+            "name": "presale",
+            "type": "u64"
+          },
+          {
+            "name": "poolReserve",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Allowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllowlistProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowance",
+            "type": "u64"
+          },
+          {
+            "name": "proof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LinearCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExponentialCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "growthBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveSegment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "supplyBps",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PiecewiseLinearCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "segments",
+            "type": {
+              "vec": {
+                "defined": "CurveSegment"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingTerms",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cliff",
+            "type": "i64"
+          },
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "anchor",
+            "type": {
+              "defined": "VestingAnchor"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchProtection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "window",
+            "type": "i64"
+          },
+          {
+            "name": "maxBuyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxWalletLamports",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateBondingCurveParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "startTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "graduationDeadline",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "tokenTotalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "solLaunchThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "virtualTokenMultiplierBps",
+            "type": "u64"
+          },
+          {
             "name": "virtualSolReserves",
             "type": "u64"
           },
           {
-            "name": "virtualTokenReserves",
-            "type": "u128"
+            "name": "allocation",
+            "type": {
+              "defined": "AllocationDataParams"
+            }
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "presaleTerms",
+            "type": {
+              "option": {
+                "defined": "PresaleTerms"
+              }
+            }
+          },
+          {
+            "name": "brandkitVestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "launchProtection",
+            "type": {
+              "option": {
+                "defined": "LaunchProtection"
+              }
+            }
+          },
+          {
+            "name": "feeSchedule",
+            "type": {
+              "option": {
+                "defined": "FeeSchedule"
+              }
+            }
+          },
+          {
+            "name": "allowlist",
+            "type": {
+              "option": {
+                "defined": "Allowlist"
+              }
+            }
+          },
+          {
+            "name": "curveKind",
+            "type": {
+              "option": {
+                "defined": "CurveKind"
+              }
+            }
+          },
+          {
+            "name": "completionPolicy",
+            "type": {
+              "option": {
+                "defined": "CompletionPolicy"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "floorFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "decay",
+            "type": {
+              "defined": "FeeDecay"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GlobalAuthorityInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "globalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "withdrawAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GlobalSettingsInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tradeFeeBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "createdMintDecimals",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "launchFeeRefundBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxLaunchLeadTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "option": {
+                "defined": "ProgramStatus"
+              }
+            }
+          },
+          {
+            "name": "ammProgram",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "platformVestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "cexVestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "caps",
+            "type": {
+              "option": {
+                "defined": "SettingsCaps"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettingsCaps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxTradeFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "maxLaunchFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxCreatedMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "maxLaunchLeadTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PresaleTerms",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "openTime",
+            "type": "i64"
+          },
+          {
+            "name": "closeTime",
+            "type": "i64"
+          },
+          {
+            "name": "maxContributionPerWallet",
+            "type": "u64"
+          },
+          {
+            "name": "presaleSolCap",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ConstantProduct"
+          },
+          {
+            "name": "Linear",
+            "fields": [
+              {
+                "defined": "LinearCurve"
+              }
+            ]
+          },
+          {
+            "name": "Exponential",
+            "fields": [
+              {
+                "defined": "ExponentialCurve"
+              }
+            ]
+          },
+          {
+            "name": "PiecewiseLinear",
+            "fields": [
+              {
+                "defined": "PiecewiseLinearCurve"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingAnchor",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "StartTime"
           },
           {
-            "name": "initialVirtualTokenReserves",
-            "type": "u128"
+            "name": "CompletionTime"
           },
           {
-            "name": "realSolReserves",
-            "type": "u64"
+            "name": "Timestamp",
+            "fields": [
+              "i64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompletionPolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
           },
           {
-            "name": "realTokenReserves",
-            "type": "u64"
+            "name": "GraduationLiquidity"
           },
           {
-            "name": "tokenTotalSupply",
-            "type": "u64"
+            "name": "PlatformVault"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeDecay",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
           },
           {
-            "name": "creatorVestedSupply",
-            "type": "u64"
+            "name": "Stepwise",
+            "fields": [
+              {
+                "name": "steps",
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuthorityRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Global"
           },
           {
-            "name": "presaleSupply",
-            "type": "u64"
+            "name": "Withdraw"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProgramStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Running"
           },
           {
-            "name": "bondingSupply",
-            "type": "u64"
+            "name": "SwapOnly"
           },
           {
-            "name": "cexSupply",
-            "type": "u64"
+            "name": "SwapOnlyNoLaunch"
           },
           {
-            "name": "launchBrandkitSupply",
-            "type": "u64"
+            "name": "Paused"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "GlobalUpdateEvent",
+      "fields": [
+        {
+          "name": "globalAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "ProgramStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "tradeFeeBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeRefundBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "createdMintDecimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "maxLaunchLeadTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "ammProgram",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "platformVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "cexVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "caps",
+          "type": {
+            "defined": "SettingsCaps"
+          },
+          "index": false
+        },
+        {
+          "name": "previousGlobalAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousWithdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousStatus",
+          "type": {
+            "defined": "ProgramStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "previousTradeFeeBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousLaunchFeeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousLaunchFeeRefundBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousCreatedMintDecimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "previousMaxLaunchLeadTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "previousAmmProgram",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPlatformVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "previousCexVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "previousCaps",
+          "type": {
+            "defined": "SettingsCaps"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreateEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "name",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "symbol",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "uri",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "virtualTokenReserves",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realTokenReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenTotalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solLaunchThreshold",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawEvent",
+      "fields": [
+        {
+          "name": "withdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalWithdrawn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousWithdrawTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "newWithdrawTime",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TradeEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "requestedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "filledAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "isBuy",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "virtualTokenReserves",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realTokenReserves",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CompleteEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "virtualTokenReserves",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realTokenReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GraduationEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ammProgram",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lpAmountBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchScheduledEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "previousStartTime",
+          "type": {
+            "option": "i64"
           },
-          {
-            "name": "lifetimeBrandkitSupply",
-            "type": "u64"
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "LaunchCancelledEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "tokensBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeRefundLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CurveFailedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "graduationDeadline",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "presaleSolRaised",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokensBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefundEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PresaleContributionEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "contributed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "presaleSolRaised",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PresaleClaimEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "contributed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "VestingClaimEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "claimedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "initialVestedSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BrandkitClaimEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "brandAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "launchAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lifetimeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchBrandkitClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lifetimeBrandkitClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PlatformTokensClaimEvent",
+      "fields": [
+        {
+          "name": "withdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "platformVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousDistributionTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "newDistributionTime",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CexReleaseEvent",
+      "fields": [
+        {
+          "name": "platformAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cexVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exchangeWallet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "released",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalReleased",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferProposedEvent",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
           },
-          {
-            "name": "platformSupply",
-            "type": "u64"
+          "index": false
+        },
+        {
+          "name": "currentAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferAcceptedEvent",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
           },
-          {
-            "name": "solLaunchThreshold",
-            "type": "u64"
+          "index": false
+        },
+        {
+          "name": "previousAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferCancelledEvent",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
           },
-          {
-            "name": "startTime",
-            "type": "i64"
+          "index": false
+        },
+        {
+          "name": "currentAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SettingsScheduledEvent",
+      "fields": [
+        {
+          "name": "globalAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settings",
+          "type": {
+            "defined": "GlobalSettingsInput"
           },
-          {
-            "name": "complete",
-            "type": "bool"
+          "index": false
+        },
+        {
+          "name": "effectiveTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SettingsExecutedEvent",
+      "fields": [
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settings",
+          "type": {
+            "defined": "GlobalSettingsInput"
           },
-          {
-            "name": "vestingTerms",
-            "type": {
-              "defined": "VestingTerms"
-            }
+          "index": false
+        },
+        {
+          "name": "effectiveTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SettingsCancelledEvent",
+      "fields": [
+        {
+          "name": "globalAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settings",
+          "type": {
+            "defined": "GlobalSettingsInput"
           },
-          {
-            "name": "allocation",
-            "type": {
-              "defined": "AllocationData"
-            }
+          "index": false
+        },
+        {
+          "name": "effectiveTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SkimEvent",
+      "fields": [
+        {
+          "name": "withdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "platformVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UnsoldTokensEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "policy",
+          "type": {
+            "defined": "CompletionPolicy"
           },
-          {
-            "name": "bump",
-            "type": "u8"
+          "index": false
+        },
+        {
+          "name": "unsoldTokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "graduationTokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidGlobalAuthority",
+      "msg": "Invalid Global Authority"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidWithdrawAuthority",
+      "msg": "Invalid Withdraw Authority"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidArgument",
+      "msg": "Invalid Argument"
+    },
+    {
+      "code": 6003,
+      "name": "AlreadyInitialized",
+      "msg": "Global Already Initialized"
+    },
+    {
+      "code": 6004,
+      "name": "NotInitialized",
+      "msg": "Global Not Initialized"
+    },
+    {
+      "code": 6005,
+      "name": "ProgramNotRunning",
+      "msg": "Not in Running State"
+    },
+    {
+      "code": 6006,
+      "name": "BondingCurveComplete",
+      "msg": "Bonding Curve Complete"
+    },
+    {
+      "code": 6007,
+      "name": "BondingCurveNotComplete",
+      "msg": "Bonding Curve Not Complete"
+    },
+    {
+      "code": 6008,
+      "name": "InsufficientUserTokens",
+      "msg": "Insufficient User Tokens"
+    },
+    {
+      "code": 6009,
+      "name": "InsufficientCurveTokens",
+      "msg": "Insufficient Curve Tokens"
+    },
+    {
+      "code": 6010,
+      "name": "InsufficientUserSOL",
+      "msg": "Insufficient user SOL"
+    },
+    {
+      "code": 6011,
+      "name": "SlippageExceeded",
+      "msg": "Slippage Exceeded"
+    },
+    {
+      "code": 6012,
+      "name": "MinSwap",
+      "msg": "Swap exactInAmount is 0"
+    },
+    {
+      "code": 6013,
+      "name": "BuyFailed",
+      "msg": "Buy Failed"
+    },
+    {
+      "code": 6014,
+      "name": "SellFailed",
+      "msg": "Sell Failed"
+    },
+    {
+      "code": 6015,
+      "name": "BondingCurveInvariant",
+      "msg": "Bonding Curve Invariant Failed"
+    },
+    {
+      "code": 6016,
+      "name": "CurveNotStarted",
+      "msg": "Curve Not Started"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidAllocation",
+      "msg": "Invalid Allocation Data supplied, basis points must add up to 10000"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidStartTime",
+      "msg": "Start time is in the past"
+    },
+    {
+      "code": 6019,
+      "name": "SOLLaunchThresholdTooHigh",
+      "msg": "SOL Launch threshold not attainable even if all tokens are sold"
+    },
+    {
+      "code": 6020,
+      "name": "NoMaxAttainableSOL",
+      "msg": "Cannot compute max_attainable_sol"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidCreatorAuthority",
+      "msg": "Invalid Creator Authority"
+    },
+    {
+      "code": 6022,
+      "name": "CliffNotReached",
+      "msg": "Cliff not yet reached"
+    },
+    {
+      "code": 6023,
+      "name": "VestingPeriodNotOver",
+      "msg": "Vesting period not yet over"
+    },
+    {
+      "code": 6024,
+      "name": "NoFeesToWithdraw",
+      "msg": "Not enough fees to withdraw"
+    },
+    {
+      "code": 6025,
+      "name": "BondingCurveGraduated",
+      "msg": "Bonding Curve Already Graduated"
+    },
+    {
+      "code": 6026,
+      "name": "AmmProgramNotSet",
+      "msg": "AMM program not set on Global"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidAmmProgram",
+      "msg": "Invalid AMM program"
+    },
+    {
+      "code": 6028,
+      "name": "GraduationFailed",
+      "msg": "Graduation Failed"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidLpTokenAccount",
+      "msg": "Invalid LP token account"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidPresaleTerms",
+      "msg": "Invalid presale terms"
+    },
+    {
+      "code": 6031,
+      "name": "PresaleNotOpen",
+      "msg": "Presale is not open"
+    },
+    {
+      "code": 6032,
+      "name": "PresaleWalletCapExceeded",
+      "msg": "Presale contribution exceeds the per wallet cap"
+    },
+    {
+      "code": 6033,
+      "name": "PresaleAlreadyClaimed",
+      "msg": "Presale tokens already claimed"
+    },
+    {
+      "code": 6034,
+      "name": "NoPresaleTokens",
+      "msg": "No presale tokens to claim"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidBrandAuthority",
+      "msg": "Invalid Brand Authority"
+    },
+    {
+      "code": 6036,
+      "name": "NoBrandkitTokens",
+      "msg": "No brandkit tokens to claim"
+    },
+    {
+      "code": 6037,
+      "name": "NoPlatformTokens",
+      "msg": "No platform tokens to claim"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidPlatformAuthority",
+      "msg": "Invalid Platform Authority"
+    },
+    {
+      "code": 6039,
+      "name": "CexReleaseExceedsVested",
+      "msg": "Release exceeds vested CEX tokens"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidSwapMode",
+      "msg": "Exactly one of exactInAmount and exactOutAmount must be set"
+    },
+    {
+      "code": 6041,
+      "name": "CreateDisabled",
+      "msg": "Creating bonding curves is disabled"
+    },
+    {
+      "code": 6042,
+      "name": "BuyDisabled",
+      "msg": "Buying is disabled"
+    },
+    {
+      "code": 6043,
+      "name": "SellDisabled",
+      "msg": "Selling is disabled"
+    },
+    {
+      "code": 6044,
+      "name": "LaunchDisabled",
+      "msg": "Launching is disabled"
+    },
+    {
+      "code": 6045,
+      "name": "ClaimDisabled",
+      "msg": "Claiming is disabled"
+    },
+    {
+      "code": 6046,
+      "name": "FeeWithdrawalDisabled",
+      "msg": "Fee withdrawal is disabled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidPendingAuthority",
+      "msg": "Signer is not the pending authority"
+    },
+    {
+      "code": 6048,
+      "name": "NoPendingAuthority",
+      "msg": "No pending authority transfer"
+    },
+    {
+      "code": 6049,
+      "name": "TradeFeeTooHigh",
+      "msg": "Trade fee exceeds the maximum trade fee"
+    },
+    {
+      "code": 6050,
+      "name": "LaunchFeeTooHigh",
+      "msg": "Launch fee exceeds the maximum launch fee"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidMintDecimals",
+      "msg": "Mint decimals must be between 1 and the maximum mint decimals"
+    },
+    {
+      "code": 6052,
+      "name": "SettingsChangeRequiresTimelock",
+      "msg": "Fee and status changes must be scheduled"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidSettingsEffectiveTime",
+      "msg": "Effective time is before the settings timelock"
+    },
+    {
+      "code": 6054,
+      "name": "NoPendingSettings",
+      "msg": "No pending settings change"
+    },
+    {
+      "code": 6055,
+      "name": "SettingsNotYetEffective",
+      "msg": "Pending settings change is not yet effective"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidMintExtensions",
+      "msg": "Mint carries unsupported extensions"
+    },
+    {
+      "code": 6057,
+      "name": "MissingMetadataAccounts",
+      "msg": "Metaplex metadata accounts are required for SPL Token mints"
+    },
+    {
+      "code": 6058,
+      "name": "NoVestedTokens",
+      "msg": "No vested tokens to claim"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidVestingTerms",
+      "msg": "Invalid vesting anchor"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidGraduationDeadline",
+      "msg": "Graduation deadline must be after the curve start"
+    },
+    {
+      "code": 6061,
+      "name": "GraduationDeadlinePassed",
+      "msg": "Graduation deadline has passed"
+    },
+    {
+      "code": 6062,
+      "name": "GraduationDeadlineNotReached",
+      "msg": "Graduation deadline not yet reached"
+    },
+    {
+      "code": 6063,
+      "name": "BondingCurveFailed",
+      "msg": "Bonding Curve Failed"
+    },
+    {
+      "code": 6064,
+      "name": "BondingCurveNotFailed",
+      "msg": "Bonding Curve Not Failed"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidLaunchLeadTime",
+      "msg": "Launch lead time exceeds the maximum launch lead time"
+    },
+    {
+      "code": 6066,
+      "name": "LaunchAlreadyStarted",
+      "msg": "Launch has already started"
+    },
+    {
+      "code": 6067,
+      "name": "LaunchHasContributions",
+      "msg": "Launch has presale contributions"
+    },
+    {
+      "code": 6068,
+      "name": "RescheduleDisabled",
+      "msg": "Rescheduling launches is disabled"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidLaunchProtection",
+      "msg": "Invalid launch protection settings"
+    },
+    {
+      "code": 6070,
+      "name": "LaunchProtectionLimitExceeded",
+      "msg": "Buy exceeds the launch protection limits"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidFeeSchedule",
+      "msg": "Invalid fee schedule"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidAllowlist",
+      "msg": "Invalid allowlist"
+    },
+    {
+      "code": 6073,
+      "name": "NotAllowlisted",
+      "msg": "Wallet is not allowlisted"
+    },
+    {
+      "code": 6074,
+      "name": "AllowlistAllowanceExceeded",
+      "msg": "Buy exceeds the allowlist allowance"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidCurveKind",
+      "msg": "Invalid curve kind"
+    },
+    {
+      "code": 6076,
+      "name": "NoSurplusLamports",
+      "msg": "No surplus lamports to skim"
+    },
+    {
+      "code": 6077,
+      "name": "InvalidLaunchFeeRefund",
+      "msg": "Launch fee refund must not exceed 100%"
+    },
+    {
+      "code": 6078,
+      "name": "LaunchAlreadyTraded",
+      "msg": "Launch has already been traded"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidAmmPool",
+      "msg": "Pool or LP mint does not belong to the AMM"
+    },
+    {
+      "code": 6080,
+      "name": "PresaleCapExceeded",
+      "msg": "Contribution exceeds the presale SOL cap"
+    },
+    {
+      "code": 6081,
+      "name": "InvalidSettingsCaps",
+      "msg": "Invalid settings caps"
+    },
+    {
+      "code": 6082,
+      "name": "MissingUserPosition",
+      "msg": "User position is required for buys during launch protection or the allowlist phase"
+    }
+  ]
+};
+
+export const IDL: BillyBondingCurve = {
+  "version": "0.1.0",
+  "name": "billy_bonding_curve",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "GlobalSettingsInput"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setParams",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "newWithdrawAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "GlobalSettingsInput"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "global",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "status",
-            "type": {
-              "defined": "ProgramStatus"
-            }
-          },
-          {
-            "name": "initialized",
-            "type": "bool"
-          },
-          {
-            "name": "globalAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "withdrawAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "tradeFeeBps",
-            "type": "u64"
-          },
-          {
-            "name": "launchFeeLamports",
-            "type": "u64"
-          },
-          {
-            "name": "createdMintDecimals",
-            "type": "u8"
+      "name": "scheduleSettings",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "GlobalSettingsInput"
           }
-        ]
-      }
+        },
+        {
+          "name": "effectiveTime",
+          "type": "i64"
+        }
+      ]
     },
     {
-      "name": "creatorVault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "initialVestedSupply",
-            "type": "u64"
-          },
-          {
-            "name": "lastDistribution",
-            "type": "i64"
+      "name": "executeSettings",
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelSettings",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptAuthority",
+      "accounts": [
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelAuthorityTransfer",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createBondingCurve",
+      "accounts": [
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "brandAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "brandVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "brandVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "CreateBondingCurveParams"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "presaleVault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "initialVestedSupply",
-            "type": "u64"
+      "name": "swap",
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userPosition",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "SwapParams"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "platformVault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "initialVestedSupply",
-            "type": "u64"
-          },
-          {
-            "name": "lastDistribution",
-            "type": "i64"
-          },
-          {
-            "name": "lastFeeWithdrawal",
-            "type": "i64"
-          },
-          {
-            "name": "feesWithdrawn",
-            "type": "u64"
+      "name": "quote",
+      "accounts": [
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "SwapParams"
           }
-        ]
+        }
+      ],
+      "returns": {
+        "defined": "SwapQuote"
       }
     },
     {
-      "name": "brandVault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "launchBrandkitSupply",
-            "type": "u64"
-          },
-          {
-            "name": "lifetimeBrandkitSupply",
-            "type": "u64"
-          },
-          {
-            "name": "initialVestedSupply",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+      "name": "claimCreatorVesting",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "creatorVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
-      "name": "SwapParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "baseIn",
-            "type": "bool"
-          },
-          {
-            "name": "exactInAmount",
-            "type": "u64"
-          },
-          {
-            "name": "minOutAmount",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "claimBrandkit",
+      "accounts": [
+        {
+          "name": "brandAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "brandVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "brandVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "AllocationDataParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "cex",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "launchBrandkit",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "lifetimeBrandkit",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "platform",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "presale",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "poolReserve",
-            "type": {
-              "option": "u64"
-            }
-          }
-        ]
-      }
+      "name": "contributePresale",
+      "accounts": [
+        {
+          "name": "contributor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presalePosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "solAmount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "AllocationData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "u64"
-          },
-          {
-            "name": "cex",
-            "type": "u64"
-          },
-          {
-            "name": "launchBrandkit",
-            "type": "u64"
-          },
-          {
-            "name": "lifetimeBrandkit",
-            "type": "u64"
-          },
-          {
-            "name": "platform",
-            "type": "u64"
-          },
-          {
-            "name": "presale",
-            "type": "u64"
-          },
-          {
-            "name": "poolReserve",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "claimPresale",
+      "accounts": [
+        {
+          "name": "contributor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presalePosition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "VestingTerms",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "cliff",
-            "type": "i64"
-          },
-          {
-            "name": "duration",
-            "type": "i64"
-          }
-        ]
-      }
+      "name": "withdrawFees",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "CreateBondingCurveParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          },
-          {
-            "name": "startTime",
-            "type": {
-              "option": "i64"
-            }
-          },
-          {
-            "name": "tokenTotalSupply",
-            "type": "u64"
-          },
-          {
-            "name": "solLaunchThreshold",
-            "type": "u64"
-          },
-          {
-            "name": "virtualTokenMultiplierBps",
-            "type": "u64"
-          },
-          {
-            "name": "virtualSolReserves",
-            "type": "u64"
-          },
-          {
-            "name": "allocation",
-            "type": {
-              "defined": "AllocationDataParams"
-            }
-          },
-          {
-            "name": "vestingTerms",
-            "type": {
-              "option": {
-                "defined": "VestingTerms"
-              }
-            }
-          }
-        ]
-      }
+      "name": "skim",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "GlobalAuthorityInput",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "globalAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "withdrawAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          }
-        ]
-      }
+      "name": "claimPlatformTokens",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "GlobalSettingsInput",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tradeFeeBps",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "createdMintDecimals",
-            "type": {
-              "option": "u8"
-            }
-          },
-          {
-            "name": "launchFeeLamports",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "status",
-            "type": {
-              "option": {
-                "defined": "ProgramStatus"
-              }
-            }
-          }
-        ]
-      }
+      "name": "releaseCexTokens",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cexVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "exchangeWallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "exchangeTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "ProgramStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Running"
-          },
-          {
-            "name": "SwapOnly"
-          },
-          {
-            "name": "SwapOnlyNoLaunch"
-          },
-          {
-            "name": "Paused"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
-    {
-      "name": "GlobalUpdateEvent",
-      "fields": [
+      "name": "graduate",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "globalAuthority",
-          "type": "publicKey",
-          "index": false
+          "name": "liquidityProvider",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "withdrawAuthority",
-          "type": "publicKey",
-          "index": false
+          "name": "liquidityProviderTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "tradeFeeBps",
-          "type": "u64",
-          "index": false
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "launchFeeLamports",
-          "type": "u64",
-          "index": false
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "createdMintDecimals",
-          "type": "u8",
-          "index": false
+          "name": "liquidityProviderLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ammProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "CreateEvent",
-      "fields": [
+      "name": "failCurve",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "mint",
-          "type": "publicKey",
-          "index": false
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "creator",
-          "type": "publicKey",
-          "index": false
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "name",
-          "type": "string",
-          "index": false
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "symbol",
-          "type": "string",
-          "index": false
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "uri",
-          "type": "string",
-          "index": false
+          "name": "creatorVault",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "startTime",
-          "type": "i64",
-          "index": false
+          "name": "creatorVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "virtualSolReserves",
-          "type": "u64",
-          "index": false
+          "name": "cexVault",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "virtualTokenReserves",
-          "type": "u128",
-          "index": false
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "realSolReserves",
-          "type": "u64",
-          "index": false
+          "name": "brandVault",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "realTokenReserves",
-          "type": "u64",
-          "index": false
+          "name": "brandVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "tokenTotalSupply",
-          "type": "u64",
-          "index": false
+          "name": "platformVault",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "solLaunchThreshold",
-          "type": "u64",
-          "index": false
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "WithdrawEvent",
-      "fields": [
+      "name": "refund",
+      "accounts": [
         {
-          "name": "withdrawAuthority",
-          "type": "publicKey",
-          "index": false
+          "name": "user",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "mint",
-          "type": "publicKey",
-          "index": false
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "feeVault",
-          "type": "publicKey",
-          "index": false
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "withdrawn",
-          "type": "u64",
-          "index": false
+          "name": "userTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "totalWithdrawn",
-          "type": "u64",
-          "index": false
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "previousWithdrawTime",
-          "type": "i64",
-          "index": false
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "newWithdrawTime",
-          "type": "i64",
-          "index": false
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenAmount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "TradeEvent",
-      "fields": [
+      "name": "rescheduleLaunch",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "presaleVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "startTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "cancelLaunch",
+      "accounts": [
         {
-          "name": "mint",
-          "type": "publicKey",
-          "index": false
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "solAmount",
-          "type": "u64",
-          "index": false
+          "name": "global",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "tokenAmount",
-          "type": "u64",
-          "index": false
+          "name": "withdrawAuthority",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "feeLamports",
-          "type": "u64",
-          "index": false
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "isBuy",
-          "type": "bool",
-          "index": false
+          "name": "bondingCurve",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "user",
-          "type": "publicKey",
-          "index": false
+          "name": "bondingCurveTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "timestamp",
-          "type": "i64",
-          "index": false
+          "name": "creatorVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "virtualSolReserves",
-          "type": "u64",
-          "index": false
+          "name": "creatorVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "virtualTokenReserves",
-          "type": "u128",
-          "index": false
+          "name": "presaleVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "realSolReserves",
-          "type": "u64",
-          "index": false
+          "name": "presaleVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "realTokenReserves",
-          "type": "u64",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "CompleteEvent",
-      "fields": [
+          "name": "cexVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "user",
-          "type": "publicKey",
-          "index": false
+          "name": "cexVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "mint",
-          "type": "publicKey",
-          "index": false
+          "name": "brandVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "virtualSolReserves",
-          "type": "u64",
-          "index": false
+          "name": "brandVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "virtualTokenReserves",
-          "type": "u128",
-          "index": false
+          "name": "platformVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "realSolReserves",
-          "type": "u64",
-          "index": false
+          "name": "platformVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "realTokenReserves",
-          "type": "u64",
-          "index": false
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "timestamp",
-          "type": "i64",
-          "index": false
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     }
   ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidGlobalAuthority",
-      "msg": "Invalid Global Authority"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidWithdrawAuthority",
-      "msg": "Invalid Withdraw Authority"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidArgument",
-      "msg": "Invalid Argument"
-    },
-    {
-      "code": 6003,
-      "name": "AlreadyInitialized",
-      "msg": "Global Already Initialized"
-    },
-    {
-      "code": 6004,
-      "name": "NotInitialized",
-      "msg": "Global Not Initialized"
-    },
-    {
-      "code": 6005,
-      "name": "ProgramNotRunning",
-      "msg": "Not in Running State"
-    },
-    {
-      "code": 6006,
-      "name": "BondingCurveComplete",
-      "msg": "Bonding Curve Complete"
-    },
-    {
-      "code": 6007,
-      "name": "BondingCurveNotComplete",
-      "msg": "Bonding Curve Not Complete"
-    },
-    {
-      "code": 6008,
-      "name": "InsufficientUserTokens",
-      "msg": "Insufficient User Tokens"
-    },
-    {
-      "code": 6009,
-      "name": "InsufficientCurveTokens",
-      "msg": "Insufficient Curve Tokens"
-    },
-    {
-      "code": 6010,
-      "name": "InsufficientUserSOL",
-      "msg": "Insufficient user SOL"
-    },
-    {
-      "code": 6011,
-      "name": "SlippageExceeded",
-      "msg": "Slippage Exceeded"
-    },
+  "accounts": [
     {
-      "code": 6012,
-      "name": "MinSwap",
-      "msg": "Swap exactInAmount is 0"
+      "name": "bondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "platformAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "brandAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "virtualTokenMultiplierBps",
+            "type": "u64"
+          },
+          {
+            "name": "virtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "virtualTokenReserves",
+            "type": "u128"
+          },
+          {
+            "name": "initialVirtualTokenReserves",
+            "type": "u128"
+          },
+          {
+            "name": "realSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "realTokenReserves",
+            "type": "u64"
+          },
+          {
+            "name": "tokenTotalSupply",
+            "type": "u64"
+          },
+          {
+            "name": "creatorVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "presaleSupply",
+            "type": "u64"
+          },
+          {
+            "name": "bondingSupply",
+            "type": "u64"
+          },
+          {
+            "name": "cexSupply",
+            "type": "u64"
+          },
+          {
+            "name": "launchBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "platformSupply",
+            "type": "u64"
+          },
+          {
+            "name": "solLaunchThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "presaleSolRaised",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "complete",
+            "type": "bool"
+          },
+          {
+            "name": "graduated",
+            "type": "bool"
+          },
+          {
+            "name": "traded",
+            "type": "bool"
+          },
+          {
+            "name": "completionTime",
+            "type": "i64"
+          },
+          {
+            "name": "graduationDeadline",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "failed",
+            "type": "bool"
+          },
+          {
+            "name": "refundTokenSupply",
+            "type": "u64"
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "launchProtection",
+            "type": {
+              "defined": "LaunchProtection"
+            }
+          },
+          {
+            "name": "feeSchedule",
+            "type": {
+              "option": {
+                "defined": "FeeSchedule"
+              }
+            }
+          },
+          {
+            "name": "allowlist",
+            "type": {
+              "option": {
+                "defined": "Allowlist"
+              }
+            }
+          },
+          {
+            "name": "curveKind",
+            "type": {
+              "defined": "CurveKind"
+            }
+          },
+          {
+            "name": "completionPolicy",
+            "type": {
+              "defined": "CompletionPolicy"
+            }
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "allocation",
+            "type": {
+              "defined": "AllocationData"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "BuyFailed",
-      "msg": "Buy Failed"
+      "name": "global",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "status",
+            "type": {
+              "defined": "ProgramStatus"
+            }
+          },
+          {
+            "name": "initialized",
+            "type": "bool"
+          },
+          {
+            "name": "globalAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "withdrawAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingGlobalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "pendingWithdrawAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "tradeFeeBps",
+            "type": "u64"
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "launchFeeRefundBps",
+            "type": "u64"
+          },
+          {
+            "name": "createdMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "maxLaunchLeadTime",
+            "type": "i64"
+          },
+          {
+            "name": "ammProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "platformVestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "cexVestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "caps",
+            "type": {
+              "defined": "SettingsCaps"
+            }
+          },
+          {
+            "name": "pendingSettings",
+            "type": {
+              "option": {
+                "defined": "GlobalSettingsInput"
+              }
+            }
+          },
+          {
+            "name": "pendingSettingsEffectiveTime",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "SellFailed",
-      "msg": "Sell Failed"
+      "name": "userPosition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "protectedBuyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistBuyLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "BondingCurveInvariant",
-      "msg": "Bonding Curve Invariant Failed"
+      "name": "presalePosition",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "contributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "contributed",
+            "type": "u64"
+          },
+          {
+            "name": "claimed",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "CurveNotStarted",
-      "msg": "Curve Not Started"
+      "name": "creatorVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lastDistribution",
+            "type": "i64"
+          },
+          {
+            "name": "claimedAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "InvalidAllocation",
-      "msg": "Invalid Allocation Data supplied, basis points must add up to 10000"
+      "name": "presaleVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "presaleTerms",
+            "type": {
+              "defined": "PresaleTerms"
+            }
+          },
+          {
+            "name": "totalClaimed",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "InvalidStartTime",
-      "msg": "Start time is in the past"
+      "name": "cexVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "tokensReleased",
+            "type": "u64"
+          },
+          {
+            "name": "lastRelease",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "SOLLaunchThresholdTooHigh",
-      "msg": "SOL Launch threshold not attainable even if all tokens are sold"
+      "name": "platformVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lastDistribution",
+            "type": "i64"
+          },
+          {
+            "name": "lastFeeWithdrawal",
+            "type": "i64"
+          },
+          {
+            "name": "feesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "tokensClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "unsoldTokenAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "NoMaxAttainableSOL",
-      "msg": "Cannot compute max_attainable_sol"
-    },
+      "name": "brandVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "launchBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkitSupply",
+            "type": "u64"
+          },
+          {
+            "name": "initialVestedSupply",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeVestingTerms",
+            "type": {
+              "defined": "VestingTerms"
+            }
+          },
+          {
+            "name": "launchBrandkitClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkitClaimed",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
     {
-      "code": 6021,
-      "name": "InvalidCreatorAuthority",
-      "msg": "Invalid Creator Authority"
+      "name": "InitializePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "solAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "CliffNotReached",
-      "msg": "Cliff not yet reached"
+      "name": "SwapQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "amountOut",
+            "type": "u64"
+          },
+          {
+            "name": "feeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u64"
+          },
+          {
+            "name": "virtualSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "virtualTokenReserves",
+            "type": "u128"
+          },
+          {
+            "name": "realSolReserves",
+            "type": "u64"
+          },
+          {
+            "name": "realTokenReserves",
+            "type": "u64"
+          },
+          {
+            "name": "spotPriceBefore",
+            "type": "u128"
+          },
+          {
+            "name": "spotPriceAfter",
+            "type": "u128"
+          },
+          {
+            "name": "completesCurve",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "VestingPeriodNotOver",
-      "msg": "Vesting period not yet over"
+      "name": "SwapParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseIn",
+            "type": "bool"
+          },
+          {
+            "name": "exactInAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minOutAmount",
+            "type": "u64"
+          },
+          {
+            "name": "exactOutAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxInAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistProof",
+            "type": {
+              "option": {
+                "defined": "AllowlistProof"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "NoFeesToWithdraw",
-      "msg": "Not enough fees to withdraw"
-    }
-  ]
-};
-
-export const IDL: BillyBondingCurve = {
-  "version": "0.1.0",
-  "name": "billy_bonding_curve",
-  "instructions": [
-    {
-      "name": "initialize",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "global",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": "GlobalSettingsInput"
+      "name": "AllocationDataParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "cex",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "launchBrandkit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "lifetimeBrandkit",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "platform",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "presale",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "poolReserve",
+            "type": {
+              "option": "u64"
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "setParams",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "global",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "newAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "newWithdrawAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "isOptional": true
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": "GlobalSettingsInput"
+      "name": "AllocationData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "u64"
+          },
+          {
+            "name": "cex",
+            "type": "u64"
+          },
+          {
+            "name": "launchBrandkit",
+            "type": "u64"
+          },
+          {
+            "name": "lifetimeBrandkit",
+            "type": "u64"
+          },
+          {
+            "name": "platform",
+            "type": "u64"
+          },
+          {
+            "name": "presale",
+            "type": "u64"
+          },
+          {
+            "name": "poolReserve",
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "createBondingCurve",
-      "accounts": [
-        {
-          "name": "mint",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "creatorVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creatorVaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "presaleVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "presaleVaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "brandAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "brandVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "brandVaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "platformVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "platformVaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bondingCurve",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bondingCurveTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "global",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "metadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": "CreateBondingCurveParams"
+      "name": "Allowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllowlistProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowance",
+            "type": "u64"
+          },
+          {
+            "name": "proof",
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "swap",
-      "accounts": [
-        {
-          "name": "user",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "global",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bondingCurve",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bondingCurveTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "platformVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": "SwapParams"
+      "name": "LinearCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "claimCreatorVesting",
-      "accounts": [
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "creatorVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creatorVaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bondingCurve",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "global",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+      "name": "ExponentialCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "growthBps",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "withdrawFees",
-      "accounts": [
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "global",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "platformVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "name": "CurveSegment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "supplyBps",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
-      "name": "bondingCurve",
+      "name": "PiecewiseLinearCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "segments",
+            "type": {
+              "vec": {
+                "defined": "CurveSegment"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingTerms",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
-            "type": "publicKey"
+            "name": "cliff",
+            "type": "i64"
           },
           {
-            "name": "creator",
-            "type": "publicKey"
+            "name": "duration",
+            "type": "i64"
           },
           {
-            "name": "platformAuthority",
-            "type": "publicKey"
-          },
+            "name": "anchor",
+            "type": {
+              "defined": "VestingAnchor"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LaunchProtection",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "brandAuthority",
-            "type": "publicKey"
+            "name": "window",
+            "type": "i64"
           },
           {
-            "name": "virtualTokenMultiplierBps",
+            "name": "maxBuyLamports",
             "type": "u64"
           },
           {
-            "name": "virtualSolReserves",
+            "name": "maxWalletLamports",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateBondingCurveParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
           },
           {
-            "name": "virtualTokenReserves",
-            "type": "u128"
+            "name": "symbol",
+            "type": "string"
           },
           {
-            "name": "initialVirtualTokenReserves",
-            "type": "u128"
+            "name": "uri",
+            "type": "string"
           },
           {
-            "name": "realSolReserves",
-            "type": "u64"
+            "name": "startTime",
+            "type": {
+              "option": "i64"
+            }
           },
           {
-            "name": "realTokenReserves",
-            "type": "u64"
+            "name": "graduationDeadline",
+            "type": {
+              "option": "i64"
+            }
           },
           {
             "name": "tokenTotalSupply",
             "type": "u64"
           },
           {
-            "name": "creatorVestedSupply",
+            "name": "solLaunchThreshold",
             "type": "u64"
           },
           {
-            "name": "presaleSupply",
+            "name": "virtualTokenMultiplierBps",
             "type": "u64"
           },
           {
-            "name": "bondingSupply",
+            "name": "virtualSolReserves",
             "type": "u64"
           },
           {
-            "name": "cexSupply",
+            "name": "allocation",
+            "type": {
+              "defined": "AllocationDataParams"
+            }
+          },
+          {
+            "name": "vestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "presaleTerms",
+            "type": {
+              "option": {
+                "defined": "PresaleTerms"
+              }
+            }
+          },
+          {
+            "name": "brandkitVestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "launchProtection",
+            "type": {
+              "option": {
+                "defined": "LaunchProtection"
+              }
+            }
+          },
+          {
+            "name": "feeSchedule",
+            "type": {
+              "option": {
+                "defined": "FeeSchedule"
+              }
+            }
+          },
+          {
+            "name": "allowlist",
+            "type": {
+              "option": {
+                "defined": "Allowlist"
+              }
+            }
+          },
+          {
+            "name": "curveKind",
+            "type": {
+              "option": {
+                "defined": "CurveKind"
+              }
+            }
+          },
+          {
+            "name": "completionPolicy",
+            "type": {
+              "option": {
+                "defined": "CompletionPolicy"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialFeeBps",
             "type": "u64"
           },
           {
-            "name": "launchBrandkitSupply",
+            "name": "floorFeeBps",
             "type": "u64"
           },
           {
-            "name": "lifetimeBrandkitSupply",
+            "name": "duration",
+            "type": "i64"
+          },
+          {
+            "name": "decay",
+            "type": {
+              "defined": "FeeDecay"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GlobalAuthorityInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "globalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "withdrawAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GlobalSettingsInput",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tradeFeeBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "createdMintDecimals",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "launchFeeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "launchFeeRefundBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxLaunchLeadTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "option": {
+                "defined": "ProgramStatus"
+              }
+            }
+          },
+          {
+            "name": "ammProgram",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "platformVestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "cexVestingTerms",
+            "type": {
+              "option": {
+                "defined": "VestingTerms"
+              }
+            }
+          },
+          {
+            "name": "caps",
+            "type": {
+              "option": {
+                "defined": "SettingsCaps"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettingsCaps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxTradeFeeBps",
             "type": "u64"
           },
           {
-            "name": "platformSupply",
+            "name": "maxLaunchFeeLamports",
             "type": "u64"
           },
           {
-            "name": "solLaunchThreshold",
+            "name": "maxCreatedMintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "maxLaunchLeadTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PresaleTerms",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "openTime",
+            "type": "i64"
+          },
+          {
+            "name": "closeTime",
+            "type": "i64"
+          },
+          {
+            "name": "maxContributionPerWallet",
             "type": "u64"
           },
           {
-            "name": "startTime",
-            "type": "i64"
-          },
+            "name": "presaleSolCap",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "complete",
-            "type": "bool"
+            "name": "ConstantProduct"
           },
           {
-            "name": "vestingTerms",
-            "type": {
-              "defined": "VestingTerms"
-            }
+            "name": "Linear",
+            "fields": [
+              {
+                "defined": "LinearCurve"
+              }
+            ]
           },
           {
-            "name": "allocation",
-            "type": {
-              "defined": "AllocationData"
-            }
+            "name": "Exponential",
+            "fields": [
+              {
+                "defined": "ExponentialCurve"
+              }
+            ]
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "PiecewiseLinear",
+            "fields": [
+              {
+                "defined": "PiecewiseLinearCurve"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "global",
+      "name": "VestingAnchor",
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "status",
-            "type": {
-              "defined": "ProgramStatus"
-            }
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "initialized",
-            "type": "bool"
+            "name": "StartTime"
           },
           {
-            "name": "globalAuthority",
-            "type": "publicKey"
+            "name": "CompletionTime"
           },
           {
-            "name": "withdrawAuthority",
-            "type": "publicKey"
-          },
+            "name": "Timestamp",
+            "fields": [
+              "i64"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompletionPolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "tradeFeeBps",
-            "type": "u64"
+            "name": "Burn"
           },
           {
-            "name": "launchFeeLamports",
-            "type": "u64"
+            "name": "GraduationLiquidity"
           },
           {
-            "name": "createdMintDecimals",
-            "type": "u8"
+            "name": "PlatformVault"
           }
         ]
       }
     },
     {
-      "name": "creatorVault",
+      "name": "FeeDecay",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "initialVestedSupply",
-            "type": "u64"
+            "name": "Linear"
           },
           {
-            "name": "lastDistribution",
-            "type": "i64"
+            "name": "Stepwise",
+            "fields": [
+              {
+                "name": "steps",
+                "type": "u32"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "presaleVault",
+      "name": "AuthorityRole",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "initialVestedSupply",
-            "type": "u64"
+            "name": "Global"
+          },
+          {
+            "name": "Withdraw"
           }
         ]
       }
     },
     {
-      "name": "platformVault",
+      "name": "ProgramStatus",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "initialVestedSupply",
-            "type": "u64"
+            "name": "Running"
           },
           {
-            "name": "lastDistribution",
-            "type": "i64"
+            "name": "SwapOnly"
           },
           {
-            "name": "lastFeeWithdrawal",
-            "type": "i64"
+            "name": "SwapOnlyNoLaunch"
           },
           {
-            "name": "feesWithdrawn",
-            "type": "u64"
+            "name": "Paused"
           }
         ]
       }
+    }
+  ],
+  "events": [
+    {
+      "name": "GlobalUpdateEvent",
+      "fields": [
+        {
+          "name": "globalAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "ProgramStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "tradeFeeBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeRefundBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "createdMintDecimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "maxLaunchLeadTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "ammProgram",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "platformVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "cexVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "caps",
+          "type": {
+            "defined": "SettingsCaps"
+          },
+          "index": false
+        },
+        {
+          "name": "previousGlobalAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousWithdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousStatus",
+          "type": {
+            "defined": "ProgramStatus"
+          },
+          "index": false
+        },
+        {
+          "name": "previousTradeFeeBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousLaunchFeeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousLaunchFeeRefundBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousCreatedMintDecimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "previousMaxLaunchLeadTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "previousAmmProgram",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPlatformVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "previousCexVestingTerms",
+          "type": {
+            "defined": "VestingTerms"
+          },
+          "index": false
+        },
+        {
+          "name": "previousCaps",
+          "type": {
+            "defined": "SettingsCaps"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreateEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "name",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "symbol",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "uri",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "virtualTokenReserves",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realTokenReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenTotalSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solLaunchThreshold",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "brandVault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "launchBrandkitSupply",
-            "type": "u64"
-          },
-          {
-            "name": "lifetimeBrandkitSupply",
-            "type": "u64"
-          },
-          {
-            "name": "initialVestedSupply",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+      "name": "WithdrawEvent",
+      "fields": [
+        {
+          "name": "withdrawAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feeVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalWithdrawn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "previousWithdrawTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "newWithdrawTime",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "SwapParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "baseIn",
-            "type": "bool"
-          },
-          {
-            "name": "exactInAmount",
-            "type": "u64"
-          },
-          {
-            "name": "minOutAmount",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "TradeEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "requestedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "filledAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "isBuy",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "virtualTokenReserves",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realTokenReserves",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "AllocationDataParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "cex",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "launchBrandkit",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "lifetimeBrandkit",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "platform",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "presale",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "poolReserve",
-            "type": {
-              "option": "u64"
-            }
-          }
-        ]
-      }
+      "name": "CompleteEvent",
+      "fields": [
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "virtualSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "virtualTokenReserves",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realTokenReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GraduationEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ammProgram",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lpAmountBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "AllocationData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "u64"
-          },
-          {
-            "name": "cex",
-            "type": "u64"
-          },
-          {
-            "name": "launchBrandkit",
-            "type": "u64"
-          },
-          {
-            "name": "lifetimeBrandkit",
-            "type": "u64"
-          },
-          {
-            "name": "platform",
-            "type": "u64"
-          },
-          {
-            "name": "presale",
-            "type": "u64"
+      "name": "LaunchScheduledEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "previousStartTime",
+          "type": {
+            "option": "i64"
           },
-          {
-            "name": "poolReserve",
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "VestingTerms",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "cliff",
-            "type": "i64"
-          },
-          {
-            "name": "duration",
-            "type": "i64"
-          }
-        ]
-      }
+      "name": "LaunchCancelledEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "tokensBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchFeeRefundLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "CreateBondingCurveParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "type": "string"
-          },
-          {
-            "name": "startTime",
-            "type": {
-              "option": "i64"
-            }
-          },
-          {
-            "name": "tokenTotalSupply",
-            "type": "u64"
-          },
-          {
-            "name": "solLaunchThreshold",
-            "type": "u64"
-          },
-          {
-            "name": "virtualTokenMultiplierBps",
-            "type": "u64"
-          },
-          {
-            "name": "virtualSolReserves",
-            "type": "u64"
-          },
-          {
-            "name": "allocation",
-            "type": {
-              "defined": "AllocationDataParams"
-            }
-          },
-          {
-            "name": "vestingTerms",
-            "type": {
-              "option": {
-                "defined": "VestingTerms"
-              }
-            }
-          }
-        ]
-      }
+      "name": "CurveFailedEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "graduationDeadline",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "presaleSolRaised",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokensBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefundEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "realSolReserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "GlobalAuthorityInput",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "globalAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "withdrawAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          }
-        ]
-      }
+      "name": "PresaleContributionEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "contributed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "presaleSolRaised",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "GlobalSettingsInput",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tradeFeeBps",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "createdMintDecimals",
-            "type": {
-              "option": "u8"
-            }
-          },
-          {
-            "name": "launchFeeLamports",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "status",
-            "type": {
-              "option": {
-                "defined": "ProgramStatus"
-              }
-            }
-          }
-        ]
-      }
+      "name": "PresaleClaimEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "contributor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "contributed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ProgramStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Running"
-          },
-          {
-            "name": "SwapOnly"
-          },
-          {
-            "name": "SwapOnlyNoLaunch"
-          },
-          {
-            "name": "Paused"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
+      "name": "VestingClaimEvent",
+      "fields": [
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "creator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "claimedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "initialVestedSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "GlobalUpdateEvent",
+      "name": "BrandkitClaimEvent",
       "fields": [
         {
-          "name": "globalAuthority",
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "brandAuthority",
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "launchAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lifetimeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "launchBrandkitClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lifetimeBrandkitClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PlatformTokensClaimEvent",
+      "fields": [
         {
           "name": "withdrawAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tradeFeeBps",
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "platformVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimed",
           "type": "u64",
           "index": false
         },
         {
-          "name": "launchFeeLamports",
+          "name": "totalClaimed",
           "type": "u64",
           "index": false
         },
         {
-          "name": "createdMintDecimals",
-          "type": "u8",
+          "name": "previousDistributionTime",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "newDistributionTime",
+          "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "CreateEvent",
+      "name": "CexReleaseEvent",
       "fields": [
+        {
+          "name": "platformAuthority",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "mint",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "creator",
+          "name": "cexVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exchangeWallet",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "released",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalReleased",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferProposedEvent",
+      "fields": [
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
+          },
+          "index": false
+        },
+        {
+          "name": "currentAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "name",
-          "type": "string",
+          "name": "pendingAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "symbol",
-          "type": "string",
+          "name": "timestamp",
+          "type": "i64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferAcceptedEvent",
+      "fields": [
         {
-          "name": "uri",
-          "type": "string",
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
+          },
           "index": false
         },
         {
-          "name": "startTime",
-          "type": "i64",
+          "name": "previousAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "virtualSolReserves",
-          "type": "u64",
+          "name": "newAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "virtualTokenReserves",
-          "type": "u128",
+          "name": "timestamp",
+          "type": "i64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferCancelledEvent",
+      "fields": [
         {
-          "name": "realSolReserves",
-          "type": "u64",
+          "name": "role",
+          "type": {
+            "defined": "AuthorityRole"
+          },
           "index": false
         },
         {
-          "name": "realTokenReserves",
-          "type": "u64",
+          "name": "currentAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenTotalSupply",
-          "type": "u64",
+          "name": "cancelledAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "solLaunchThreshold",
-          "type": "u64",
+          "name": "timestamp",
+          "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "WithdrawEvent",
+      "name": "SettingsScheduledEvent",
       "fields": [
         {
-          "name": "withdrawAuthority",
+          "name": "globalAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "mint",
-          "type": "publicKey",
+          "name": "settings",
+          "type": {
+            "defined": "GlobalSettingsInput"
+          },
           "index": false
         },
         {
-          "name": "feeVault",
-          "type": "publicKey",
+          "name": "effectiveTime",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "withdrawn",
-          "type": "u64",
+          "name": "timestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SettingsExecutedEvent",
+      "fields": [
+        {
+          "name": "executor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "totalWithdrawn",
-          "type": "u64",
+          "name": "settings",
+          "type": {
+            "defined": "GlobalSettingsInput"
+          },
           "index": false
         },
         {
-          "name": "previousWithdrawTime",
+          "name": "effectiveTime",
           "type": "i64",
           "index": false
         },
         {
-          "name": "newWithdrawTime",
+          "name": "timestamp",
           "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "TradeEvent",
+      "name": "SettingsCancelledEvent",
       "fields": [
         {
-          "name": "mint",
+          "name": "globalAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "solAmount",
-          "type": "u64",
+          "name": "settings",
+          "type": {
+            "defined": "GlobalSettingsInput"
+          },
           "index": false
         },
         {
-          "name": "tokenAmount",
-          "type": "u64",
+          "name": "effectiveTime",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "feeLamports",
-          "type": "u64",
+          "name": "timestamp",
+          "type": "i64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "SkimEvent",
+      "fields": [
         {
-          "name": "isBuy",
-          "type": "bool",
+          "name": "withdrawAuthority",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "user",
+          "name": "mint",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "timestamp",
-          "type": "i64",
+          "name": "platformVault",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "virtualSolReserves",
+          "name": "amount",
           "type": "u64",
           "index": false
         },
-        {
-          "name": "virtualTokenReserves",
-          "type": "u128",
-          "index": false
-        },
         {
           "name": "realSolReserves",
           "type": "u64",
           "index": false
         },
         {
-          "name": "realTokenReserves",
-          "type": "u64",
+          "name": "timestamp",
+          "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "CompleteEvent",
+      "name": "UnsoldTokensEvent",
       "fields": [
-        {
-          "name": "user",
-          "type": "publicKey",
-          "index": false
-        },
         {
           "name": "mint",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "virtualSolReserves",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "virtualTokenReserves",
-          "type": "u128",
+          "name": "policy",
+          "type": {
+            "defined": "CompletionPolicy"
+          },
           "index": false
         },
         {
-          "name": "realSolReserves",
+          "name": "unsoldTokenAmount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "realTokenReserves",
+          "name": "graduationTokenAmount",
           "type": "u64",
           "index": false
         },
@@ -2526,6 +7804,296 @@ export const IDL: BillyBondingCurve = {
       "code": 6024,
       "name": "NoFeesToWithdraw",
       "msg": "Not enough fees to withdraw"
+    },
+    {
+      "code": 6025,
+      "name": "BondingCurveGraduated",
+      "msg": "Bonding Curve Already Graduated"
+    },
+    {
+      "code": 6026,
+      "name": "AmmProgramNotSet",
+      "msg": "AMM program not set on Global"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidAmmProgram",
+      "msg": "Invalid AMM program"
+    },
+    {
+      "code": 6028,
+      "name": "GraduationFailed",
+      "msg": "Graduation Failed"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidLpTokenAccount",
+      "msg": "Invalid LP token account"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidPresaleTerms",
+      "msg": "Invalid presale terms"
+    },
+    {
+      "code": 6031,
+      "name": "PresaleNotOpen",
+      "msg": "Presale is not open"
+    },
+    {
+      "code": 6032,
+      "name": "PresaleWalletCapExceeded",
+      "msg": "Presale contribution exceeds the per wallet cap"
+    },
+    {
+      "code": 6033,
+      "name": "PresaleAlreadyClaimed",
+      "msg": "Presale tokens already claimed"
+    },
+    {
+      "code": 6034,
+      "name": "NoPresaleTokens",
+      "msg": "No presale tokens to claim"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidBrandAuthority",
+      "msg": "Invalid Brand Authority"
+    },
+    {
+      "code": 6036,
+      "name": "NoBrandkitTokens",
+      "msg": "No brandkit tokens to claim"
+    },
+    {
+      "code": 6037,
+      "name": "NoPlatformTokens",
+      "msg": "No platform tokens to claim"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidPlatformAuthority",
+      "msg": "Invalid Platform Authority"
+    },
+    {
+      "code": 6039,
+      "name": "CexReleaseExceedsVested",
+      "msg": "Release exceeds vested CEX tokens"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidSwapMode",
+      "msg": "Exactly one of exactInAmount and exactOutAmount must be set"
+    },
+    {
+      "code": 6041,
+      "name": "CreateDisabled",
+      "msg": "Creating bonding curves is disabled"
+    },
+    {
+      "code": 6042,
+      "name": "BuyDisabled",
+      "msg": "Buying is disabled"
+    },
+    {
+      "code": 6043,
+      "name": "SellDisabled",
+      "msg": "Selling is disabled"
+    },
+    {
+      "code": 6044,
+      "name": "LaunchDisabled",
+      "msg": "Launching is disabled"
+    },
+    {
+      "code": 6045,
+      "name": "ClaimDisabled",
+      "msg": "Claiming is disabled"
+    },
+    {
+      "code": 6046,
+      "name": "FeeWithdrawalDisabled",
+      "msg": "Fee withdrawal is disabled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidPendingAuthority",
+      "msg": "Signer is not the pending authority"
+    },
+    {
+      "code": 6048,
+      "name": "NoPendingAuthority",
+      "msg": "No pending authority transfer"
+    },
+    {
+      "code": 6049,
+      "name": "TradeFeeTooHigh",
+      "msg": "Trade fee exceeds the maximum trade fee"
+    },
+    {
+      "code": 6050,
+      "name": "LaunchFeeTooHigh",
+      "msg": "Launch fee exceeds the maximum launch fee"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidMintDecimals",
+      "msg": "Mint decimals must be between 1 and the maximum mint decimals"
+    },
+    {
+      "code": 6052,
+      "name": "SettingsChangeRequiresTimelock",
+      "msg": "Fee and status changes must be scheduled"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidSettingsEffectiveTime",
+      "msg": "Effective time is before the settings timelock"
+    },
+    {
+      "code": 6054,
+      "name": "NoPendingSettings",
+      "msg": "No pending settings change"
+    },
+    {
+      "code": 6055,
+      "name": "SettingsNotYetEffective",
+      "msg": "Pending settings change is not yet effective"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidMintExtensions",
+      "msg": "Mint carries unsupported extensions"
+    },
+    {
+      "code": 6057,
+      "name": "MissingMetadataAccounts",
+      "msg": "Metaplex metadata accounts are required for SPL Token mints"
+    },
+    {
+      "code": 6058,
+      "name": "NoVestedTokens",
+      "msg": "No vested tokens to claim"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidVestingTerms",
+      "msg": "Invalid vesting anchor"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidGraduationDeadline",
+      "msg": "Graduation deadline must be after the curve start"
+    },
+    {
+      "code": 6061,
+      "name": "GraduationDeadlinePassed",
+      "msg": "Graduation deadline has passed"
+    },
+    {
+      "code": 6062,
+      "name": "GraduationDeadlineNotReached",
+      "msg": "Graduation deadline not yet reached"
+    },
+    {
+      "code": 6063,
+      "name": "BondingCurveFailed",
+      "msg": "Bonding Curve Failed"
+    },
+    {
+      "code": 6064,
+      "name": "BondingCurveNotFailed",
+      "msg": "Bonding Curve Not Failed"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidLaunchLeadTime",
+      "msg": "Launch lead time exceeds the maximum launch lead time"
+    },
+    {
+      "code": 6066,
+      "name": "LaunchAlreadyStarted",
+      "msg": "Launch has already started"
+    },
+    {
+      "code": 6067,
+      "name": "LaunchHasContributions",
+      "msg": "Launch has presale contributions"
+    },
+    {
+      "code": 6068,
+      "name": "RescheduleDisabled",
+      "msg": "Rescheduling launches is disabled"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidLaunchProtection",
+      "msg": "Invalid launch protection settings"
+    },
+    {
+      "code": 6070,
+      "name": "LaunchProtectionLimitExceeded",
+      "msg": "Buy exceeds the launch protection limits"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidFeeSchedule",
+      "msg": "Invalid fee schedule"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidAllowlist",
+      "msg": "Invalid allowlist"
+    },
+    {
+      "code": 6073,
+      "name": "NotAllowlisted",
+      "msg": "Wallet is not allowlisted"
+    },
+    {
+      "code": 6074,
+      "name": "AllowlistAllowanceExceeded",
+      "msg": "Buy exceeds the allowlist allowance"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidCurveKind",
+      "msg": "Invalid curve kind"
+    },
+    {
+      "code": 6076,
+      "name": "NoSurplusLamports",
+      "msg": "No surplus lamports to skim"
+    },
+    {
+      "code": 6077,
+      "name": "InvalidLaunchFeeRefund",
+      "msg": "Launch fee refund must not exceed 100%"
+    },
+    {
+      "code": 6078,
+      "name": "LaunchAlreadyTraded",
+      "msg": "Launch has already been traded"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidAmmPool",
+      "msg": "Pool or LP mint does not belong to the AMM"
+    },
+    {
+      "code": 6080,
+      "name": "PresaleCapExceeded",
+      "msg": "Contribution exceeds the presale SOL cap"
+    },
+    {
+      "code": 6081,
+      "name": "InvalidSettingsCaps",
+      "msg": "Invalid settings caps"
+    },
+    {
+      "code": 6082,
+      "name": "MissingUserPosition",
+      "msg": "User position is required for buys during launch protection or the allowlist phase"
     }
   ]
 };
@@ -1,8 +1,8 @@
 import { SPL_SYSTEM_PROGRAM_ID } from "@metaplex-foundation/mpl-toolbox";
-import { none, OptionOrNullable, PublicKey, Umi } from "@metaplex-foundation/umi";
+import { none, PublicKey, Umi } from "@metaplex-foundation/umi";
 import { fromWeb3JsPublicKey } from "@metaplex-foundation/umi-web3js-adapters";
 import { SYSVAR_CLOCK_PUBKEY } from "@solana/web3.js";
-import { findPlatformVaultPda, GlobalSettingsInputArgs, ProgramStatus, withdrawFees } from "../generated";
+import { acceptAuthority, AuthorityRoleArgs, cancelSettings, executeSettings, findBondingCurvePda, findPlatformVaultPda, GlobalSettingsInputArgs, scheduleSettings, withdrawFees } from "../generated";
 import { setParams, SetParamsInstructionAccounts } from '../generated/instructions/setParams';
 import { initialize, } from '../generated/instructions/initialize';
 import { BillySDK } from "./billy";
@@ -18,11 +18,11 @@ export class AdminSDK{
         this.umi = sdk.umi;
     }
 
-    initialize(params:GlobalSettingsInputArgs){
+    initialize(params:Partial<GlobalSettingsInputArgs>){
         const txBuilder = initialize(this.Billy.umi, {
             global: this.Billy.globalPda[0],
             authority: this.umi.identity,
-            params,
+            params:this.parseSettings(params),
             systemProgram: SPL_SYSTEM_PROGRAM_ID,
             ...this.Billy.evtAuthAccs,
           });
@@ -34,6 +34,7 @@ export class AdminSDK{
             global: this.Billy.globalPda[0],
             authority: this.umi.identity,
             mint,
+            bondingCurve: findBondingCurvePda(this.Billy.umi, {mint})[0],
             platformVault: findPlatformVaultPda(this.Billy.umi, {mint})[0],
             clock: fromWeb3JsPublicKey(SYSVAR_CLOCK_PUBKEY),
             ...this.Billy.evtAuthAccs,
@@ -41,28 +42,74 @@ export class AdminSDK{
         return txBuilder;
     }
 
+    // fills the settings left out with none so they stay unchanged
+    parseSettings(params:Partial<GlobalSettingsInputArgs>):GlobalSettingsInputArgs{
+        return {
+            tradeFeeBps:params.tradeFeeBps ?? none(),
+            createdMintDecimals:params.createdMintDecimals ?? none(),
+            launchFeeLamports:params.launchFeeLamports ?? none(),
+            launchFeeRefundBps:params.launchFeeRefundBps ?? none(),
+            maxLaunchLeadTime:params.maxLaunchLeadTime ?? none(),
+            status:params.status ?? none(),
+            ammProgram:params.ammProgram ?? none(),
+            platformVestingTerms:params.platformVestingTerms ?? none(),
+            cexVestingTerms:params.cexVestingTerms ?? none(),
+            caps:params.caps ?? none(),
+        };
+    }
+
+    // fee changes, cap changes, amm changes and status changes other than pausing have to go through scheduleSettings
     setParams(params:SetParamsInput){
         const {newWithdrawAuthority, newAuthority,...ixParams} = params;
-        let status:OptionOrNullable<ProgramStatus>;
-        if(ixParams.status!==undefined){
-            status = ixParams.status;
-        }else{
-            status = none();
-        }
-        const parsedParams:GlobalSettingsInputArgs = {
-            status,
-            launchFeeLamports:ixParams?.launchFeeLamports||none(),
-            tradeFeeBps:ixParams?.tradeFeeBps||none(),
-            createdMintDecimals:ixParams?.createdMintDecimals||none(),
-        };
         const txBuilder = setParams(this.Billy.umi, {
             global: this.Billy.globalPda[0],
             authority: this.umi.identity,
-            params:parsedParams,
+            params:this.parseSettings(ixParams),
             newWithdrawAuthority,
             newAuthority,
             ...this.Billy.evtAuthAccs,
           });
         return txBuilder;
     }
+
+    scheduleSettings(params:Partial<GlobalSettingsInputArgs>, effectiveTime:number|bigint){
+        const txBuilder = scheduleSettings(this.Billy.umi, {
+            global: this.Billy.globalPda[0],
+            authority: this.umi.identity,
+            params:this.parseSettings(params),
+            effectiveTime,
+            ...this.Billy.evtAuthAccs,
+          });
+        return txBuilder;
+    }
+
+    // permissionless once the effective time has passed
+    executeSettings(){
+        const txBuilder = executeSettings(this.Billy.umi, {
+            global: this.Billy.globalPda[0],
+            executor: this.umi.identity,
+            ...this.Billy.evtAuthAccs,
+          });
+        return txBuilder;
+    }
+
+    cancelSettings(){
+        const txBuilder = cancelSettings(this.Billy.umi, {
+            global: this.Billy.globalPda[0],
+            authority: this.umi.identity,
+            ...this.Billy.evtAuthAccs,
+          });
+        return txBuilder;
+    }
+
+    // signed by the nominated authority
+    acceptAuthority(role:AuthorityRoleArgs){
+        const txBuilder = acceptAuthority(this.Billy.umi, {
+            global: this.Billy.globalPda[0],
+            newAuthority: this.umi.identity,
+            role,
+            ...this.Billy.evtAuthAccs,
+          });
+        return txBuilder;
+    }
 }
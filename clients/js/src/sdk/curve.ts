@@ -1,11 +1,11 @@
-import { createSignerFromKeypair, Keypair, Pda, PublicKey, Umi } from "@metaplex-foundation/umi";
+import { createSignerFromKeypair, isSome, Keypair, none, Pda, PublicKey, Umi } from "@metaplex-foundation/umi";
 import { findAssociatedTokenPda, SPL_ASSOCIATED_TOKEN_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID } from "@metaplex-foundation/mpl-toolbox";
 import {
     findMetadataPda,
   } from "@metaplex-foundation/mpl-token-metadata";
 import { fromWeb3JsPublicKey } from "@metaplex-foundation/umi-web3js-adapters";
 import { SYSVAR_CLOCK_PUBKEY } from "@solana/web3.js";
-import { createBondingCurve, CreateBondingCurveInstructionDataArgs, fetchBondingCurve, findBondingCurvePda, findBrandVaultPda, findCreatorVaultPda, findPlatformVaultPda, findPresaleVaultPda, swap, SwapInstructionArgs } from "../generated";
+import { BondingCurve, createBondingCurve, CreateBondingCurveInstructionDataArgs, fetchBondingCurve, findBondingCurvePda, findBrandVaultPda, findCexVaultPda, findCreatorVaultPda, findPlatformVaultPda, findPresaleVaultPda, findUserPositionPda, swap, SwapParamsArgs } from "../generated";
 import { BillySDK } from "./billy";
 import { claimCreatorVesting } from '../generated/instructions/claimCreatorVesting';
 
@@ -34,24 +34,47 @@ export class CurveSDK{
     platformVaultPda:Pda;
     platformVaultTokenAccount:Pda;
 
+    cexVaultPda:Pda;
+    cexVaultTokenAccount:Pda;
+
+    userPositionPda:Pda;
+
     fetchData(){
         return fetchBondingCurve(this.umi, this.bondingCurvePda[0]);
     }
 
+    // buys are recorded per wallet only while launch protection or the allowlist phase caps them,
+    // matches BondingCurve::tracks_buys
+    static tracksBuys(curve:BondingCurve, now:bigint){
+        const protection = curve.launchProtection;
+        return (protection.window > 0 && now < curve.startTime + protection.window)
+            || (isSome(curve.allowlist) && now < curve.allowlist.value.endTime);
+    }
+
     swap(params:{
         direction:"buy"|"sell",
-    }&Pick<SwapInstructionArgs, "exactInAmount" | "minOutAmount">){
+        // set for buys on a curve that tracks them, see tracksBuys
+        trackBuy?:boolean,
+    }&Partial<Omit<SwapParamsArgs, "baseIn">>){
+        // exact-in unless an exactOutAmount is given, unused amounts stay zero
         return swap(this.umi, {
             global: this.Billy.globalPda[0],
             user: this.umi.identity,
-            baseIn: params.direction !== "buy",
-            exactInAmount: params.exactInAmount,
-            minOutAmount: params.minOutAmount,
+            params: {
+                baseIn: params.direction !== "buy",
+                exactInAmount: params.exactInAmount || 0,
+                minOutAmount: params.minOutAmount || 0,
+                exactOutAmount: params.exactOutAmount || 0,
+                maxInAmount: params.maxInAmount || 0,
+                allowlistProof: params.allowlistProof || none(),
+            },
             mint: this.mint,
             bondingCurve: this.bondingCurvePda[0],
             bondingCurveTokenAccount: this.bondingCurveTokenAccount[0],
             userTokenAccount: this.userTokenAccount[0],
+            userPosition: params.direction === "buy" && params.trackBuy ? this.userPositionPda[0] : undefined,
             platformVault: this.platformVaultPda[0],
+            platformVaultTokenAccount: this.platformVaultTokenAccount[0],
             clock: fromWeb3JsPublicKey(SYSVAR_CLOCK_PUBKEY),
             associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
             ...this.Billy.evtAuthAccs,
@@ -86,6 +109,8 @@ export class CurveSDK{
             platformVault: this.platformVaultPda[0],
             platformVaultTokenAccount: this.platformVaultTokenAccount[0],
 
+            cexVault: this.cexVaultPda[0],
+            cexVaultTokenAccount: this.cexVaultTokenAccount[0],
 
             metadata: this.mintMetaPda[0],
 
@@ -153,6 +178,21 @@ export class CurveSDK{
             mint: this.mint,
             owner: this.platformVaultPda[0],
         });
+
+
+        this.cexVaultPda = findCexVaultPda(this.umi, {
+            mint: this.mint,
+        });
+        this.cexVaultTokenAccount = findAssociatedTokenPda(this.umi, {
+            mint: this.mint,
+            owner: this.cexVaultPda[0],
+        });
+
+
+        this.userPositionPda = findUserPositionPda(this.umi, {
+            mint: this.mint,
+            user: this.umi.identity.publicKey,
+        });
     }
 
     claimCreatorVesting(){
@@ -6,6 +6,11 @@
 //!
 
 use crate::generated::types::AllocationData;
+use crate::generated::types::Allowlist;
+use crate::generated::types::CompletionPolicy;
+use crate::generated::types::CurveKind;
+use crate::generated::types::FeeSchedule;
+use crate::generated::types::LaunchProtection;
 use crate::generated::types::VestingTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
@@ -54,16 +59,27 @@ pub struct BondingCurve {
     pub lifetime_brandkit_supply: u64,
     pub platform_supply: u64,
     pub sol_launch_threshold: u64,
+    pub presale_sol_raised: u64,
     pub start_time: i64,
     pub complete: bool,
+    pub graduated: bool,
+    pub traded: bool,
+    pub completion_time: i64,
+    pub graduation_deadline: Option<i64>,
+    pub failed: bool,
+    pub refund_token_supply: u64,
+    pub launch_fee_lamports: u64,
+    pub launch_protection: LaunchProtection,
+    pub fee_schedule: Option<FeeSchedule>,
+    pub allowlist: Option<Allowlist>,
+    pub curve_kind: CurveKind,
+    pub completion_policy: CompletionPolicy,
     pub vesting_terms: VestingTerms,
     pub allocation: AllocationData,
     pub bump: u8,
 }
 
 impl BondingCurve {
-    pub const LEN: usize = 354;
-
     /// Prefix values used to generate a PDA for this account.
     ///
     /// Values are positional and appear in the following order:
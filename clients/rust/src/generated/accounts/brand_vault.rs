@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::VestingTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -20,11 +21,12 @@ pub struct BrandVault {
     pub launch_brandkit_supply: u64,
     pub lifetime_brandkit_supply: u64,
     pub initial_vested_supply: u64,
+    pub lifetime_vesting_terms: VestingTerms,
+    pub launch_brandkit_claimed: u64,
+    pub lifetime_brandkit_claimed: u64,
 }
 
 impl BrandVault {
-    pub const LEN: usize = 32;
-
     /// Prefix values used to generate a PDA for this account.
     ///
     /// Values are positional and appear in the following order:
@@ -19,10 +19,11 @@ pub struct CreatorVault {
     pub discriminator: [u8; 8],
     pub initial_vested_supply: u64,
     pub last_distribution: i64,
+    pub claimed_amount: u64,
 }
 
 impl CreatorVault {
-    pub const LEN: usize = 24;
+    pub const LEN: usize = 32;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -5,7 +5,10 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::GlobalSettingsInput;
 use crate::generated::types::ProgramStatus;
+use crate::generated::types::SettingsCaps;
+use crate::generated::types::VestingTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -30,14 +33,26 @@ pub struct Global {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub withdraw_authority: Pubkey,
+    pub pending_global_authority: Option<Pubkey>,
+    pub pending_withdraw_authority: Option<Pubkey>,
     pub trade_fee_bps: u64,
     pub launch_fee_lamports: u64,
+    pub launch_fee_refund_bps: u64,
     pub created_mint_decimals: u8,
+    pub max_launch_lead_time: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub amm_program: Pubkey,
+    pub platform_vesting_terms: VestingTerms,
+    pub cex_vesting_terms: VestingTerms,
+    pub caps: SettingsCaps,
+    pub pending_settings: Option<GlobalSettingsInput>,
+    pub pending_settings_effective_time: i64,
 }
 
 impl Global {
-    pub const LEN: usize = 91;
-
     /// Prefix values used to generate a PDA for this account.
     ///
     /// Values are positional and appear in the following order:
@@ -7,14 +7,20 @@
 
 pub(crate) mod r#bonding_curve;
 pub(crate) mod r#brand_vault;
+pub(crate) mod r#cex_vault;
 pub(crate) mod r#creator_vault;
 pub(crate) mod r#global;
 pub(crate) mod r#platform_vault;
+pub(crate) mod r#presale_position;
 pub(crate) mod r#presale_vault;
+pub(crate) mod r#user_position;
 
 pub use self::r#bonding_curve::*;
 pub use self::r#brand_vault::*;
+pub use self::r#cex_vault::*;
 pub use self::r#creator_vault::*;
 pub use self::r#global::*;
 pub use self::r#platform_vault::*;
+pub use self::r#presale_position::*;
 pub use self::r#presale_vault::*;
+pub use self::r#user_position::*;
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::VestingTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -21,11 +22,12 @@ pub struct PlatformVault {
     pub last_distribution: i64,
     pub last_fee_withdrawal: i64,
     pub fees_withdrawn: u64,
+    pub vesting_terms: VestingTerms,
+    pub tokens_claimed: u64,
+    pub unsold_token_amount: u64,
 }
 
 impl PlatformVault {
-    pub const LEN: usize = 40;
-
     /// Prefix values used to generate a PDA for this account.
     ///
     /// Values are positional and appear in the following order:
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::PresaleTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -18,10 +19,12 @@ use solana_program::pubkey::Pubkey;
 pub struct PresaleVault {
     pub discriminator: [u8; 8],
     pub initial_vested_supply: u64,
+    pub presale_terms: PresaleTerms,
+    pub total_claimed: u64,
 }
 
 impl PresaleVault {
-    pub const LEN: usize = 16;
+    pub const LEN: usize = 56;
 
     /// Prefix values used to generate a PDA for this account.
     ///
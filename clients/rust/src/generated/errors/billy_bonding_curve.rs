@@ -85,6 +85,180 @@ pub enum BillyBondingCurveError {
     /// 6024 (0x1788) - Not enough fees to withdraw
     #[error("Not enough fees to withdraw")]
     NoFeesToWithdraw,
+    /// 6025 (0x1789) - Bonding Curve Already Graduated
+    #[error("Bonding Curve Already Graduated")]
+    BondingCurveGraduated,
+    /// 6026 (0x178A) - AMM program not set on Global
+    #[error("AMM program not set on Global")]
+    AmmProgramNotSet,
+    /// 6027 (0x178B) - Invalid AMM program
+    #[error("Invalid AMM program")]
+    InvalidAmmProgram,
+    /// 6028 (0x178C) - Graduation Failed
+    #[error("Graduation Failed")]
+    GraduationFailed,
+    /// 6029 (0x178D) - Invalid LP token account
+    #[error("Invalid LP token account")]
+    InvalidLpTokenAccount,
+    /// 6030 (0x178E) - Invalid presale terms
+    #[error("Invalid presale terms")]
+    InvalidPresaleTerms,
+    /// 6031 (0x178F) - Presale is not open
+    #[error("Presale is not open")]
+    PresaleNotOpen,
+    /// 6032 (0x1790) - Presale contribution exceeds the per wallet cap
+    #[error("Presale contribution exceeds the per wallet cap")]
+    PresaleWalletCapExceeded,
+    /// 6033 (0x1791) - Presale tokens already claimed
+    #[error("Presale tokens already claimed")]
+    PresaleAlreadyClaimed,
+    /// 6034 (0x1792) - No presale tokens to claim
+    #[error("No presale tokens to claim")]
+    NoPresaleTokens,
+    /// 6035 (0x1793) - Invalid Brand Authority
+    #[error("Invalid Brand Authority")]
+    InvalidBrandAuthority,
+    /// 6036 (0x1794) - No brandkit tokens to claim
+    #[error("No brandkit tokens to claim")]
+    NoBrandkitTokens,
+    /// 6037 (0x1795) - No platform tokens to claim
+    #[error("No platform tokens to claim")]
+    NoPlatformTokens,
+    /// 6038 (0x1796) - Invalid Platform Authority
+    #[error("Invalid Platform Authority")]
+    InvalidPlatformAuthority,
+    /// 6039 (0x1797) - Release exceeds vested CEX tokens
+    #[error("Release exceeds vested CEX tokens")]
+    CexReleaseExceedsVested,
+    /// 6040 (0x1798) - Exactly one of exactInAmount and exactOutAmount must be set
+    #[error("Exactly one of exactInAmount and exactOutAmount must be set")]
+    InvalidSwapMode,
+    /// 6041 (0x1799) - Creating bonding curves is disabled
+    #[error("Creating bonding curves is disabled")]
+    CreateDisabled,
+    /// 6042 (0x179A) - Buying is disabled
+    #[error("Buying is disabled")]
+    BuyDisabled,
+    /// 6043 (0x179B) - Selling is disabled
+    #[error("Selling is disabled")]
+    SellDisabled,
+    /// 6044 (0x179C) - Launching is disabled
+    #[error("Launching is disabled")]
+    LaunchDisabled,
+    /// 6045 (0x179D) - Claiming is disabled
+    #[error("Claiming is disabled")]
+    ClaimDisabled,
+    /// 6046 (0x179E) - Fee withdrawal is disabled
+    #[error("Fee withdrawal is disabled")]
+    FeeWithdrawalDisabled,
+    /// 6047 (0x179F) - Signer is not the pending authority
+    #[error("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+    /// 6048 (0x17A0) - No pending authority transfer
+    #[error("No pending authority transfer")]
+    NoPendingAuthority,
+    /// 6049 (0x17A1) - Trade fee exceeds the maximum trade fee
+    #[error("Trade fee exceeds the maximum trade fee")]
+    TradeFeeTooHigh,
+    /// 6050 (0x17A2) - Launch fee exceeds the maximum launch fee
+    #[error("Launch fee exceeds the maximum launch fee")]
+    LaunchFeeTooHigh,
+    /// 6051 (0x17A3) - Mint decimals must be between 1 and the maximum mint decimals
+    #[error("Mint decimals must be between 1 and the maximum mint decimals")]
+    InvalidMintDecimals,
+    /// 6052 (0x17A4) - Fee and status changes must be scheduled
+    #[error("Fee and status changes must be scheduled")]
+    SettingsChangeRequiresTimelock,
+    /// 6053 (0x17A5) - Effective time is before the settings timelock
+    #[error("Effective time is before the settings timelock")]
+    InvalidSettingsEffectiveTime,
+    /// 6054 (0x17A6) - No pending settings change
+    #[error("No pending settings change")]
+    NoPendingSettings,
+    /// 6055 (0x17A7) - Pending settings change is not yet effective
+    #[error("Pending settings change is not yet effective")]
+    SettingsNotYetEffective,
+    /// 6056 (0x17A8) - Mint carries unsupported extensions
+    #[error("Mint carries unsupported extensions")]
+    InvalidMintExtensions,
+    /// 6057 (0x17A9) - Metaplex metadata accounts are required for SPL Token mints
+    #[error("Metaplex metadata accounts are required for SPL Token mints")]
+    MissingMetadataAccounts,
+    /// 6058 (0x17AA) - No vested tokens to claim
+    #[error("No vested tokens to claim")]
+    NoVestedTokens,
+    /// 6059 (0x17AB) - Invalid vesting anchor
+    #[error("Invalid vesting anchor")]
+    InvalidVestingTerms,
+    /// 6060 (0x17AC) - Graduation deadline must be after the curve start
+    #[error("Graduation deadline must be after the curve start")]
+    InvalidGraduationDeadline,
+    /// 6061 (0x17AD) - Graduation deadline has passed
+    #[error("Graduation deadline has passed")]
+    GraduationDeadlinePassed,
+    /// 6062 (0x17AE) - Graduation deadline not yet reached
+    #[error("Graduation deadline not yet reached")]
+    GraduationDeadlineNotReached,
+    /// 6063 (0x17AF) - Bonding Curve Failed
+    #[error("Bonding Curve Failed")]
+    BondingCurveFailed,
+    /// 6064 (0x17B0) - Bonding Curve Not Failed
+    #[error("Bonding Curve Not Failed")]
+    BondingCurveNotFailed,
+    /// 6065 (0x17B1) - Launch lead time exceeds the maximum launch lead time
+    #[error("Launch lead time exceeds the maximum launch lead time")]
+    InvalidLaunchLeadTime,
+    /// 6066 (0x17B2) - Launch has already started
+    #[error("Launch has already started")]
+    LaunchAlreadyStarted,
+    /// 6067 (0x17B3) - Launch has presale contributions
+    #[error("Launch has presale contributions")]
+    LaunchHasContributions,
+    /// 6068 (0x17B4) - Rescheduling launches is disabled
+    #[error("Rescheduling launches is disabled")]
+    RescheduleDisabled,
+    /// 6069 (0x17B5) - Invalid launch protection settings
+    #[error("Invalid launch protection settings")]
+    InvalidLaunchProtection,
+    /// 6070 (0x17B6) - Buy exceeds the launch protection limits
+    #[error("Buy exceeds the launch protection limits")]
+    LaunchProtectionLimitExceeded,
+    /// 6071 (0x17B7) - Invalid fee schedule
+    #[error("Invalid fee schedule")]
+    InvalidFeeSchedule,
+    /// 6072 (0x17B8) - Invalid allowlist
+    #[error("Invalid allowlist")]
+    InvalidAllowlist,
+    /// 6073 (0x17B9) - Wallet is not allowlisted
+    #[error("Wallet is not allowlisted")]
+    NotAllowlisted,
+    /// 6074 (0x17BA) - Buy exceeds the allowlist allowance
+    #[error("Buy exceeds the allowlist allowance")]
+    AllowlistAllowanceExceeded,
+    /// 6075 (0x17BB) - Invalid curve kind
+    #[error("Invalid curve kind")]
+    InvalidCurveKind,
+    /// 6076 (0x17BC) - No surplus lamports to skim
+    #[error("No surplus lamports to skim")]
+    NoSurplusLamports,
+    /// 6077 (0x17BD) - Launch fee refund must not exceed 100%
+    #[error("Launch fee refund must not exceed 100%")]
+    InvalidLaunchFeeRefund,
+    /// 6078 (0x17BE) - Launch has already been traded
+    #[error("Launch has already been traded")]
+    LaunchAlreadyTraded,
+    /// 6079 (0x17BF) - Pool or LP mint does not belong to the AMM
+    #[error("Pool or LP mint does not belong to the AMM")]
+    InvalidAmmPool,
+    /// 6080 (0x17C0) - Contribution exceeds the presale SOL cap
+    #[error("Contribution exceeds the presale SOL cap")]
+    PresaleCapExceeded,
+    /// 6081 (0x17C1) - Invalid settings caps
+    #[error("Invalid settings caps")]
+    InvalidSettingsCaps,
+    /// 6082 (0x17C2) - User position is required for buys during launch protection or the allowlist phase
+    #[error("User position is required for buys during launch protection or the allowlist phase")]
+    MissingUserPosition,
 }
 
 impl solana_program::program_error::PrintProgramError for BillyBondingCurveError {
@@ -0,0 +1,1028 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CancelLaunch {
+    pub creator: solana_program::pubkey::Pubkey,
+
+    pub global: solana_program::pubkey::Pubkey,
+
+    pub withdraw_authority: solana_program::pubkey::Pubkey,
+
+    pub mint: solana_program::pubkey::Pubkey,
+
+    pub bonding_curve: solana_program::pubkey::Pubkey,
+
+    pub bonding_curve_token_account: solana_program::pubkey::Pubkey,
+
+    pub creator_vault: solana_program::pubkey::Pubkey,
+
+    pub creator_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub presale_vault: solana_program::pubkey::Pubkey,
+
+    pub presale_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub cex_vault: solana_program::pubkey::Pubkey,
+
+    pub cex_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub brand_vault: solana_program::pubkey::Pubkey,
+
+    pub brand_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub platform_vault: solana_program::pubkey::Pubkey,
+
+    pub platform_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
+}
+
+impl CancelLaunch {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.creator,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.global,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.withdraw_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.bonding_curve,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.bonding_curve_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.creator_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.creator_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.presale_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.presale_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cex_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cex_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.brand_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.brand_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.platform_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.platform_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = CancelLaunchInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BILLY_BONDING_CURVE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct CancelLaunchInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl CancelLaunchInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [120, 69, 17, 7, 41, 48, 40, 37],
+        }
+    }
+}
+
+/// Instruction builder for `CancelLaunch`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` creator
+///   1. `[]` global
+///   2. `[writable]` withdraw_authority
+///   3. `[writable]` mint
+///   4. `[writable]` bonding_curve
+///   5. `[writable]` bonding_curve_token_account
+///   6. `[writable]` creator_vault
+///   7. `[writable]` creator_vault_token_account
+///   8. `[writable]` presale_vault
+///   9. `[writable]` presale_vault_token_account
+///   10. `[writable]` cex_vault
+///   11. `[writable]` cex_vault_token_account
+///   12. `[writable]` brand_vault
+///   13. `[writable]` brand_vault_token_account
+///   14. `[writable]` platform_vault
+///   15. `[writable]` platform_vault_token_account
+///   16. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   17. `[]` event_authority
+///   18. `[]` program
+#[derive(Default)]
+pub struct CancelLaunchBuilder {
+    creator: Option<solana_program::pubkey::Pubkey>,
+    global: Option<solana_program::pubkey::Pubkey>,
+    withdraw_authority: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    bonding_curve: Option<solana_program::pubkey::Pubkey>,
+    bonding_curve_token_account: Option<solana_program::pubkey::Pubkey>,
+    creator_vault: Option<solana_program::pubkey::Pubkey>,
+    creator_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    presale_vault: Option<solana_program::pubkey::Pubkey>,
+    presale_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    cex_vault: Option<solana_program::pubkey::Pubkey>,
+    cex_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    brand_vault: Option<solana_program::pubkey::Pubkey>,
+    brand_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    platform_vault: Option<solana_program::pubkey::Pubkey>,
+    platform_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CancelLaunchBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn creator(&mut self, creator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.creator = Some(creator);
+        self
+    }
+    #[inline(always)]
+    pub fn global(&mut self, global: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.global = Some(global);
+        self
+    }
+    #[inline(always)]
+    pub fn withdraw_authority(
+        &mut self,
+        withdraw_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.withdraw_authority = Some(withdraw_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn bonding_curve(&mut self, bonding_curve: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bonding_curve = Some(bonding_curve);
+        self
+    }
+    #[inline(always)]
+    pub fn bonding_curve_token_account(
+        &mut self,
+        bonding_curve_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.bonding_curve_token_account = Some(bonding_curve_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn creator_vault(&mut self, creator_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.creator_vault = Some(creator_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn creator_vault_token_account(
+        &mut self,
+        creator_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.creator_vault_token_account = Some(creator_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn presale_vault(&mut self, presale_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.presale_vault = Some(presale_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn presale_vault_token_account(
+        &mut self,
+        presale_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.presale_vault_token_account = Some(presale_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn cex_vault(&mut self, cex_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.cex_vault = Some(cex_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn cex_vault_token_account(
+        &mut self,
+        cex_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.cex_vault_token_account = Some(cex_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault(&mut self, brand_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.brand_vault = Some(brand_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault_token_account(
+        &mut self,
+        brand_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.brand_vault_token_account = Some(brand_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn platform_vault(&mut self, platform_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.platform_vault = Some(platform_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn platform_vault_token_account(
+        &mut self,
+        platform_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.platform_vault_token_account = Some(platform_vault_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CancelLaunch {
+            creator: self.creator.expect("creator is not set"),
+            global: self.global.expect("global is not set"),
+            withdraw_authority: self
+                .withdraw_authority
+                .expect("withdraw_authority is not set"),
+            mint: self.mint.expect("mint is not set"),
+            bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
+            bonding_curve_token_account: self
+                .bonding_curve_token_account
+                .expect("bonding_curve_token_account is not set"),
+            creator_vault: self.creator_vault.expect("creator_vault is not set"),
+            creator_vault_token_account: self
+                .creator_vault_token_account
+                .expect("creator_vault_token_account is not set"),
+            presale_vault: self.presale_vault.expect("presale_vault is not set"),
+            presale_vault_token_account: self
+                .presale_vault_token_account
+                .expect("presale_vault_token_account is not set"),
+            cex_vault: self.cex_vault.expect("cex_vault is not set"),
+            cex_vault_token_account: self
+                .cex_vault_token_account
+                .expect("cex_vault_token_account is not set"),
+            brand_vault: self.brand_vault.expect("brand_vault is not set"),
+            brand_vault_token_account: self
+                .brand_vault_token_account
+                .expect("brand_vault_token_account is not set"),
+            platform_vault: self.platform_vault.expect("platform_vault is not set"),
+            platform_vault_token_account: self
+                .platform_vault_token_account
+                .expect("platform_vault_token_account is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `cancel_launch` CPI accounts.
+pub struct CancelLaunchCpiAccounts<'a, 'b> {
+    pub creator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub global: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub withdraw_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub bonding_curve_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub creator_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub creator_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub presale_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub presale_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub cex_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub cex_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `cancel_launch` CPI instruction.
+pub struct CancelLaunchCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub creator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub global: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub withdraw_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub bonding_curve_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub creator_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub creator_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub presale_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub presale_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub cex_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub cex_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CancelLaunchCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CancelLaunchCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            creator: accounts.creator,
+            global: accounts.global,
+            withdraw_authority: accounts.withdraw_authority,
+            mint: accounts.mint,
+            bonding_curve: accounts.bonding_curve,
+            bonding_curve_token_account: accounts.bonding_curve_token_account,
+            creator_vault: accounts.creator_vault,
+            creator_vault_token_account: accounts.creator_vault_token_account,
+            presale_vault: accounts.presale_vault,
+            presale_vault_token_account: accounts.presale_vault_token_account,
+            cex_vault: accounts.cex_vault,
+            cex_vault_token_account: accounts.cex_vault_token_account,
+            brand_vault: accounts.brand_vault,
+            brand_vault_token_account: accounts.brand_vault_token_account,
+            platform_vault: accounts.platform_vault,
+            platform_vault_token_account: accounts.platform_vault_token_account,
+            token_program: accounts.token_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.creator.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.global.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.withdraw_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.bonding_curve.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.bonding_curve_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.creator_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.creator_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.presale_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.presale_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cex_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cex_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.brand_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.brand_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.platform_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.platform_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = CancelLaunchInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BILLY_BONDING_CURVE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(19 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.creator.clone());
+        account_infos.push(self.global.clone());
+        account_infos.push(self.withdraw_authority.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.bonding_curve.clone());
+        account_infos.push(self.bonding_curve_token_account.clone());
+        account_infos.push(self.creator_vault.clone());
+        account_infos.push(self.creator_vault_token_account.clone());
+        account_infos.push(self.presale_vault.clone());
+        account_infos.push(self.presale_vault_token_account.clone());
+        account_infos.push(self.cex_vault.clone());
+        account_infos.push(self.cex_vault_token_account.clone());
+        account_infos.push(self.brand_vault.clone());
+        account_infos.push(self.brand_vault_token_account.clone());
+        account_infos.push(self.platform_vault.clone());
+        account_infos.push(self.platform_vault_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CancelLaunch` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` creator
+///   1. `[]` global
+///   2. `[writable]` withdraw_authority
+///   3. `[writable]` mint
+///   4. `[writable]` bonding_curve
+///   5. `[writable]` bonding_curve_token_account
+///   6. `[writable]` creator_vault
+///   7. `[writable]` creator_vault_token_account
+///   8. `[writable]` presale_vault
+///   9. `[writable]` presale_vault_token_account
+///   10. `[writable]` cex_vault
+///   11. `[writable]` cex_vault_token_account
+///   12. `[writable]` brand_vault
+///   13. `[writable]` brand_vault_token_account
+///   14. `[writable]` platform_vault
+///   15. `[writable]` platform_vault_token_account
+///   16. `[]` token_program
+///   17. `[]` event_authority
+///   18. `[]` program
+pub struct CancelLaunchCpiBuilder<'a, 'b> {
+    instruction: Box<CancelLaunchCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CancelLaunchCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CancelLaunchCpiBuilderInstruction {
+            __program: program,
+            creator: None,
+            global: None,
+            withdraw_authority: None,
+            mint: None,
+            bonding_curve: None,
+            bonding_curve_token_account: None,
+            creator_vault: None,
+            creator_vault_token_account: None,
+            presale_vault: None,
+            presale_vault_token_account: None,
+            cex_vault: None,
+            cex_vault_token_account: None,
+            brand_vault: None,
+            brand_vault_token_account: None,
+            platform_vault: None,
+            platform_vault_token_account: None,
+            token_program: None,
+            event_authority: None,
+            program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn creator(
+        &mut self,
+        creator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.creator = Some(creator);
+        self
+    }
+    #[inline(always)]
+    pub fn global(
+        &mut self,
+        global: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.global = Some(global);
+        self
+    }
+    #[inline(always)]
+    pub fn withdraw_authority(
+        &mut self,
+        withdraw_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.withdraw_authority = Some(withdraw_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn bonding_curve(
+        &mut self,
+        bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bonding_curve = Some(bonding_curve);
+        self
+    }
+    #[inline(always)]
+    pub fn bonding_curve_token_account(
+        &mut self,
+        bonding_curve_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bonding_curve_token_account = Some(bonding_curve_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn creator_vault(
+        &mut self,
+        creator_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.creator_vault = Some(creator_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn creator_vault_token_account(
+        &mut self,
+        creator_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.creator_vault_token_account = Some(creator_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn presale_vault(
+        &mut self,
+        presale_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.presale_vault = Some(presale_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn presale_vault_token_account(
+        &mut self,
+        presale_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.presale_vault_token_account = Some(presale_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn cex_vault(
+        &mut self,
+        cex_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.cex_vault = Some(cex_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn cex_vault_token_account(
+        &mut self,
+        cex_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.cex_vault_token_account = Some(cex_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault(
+        &mut self,
+        brand_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.brand_vault = Some(brand_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault_token_account(
+        &mut self,
+        brand_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.brand_vault_token_account = Some(brand_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn platform_vault(
+        &mut self,
+        platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.platform_vault = Some(platform_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn platform_vault_token_account(
+        &mut self,
+        platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.platform_vault_token_account = Some(platform_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CancelLaunchCpi {
+            __program: self.instruction.__program,
+
+            creator: self.instruction.creator.expect("creator is not set"),
+
+            global: self.instruction.global.expect("global is not set"),
+
+            withdraw_authority: self
+                .instruction
+                .withdraw_authority
+                .expect("withdraw_authority is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            bonding_curve: self
+                .instruction
+                .bonding_curve
+                .expect("bonding_curve is not set"),
+
+            bonding_curve_token_account: self
+                .instruction
+                .bonding_curve_token_account
+                .expect("bonding_curve_token_account is not set"),
+
+            creator_vault: self
+                .instruction
+                .creator_vault
+                .expect("creator_vault is not set"),
+
+            creator_vault_token_account: self
+                .instruction
+                .creator_vault_token_account
+                .expect("creator_vault_token_account is not set"),
+
+            presale_vault: self
+                .instruction
+                .presale_vault
+                .expect("presale_vault is not set"),
+
+            presale_vault_token_account: self
+                .instruction
+                .presale_vault_token_account
+                .expect("presale_vault_token_account is not set"),
+
+            cex_vault: self.instruction.cex_vault.expect("cex_vault is not set"),
+
+            cex_vault_token_account: self
+                .instruction
+                .cex_vault_token_account
+                .expect("cex_vault_token_account is not set"),
+
+            brand_vault: self
+                .instruction
+                .brand_vault
+                .expect("brand_vault is not set"),
+
+            brand_vault_token_account: self
+                .instruction
+                .brand_vault_token_account
+                .expect("brand_vault_token_account is not set"),
+
+            platform_vault: self
+                .instruction
+                .platform_vault
+                .expect("platform_vault is not set"),
+
+            platform_vault_token_account: self
+                .instruction
+                .platform_vault_token_account
+                .expect("platform_vault_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct CancelLaunchCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    creator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    global: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    withdraw_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bonding_curve: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bonding_curve_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    creator_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    creator_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    presale_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    presale_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cex_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cex_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    brand_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    brand_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    platform_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    platform_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,740 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ClaimBrandkit {
+    pub brand_authority: solana_program::pubkey::Pubkey,
+
+    pub brand_vault: solana_program::pubkey::Pubkey,
+
+    pub brand_vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub bonding_curve: solana_program::pubkey::Pubkey,
+
+    pub user_token_account: solana_program::pubkey::Pubkey,
+
+    pub global: solana_program::pubkey::Pubkey,
+
+    pub mint: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
+}
+
+impl ClaimBrandkit {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.brand_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.brand_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.brand_vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.bonding_curve,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.user_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.global,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = ClaimBrandkitInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::BILLY_BONDING_CURVE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct ClaimBrandkitInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl ClaimBrandkitInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [149, 82, 199, 252, 6, 200, 9, 151],
+        }
+    }
+}
+
+/// Instruction builder for `ClaimBrandkit`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` brand_authority
+///   1. `[writable]` brand_vault
+///   2. `[writable]` brand_vault_token_account
+///   3. `[]` bonding_curve
+///   4. `[writable]` user_token_account
+///   5. `[]` global
+///   6. `[]` mint
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[]` associated_token_program
+///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   10. `[]` event_authority
+///   11. `[]` program
+#[derive(Default)]
+pub struct ClaimBrandkitBuilder {
+    brand_authority: Option<solana_program::pubkey::Pubkey>,
+    brand_vault: Option<solana_program::pubkey::Pubkey>,
+    brand_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    bonding_curve: Option<solana_program::pubkey::Pubkey>,
+    user_token_account: Option<solana_program::pubkey::Pubkey>,
+    global: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ClaimBrandkitBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn brand_authority(
+        &mut self,
+        brand_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.brand_authority = Some(brand_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault(&mut self, brand_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.brand_vault = Some(brand_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault_token_account(
+        &mut self,
+        brand_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.brand_vault_token_account = Some(brand_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn bonding_curve(&mut self, bonding_curve: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bonding_curve = Some(bonding_curve);
+        self
+    }
+    #[inline(always)]
+    pub fn user_token_account(
+        &mut self,
+        user_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.user_token_account = Some(user_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn global(&mut self, global: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.global = Some(global);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ClaimBrandkit {
+            brand_authority: self.brand_authority.expect("brand_authority is not set"),
+            brand_vault: self.brand_vault.expect("brand_vault is not set"),
+            brand_vault_token_account: self
+                .brand_vault_token_account
+                .expect("brand_vault_token_account is not set"),
+            bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
+            user_token_account: self
+                .user_token_account
+                .expect("user_token_account is not set"),
+            global: self.global.expect("global is not set"),
+            mint: self.mint.expect("mint is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            associated_token_program: self
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `claim_brandkit` CPI accounts.
+pub struct ClaimBrandkitCpiAccounts<'a, 'b> {
+    pub brand_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub global: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `claim_brandkit` CPI instruction.
+pub struct ClaimBrandkitCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub brand_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub global: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> ClaimBrandkitCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ClaimBrandkitCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            brand_authority: accounts.brand_authority,
+            brand_vault: accounts.brand_vault,
+            brand_vault_token_account: accounts.brand_vault_token_account,
+            bonding_curve: accounts.bonding_curve,
+            user_token_account: accounts.user_token_account,
+            global: accounts.global,
+            mint: accounts.mint,
+            system_program: accounts.system_program,
+            associated_token_program: accounts.associated_token_program,
+            token_program: accounts.token_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.brand_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.brand_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.brand_vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.bonding_curve.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.user_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.global.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = ClaimBrandkitInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::BILLY_BONDING_CURVE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.brand_authority.clone());
+        account_infos.push(self.brand_vault.clone());
+        account_infos.push(self.brand_vault_token_account.clone());
+        account_infos.push(self.bonding_curve.clone());
+        account_infos.push(self.user_token_account.clone());
+        account_infos.push(self.global.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ClaimBrandkit` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` brand_authority
+///   1. `[writable]` brand_vault
+///   2. `[writable]` brand_vault_token_account
+///   3. `[]` bonding_curve
+///   4. `[writable]` user_token_account
+///   5. `[]` global
+///   6. `[]` mint
+///   7. `[]` system_program
+///   8. `[]` associated_token_program
+///   9. `[]` token_program
+///   10. `[]` event_authority
+///   11. `[]` program
+pub struct ClaimBrandkitCpiBuilder<'a, 'b> {
+    instruction: Box<ClaimBrandkitCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ClaimBrandkitCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ClaimBrandkitCpiBuilderInstruction {
+            __program: program,
+            brand_authority: None,
+            brand_vault: None,
+            brand_vault_token_account: None,
+            bonding_curve: None,
+            user_token_account: None,
+            global: None,
+            mint: None,
+            system_program: None,
+            associated_token_program: None,
+            token_program: None,
+            event_authority: None,
+            program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn brand_authority(
+        &mut self,
+        brand_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.brand_authority = Some(brand_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault(
+        &mut self,
+        brand_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.brand_vault = Some(brand_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn brand_vault_token_account(
+        &mut self,
+        brand_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.brand_vault_token_account = Some(brand_vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn bonding_curve(
+        &mut self,
+        bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bonding_curve = Some(bonding_curve);
+        self
+    }
+    #[inline(always)]
+    pub fn user_token_account(
+        &mut self,
+        user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.user_token_account = Some(user_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn global(
+        &mut self,
+        global: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.global = Some(global);
+        self
+    }
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = ClaimBrandkitCpi {
+            __program: self.instruction.__program,
+
+            brand_authority: self
+                .instruction
+                .brand_authority
+                .expect("brand_authority is not set"),
+
+            brand_vault: self
+                .instruction
+                .brand_vault
+                .expect("brand_vault is not set"),
+
+            brand_vault_token_account: self
+                .instruction
+                .brand_vault_token_account
+                .expect("brand_vault_token_account is not set"),
+
+            bonding_curve: self
+                .instruction
+                .bonding_curve
+                .expect("bonding_curve is not set"),
+
+            user_token_account: self
+                .instruction
+                .user_token_account
+                .expect("user_token_account is not set"),
+
+            global: self.instruction.global.expect("global is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct ClaimBrandkitCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    brand_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    brand_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    brand_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bonding_curve: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    user_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    global: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -6,6 +6,12 @@
 //!
 
 use crate::generated::types::AllocationDataParams;
+use crate::generated::types::Allowlist;
+use crate::generated::types::CompletionPolicy;
+use crate::generated::types::CurveKind;
+use crate::generated::types::FeeSchedule;
+use crate::generated::types::LaunchProtection;
+use crate::generated::types::PresaleTerms;
 use crate::generated::types::VestingTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
@@ -36,13 +42,17 @@ pub struct CreateBondingCurve {
 
     pub platform_vault_token_account: solana_program::pubkey::Pubkey,
 
+    pub cex_vault: solana_program::pubkey::Pubkey,
+
+    pub cex_vault_token_account: solana_program::pubkey::Pubkey,
+
     pub bonding_curve: solana_program::pubkey::Pubkey,
 
     pub bonding_curve_token_account: solana_program::pubkey::Pubkey,
 
     pub global: solana_program::pubkey::Pubkey,
 
-    pub metadata: solana_program::pubkey::Pubkey,
+    pub metadata: Option<solana_program::pubkey::Pubkey>,
 
     pub system_program: solana_program::pubkey::Pubkey,
 
@@ -50,7 +60,7 @@ pub struct CreateBondingCurve {
 
     pub associated_token_program: solana_program::pubkey::Pubkey,
 
-    pub token_metadata_program: solana_program::pubkey::Pubkey,
+    pub token_metadata_program: Option<solana_program::pubkey::Pubkey>,
 
     pub rent: solana_program::pubkey::Pubkey,
 
@@ -74,7 +84,7 @@ impl CreateBondingCurve {
         args: CreateBondingCurveInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(23 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint, true,
         ));
@@ -118,6 +128,14 @@ impl CreateBondingCurve {
             self.platform_vault_token_account,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cex_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cex_vault_token_account,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.bonding_curve,
             false,
@@ -130,10 +148,16 @@ impl CreateBondingCurve {
             self.global,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            self.metadata,
-            false,
-        ));
+        if let Some(metadata) = self.metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                metadata, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BILLY_BONDING_CURVE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -146,10 +170,17 @@ impl CreateBondingCurve {
             self.associated_token_program,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.token_metadata_program,
-            false,
-        ));
+        if let Some(token_metadata_program) = self.token_metadata_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_metadata_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BILLY_BONDING_CURVE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.rent, false,
         ));
@@ -202,12 +233,20 @@ pub struct CreateBondingCurveInstructionArgs {
     pub symbol: String,
     pub uri: String,
     pub start_time: Option<i64>,
+    pub graduation_deadline: Option<i64>,
     pub token_total_supply: u64,
     pub sol_launch_threshold: u64,
     pub virtual_token_multiplier_bps: u64,
     pub virtual_sol_reserves: u64,
     pub allocation: AllocationDataParams,
     pub vesting_terms: Option<VestingTerms>,
+    pub presale_terms: Option<PresaleTerms>,
+    pub brandkit_vesting_terms: Option<VestingTerms>,
+    pub launch_protection: Option<LaunchProtection>,
+    pub fee_schedule: Option<FeeSchedule>,
+    pub allowlist: Option<Allowlist>,
+    pub curve_kind: Option<CurveKind>,
+    pub completion_policy: Option<CompletionPolicy>,
 }
 
 /// Instruction builder for `CreateBondingCurve`.
@@ -225,18 +264,20 @@ pub struct CreateBondingCurveInstructionArgs {
 ///   8. `[writable]` brand_vault_token_account
 ///   9. `[writable]` platform_vault
 ///   10. `[writable]` platform_vault_token_account
-///   11. `[writable]` bonding_curve
-///   12. `[writable]` bonding_curve_token_account
-///   13. `[]` global
-///   14. `[writable]` metadata
-///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   16. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   17. `[]` associated_token_program
-///   18. `[optional]` token_metadata_program (default to `metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`)
-///   19. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
-///   20. `[]` clock
-///   21. `[]` event_authority
-///   22. `[]` program
+///   11. `[writable]` cex_vault
+///   12. `[writable]` cex_vault_token_account
+///   13. `[writable]` bonding_curve
+///   14. `[writable]` bonding_curve_token_account
+///   15. `[]` global
+///   16. `[writable, optional]` metadata
+///   17. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   18. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   19. `[]` associated_token_program
+///   20. `[optional]` token_metadata_program (default to `metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`)
+///   21. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   22. `[]` clock
+///   23. `[]` event_authority
+///   24. `[]` program
 #[derive(Default)]
 pub struct CreateBondingCurveBuilder {
     mint: Option<solana_program::pubkey::Pubkey>,
@@ -250,6 +291,8 @@ pub struct CreateBondingCurveBuilder {
     brand_vault_token_account: Option<solana_program::pubkey::Pubkey>,
     platform_vault: Option<solana_program::pubkey::Pubkey>,
     platform_vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    cex_vault: Option<solana_program::pubkey::Pubkey>,
+    cex_vault_token_account: Option<solana_program::pubkey::Pubkey>,
     bonding_curve: Option<solana_program::pubkey::Pubkey>,
     bonding_curve_token_account: Option<solana_program::pubkey::Pubkey>,
     global: Option<solana_program::pubkey::Pubkey>,
@@ -266,12 +309,20 @@ pub struct CreateBondingCurveBuilder {
     symbol: Option<String>,
     uri: Option<String>,
     start_time: Option<i64>,
+    graduation_deadline: Option<i64>,
     token_total_supply: Option<u64>,
     sol_launch_threshold: Option<u64>,
     virtual_token_multiplier_bps: Option<u64>,
     virtual_sol_reserves: Option<u64>,
     allocation: Option<AllocationDataParams>,
     vesting_terms: Option<VestingTerms>,
+    presale_terms: Option<PresaleTerms>,
+    brandkit_vesting_terms: Option<VestingTerms>,
+    launch_protection: Option<LaunchProtection>,
+    fee_schedule: Option<FeeSchedule>,
+    allowlist: Option<Allowlist>,
+    curve_kind: Option<CurveKind>,
+    completion_policy: Option<CompletionPolicy>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -350,6 +401,19 @@ impl CreateBondingCurveBuilder {
         self
     }
     #[inline(always)]
+    pub fn cex_vault(&mut self, cex_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.cex_vault = Some(cex_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn cex_vault_token_account(
+        &mut self,
+        cex_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.cex_vault_token_account = Some(cex_vault_token_account);
+        self
+    }
+    #[inline(always)]
     pub fn bonding_curve(&mut self, bonding_curve: solana_program::pubkey::Pubkey) -> &mut Self {
         self.bonding_curve = Some(bonding_curve);
         self
@@ -367,9 +431,10 @@ impl CreateBondingCurveBuilder {
         self.global = Some(global);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn metadata(&mut self, metadata: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.metadata = Some(metadata);
+    pub fn metadata(&mut self, metadata: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.metadata = metadata;
         self
     }
     /// `[optional account, default to '11111111111111111111111111111111']`
@@ -396,9 +461,9 @@ impl CreateBondingCurveBuilder {
     #[inline(always)]
     pub fn token_metadata_program(
         &mut self,
-        token_metadata_program: solana_program::pubkey::Pubkey,
+        token_metadata_program: Option<solana_program::pubkey::Pubkey>,
     ) -> &mut Self {
-        self.token_metadata_program = Some(token_metadata_program);
+        self.token_metadata_program = token_metadata_program;
         self
     }
     /// `[optional account, default to 'SysvarRent111111111111111111111111111111111']`
@@ -446,6 +511,12 @@ impl CreateBondingCurveBuilder {
         self.start_time = Some(start_time);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn graduation_deadline(&mut self, graduation_deadline: i64) -> &mut Self {
+        self.graduation_deadline = Some(graduation_deadline);
+        self
+    }
     #[inline(always)]
     pub fn token_total_supply(&mut self, token_total_supply: u64) -> &mut Self {
         self.token_total_supply = Some(token_total_supply);
@@ -477,6 +548,48 @@ impl CreateBondingCurveBuilder {
         self.vesting_terms = Some(vesting_terms);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn presale_terms(&mut self, presale_terms: PresaleTerms) -> &mut Self {
+        self.presale_terms = Some(presale_terms);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn brandkit_vesting_terms(&mut self, brandkit_vesting_terms: VestingTerms) -> &mut Self {
+        self.brandkit_vesting_terms = Some(brandkit_vesting_terms);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn launch_protection(&mut self, launch_protection: LaunchProtection) -> &mut Self {
+        self.launch_protection = Some(launch_protection);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_schedule(&mut self, fee_schedule: FeeSchedule) -> &mut Self {
+        self.fee_schedule = Some(fee_schedule);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist(&mut self, allowlist: Allowlist) -> &mut Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn curve_kind(&mut self, curve_kind: CurveKind) -> &mut Self {
+        self.curve_kind = Some(curve_kind);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn completion_policy(&mut self, completion_policy: CompletionPolicy) -> &mut Self {
+        self.completion_policy = Some(completion_policy);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -497,57 +610,59 @@ impl CreateBondingCurveBuilder {
     }
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        let accounts =
-            CreateBondingCurve {
-                mint: self.mint.expect("mint is not set"),
-                creator: self.creator.expect("creator is not set"),
-                creator_vault: self.creator_vault.expect("creator_vault is not set"),
-                creator_vault_token_account: self
-                    .creator_vault_token_account
-                    .expect("creator_vault_token_account is not set"),
-                presale_vault: self.presale_vault.expect("presale_vault is not set"),
-                presale_vault_token_account: self
-                    .presale_vault_token_account
-                    .expect("presale_vault_token_account is not set"),
-                brand_authority: self.brand_authority.expect("brand_authority is not set"),
-                brand_vault: self.brand_vault.expect("brand_vault is not set"),
-                brand_vault_token_account: self
-                    .brand_vault_token_account
-                    .expect("brand_vault_token_account is not set"),
-                platform_vault: self.platform_vault.expect("platform_vault is not set"),
-                platform_vault_token_account: self
-                    .platform_vault_token_account
-                    .expect("platform_vault_token_account is not set"),
-                bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
-                bonding_curve_token_account: self
-                    .bonding_curve_token_account
-                    .expect("bonding_curve_token_account is not set"),
-                global: self.global.expect("global is not set"),
-                metadata: self.metadata.expect("metadata is not set"),
-                system_program: self
-                    .system_program
-                    .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
-                token_program: self.token_program.unwrap_or(solana_program::pubkey!(
-                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-                )),
-                associated_token_program: self
-                    .associated_token_program
-                    .expect("associated_token_program is not set"),
-                token_metadata_program: self.token_metadata_program.unwrap_or(
-                    solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
-                ),
-                rent: self.rent.unwrap_or(solana_program::pubkey!(
-                    "SysvarRent111111111111111111111111111111111"
-                )),
-                clock: self.clock.expect("clock is not set"),
-                event_authority: self.event_authority.expect("event_authority is not set"),
-                program: self.program.expect("program is not set"),
-            };
+        let accounts = CreateBondingCurve {
+            mint: self.mint.expect("mint is not set"),
+            creator: self.creator.expect("creator is not set"),
+            creator_vault: self.creator_vault.expect("creator_vault is not set"),
+            creator_vault_token_account: self
+                .creator_vault_token_account
+                .expect("creator_vault_token_account is not set"),
+            presale_vault: self.presale_vault.expect("presale_vault is not set"),
+            presale_vault_token_account: self
+                .presale_vault_token_account
+                .expect("presale_vault_token_account is not set"),
+            brand_authority: self.brand_authority.expect("brand_authority is not set"),
+            brand_vault: self.brand_vault.expect("brand_vault is not set"),
+            brand_vault_token_account: self
+                .brand_vault_token_account
+                .expect("brand_vault_token_account is not set"),
+            platform_vault: self.platform_vault.expect("platform_vault is not set"),
+            platform_vault_token_account: self
+                .platform_vault_token_account
+                .expect("platform_vault_token_account is not set"),
+            cex_vault: self.cex_vault.expect("cex_vault is not set"),
+            cex_vault_token_account: self
+                .cex_vault_token_account
+                .expect("cex_vault_token_account is not set"),
+            bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
+            bonding_curve_token_account: self
+                .bonding_curve_token_account
+                .expect("bonding_curve_token_account is not set"),
+            global: self.global.expect("global is not set"),
+            metadata: self.metadata,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            associated_token_program: self
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+            token_metadata_program: self.token_metadata_program,
+            rent: self.rent.unwrap_or(solana_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            clock: self.clock.expect("clock is not set"),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
+        };
         let args = CreateBondingCurveInstructionArgs {
             name: self.name.clone().expect("name is not set"),
             symbol: self.symbol.clone().expect("symbol is not set"),
             uri: self.uri.clone().expect("uri is not set"),
             start_time: self.start_time.clone(),
+            graduation_deadline: self.graduation_deadline.clone(),
             token_total_supply: self
                 .token_total_supply
                 .clone()
@@ -566,6 +681,13 @@ impl CreateBondingCurveBuilder {
                 .expect("virtual_sol_reserves is not set"),
             allocation: self.allocation.clone().expect("allocation is not set"),
             vesting_terms: self.vesting_terms.clone(),
+            presale_terms: self.presale_terms.clone(),
+            brandkit_vesting_terms: self.brandkit_vesting_terms.clone(),
+            launch_protection: self.launch_protection.clone(),
+            fee_schedule: self.fee_schedule.clone(),
+            allowlist: self.allowlist.clone(),
+            curve_kind: self.curve_kind.clone(),
+            completion_policy: self.completion_policy.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -596,13 +718,17 @@ pub struct CreateBondingCurveCpiAccounts<'a, 'b> {
 
     pub platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub cex_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub cex_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub bonding_curve_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub global: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    pub metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -610,7 +736,7 @@ pub struct CreateBondingCurveCpiAccounts<'a, 'b> {
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
+    pub token_metadata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub rent: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -648,13 +774,17 @@ pub struct CreateBondingCurveCpi<'a, 'b> {
 
     pub platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub cex_vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub cex_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub bonding_curve_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub global: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    pub metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -662,7 +792,7 @@ pub struct CreateBondingCurveCpi<'a, 'b> {
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
+    pub token_metadata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub rent: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -694,6 +824,8 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
             brand_vault_token_account: accounts.brand_vault_token_account,
             platform_vault: accounts.platform_vault,
             platform_vault_token_account: accounts.platform_vault_token_account,
+            cex_vault: accounts.cex_vault,
+            cex_vault_token_account: accounts.cex_vault_token_account,
             bonding_curve: accounts.bonding_curve,
             bonding_curve_token_account: accounts.bonding_curve_token_account,
             global: accounts.global,
@@ -742,7 +874,7 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(23 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(25 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.mint.key,
             true,
@@ -787,6 +919,14 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
             *self.platform_vault_token_account.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cex_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cex_vault_token_account.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.bonding_curve.key,
             false,
@@ -799,10 +939,17 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
             *self.global.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.metadata.key,
-            false,
-        ));
+        if let Some(metadata) = self.metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *metadata.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BILLY_BONDING_CURVE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -815,10 +962,17 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
             *self.associated_token_program.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.token_metadata_program.key,
-            false,
-        ));
+        if let Some(token_metadata_program) = self.token_metadata_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_metadata_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BILLY_BONDING_CURVE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.rent.key,
             false,
@@ -853,7 +1007,7 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(23 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(25 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.creator.clone());
@@ -866,14 +1020,20 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
         account_infos.push(self.brand_vault_token_account.clone());
         account_infos.push(self.platform_vault.clone());
         account_infos.push(self.platform_vault_token_account.clone());
+        account_infos.push(self.cex_vault.clone());
+        account_infos.push(self.cex_vault_token_account.clone());
         account_infos.push(self.bonding_curve.clone());
         account_infos.push(self.bonding_curve_token_account.clone());
         account_infos.push(self.global.clone());
-        account_infos.push(self.metadata.clone());
+        if let Some(metadata) = self.metadata {
+            account_infos.push(metadata.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
-        account_infos.push(self.token_metadata_program.clone());
+        if let Some(token_metadata_program) = self.token_metadata_program {
+            account_infos.push(token_metadata_program.clone());
+        }
         account_infos.push(self.rent.clone());
         account_infos.push(self.clock.clone());
         account_infos.push(self.event_authority.clone());
@@ -905,18 +1065,20 @@ impl<'a, 'b> CreateBondingCurveCpi<'a, 'b> {
 ///   8. `[writable]` brand_vault_token_account
 ///   9. `[writable]` platform_vault
 ///   10. `[writable]` platform_vault_token_account
-///   11. `[writable]` bonding_curve
-///   12. `[writable]` bonding_curve_token_account
-///   13. `[]` global
-///   14. `[writable]` metadata
-///   15. `[]` system_program
-///   16. `[]` token_program
-///   17. `[]` associated_token_program
-///   18. `[]` token_metadata_program
-///   19. `[]` rent
-///   20. `[]` clock
-///   21. `[]` event_authority
-///   22. `[]` program
+///   11. `[writable]` cex_vault
+///   12. `[writable]` cex_vault_token_account
+///   13. `[writable]` bonding_curve
+///   14. `[writable]` bonding_curve_token_account
+///   15. `[]` global
+///   16. `[writable, optional]` metadata
+///   17. `[]` system_program
+///   18. `[]` token_program
+///   19. `[]` associated_token_program
+///   20. `[optional]` token_metadata_program
+///   21. `[]` rent
+///   22. `[]` clock
+///   23. `[]` event_authority
+///   24. `[]` program
 pub struct CreateBondingCurveCpiBuilder<'a, 'b> {
     instruction: Box<CreateBondingCurveCpiBuilderInstruction<'a, 'b>>,
 }
@@ -936,6 +1098,8 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
             brand_vault_token_account: None,
             platform_vault: None,
             platform_vault_token_account: None,
+            cex_vault: None,
+            cex_vault_token_account: None,
             bonding_curve: None,
             bonding_curve_token_account: None,
             global: None,
@@ -952,12 +1116,20 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
             symbol: None,
             uri: None,
             start_time: None,
+            graduation_deadline: None,
             token_total_supply: None,
             sol_launch_threshold: None,
             virtual_token_multiplier_bps: None,
             virtual_sol_reserves: None,
             allocation: None,
             vesting_terms: None,
+            presale_terms: None,
+            brandkit_vesting_terms: None,
+            launch_protection: None,
+            fee_schedule: None,
+            allowlist: None,
+            curve_kind: None,
+            completion_policy: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -1048,6 +1220,22 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn cex_vault(
+        &mut self,
+        cex_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.cex_vault = Some(cex_vault);
+        self
+    }
+    #[inline(always)]
+    pub fn cex_vault_token_account(
+        &mut self,
+        cex_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.cex_vault_token_account = Some(cex_vault_token_account);
+        self
+    }
+    #[inline(always)]
     pub fn bonding_curve(
         &mut self,
         bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
@@ -1071,12 +1259,13 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
         self.instruction.global = Some(global);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn metadata(
         &mut self,
-        metadata: &'b solana_program::account_info::AccountInfo<'a>,
+        metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.metadata = Some(metadata);
+        self.instruction.metadata = metadata;
         self
     }
     #[inline(always)]
@@ -1103,12 +1292,13 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
         self.instruction.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn token_metadata_program(
         &mut self,
-        token_metadata_program: &'b solana_program::account_info::AccountInfo<'a>,
+        token_metadata_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.token_metadata_program = Some(token_metadata_program);
+        self.instruction.token_metadata_program = token_metadata_program;
         self
     }
     #[inline(always)]
@@ -1158,6 +1348,12 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
         self.instruction.start_time = Some(start_time);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn graduation_deadline(&mut self, graduation_deadline: i64) -> &mut Self {
+        self.instruction.graduation_deadline = Some(graduation_deadline);
+        self
+    }
     #[inline(always)]
     pub fn token_total_supply(&mut self, token_total_supply: u64) -> &mut Self {
         self.instruction.token_total_supply = Some(token_total_supply);
@@ -1189,6 +1385,48 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
         self.instruction.vesting_terms = Some(vesting_terms);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn presale_terms(&mut self, presale_terms: PresaleTerms) -> &mut Self {
+        self.instruction.presale_terms = Some(presale_terms);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn brandkit_vesting_terms(&mut self, brandkit_vesting_terms: VestingTerms) -> &mut Self {
+        self.instruction.brandkit_vesting_terms = Some(brandkit_vesting_terms);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn launch_protection(&mut self, launch_protection: LaunchProtection) -> &mut Self {
+        self.instruction.launch_protection = Some(launch_protection);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_schedule(&mut self, fee_schedule: FeeSchedule) -> &mut Self {
+        self.instruction.fee_schedule = Some(fee_schedule);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn allowlist(&mut self, allowlist: Allowlist) -> &mut Self {
+        self.instruction.allowlist = Some(allowlist);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn curve_kind(&mut self, curve_kind: CurveKind) -> &mut Self {
+        self.instruction.curve_kind = Some(curve_kind);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn completion_policy(&mut self, completion_policy: CompletionPolicy) -> &mut Self {
+        self.instruction.completion_policy = Some(completion_policy);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -1235,6 +1473,7 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
             symbol: self.instruction.symbol.clone().expect("symbol is not set"),
             uri: self.instruction.uri.clone().expect("uri is not set"),
             start_time: self.instruction.start_time.clone(),
+            graduation_deadline: self.instruction.graduation_deadline.clone(),
             token_total_supply: self
                 .instruction
                 .token_total_supply
@@ -1261,6 +1500,13 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("allocation is not set"),
             vesting_terms: self.instruction.vesting_terms.clone(),
+            presale_terms: self.instruction.presale_terms.clone(),
+            brandkit_vesting_terms: self.instruction.brandkit_vesting_terms.clone(),
+            launch_protection: self.instruction.launch_protection.clone(),
+            fee_schedule: self.instruction.fee_schedule.clone(),
+            allowlist: self.instruction.allowlist.clone(),
+            curve_kind: self.instruction.curve_kind.clone(),
+            completion_policy: self.instruction.completion_policy.clone(),
         };
         let instruction = CreateBondingCurveCpi {
             __program: self.instruction.__program,
@@ -1314,6 +1560,13 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
                 .platform_vault_token_account
                 .expect("platform_vault_token_account is not set"),
 
+            cex_vault: self.instruction.cex_vault.expect("cex_vault is not set"),
+
+            cex_vault_token_account: self
+                .instruction
+                .cex_vault_token_account
+                .expect("cex_vault_token_account is not set"),
+
             bonding_curve: self
                 .instruction
                 .bonding_curve
@@ -1326,7 +1579,7 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
 
             global: self.instruction.global.expect("global is not set"),
 
-            metadata: self.instruction.metadata.expect("metadata is not set"),
+            metadata: self.instruction.metadata,
 
             system_program: self
                 .instruction
@@ -1343,10 +1596,7 @@ impl<'a, 'b> CreateBondingCurveCpiBuilder<'a, 'b> {
                 .associated_token_program
                 .expect("associated_token_program is not set"),
 
-            token_metadata_program: self
-                .instruction
-                .token_metadata_program
-                .expect("token_metadata_program is not set"),
+            token_metadata_program: self.instruction.token_metadata_program,
 
             rent: self.instruction.rent.expect("rent is not set"),
 
@@ -1380,6 +1630,8 @@ struct CreateBondingCurveCpiBuilderInstruction<'a, 'b> {
     brand_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     platform_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     platform_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cex_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    cex_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bonding_curve: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bonding_curve_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     global: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -1396,12 +1648,20 @@ struct CreateBondingCurveCpiBuilderInstruction<'a, 'b> {
     symbol: Option<String>,
     uri: Option<String>,
     start_time: Option<i64>,
+    graduation_deadline: Option<i64>,
     token_total_supply: Option<u64>,
     sol_launch_threshold: Option<u64>,
     virtual_token_multiplier_bps: Option<u64>,
     virtual_sol_reserves: Option<u64>,
     allocation: Option<AllocationDataParams>,
     vesting_terms: Option<VestingTerms>,
+    presale_terms: Option<PresaleTerms>,
+    brandkit_vesting_terms: Option<VestingTerms>,
+    launch_protection: Option<LaunchProtection>,
+    fee_schedule: Option<FeeSchedule>,
+    allowlist: Option<Allowlist>,
+    curve_kind: Option<CurveKind>,
+    completion_policy: Option<CompletionPolicy>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
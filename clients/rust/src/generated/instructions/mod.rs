@@ -5,16 +5,50 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_authority;
+pub(crate) mod r#cancel_authority_transfer;
+pub(crate) mod r#cancel_launch;
+pub(crate) mod r#cancel_settings;
+pub(crate) mod r#claim_brandkit;
 pub(crate) mod r#claim_creator_vesting;
+pub(crate) mod r#claim_platform_tokens;
+pub(crate) mod r#claim_presale;
+pub(crate) mod r#contribute_presale;
 pub(crate) mod r#create_bonding_curve;
+pub(crate) mod r#execute_settings;
+pub(crate) mod r#fail_curve;
+pub(crate) mod r#graduate;
 pub(crate) mod r#initialize;
+pub(crate) mod r#quote;
+pub(crate) mod r#refund;
+pub(crate) mod r#release_cex_tokens;
+pub(crate) mod r#reschedule_launch;
+pub(crate) mod r#schedule_settings;
 pub(crate) mod r#set_params;
+pub(crate) mod r#skim;
 pub(crate) mod r#swap;
 pub(crate) mod r#withdraw_fees;
 
+pub use self::r#accept_authority::*;
+pub use self::r#cancel_authority_transfer::*;
+pub use self::r#cancel_launch::*;
+pub use self::r#cancel_settings::*;
+pub use self::r#claim_brandkit::*;
 pub use self::r#claim_creator_vesting::*;
+pub use self::r#claim_platform_tokens::*;
+pub use self::r#claim_presale::*;
+pub use self::r#contribute_presale::*;
 pub use self::r#create_bonding_curve::*;
+pub use self::r#execute_settings::*;
+pub use self::r#fail_curve::*;
+pub use self::r#graduate::*;
 pub use self::r#initialize::*;
+pub use self::r#quote::*;
+pub use self::r#refund::*;
+pub use self::r#release_cex_tokens::*;
+pub use self::r#reschedule_launch::*;
+pub use self::r#schedule_settings::*;
 pub use self::r#set_params::*;
+pub use self::r#skim::*;
 pub use self::r#swap::*;
 pub use self::r#withdraw_fees::*;
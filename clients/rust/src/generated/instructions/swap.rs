@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::SwapParams;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -24,8 +25,12 @@ pub struct Swap {
 
     pub platform_vault: solana_program::pubkey::Pubkey,
 
+    pub platform_vault_token_account: solana_program::pubkey::Pubkey,
+
     pub user_token_account: solana_program::pubkey::Pubkey,
 
+    pub user_position: Option<solana_program::pubkey::Pubkey>,
+
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
@@ -52,7 +57,7 @@ impl Swap {
         args: SwapInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.user, true,
         ));
@@ -60,7 +65,7 @@ impl Swap {
             self.global,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.mint, false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
@@ -75,10 +80,25 @@ impl Swap {
             self.platform_vault,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.platform_vault_token_account,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.user_token_account,
             false,
         ));
+        if let Some(user_position) = self.user_position {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                user_position,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BILLY_BONDING_CURVE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -134,9 +154,7 @@ impl SwapInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SwapInstructionArgs {
-    pub base_in: bool,
-    pub exact_in_amount: u64,
-    pub min_out_amount: u64,
+    pub params: SwapParams,
 }
 
 /// Instruction builder for `Swap`.
@@ -145,17 +163,19 @@ pub struct SwapInstructionArgs {
 ///
 ///   0. `[writable, signer]` user
 ///   1. `[]` global
-///   2. `[]` mint
+///   2. `[writable]` mint
 ///   3. `[writable]` bonding_curve
 ///   4. `[writable]` bonding_curve_token_account
 ///   5. `[writable]` platform_vault
-///   6. `[writable]` user_token_account
-///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   9. `[]` associated_token_program
-///   10. `[]` clock
-///   11. `[]` event_authority
-///   12. `[]` program
+///   6. `[writable]` platform_vault_token_account
+///   7. `[writable]` user_token_account
+///   8. `[writable, optional]` user_position
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   11. `[]` associated_token_program
+///   12. `[]` clock
+///   13. `[]` event_authority
+///   14. `[]` program
 #[derive(Default)]
 pub struct SwapBuilder {
     user: Option<solana_program::pubkey::Pubkey>,
@@ -164,16 +184,16 @@ pub struct SwapBuilder {
     bonding_curve: Option<solana_program::pubkey::Pubkey>,
     bonding_curve_token_account: Option<solana_program::pubkey::Pubkey>,
     platform_vault: Option<solana_program::pubkey::Pubkey>,
+    platform_vault_token_account: Option<solana_program::pubkey::Pubkey>,
     user_token_account: Option<solana_program::pubkey::Pubkey>,
+    user_position: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
     clock: Option<solana_program::pubkey::Pubkey>,
     event_authority: Option<solana_program::pubkey::Pubkey>,
     program: Option<solana_program::pubkey::Pubkey>,
-    base_in: Option<bool>,
-    exact_in_amount: Option<u64>,
-    min_out_amount: Option<u64>,
+    params: Option<SwapParams>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -215,6 +235,14 @@ impl SwapBuilder {
         self
     }
     #[inline(always)]
+    pub fn platform_vault_token_account(
+        &mut self,
+        platform_vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.platform_vault_token_account = Some(platform_vault_token_account);
+        self
+    }
+    #[inline(always)]
     pub fn user_token_account(
         &mut self,
         user_token_account: solana_program::pubkey::Pubkey,
@@ -222,6 +250,15 @@ impl SwapBuilder {
         self.user_token_account = Some(user_token_account);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn user_position(
+        &mut self,
+        user_position: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.user_position = user_position;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
@@ -261,18 +298,8 @@ impl SwapBuilder {
         self
     }
     #[inline(always)]
-    pub fn base_in(&mut self, base_in: bool) -> &mut Self {
-        self.base_in = Some(base_in);
-        self
-    }
-    #[inline(always)]
-    pub fn exact_in_amount(&mut self, exact_in_amount: u64) -> &mut Self {
-        self.exact_in_amount = Some(exact_in_amount);
-        self
-    }
-    #[inline(always)]
-    pub fn min_out_amount(&mut self, min_out_amount: u64) -> &mut Self {
-        self.min_out_amount = Some(min_out_amount);
+    pub fn params(&mut self, params: SwapParams) -> &mut Self {
+        self.params = Some(params);
         self
     }
     /// Add an aditional account to the instruction.
@@ -304,9 +331,13 @@ impl SwapBuilder {
                 .bonding_curve_token_account
                 .expect("bonding_curve_token_account is not set"),
             platform_vault: self.platform_vault.expect("platform_vault is not set"),
+            platform_vault_token_account: self
+                .platform_vault_token_account
+                .expect("platform_vault_token_account is not set"),
             user_token_account: self
                 .user_token_account
                 .expect("user_token_account is not set"),
+            user_position: self.user_position,
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -321,15 +352,7 @@ impl SwapBuilder {
             program: self.program.expect("program is not set"),
         };
         let args = SwapInstructionArgs {
-            base_in: self.base_in.clone().expect("base_in is not set"),
-            exact_in_amount: self
-                .exact_in_amount
-                .clone()
-                .expect("exact_in_amount is not set"),
-            min_out_amount: self
-                .min_out_amount
-                .clone()
-                .expect("min_out_amount is not set"),
+            params: self.params.clone().expect("params is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -350,8 +373,12 @@ pub struct SwapCpiAccounts<'a, 'b> {
 
     pub platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub user_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -382,8 +409,12 @@ pub struct SwapCpi<'a, 'b> {
 
     pub platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub user_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -413,7 +444,9 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             bonding_curve: accounts.bonding_curve,
             bonding_curve_token_account: accounts.bonding_curve_token_account,
             platform_vault: accounts.platform_vault,
+            platform_vault_token_account: accounts.platform_vault_token_account,
             user_token_account: accounts.user_token_account,
+            user_position: accounts.user_position,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
@@ -456,7 +489,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.user.key,
             true,
@@ -465,7 +498,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             *self.global.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.mint.key,
             false,
         ));
@@ -481,10 +514,25 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             *self.platform_vault.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.platform_vault_token_account.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.user_token_account.key,
             false,
         ));
+        if let Some(user_position) = self.user_position {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *user_position.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::BILLY_BONDING_CURVE_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -525,7 +573,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(15 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.user.clone());
         account_infos.push(self.global.clone());
@@ -533,7 +581,11 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
         account_infos.push(self.bonding_curve.clone());
         account_infos.push(self.bonding_curve_token_account.clone());
         account_infos.push(self.platform_vault.clone());
+        account_infos.push(self.platform_vault_token_account.clone());
         account_infos.push(self.user_token_account.clone());
+        if let Some(user_position) = self.user_position {
+            account_infos.push(user_position.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
@@ -558,17 +610,19 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` user
 ///   1. `[]` global
-///   2. `[]` mint
+///   2. `[writable]` mint
 ///   3. `[writable]` bonding_curve
 ///   4. `[writable]` bonding_curve_token_account
 ///   5. `[writable]` platform_vault
-///   6. `[writable]` user_token_account
-///   7. `[]` system_program
-///   8. `[]` token_program
-///   9. `[]` associated_token_program
-///   10. `[]` clock
-///   11. `[]` event_authority
-///   12. `[]` program
+///   6. `[writable]` platform_vault_token_account
+///   7. `[writable]` user_token_account
+///   8. `[writable, optional]` user_position
+///   9. `[]` system_program
+///   10. `[]` token_program
+///   11. `[]` associated_token_program
+///   12. `[]` clock
+///   13. `[]` event_authority
+///   14. `[]` program
 pub struct SwapCpiBuilder<'a, 'b> {
     instruction: Box<SwapCpiBuilderInstruction<'a, 'b>>,
 }
@@ -583,16 +637,16 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
             bonding_curve: None,
             bonding_curve_token_account: None,
             platform_vault: None,
+            platform_vault_token_account: None,
             user_token_account: None,
+            user_position: None,
             system_program: None,
             token_program: None,
             associated_token_program: None,
             clock: None,
             event_authority: None,
             program: None,
-            base_in: None,
-            exact_in_amount: None,
-            min_out_amount: None,
+            params: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -640,6 +694,14 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn platform_vault_token_account(
+        &mut self,
+        platform_vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.platform_vault_token_account = Some(platform_vault_token_account);
+        self
+    }
+    #[inline(always)]
     pub fn user_token_account(
         &mut self,
         user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
@@ -647,6 +709,15 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
         self.instruction.user_token_account = Some(user_token_account);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn user_position(
+        &mut self,
+        user_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.user_position = user_position;
+        self
+    }
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -693,18 +764,8 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn base_in(&mut self, base_in: bool) -> &mut Self {
-        self.instruction.base_in = Some(base_in);
-        self
-    }
-    #[inline(always)]
-    pub fn exact_in_amount(&mut self, exact_in_amount: u64) -> &mut Self {
-        self.instruction.exact_in_amount = Some(exact_in_amount);
-        self
-    }
-    #[inline(always)]
-    pub fn min_out_amount(&mut self, min_out_amount: u64) -> &mut Self {
-        self.instruction.min_out_amount = Some(min_out_amount);
+    pub fn params(&mut self, params: SwapParams) -> &mut Self {
+        self.instruction.params = Some(params);
         self
     }
     /// Add an additional account to the instruction.
@@ -749,21 +810,7 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = SwapInstructionArgs {
-            base_in: self
-                .instruction
-                .base_in
-                .clone()
-                .expect("base_in is not set"),
-            exact_in_amount: self
-                .instruction
-                .exact_in_amount
-                .clone()
-                .expect("exact_in_amount is not set"),
-            min_out_amount: self
-                .instruction
-                .min_out_amount
-                .clone()
-                .expect("min_out_amount is not set"),
+            params: self.instruction.params.clone().expect("params is not set"),
         };
         let instruction = SwapCpi {
             __program: self.instruction.__program,
@@ -789,11 +836,18 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                 .platform_vault
                 .expect("platform_vault is not set"),
 
+            platform_vault_token_account: self
+                .instruction
+                .platform_vault_token_account
+                .expect("platform_vault_token_account is not set"),
+
             user_token_account: self
                 .instruction
                 .user_token_account
                 .expect("user_token_account is not set"),
 
+            user_position: self.instruction.user_position,
+
             system_program: self
                 .instruction
                 .system_program
@@ -834,16 +888,16 @@ struct SwapCpiBuilderInstruction<'a, 'b> {
     bonding_curve: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     bonding_curve_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     platform_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    platform_vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     user_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    user_position: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     clock: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    base_in: Option<bool>,
-    exact_in_amount: Option<u64>,
-    min_out_amount: Option<u64>,
+    params: Option<SwapParams>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
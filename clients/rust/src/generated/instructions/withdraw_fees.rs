@@ -18,6 +18,8 @@ pub struct WithdrawFees {
 
     pub mint: solana_program::pubkey::Pubkey,
 
+    pub bonding_curve: solana_program::pubkey::Pubkey,
+
     pub platform_vault: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
@@ -40,7 +42,7 @@ impl WithdrawFees {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -52,6 +54,10 @@ impl WithdrawFees {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.bonding_curve,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.platform_vault,
             false,
@@ -107,17 +113,19 @@ impl WithdrawFeesInstructionData {
 ///   0. `[writable, signer]` authority
 ///   1. `[]` global
 ///   2. `[]` mint
-///   3. `[writable]` platform_vault
-///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   5. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-///   6. `[]` clock
-///   7. `[]` event_authority
-///   8. `[]` program
+///   3. `[]` bonding_curve
+///   4. `[writable]` platform_vault
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   7. `[]` clock
+///   8. `[]` event_authority
+///   9. `[]` program
 #[derive(Default)]
 pub struct WithdrawFeesBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     global: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
+    bonding_curve: Option<solana_program::pubkey::Pubkey>,
     platform_vault: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
@@ -147,6 +155,11 @@ impl WithdrawFeesBuilder {
         self
     }
     #[inline(always)]
+    pub fn bonding_curve(&mut self, bonding_curve: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.bonding_curve = Some(bonding_curve);
+        self
+    }
+    #[inline(always)]
     pub fn platform_vault(&mut self, platform_vault: solana_program::pubkey::Pubkey) -> &mut Self {
         self.platform_vault = Some(platform_vault);
         self
@@ -205,6 +218,7 @@ impl WithdrawFeesBuilder {
             authority: self.authority.expect("authority is not set"),
             global: self.global.expect("global is not set"),
             mint: self.mint.expect("mint is not set"),
+            bonding_curve: self.bonding_curve.expect("bonding_curve is not set"),
             platform_vault: self.platform_vault.expect("platform_vault is not set"),
             system_program: self
                 .system_program
@@ -229,6 +243,8 @@ pub struct WithdrawFeesCpiAccounts<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -253,6 +269,8 @@ pub struct WithdrawFeesCpi<'a, 'b> {
 
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
 
+    pub bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+
     pub platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -276,6 +294,7 @@ impl<'a, 'b> WithdrawFeesCpi<'a, 'b> {
             authority: accounts.authority,
             global: accounts.global,
             mint: accounts.mint,
+            bonding_curve: accounts.bonding_curve,
             platform_vault: accounts.platform_vault,
             system_program: accounts.system_program,
             token_program: accounts.token_program,
@@ -317,7 +336,7 @@ impl<'a, 'b> WithdrawFeesCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -330,6 +349,10 @@ impl<'a, 'b> WithdrawFeesCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.bonding_curve.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.platform_vault.key,
             false,
@@ -368,11 +391,12 @@ impl<'a, 'b> WithdrawFeesCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.global.clone());
         account_infos.push(self.mint.clone());
+        account_infos.push(self.bonding_curve.clone());
         account_infos.push(self.platform_vault.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
@@ -398,12 +422,13 @@ impl<'a, 'b> WithdrawFeesCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[]` global
 ///   2. `[]` mint
-///   3. `[writable]` platform_vault
-///   4. `[]` system_program
-///   5. `[]` token_program
-///   6. `[]` clock
-///   7. `[]` event_authority
-///   8. `[]` program
+///   3. `[]` bonding_curve
+///   4. `[writable]` platform_vault
+///   5. `[]` system_program
+///   6. `[]` token_program
+///   7. `[]` clock
+///   8. `[]` event_authority
+///   9. `[]` program
 pub struct WithdrawFeesCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawFeesCpiBuilderInstruction<'a, 'b>>,
 }
@@ -415,6 +440,7 @@ impl<'a, 'b> WithdrawFeesCpiBuilder<'a, 'b> {
             authority: None,
             global: None,
             mint: None,
+            bonding_curve: None,
             platform_vault: None,
             system_program: None,
             token_program: None,
@@ -447,6 +473,14 @@ impl<'a, 'b> WithdrawFeesCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn bonding_curve(
+        &mut self,
+        bonding_curve: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bonding_curve = Some(bonding_curve);
+        self
+    }
+    #[inline(always)]
     pub fn platform_vault(
         &mut self,
         platform_vault: &'b solana_program::account_info::AccountInfo<'a>,
@@ -541,6 +575,11 @@ impl<'a, 'b> WithdrawFeesCpiBuilder<'a, 'b> {
 
             mint: self.instruction.mint.expect("mint is not set"),
 
+            bonding_curve: self
+                .instruction
+                .bonding_curve
+                .expect("bonding_curve is not set"),
+
             platform_vault: self
                 .instruction
                 .platform_vault
@@ -577,6 +616,7 @@ struct WithdrawFeesCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     global: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bonding_curve: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     platform_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -6,10 +6,13 @@
 //!
 
 use crate::generated::types::ProgramStatus;
+use crate::generated::types::SettingsCaps;
+use crate::generated::types::VestingTerms;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
@@ -19,5 +22,11 @@ pub struct GlobalSettingsInput {
     pub trade_fee_bps: Option<u64>,
     pub created_mint_decimals: Option<u8>,
     pub launch_fee_lamports: Option<u64>,
+    pub launch_fee_refund_bps: Option<u64>,
+    pub max_launch_lead_time: Option<i64>,
     pub status: Option<ProgramStatus>,
+    pub amm_program: Option<Pubkey>,
+    pub platform_vesting_terms: Option<VestingTerms>,
+    pub cex_vesting_terms: Option<VestingTerms>,
+    pub caps: Option<SettingsCaps>,
 }
@@ -7,14 +7,50 @@
 
 pub(crate) mod r#allocation_data;
 pub(crate) mod r#allocation_data_params;
+pub(crate) mod r#allowlist;
+pub(crate) mod r#allowlist_proof;
+pub(crate) mod r#authority_role;
+pub(crate) mod r#completion_policy;
+pub(crate) mod r#curve_kind;
+pub(crate) mod r#curve_segment;
+pub(crate) mod r#exponential_curve;
+pub(crate) mod r#fee_decay;
+pub(crate) mod r#fee_schedule;
 pub(crate) mod r#global_authority_input;
 pub(crate) mod r#global_settings_input;
+pub(crate) mod r#initialize_pool_args;
+pub(crate) mod r#launch_protection;
+pub(crate) mod r#linear_curve;
+pub(crate) mod r#piecewise_linear_curve;
+pub(crate) mod r#presale_terms;
 pub(crate) mod r#program_status;
+pub(crate) mod r#settings_caps;
+pub(crate) mod r#swap_params;
+pub(crate) mod r#swap_quote;
+pub(crate) mod r#vesting_anchor;
 pub(crate) mod r#vesting_terms;
 
 pub use self::r#allocation_data::*;
 pub use self::r#allocation_data_params::*;
+pub use self::r#allowlist::*;
+pub use self::r#allowlist_proof::*;
+pub use self::r#authority_role::*;
+pub use self::r#completion_policy::*;
+pub use self::r#curve_kind::*;
+pub use self::r#curve_segment::*;
+pub use self::r#exponential_curve::*;
+pub use self::r#fee_decay::*;
+pub use self::r#fee_schedule::*;
 pub use self::r#global_authority_input::*;
 pub use self::r#global_settings_input::*;
+pub use self::r#initialize_pool_args::*;
+pub use self::r#launch_protection::*;
+pub use self::r#linear_curve::*;
+pub use self::r#piecewise_linear_curve::*;
+pub use self::r#presale_terms::*;
 pub use self::r#program_status::*;
+pub use self::r#settings_caps::*;
+pub use self::r#swap_params::*;
+pub use self::r#swap_quote::*;
+pub use self::r#vesting_anchor::*;
 pub use self::r#vesting_terms::*;
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::VestingAnchor;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -17,4 +18,5 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub struct VestingTerms {
     pub cliff: i64,
     pub duration: i64,
+    pub anchor: VestingAnchor,
 }
@@ -54,6 +54,34 @@ kinobi.update(
         k.variablePdaSeedNode("mint", k.publicKeyTypeNode(), MINT_NODE_DESC),
       ],
     },
+    cexVault: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("cex-vault"),
+        k.variablePdaSeedNode("mint", k.publicKeyTypeNode(), MINT_NODE_DESC),
+      ],
+    },
+    userPosition: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("user-position"),
+        k.variablePdaSeedNode("mint", k.publicKeyTypeNode(), MINT_NODE_DESC),
+        k.variablePdaSeedNode(
+          "user",
+          k.publicKeyTypeNode(),
+          "The wallet trading on the bonding curve"
+        ),
+      ],
+    },
+    presalePosition: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("presale-position"),
+        k.variablePdaSeedNode("mint", k.publicKeyTypeNode(), MINT_NODE_DESC),
+        k.variablePdaSeedNode(
+          "contributor",
+          k.publicKeyTypeNode(),
+          "The wallet contributing to the presale"
+        ),
+      ],
+    },
     eventAuthority: {
       seeds: [k.constantPdaSeedNodeFromString("__event_authority")],
     },
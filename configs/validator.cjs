@@ -16,6 +16,11 @@ module.exports = {
         programId: "71odFTZ59cG8yyBtEZrnJdBYaepzri2A12hEc16vK6WP",
         deployPath: getProgram("billy_bonding_curve.so"),
       },
+      {
+        label: "Mock AMM Program",
+        programId: "CQja8FXJWb3ioMdwCRuWiXQsYoDexh5RAXV1amDMMrwa",
+        deployPath: getProgram("mock_amm.so"),
+      },
 
       // Below are external programs that should be included in the local validator.
       // You may configure which ones to fetch from the cluster when building
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+
+// Graduation hands liquidity to an external AMM through a single instruction.
+// Any program (or adapter program) that exposes `initialize_pool` with the
+// account layout below can be configured as `Global.amm_program`.
+// AMM specific accounts (pool vaults, config, ...) are forwarded untouched
+// from the graduate instruction's remaining accounts.
+// The pool must be owned by the AMM program and be the mint authority of the
+// LP mint, graduation checks both before burning the LP tokens.
+
+/// sha256("global:initialize_pool")[..8]
+pub const INITIALIZE_POOL_DISCRIMINATOR: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+
+pub const LIQUIDITY_PROVIDER_SEED_PREFIX: &str = "liquidity-provider";
+
+pub fn get_liquidity_provider_signer<'a>(bump: &'a u8, mint: &'a Pubkey) -> [&'a [u8]; 3] {
+    [
+        LIQUIDITY_PROVIDER_SEED_PREFIX.as_bytes(),
+        mint.as_ref(),
+        std::slice::from_ref(bump),
+    ]
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializePoolArgs {
+    pub sol_amount: u64,
+    pub token_amount: u64,
+}
+
+pub struct InitializePool<'info> {
+    // pays rent for the accounts created by the AMM
+    pub payer: AccountInfo<'info>,
+    // system owned PDA holding the SOL side, signs for the token side
+    pub liquidity_provider: AccountInfo<'info>,
+    pub liquidity_provider_token_account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub pool: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub liquidity_provider_lp_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for InitializePool<'_> {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(*self.payer.key, true),
+            AccountMeta::new(*self.liquidity_provider.key, true),
+            AccountMeta::new(*self.liquidity_provider_token_account.key, false),
+            AccountMeta::new_readonly(*self.mint.key, false),
+            AccountMeta::new(*self.pool.key, false),
+            AccountMeta::new(*self.lp_mint.key, false),
+            AccountMeta::new(*self.liquidity_provider_lp_token_account.key, false),
+            AccountMeta::new_readonly(*self.token_program.key, false),
+            AccountMeta::new_readonly(*self.associated_token_program.key, false),
+            AccountMeta::new_readonly(*self.system_program.key, false),
+        ]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for InitializePool<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.payer.clone(),
+            self.liquidity_provider.clone(),
+            self.liquidity_provider_token_account.clone(),
+            self.mint.clone(),
+            self.pool.clone(),
+            self.lp_mint.clone(),
+            self.liquidity_provider_lp_token_account.clone(),
+            self.token_program.clone(),
+            self.associated_token_program.clone(),
+            self.system_program.clone(),
+        ]
+    }
+}
+
+pub fn initialize_pool<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializePool<'info>>,
+    sol_amount: u64,
+    token_amount: u64,
+) -> Result<()> {
+    let mut data = INITIALIZE_POOL_DISCRIMINATOR.to_vec();
+    InitializePoolArgs {
+        sol_amount,
+        token_amount,
+    }
+    .serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: ctx.program.key(),
+        accounts: ctx.to_account_metas(None),
+        data,
+    };
+    invoke_signed(&ix, &ctx.to_account_infos(), ctx.signer_seeds).map_err(Into::into)
+}
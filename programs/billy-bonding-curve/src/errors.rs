@@ -69,4 +69,146 @@ pub enum ContractError {
 
     #[msg("Not enough fees to withdraw")]
     NoFeesToWithdraw,
+
+    #[msg("Bonding Curve Already Graduated")]
+    BondingCurveGraduated,
+    #[msg("AMM program not set on Global")]
+    AmmProgramNotSet,
+    #[msg("Invalid AMM program")]
+    InvalidAmmProgram,
+    #[msg("Graduation Failed")]
+    GraduationFailed,
+    #[msg("Invalid LP token account")]
+    InvalidLpTokenAccount,
+
+    #[msg("Invalid presale terms")]
+    InvalidPresaleTerms,
+    #[msg("Presale is not open")]
+    PresaleNotOpen,
+    #[msg("Presale contribution exceeds the per wallet cap")]
+    PresaleWalletCapExceeded,
+    #[msg("Presale tokens already claimed")]
+    PresaleAlreadyClaimed,
+    #[msg("No presale tokens to claim")]
+    NoPresaleTokens,
+
+    #[msg("Invalid Brand Authority")]
+    InvalidBrandAuthority,
+    #[msg("No brandkit tokens to claim")]
+    NoBrandkitTokens,
+
+    #[msg("No platform tokens to claim")]
+    NoPlatformTokens,
+
+    #[msg("Invalid Platform Authority")]
+    InvalidPlatformAuthority,
+    #[msg("Release exceeds vested CEX tokens")]
+    CexReleaseExceedsVested,
+
+    #[msg("Exactly one of exactInAmount and exactOutAmount must be set")]
+    InvalidSwapMode,
+
+    #[msg("Creating bonding curves is disabled")]
+    CreateDisabled,
+    #[msg("Buying is disabled")]
+    BuyDisabled,
+    #[msg("Selling is disabled")]
+    SellDisabled,
+    #[msg("Launching is disabled")]
+    LaunchDisabled,
+    #[msg("Claiming is disabled")]
+    ClaimDisabled,
+    #[msg("Fee withdrawal is disabled")]
+    FeeWithdrawalDisabled,
+
+    #[msg("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+
+    #[msg("Trade fee exceeds the maximum trade fee")]
+    TradeFeeTooHigh,
+    #[msg("Launch fee exceeds the maximum launch fee")]
+    LaunchFeeTooHigh,
+    #[msg("Mint decimals must be between 1 and the maximum mint decimals")]
+    InvalidMintDecimals,
+
+    #[msg("Fee and status changes must be scheduled")]
+    SettingsChangeRequiresTimelock,
+    #[msg("Effective time is before the settings timelock")]
+    InvalidSettingsEffectiveTime,
+    #[msg("No pending settings change")]
+    NoPendingSettings,
+    #[msg("Pending settings change is not yet effective")]
+    SettingsNotYetEffective,
+
+    #[msg("Mint carries unsupported extensions")]
+    InvalidMintExtensions,
+    #[msg("Metaplex metadata accounts are required for SPL Token mints")]
+    MissingMetadataAccounts,
+
+    #[msg("No vested tokens to claim")]
+    NoVestedTokens,
+
+    #[msg("Invalid vesting anchor")]
+    InvalidVestingTerms,
+
+    #[msg("Graduation deadline must be after the curve start")]
+    InvalidGraduationDeadline,
+    #[msg("Graduation deadline has passed")]
+    GraduationDeadlinePassed,
+    #[msg("Graduation deadline not yet reached")]
+    GraduationDeadlineNotReached,
+    #[msg("Bonding Curve Failed")]
+    BondingCurveFailed,
+    #[msg("Bonding Curve Not Failed")]
+    BondingCurveNotFailed,
+
+    #[msg("Launch lead time exceeds the maximum launch lead time")]
+    InvalidLaunchLeadTime,
+    #[msg("Launch has already started")]
+    LaunchAlreadyStarted,
+    #[msg("Launch has presale contributions")]
+    LaunchHasContributions,
+    #[msg("Rescheduling launches is disabled")]
+    RescheduleDisabled,
+
+    #[msg("Invalid launch protection settings")]
+    InvalidLaunchProtection,
+    #[msg("Buy exceeds the launch protection limits")]
+    LaunchProtectionLimitExceeded,
+
+    #[msg("Invalid fee schedule")]
+    InvalidFeeSchedule,
+
+    #[msg("Invalid allowlist")]
+    InvalidAllowlist,
+    #[msg("Wallet is not allowlisted")]
+    NotAllowlisted,
+    #[msg("Buy exceeds the allowlist allowance")]
+    AllowlistAllowanceExceeded,
+
+    #[msg("Invalid curve kind")]
+    InvalidCurveKind,
+
+    #[msg("No surplus lamports to skim")]
+    NoSurplusLamports,
+
+    #[msg("Launch fee refund must not exceed 100%")]
+    InvalidLaunchFeeRefund,
+
+    #[msg("Launch has already been traded")]
+    LaunchAlreadyTraded,
+
+    #[msg("Pool or LP mint does not belong to the AMM")]
+    InvalidAmmPool,
+
+    #[msg("Contribution exceeds the presale SOL cap")]
+    PresaleCapExceeded,
+
+    #[msg("Invalid settings caps")]
+    InvalidSettingsCaps,
+
+    #[msg("User position is required for buys during launch protection or the allowlist phase")]
+    MissingUserPosition,
 }
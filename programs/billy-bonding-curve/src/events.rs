@@ -1,12 +1,37 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{
+    bonding_curve::{CompletionPolicy, VestingTerms},
+    global::{AuthorityRole, GlobalSettingsInput, ProgramStatus, SettingsCaps},
+};
+
 #[event]
 pub struct GlobalUpdateEvent {
     pub global_authority: Pubkey,
     pub withdraw_authority: Pubkey,
+    pub status: ProgramStatus,
     pub trade_fee_bps: u64,
     pub launch_fee_lamports: u64,
+    pub launch_fee_refund_bps: u64,
     pub created_mint_decimals: u8,
+    pub max_launch_lead_time: i64,
+    pub amm_program: Pubkey,
+    pub platform_vesting_terms: VestingTerms,
+    pub cex_vesting_terms: VestingTerms,
+    pub caps: SettingsCaps,
+
+    pub previous_global_authority: Pubkey,
+    pub previous_withdraw_authority: Pubkey,
+    pub previous_status: ProgramStatus,
+    pub previous_trade_fee_bps: u64,
+    pub previous_launch_fee_lamports: u64,
+    pub previous_launch_fee_refund_bps: u64,
+    pub previous_created_mint_decimals: u8,
+    pub previous_max_launch_lead_time: i64,
+    pub previous_amm_program: Pubkey,
+    pub previous_platform_vesting_terms: VestingTerms,
+    pub previous_cex_vesting_terms: VestingTerms,
+    pub previous_caps: SettingsCaps,
 }
 
 #[event]
@@ -44,6 +69,11 @@ pub struct TradeEvent {
     pub sol_amount: u64,
     pub token_amount: u64,
     pub fee_lamports: u64,
+    // effective fee rate of the trade
+    pub fee_bps: u64,
+    // input for exact-in and output for exact-out trades, less than requested on a partial fill
+    pub requested_amount: u64,
+    pub filled_amount: u64,
     pub is_buy: bool,
     pub user: Pubkey,
     pub timestamp: i64,
@@ -64,6 +94,204 @@ pub struct CompleteEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct GraduationEvent {
+    pub mint: Pubkey,
+    pub payer: Pubkey,
+    pub amm_program: Pubkey,
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub lp_amount_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LaunchScheduledEvent {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub start_time: i64,
+    // set when an upcoming launch is rescheduled
+    pub previous_start_time: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LaunchCancelledEvent {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub start_time: i64,
+    pub tokens_burned: u64,
+    pub launch_fee_lamports: u64,
+    // part of the launch fee returned to the creator
+    pub launch_fee_refund_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CurveFailedEvent {
+    pub mint: Pubkey,
+    pub payer: Pubkey,
+    pub graduation_deadline: i64,
+    pub real_sol_reserves: u64,
+    pub presale_sol_raised: u64,
+    pub tokens_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundEvent {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub real_sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PresaleContributionEvent {
+    pub mint: Pubkey,
+    pub contributor: Pubkey,
+    pub sol_amount: u64,
+    pub contributed: u64,
+    pub presale_sol_raised: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PresaleClaimEvent {
+    pub mint: Pubkey,
+    pub contributor: Pubkey,
+    pub contributed: u64,
+    pub token_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingClaimEvent {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub initial_vested_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BrandkitClaimEvent {
+    pub mint: Pubkey,
+    pub brand_authority: Pubkey,
+    pub launch_amount: u64,
+    pub lifetime_amount: u64,
+    pub launch_brandkit_claimed: u64,
+    pub lifetime_brandkit_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformTokensClaimEvent {
+    pub withdraw_authority: Pubkey,
+    pub mint: Pubkey,
+    pub platform_vault: Pubkey,
+
+    pub claimed: u64,
+    pub total_claimed: u64,
+
+    pub previous_distribution_time: i64,
+    pub new_distribution_time: i64,
+}
+
+#[event]
+pub struct CexReleaseEvent {
+    pub platform_authority: Pubkey,
+    pub mint: Pubkey,
+    pub cex_vault: Pubkey,
+    pub exchange_wallet: Pubkey,
+
+    pub released: u64,
+    pub total_released: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposedEvent {
+    pub role: AuthorityRole,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferAcceptedEvent {
+    pub role: AuthorityRole,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelledEvent {
+    pub role: AuthorityRole,
+    pub current_authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettingsScheduledEvent {
+    pub global_authority: Pubkey,
+    pub settings: GlobalSettingsInput,
+    pub effective_time: i64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettingsExecutedEvent {
+    pub executor: Pubkey,
+    pub settings: GlobalSettingsInput,
+    pub effective_time: i64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettingsCancelledEvent {
+    pub global_authority: Pubkey,
+    pub settings: GlobalSettingsInput,
+    pub effective_time: i64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SkimEvent {
+    pub withdraw_authority: Pubkey,
+    pub mint: Pubkey,
+    pub platform_vault: Pubkey,
+
+    pub amount: u64,
+    pub real_sol_reserves: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnsoldTokensEvent {
+    pub mint: Pubkey,
+    pub policy: CompletionPolicy,
+    pub unsold_token_amount: u64,
+    // left on the curve for the AMM pool
+    pub graduation_token_amount: u64,
+    pub timestamp: i64,
+}
+
 pub trait IntoEvent<T: anchor_lang::Event> {
     fn into_event(&self) -> T;
 }
@@ -0,0 +1,47 @@
+use crate::{errors::ContractError, events::*, state::global::*};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: AuthorityRole)]
+pub struct AcceptAuthority<'info> {
+    new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+}
+
+impl AcceptAuthority<'_> {
+    pub fn validate(&self, role: AuthorityRole) -> Result<()> {
+        require!(
+            self.global.get_pending_authority(role) == Some(self.new_authority.key()),
+            ContractError::InvalidPendingAuthority
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<AcceptAuthority>, role: AuthorityRole) -> Result<()> {
+        let clock = Clock::get()?;
+        let new_authority = ctx.accounts.new_authority.key();
+        let global = &mut ctx.accounts.global;
+        let previous = Global::clone(global);
+
+        let previous_authority = global.accept_authority(role, new_authority)?;
+
+        emit_cpi!(AuthorityTransferAcceptedEvent {
+            role,
+            previous_authority,
+            new_authority,
+            timestamp: clock.unix_timestamp,
+        });
+        emit_cpi!(global.into_update_event(&previous));
+        msg!("Accepted {:?} authority", role);
+
+        Ok(())
+    }
+}
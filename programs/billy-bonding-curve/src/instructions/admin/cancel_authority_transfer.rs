@@ -0,0 +1,51 @@
+use crate::{errors::ContractError, events::*, state::global::*};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(role: AuthorityRole)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        constraint = authority.key() == global.global_authority.key() @ ContractError::InvalidGlobalAuthority
+    )]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+}
+
+impl CancelAuthorityTransfer<'_> {
+    pub fn validate(&self, role: AuthorityRole) -> Result<()> {
+        require!(
+            self.global.get_pending_authority(role).is_some(),
+            ContractError::NoPendingAuthority
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<CancelAuthorityTransfer>, role: AuthorityRole) -> Result<()> {
+        let clock = Clock::get()?;
+        let global = &mut ctx.accounts.global;
+
+        let cancelled_authority = global.cancel_authority_transfer(role)?;
+        let current_authority = match role {
+            AuthorityRole::Global => global.global_authority,
+            AuthorityRole::Withdraw => global.withdraw_authority,
+        };
+
+        emit_cpi!(AuthorityTransferCancelledEvent {
+            role,
+            current_authority,
+            cancelled_authority,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("Cancelled {:?} authority transfer", role);
+
+        Ok(())
+    }
+}
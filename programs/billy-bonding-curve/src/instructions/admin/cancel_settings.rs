@@ -0,0 +1,51 @@
+use crate::{errors::ContractError, events::*, state::global::*};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelSettings<'info> {
+    #[account(
+        constraint = authority.key() == global.global_authority.key() @ ContractError::InvalidGlobalAuthority
+    )]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+}
+
+impl CancelSettings<'_> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.global.pending_settings.is_some(),
+            ContractError::NoPendingSettings
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<CancelSettings>) -> Result<()> {
+        let clock = Clock::get()?;
+        let global = &mut ctx.accounts.global;
+
+        let settings = global
+            .pending_settings
+            .take()
+            .ok_or(ContractError::NoPendingSettings)?;
+        let effective_time = global.pending_settings_effective_time;
+        global.pending_settings_effective_time = 0;
+
+        emit_cpi!(SettingsCancelledEvent {
+            global_authority: ctx.accounts.authority.key(),
+            settings,
+            effective_time,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("Cancelled scheduled settings change");
+
+        Ok(())
+    }
+}
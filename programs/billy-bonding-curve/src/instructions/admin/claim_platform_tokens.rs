@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::{bonding_curve::BondingCurve, vaults::PlatformVault};
+use crate::{errors::ContractError, events::PlatformTokensClaimEvent};
+
+use crate::state::global::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimPlatformTokens<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.withdraw_authority == *authority.key @ ContractError::InvalidWithdrawAuthority,
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_claim() @ ContractError::ClaimDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account()]
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        constraint = !bonding_curve.failed @ ContractError::BondingCurveFailed,
+        constraint = bonding_curve.can_release_allocations() @ ContractError::BondingCurveNotComplete,
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [PlatformVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    platform_vault: Box<Account<'info, PlatformVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = platform_vault,
+        associated_token::token_program = token_program,
+    )]
+    platform_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl ClaimPlatformTokens<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            self.bonding_curve.is_started(&clock),
+            ContractError::CurveNotStarted
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<ClaimPlatformTokens>) -> Result<()> {
+        // transfer vested platform tokens to withdraw authority from platform_vault
+
+        let clock = Clock::get()?;
+        let platform_vault = &ctx.accounts.platform_vault;
+        let amount = platform_vault
+            .claimable_at(&ctx.accounts.bonding_curve, clock.unix_timestamp)
+            .ok_or(ContractError::NoPlatformTokens)?;
+
+        msg!(
+            "unsold:{}, amount:{}",
+            platform_vault.unsold_token_amount,
+            amount
+        );
+        require_gt!(amount, 0, ContractError::NoPlatformTokens);
+
+        let mint_k = ctx.accounts.mint.key();
+        let signer = PlatformVault::get_signer(&ctx.bumps.platform_vault, &mint_k);
+        let signer_seeds = &[&signer[..]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.platform_vault_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.platform_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let platform_vault = &mut ctx.accounts.platform_vault;
+        let prev_distribution_time = platform_vault.last_distribution;
+        platform_vault.last_distribution = clock.unix_timestamp;
+        platform_vault.tokens_claimed += amount;
+
+        emit_cpi!(PlatformTokensClaimEvent {
+            withdraw_authority: ctx.accounts.authority.key(),
+            mint: mint_k,
+            platform_vault: platform_vault.key(),
+
+            claimed: amount,
+            total_claimed: platform_vault.tokens_claimed,
+
+            previous_distribution_time: prev_distribution_time,
+            new_distribution_time: platform_vault.last_distribution,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{errors::ContractError, events::*, state::global::*};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteSettings<'info> {
+    executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+}
+
+impl ExecuteSettings<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let clock = Clock::get()?;
+        let settings = self
+            .global
+            .pending_settings
+            .as_ref()
+            .ok_or(ContractError::NoPendingSettings)?;
+        require!(
+            clock.unix_timestamp >= self.global.pending_settings_effective_time,
+            ContractError::SettingsNotYetEffective
+        );
+        // checked again against the settings in force now
+        self.global.validate_settings(settings)
+    }
+
+    pub fn handler(ctx: Context<ExecuteSettings>) -> Result<()> {
+        let clock = Clock::get()?;
+        let global = &mut ctx.accounts.global;
+        let previous = Global::clone(global);
+
+        let settings = global
+            .pending_settings
+            .take()
+            .ok_or(ContractError::NoPendingSettings)?;
+        let effective_time = global.pending_settings_effective_time;
+        global.pending_settings_effective_time = 0;
+        global.update_settings(settings.clone());
+
+        emit_cpi!(SettingsExecutedEvent {
+            executor: ctx.accounts.executor.key(),
+            settings,
+            effective_time,
+            timestamp: clock.unix_timestamp,
+        });
+        emit_cpi!(global.into_update_event(&previous));
+        msg!("Executed scheduled settings change");
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{errors::ContractError, events::*, state::global::*};
+use crate::{
+    errors::ContractError,
+    state::{bonding_curve::VestingTerms, global::*},
+};
 use anchor_lang::prelude::*;
 
 #[event_cpi]
@@ -22,24 +25,28 @@ pub struct Initialize<'info> {
 }
 
 impl Initialize<'_> {
+    pub fn validate(&self, params: &GlobalSettingsInput) -> Result<()> {
+        params.validate()
+    }
+
     pub fn handler(ctx: Context<Initialize>, params: GlobalSettingsInput) -> Result<()> {
         let global = &mut ctx.accounts.global;
+        let previous = Global::clone(global);
         global.update_authority(GlobalAuthorityInput {
             global_authority: Some(ctx.accounts.authority.key()),
             withdraw_authority: Some(ctx.accounts.authority.key()),
         });
+        global.platform_vesting_terms = VestingTerms::default();
+        global.cex_vesting_terms = VestingTerms::default();
+        global.max_launch_lead_time = Global::DEFAULT_LAUNCH_LEAD_TIME;
+        global.caps = SettingsCaps::default();
         global.update_settings(params);
-
-        require_gt!(
-            global.created_mint_decimals,
-            0,
-            ContractError::InvalidArgument
-        );
+        global.validate_caps()?;
 
         global.status = ProgramStatus::Running;
         global.initialized = true;
 
-        emit_cpi!(global.into_event());
+        emit_cpi!(global.into_update_event(&previous));
         msg!("Initialized global state");
         Ok(())
     }
@@ -1,3 +1,11 @@
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod cancel_settings;
+pub mod claim_platform_tokens;
+pub mod execute_settings;
 pub mod initialize;
+pub mod release_cex_tokens;
+pub mod schedule_settings;
 pub mod set_params;
+pub mod skim;
 pub mod withdraw_fees;
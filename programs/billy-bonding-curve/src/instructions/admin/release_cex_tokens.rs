@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::{bonding_curve::BondingCurve, vaults::CexVault};
+use crate::{errors::ContractError, events::CexReleaseEvent};
+
+use crate::state::global::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct ReleaseCexTokens<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    // checked against the current withdraw authority so a rotation covers existing curves
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.withdraw_authority == *authority.key @ ContractError::InvalidWithdrawAuthority,
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_claim() @ ContractError::ClaimDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account()]
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        constraint = !bonding_curve.failed @ ContractError::BondingCurveFailed,
+        constraint = bonding_curve.can_release_allocations() @ ContractError::BondingCurveNotComplete,
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [CexVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    cex_vault: Box<Account<'info, CexVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = cex_vault,
+        associated_token::token_program = token_program,
+    )]
+    cex_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account()]
+    exchange_wallet: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = exchange_wallet,
+        associated_token::token_program = token_program,
+    )]
+    exchange_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl ReleaseCexTokens<'_> {
+    pub fn validate(&self, amount: u64) -> Result<()> {
+        let clock = Clock::get()?;
+        require_gt!(amount, 0, ContractError::InvalidArgument);
+        require!(
+            self.bonding_curve.is_started(&clock),
+            ContractError::CurveNotStarted
+        );
+
+        let vested = self
+            .bonding_curve
+            .get_vested_amount(
+                &self.cex_vault.vesting_terms,
+                self.cex_vault.initial_vested_supply,
+                clock.unix_timestamp,
+            )
+            .ok_or(ContractError::CexReleaseExceedsVested)?;
+        msg!(
+            "vested:{}, released:{}, amount:{}",
+            vested,
+            self.cex_vault.tokens_released,
+            amount
+        );
+        require!(
+            amount <= vested.saturating_sub(self.cex_vault.tokens_released),
+            ContractError::CexReleaseExceedsVested
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<ReleaseCexTokens>, amount: u64) -> Result<()> {
+        // transfer vested cex tokens to the exchange wallet from cex_vault
+
+        let clock = Clock::get()?;
+        let mint_k = ctx.accounts.mint.key();
+        let signer = CexVault::get_signer(&ctx.bumps.cex_vault, &mint_k);
+        let signer_seeds = &[&signer[..]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.cex_vault_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.exchange_token_account.to_account_info(),
+                    authority: ctx.accounts.cex_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let cex_vault = &mut ctx.accounts.cex_vault;
+        cex_vault.tokens_released += amount;
+        cex_vault.last_release = clock.unix_timestamp;
+
+        emit_cpi!(CexReleaseEvent {
+            platform_authority: ctx.accounts.authority.key(),
+            mint: mint_k,
+            cex_vault: cex_vault.key(),
+            exchange_wallet: ctx.accounts.exchange_wallet.key(),
+
+            released: amount,
+            total_released: cex_vault.tokens_released,
+
+            timestamp: cex_vault.last_release,
+        });
+
+        Ok(())
+    }
+}
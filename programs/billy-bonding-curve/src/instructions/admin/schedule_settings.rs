@@ -0,0 +1,54 @@
+use crate::{errors::ContractError, events::*, state::global::*};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: GlobalSettingsInput, effective_time: i64)]
+pub struct ScheduleSettings<'info> {
+    #[account(
+        constraint = authority.key() == global.global_authority.key() @ ContractError::InvalidGlobalAuthority
+    )]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+}
+
+impl ScheduleSettings<'_> {
+    pub fn validate(&self, params: &GlobalSettingsInput, effective_time: i64) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            effective_time >= clock.unix_timestamp + Global::SETTINGS_TIMELOCK_SECONDS,
+            ContractError::InvalidSettingsEffectiveTime
+        );
+        self.global.validate_settings(params)
+    }
+
+    pub fn handler(
+        ctx: Context<ScheduleSettings>,
+        params: GlobalSettingsInput,
+        effective_time: i64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let global = &mut ctx.accounts.global;
+
+        // replaces any change that is already queued
+        global.pending_settings = Some(params.clone());
+        global.pending_settings_effective_time = effective_time;
+
+        emit_cpi!(SettingsScheduledEvent {
+            global_authority: ctx.accounts.authority.key(),
+            settings: params,
+            effective_time,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("Scheduled settings change at {}", effective_time);
+
+        Ok(())
+    }
+}
@@ -30,10 +30,21 @@ pub struct SetParams<'info> {
 }
 
 impl SetParams<'_> {
+    pub fn validate(&self, params: &GlobalSettingsInput) -> Result<()> {
+        require!(
+            !Global::requires_timelock(params),
+            ContractError::SettingsChangeRequiresTimelock
+        );
+        self.global.validate_settings(params)
+    }
+
     pub fn handler(ctx: Context<SetParams>, params: GlobalSettingsInput) -> Result<()> {
+        let clock = Clock::get()?;
         let global = &mut ctx.accounts.global;
+        let previous = Global::clone(global);
 
-        global.update_authority(GlobalAuthorityInput {
+        // authorities only change once the nominee accepts
+        global.propose_authority(GlobalAuthorityInput {
             global_authority: if let Some(new_authority) = ctx.accounts.new_authority.as_ref() {
                 Some(*new_authority.key)
             } else {
@@ -49,7 +60,23 @@ impl SetParams<'_> {
         });
         global.update_settings(params);
 
-        emit_cpi!(global.into_event());
+        if let Some(new_authority) = ctx.accounts.new_authority.as_ref() {
+            emit_cpi!(AuthorityTransferProposedEvent {
+                role: AuthorityRole::Global,
+                current_authority: global.global_authority,
+                pending_authority: *new_authority.key,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        if let Some(new_withdraw_authority) = ctx.accounts.new_withdraw_authority.as_ref() {
+            emit_cpi!(AuthorityTransferProposedEvent {
+                role: AuthorityRole::Withdraw,
+                current_authority: global.withdraw_authority,
+                pending_authority: *new_withdraw_authority.key,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        emit_cpi!(global.into_update_event(&previous));
         msg!("Updated global state");
 
         Ok(())
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{bonding_curve::BondingCurve, vaults::PlatformVault};
+use crate::{errors::ContractError, events::SkimEvent};
+
+use crate::state::global::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Skim<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.withdraw_authority == *authority.key @ ContractError::InvalidWithdrawAuthority,
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_withdraw_fees() @ ContractError::FeeWithdrawalDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    /// CHECK: only used as a seed
+    mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [PlatformVault::SEED_PREFIX.as_bytes(), mint.key.as_ref()],
+        bump,
+    )]
+    platform_vault: Box<Account<'info, PlatformVault>>,
+}
+
+impl Skim<'_> {
+    pub fn handler(ctx: Context<Skim>) -> Result<()> {
+        // move lamports the curve can't account for to the platform vault
+        let min_balance = Rent::get()?.minimum_balance(8 + BondingCurve::INIT_SPACE);
+        let amount = ctx
+            .accounts
+            .bonding_curve
+            .get_surplus_lamports(ctx.accounts.bonding_curve.get_lamports(), min_balance);
+        msg!(
+            "Skim::handler: min_balance: {}, amount: {}",
+            min_balance,
+            amount
+        );
+        require_gt!(amount, 0, ContractError::NoSurplusLamports);
+
+        // sender is PDA, can use lamport utilities
+        ctx.accounts.bonding_curve.sub_lamports(amount)?;
+        ctx.accounts.platform_vault.add_lamports(amount)?;
+
+        emit_cpi!(SkimEvent {
+            withdraw_authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            platform_vault: ctx.accounts.platform_vault.key(),
+
+            amount,
+            real_sol_reserves: ctx.accounts.bonding_curve.real_sol_reserves,
+
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token_interface::{Mint, TokenInterface};
 
-use crate::state::vaults::PlatformVault;
+use crate::state::{bonding_curve::BondingCurve, vaults::PlatformVault};
 use crate::{errors::ContractError, events::WithdrawEvent};
 
 use crate::state::global::*;
@@ -17,12 +17,19 @@ pub struct WithdrawFees<'info> {
         seeds = [Global::SEED_PREFIX.as_bytes()],
         constraint = global.withdraw_authority == *authority.key @ ContractError::InvalidWithdrawAuthority,
         constraint = global.initialized == true @ ContractError::NotInitialized,
+        constraint = global.status.can_withdraw_fees() @ ContractError::FeeWithdrawalDisabled,
         bump,
     )]
     global: Box<Account<'info, Global>>,
 
     #[account()]
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
 
     #[account(
         mut,
@@ -33,7 +40,7 @@ pub struct WithdrawFees<'info> {
 
     system_program: Program<'info, System>,
 
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     clock: Sysvar<'info, Clock>,
 }
 
@@ -48,9 +55,29 @@ impl WithdrawFees<'_> {
         msg!("vault_size: {}", vault_size);
         let min_balance = Rent::get()?.minimum_balance(vault_size);
 
-        let amount = from.get_lamports() - min_balance;
-
-        msg!("min_balance:{}, amount:{}", min_balance, amount);
+        // the creator's share of the launch fee stays in the vault while the launch can be cancelled
+        let bonding_curve = &ctx.accounts.bonding_curve;
+        let launch_fee_reserve = if bonding_curve.is_cancellable() {
+            let (launch_fee_refund_lamports, _) = ctx
+                .accounts
+                .global
+                .get_launch_fee_refund(bonding_curve.launch_fee_lamports)
+                .ok_or(ContractError::InvalidLaunchFeeRefund)?;
+            launch_fee_refund_lamports
+        } else {
+            0
+        };
+
+        let amount = from
+            .get_lamports()
+            .saturating_sub(min_balance + launch_fee_reserve);
+
+        msg!(
+            "min_balance:{}, launch_fee_reserve:{}, amount:{}",
+            min_balance,
+            launch_fee_reserve,
+            amount
+        );
         require_gt!(amount, 0, ContractError::NoFeesToWithdraw);
 
         // sender is PDA, can use lamport utilities
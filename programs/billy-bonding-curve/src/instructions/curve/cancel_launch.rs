@@ -0,0 +1,288 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ContractError,
+    events::LaunchCancelledEvent,
+    state::{
+        bonding_curve::*,
+        global::*,
+        vaults::{BrandVault, CexVault, CreatorVault, PlatformVault, PresaleVault},
+    },
+};
+
+use crate::state::bonding_curve::locker::{BondingCurveLockerCtx, IntoBondingCurveLockerCtx};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelLaunch<'info> {
+    #[account(
+        mut,
+        constraint = creator.key() == bonding_curve.creator.key() @ ContractError::InvalidCreatorAuthority
+    )]
+    creator: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_reschedule() @ ContractError::RescheduleDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    /// CHECK: receives the platform's share of the launch fee from the platform vault
+    #[account(
+        mut,
+        constraint = withdraw_authority.key() == global.withdraw_authority @ ContractError::InvalidWithdrawAuthority
+    )]
+    withdraw_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve,
+        associated_token::token_program = token_program,
+    )]
+    bonding_curve_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [CreatorVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    creator_vault: Box<Account<'info, CreatorVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator_vault,
+        associated_token::token_program = token_program,
+    )]
+    creator_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [PresaleVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    presale_vault: Box<Account<'info, PresaleVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = presale_vault,
+        associated_token::token_program = token_program,
+    )]
+    presale_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [CexVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    cex_vault: Box<Account<'info, CexVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = cex_vault,
+        associated_token::token_program = token_program,
+    )]
+    cex_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [BrandVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    brand_vault: Box<Account<'info, BrandVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = brand_vault,
+        associated_token::token_program = token_program,
+    )]
+    brand_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [PlatformVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    platform_vault: Box<Account<'info, PlatformVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = platform_vault,
+        associated_token::token_program = token_program,
+    )]
+    platform_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> IntoBondingCurveLockerCtx<'info> for CancelLaunch<'info> {
+    fn into_bonding_curve_locker_ctx(
+        &self,
+        bonding_curve_bump: u8,
+    ) -> BondingCurveLockerCtx<'info> {
+        BondingCurveLockerCtx {
+            bonding_curve_bump,
+            mint: self.mint.to_account_info(),
+            bonding_curve: self.bonding_curve.clone(),
+            bonding_curve_token_account: self.bonding_curve_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+impl<'info> CancelLaunch<'info> {
+    pub fn validate(&self) -> Result<()> {
+        // a started launch can still be cancelled until someone trades on it
+        require!(
+            !self.bonding_curve.traded,
+            ContractError::LaunchAlreadyTraded
+        );
+        require!(
+            self.bonding_curve.presale_sol_raised == 0,
+            ContractError::LaunchHasContributions
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<CancelLaunch>) -> Result<()> {
+        let clock = Clock::get()?;
+        let mint_k = ctx.accounts.mint.key();
+
+        let locker: &mut BondingCurveLockerCtx = &mut ctx
+            .accounts
+            .into_bonding_curve_locker_ctx(ctx.bumps.bonding_curve);
+        locker.unlock_ata()?;
+
+        // every allocation is burned and its token account closed, the rent goes back to the creator
+        let mut tokens_burned = 0;
+        let curve_signer = BondingCurve::get_signer(&ctx.bumps.bonding_curve, &mint_k);
+        tokens_burned += ctx.accounts.burn_and_close(
+            &ctx.accounts.bonding_curve_token_account,
+            ctx.accounts.bonding_curve.to_account_info(),
+            &[&curve_signer[..]],
+        )?;
+        let creator_signer = CreatorVault::get_signer(&ctx.bumps.creator_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_and_close(
+            &ctx.accounts.creator_vault_token_account,
+            ctx.accounts.creator_vault.to_account_info(),
+            &[&creator_signer[..]],
+        )?;
+        let presale_signer = PresaleVault::get_signer(&ctx.bumps.presale_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_and_close(
+            &ctx.accounts.presale_vault_token_account,
+            ctx.accounts.presale_vault.to_account_info(),
+            &[&presale_signer[..]],
+        )?;
+        let cex_signer = CexVault::get_signer(&ctx.bumps.cex_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_and_close(
+            &ctx.accounts.cex_vault_token_account,
+            ctx.accounts.cex_vault.to_account_info(),
+            &[&cex_signer[..]],
+        )?;
+        let brand_signer = BrandVault::get_signer(&ctx.bumps.brand_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_and_close(
+            &ctx.accounts.brand_vault_token_account,
+            ctx.accounts.brand_vault.to_account_info(),
+            &[&brand_signer[..]],
+        )?;
+        let platform_signer = PlatformVault::get_signer(&ctx.bumps.platform_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_and_close(
+            &ctx.accounts.platform_vault_token_account,
+            ctx.accounts.platform_vault.to_account_info(),
+            &[&platform_signer[..]],
+        )?;
+        msg!("CancelLaunch::handler: tokens_burned: {}", tokens_burned);
+
+        // the configured share of the launch fee paid at creation is refunded to the creator,
+        // the platform keeps the rest of the vault, fees and donations included
+        let launch_fee_lamports = ctx.accounts.bonding_curve.launch_fee_lamports;
+        let (launch_fee_refund_lamports, _) = ctx
+            .accounts
+            .global
+            .get_launch_fee_refund(launch_fee_lamports)
+            .ok_or(ContractError::InvalidLaunchFeeRefund)?;
+        let platform_vault_info = ctx.accounts.platform_vault.to_account_info();
+        let vault_lamports = platform_vault_info
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(platform_vault_info.data_len()));
+        let platform_lamports = vault_lamports
+            .checked_sub(launch_fee_refund_lamports)
+            .ok_or(ContractError::InvalidLaunchFeeRefund)?;
+        msg!(
+            "CancelLaunch::handler: launch_fee_lamports: {}, launch_fee_refund_lamports: {}, platform_lamports: {}",
+            launch_fee_lamports,
+            launch_fee_refund_lamports,
+            platform_lamports
+        );
+        ctx.accounts.platform_vault.sub_lamports(vault_lamports)?;
+        ctx.accounts
+            .withdraw_authority
+            .add_lamports(platform_lamports)?;
+        ctx.accounts
+            .creator
+            .add_lamports(launch_fee_refund_lamports)?;
+
+        emit_cpi!(LaunchCancelledEvent {
+            mint: mint_k,
+            creator: ctx.accounts.creator.key(),
+            start_time: ctx.accounts.bonding_curve.start_time,
+            tokens_burned,
+            launch_fee_lamports,
+            launch_fee_refund_lamports,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("CancelLaunch::handler: done");
+        Ok(())
+    }
+
+    fn burn_and_close(
+        &self,
+        token_account: &InterfaceAccount<'info, TokenAccount>,
+        authority: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64> {
+        let amount = token_account.amount;
+        if amount > 0 {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Burn {
+                        mint: self.mint.to_account_info(),
+                        from: token_account.to_account_info(),
+                        authority: authority.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: token_account.to_account_info(),
+                destination: self.creator.to_account_info(),
+                authority,
+            },
+            signer_seeds,
+        ))?;
+        Ok(amount)
+    }
+}
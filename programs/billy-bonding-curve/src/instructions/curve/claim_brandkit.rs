@@ -0,0 +1,139 @@
+use crate::{
+    errors::ContractError,
+    events::BrandkitClaimEvent,
+    state::{bonding_curve::BondingCurve, global::*, vaults::BrandVault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimBrandkit<'info> {
+    #[account(mut,
+    constraint = brand_authority.key() == bonding_curve.brand_authority.key() @ ContractError::InvalidBrandAuthority
+    )]
+    brand_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BrandVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    brand_vault: Box<Account<'info, BrandVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = brand_vault,
+        associated_token::token_program = token_program,
+    )]
+    brand_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        constraint = !bonding_curve.failed @ ContractError::BondingCurveFailed,
+        constraint = bonding_curve.can_release_allocations() @ ContractError::BondingCurveNotComplete,
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        init_if_needed,
+        payer = brand_authority,
+        associated_token::mint = mint,
+        associated_token::authority = brand_authority,
+        associated_token::token_program = token_program,
+    )]
+    user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_claim() @ ContractError::ClaimDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    system_program: Program<'info, System>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl ClaimBrandkit<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            self.bonding_curve.is_started(&clock),
+            ContractError::CurveNotStarted
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<ClaimBrandkit>) -> Result<()> {
+        let clock = Clock::get()?;
+        let brand_vault = &ctx.accounts.brand_vault;
+        let bonding_curve = &ctx.accounts.bonding_curve;
+
+        // the launch share is released in full once the curve completes
+        let launch_amount = if bonding_curve.complete {
+            brand_vault.launch_brandkit_supply - brand_vault.launch_brandkit_claimed
+        } else {
+            0
+        };
+        msg!("ClaimBrandkit::handler: launch_amount: {}", launch_amount);
+
+        // the lifetime share streams from the vesting anchor
+        let lifetime_vested = bonding_curve
+            .get_vested_amount(
+                &brand_vault.lifetime_vesting_terms,
+                brand_vault.lifetime_brandkit_supply,
+                clock.unix_timestamp,
+            )
+            .ok_or(ContractError::NoBrandkitTokens)?;
+        let lifetime_amount = lifetime_vested.saturating_sub(brand_vault.lifetime_brandkit_claimed);
+        msg!(
+            "ClaimBrandkit::handler: lifetime_amount: {}",
+            lifetime_amount
+        );
+
+        let tokens_to_distribute = launch_amount + lifetime_amount;
+        require_gt!(tokens_to_distribute, 0, ContractError::NoBrandkitTokens);
+
+        let mint_k = ctx.accounts.mint.key();
+        let signer = BrandVault::get_signer(&ctx.bumps.brand_vault, &mint_k);
+        let signer_seeds = &[&signer[..]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.brand_vault_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.brand_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            tokens_to_distribute,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let brand_vault = &mut ctx.accounts.brand_vault;
+        brand_vault.launch_brandkit_claimed += launch_amount;
+        brand_vault.lifetime_brandkit_claimed += lifetime_amount;
+
+        emit_cpi!(BrandkitClaimEvent {
+            mint: mint_k,
+            brand_authority: ctx.accounts.brand_authority.key(),
+            launch_amount,
+            lifetime_amount,
+            launch_brandkit_claimed: brand_vault.launch_brandkit_claimed,
+            lifetime_brandkit_claimed: brand_vault.lifetime_brandkit_claimed,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("ClaimBrandkit::handler: done");
+        Ok(())
+    }
+}
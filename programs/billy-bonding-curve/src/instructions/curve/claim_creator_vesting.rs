@@ -1,11 +1,12 @@
 use crate::{
     errors::ContractError,
+    events::VestingClaimEvent,
     state::{bonding_curve::BondingCurve, global::*, vaults::CreatorVault},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Token, TokenAccount},
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
 };
 
 #[event_cpi]
@@ -26,11 +27,14 @@ pub struct ClaimCreatorVesting<'info> {
         mut,
         associated_token::mint = mint,
         associated_token::authority = creator_vault,
+        associated_token::token_program = token_program,
     )]
-    creator_vault_token_account: Box<Account<'info, TokenAccount>>,
+    creator_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        constraint = !bonding_curve.failed @ ContractError::BondingCurveFailed,
+        constraint = bonding_curve.can_release_allocations() @ ContractError::BondingCurveNotComplete,
         bump,
     )]
     bonding_curve: Box<Account<'info, BondingCurve>>,
@@ -39,25 +43,25 @@ pub struct ClaimCreatorVesting<'info> {
         payer = creator,
         associated_token::mint = mint,
         associated_token::authority = creator,
+        associated_token::token_program = token_program,
     )]
-    user_token_account: Box<Account<'info, TokenAccount>>,
+    user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         seeds = [Global::SEED_PREFIX.as_bytes()],
         constraint = global.initialized == true @ ContractError::NotInitialized,
-        constraint = global.status != ProgramStatus::Paused @ ContractError::ProgramNotRunning,
+        constraint = global.status.can_claim() @ ContractError::ClaimDisabled,
         bump,
     )]
     global: Box<Account<'info, Global>>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    mint: UncheckedAccount<'info>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     system_program: Program<'info, System>,
     clock: Sysvar<'info, Clock>,
     rent: Sysvar<'info, Rent>,
     associated_token_program: Program<'info, AssociatedToken>,
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
 }
 
 impl ClaimCreatorVesting<'_> {
@@ -68,13 +72,15 @@ impl ClaimCreatorVesting<'_> {
             ContractError::CurveNotStarted
         );
 
-        let seconds_since_start = clock
-            .unix_timestamp
-            .checked_sub(self.bonding_curve.start_time)
-            .unwrap();
+        // the schedule may only start once the curve completes
+        let anchor_time = self
+            .bonding_curve
+            .get_vesting_anchor_time(&self.bonding_curve.vesting_terms.anchor)
+            .ok_or(ContractError::BondingCurveNotComplete)?;
+        let seconds_since_anchor = clock.unix_timestamp.checked_sub(anchor_time).unwrap();
 
         require!(
-            seconds_since_start > self.bonding_curve.vesting_terms.cliff,
+            seconds_since_anchor > self.bonding_curve.vesting_terms.cliff,
             ContractError::CliffNotReached
         );
 
@@ -82,67 +88,50 @@ impl ClaimCreatorVesting<'_> {
     }
     pub fn handler(ctx: Context<ClaimCreatorVesting>) -> Result<()> {
         let clock = Clock::get()?;
+        let creator_vault = &ctx.accounts.creator_vault;
+        let bonding_curve = &ctx.accounts.bonding_curve;
 
-        let tokens_per_second = (ctx.accounts.creator_vault.initial_vested_supply as i64)
-            .checked_div(ctx.accounts.bonding_curve.vesting_terms.duration)
-            .unwrap() as u64;
-
-        msg!(
-            "ClaimCreatorVesting::handler: tokens_per_second: {}",
-            tokens_per_second
-        );
-        let start_second: i64;
-        if i64::default() != ctx.accounts.creator_vault.last_distribution {
-            let last_distribution = ctx.accounts.creator_vault.last_distribution;
-            msg!(
-                "ClaimCreatorVesting::handler: last_distribution: {}",
-                last_distribution
-            );
-            require!(
-                clock.unix_timestamp > last_distribution,
-                ContractError::VestingPeriodNotOver
-            );
-            start_second = last_distribution + 1;
-        } else {
-            msg!("First distribution");
-            start_second = ctx.accounts.bonding_curve.start_time
-                + ctx.accounts.bonding_curve.vesting_terms.cliff
-        }
-        msg!(
-            "ClaimCreatorVesting::handler: start_second: {}",
-            start_second
-        );
-        let seconds_since_start_second = clock.unix_timestamp.checked_sub(start_second).unwrap();
-        msg!(
-            "ClaimCreatorVesting::handler: seconds_since_start_second: {}",
-            seconds_since_start_second
-        );
-        let tokens_to_distribute = tokens_per_second
-            .checked_mul(seconds_since_start_second as u64)
-            .unwrap();
+        // pays out everything vested so far minus what was already claimed,
+        // so claims add up exactly to the vested supply whatever their timing
+        let tokens_to_distribute = creator_vault
+            .claimable_at(bonding_curve, clock.unix_timestamp)
+            .ok_or(ContractError::NoVestedTokens)?;
         msg!(
             "ClaimCreatorVesting::handler: tokens_to_distribute: {}",
             tokens_to_distribute
         );
-        let user_token_account = ctx.accounts.user_token_account.to_account_info();
-        let creator_vault_token_account =
-            ctx.accounts.creator_vault_token_account.to_account_info();
-        let mint_k = ctx.accounts.bonding_curve.mint.key();
+        require_gt!(tokens_to_distribute, 0, ContractError::NoVestedTokens);
+
+        let mint_k = bonding_curve.mint.key();
         let signer = CreatorVault::get_signer(&ctx.bumps.creator_vault, &mint_k);
         let signer_seeds = &[&signer[..]];
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
-                token::Transfer {
-                    from: creator_vault_token_account,
-                    to: user_token_account,
+                token_interface::TransferChecked {
+                    from: ctx.accounts.creator_vault_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
                     authority: ctx.accounts.creator_vault.to_account_info(),
                 },
                 signer_seeds,
             ),
             tokens_to_distribute,
+            ctx.accounts.mint.decimals,
         )?;
-        ctx.accounts.creator_vault.last_distribution = clock.unix_timestamp;
+
+        let creator_vault = &mut ctx.accounts.creator_vault;
+        creator_vault.claimed_amount += tokens_to_distribute;
+        creator_vault.last_distribution = clock.unix_timestamp;
+
+        emit_cpi!(VestingClaimEvent {
+            mint: mint_k,
+            creator: ctx.accounts.creator.key(),
+            amount: tokens_to_distribute,
+            claimed_amount: creator_vault.claimed_amount,
+            initial_vested_supply: creator_vault.initial_vested_supply,
+            timestamp: clock.unix_timestamp,
+        });
         msg!("ClaimCreatorVesting::handler: done");
         Ok(())
     }
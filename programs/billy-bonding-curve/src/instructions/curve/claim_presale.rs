@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    errors::ContractError,
+    events::PresaleClaimEvent,
+    state::{
+        bonding_curve::BondingCurve, global::*, presale::PresalePosition, vaults::PresaleVault,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimPresale<'info> {
+    #[account(mut)]
+    contributor: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_claim() @ ContractError::ClaimDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [PresaleVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    presale_vault: Box<Account<'info, PresaleVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = presale_vault,
+        associated_token::token_program = token_program,
+    )]
+    presale_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            PresalePosition::SEED_PREFIX.as_bytes(),
+            mint.to_account_info().key.as_ref(),
+            contributor.to_account_info().key.as_ref()
+        ],
+        constraint = !presale_position.claimed @ ContractError::PresaleAlreadyClaimed,
+        bump = presale_position.bump,
+    )]
+    presale_position: Box<Account<'info, PresalePosition>>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        associated_token::mint = mint,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
+    )]
+    user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl ClaimPresale<'_> {
+    pub fn validate(&self) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            self.bonding_curve.is_started(&clock),
+            ContractError::CurveNotStarted
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<ClaimPresale>) -> Result<()> {
+        let token_amount = ctx
+            .accounts
+            .presale_position
+            .get_claimable_tokens(
+                ctx.accounts.presale_vault.initial_vested_supply,
+                ctx.accounts.bonding_curve.presale_sol_raised,
+            )
+            .ok_or(ContractError::NoPresaleTokens)?;
+        msg!("ClaimPresale::handler: token_amount: {}", token_amount);
+        require_gt!(token_amount, 0, ContractError::NoPresaleTokens);
+
+        let mint_k = ctx.accounts.mint.key();
+        let signer = PresaleVault::get_signer(&ctx.bumps.presale_vault, &mint_k);
+        let signer_seeds = &[&signer[..]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.presale_vault_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.presale_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            token_amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let position = &mut ctx.accounts.presale_position;
+        position.claimed = true;
+        ctx.accounts.presale_vault.total_claimed += token_amount;
+
+        emit_cpi!(PresaleClaimEvent {
+            mint: mint_k,
+            contributor: position.contributor,
+            contributed: position.contributed,
+            token_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("ClaimPresale::handler: done");
+        Ok(())
+    }
+}
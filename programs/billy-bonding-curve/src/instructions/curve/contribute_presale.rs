@@ -0,0 +1,124 @@
+use anchor_lang::{prelude::*, solana_program::system_instruction};
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    errors::ContractError,
+    events::PresaleContributionEvent,
+    state::{
+        bonding_curve::BondingCurve, global::*, presale::PresalePosition, vaults::PresaleVault,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(sol_amount: u64)]
+pub struct ContributePresale<'info> {
+    #[account(mut)]
+    contributor: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_buy() @ ContractError::BuyDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [PresaleVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    presale_vault: Box<Account<'info, PresaleVault>>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        seeds = [
+            PresalePosition::SEED_PREFIX.as_bytes(),
+            mint.to_account_info().key.as_ref(),
+            contributor.to_account_info().key.as_ref()
+        ],
+        space = 8 + PresalePosition::INIT_SPACE,
+        bump,
+    )]
+    presale_position: Box<Account<'info, PresalePosition>>,
+
+    system_program: Program<'info, System>,
+}
+
+impl ContributePresale<'_> {
+    pub fn validate(&self, sol_amount: u64) -> Result<()> {
+        let clock = Clock::get()?;
+        require_gt!(sol_amount, 0, ContractError::InvalidArgument);
+        require!(
+            self.presale_vault.presale_terms.is_open(&clock),
+            ContractError::PresaleNotOpen
+        );
+
+        let contributed = self
+            .presale_position
+            .contributed
+            .checked_add(sol_amount)
+            .ok_or(ContractError::PresaleWalletCapExceeded)?;
+        require!(
+            contributed <= self.presale_vault.presale_terms.max_contribution_per_wallet,
+            ContractError::PresaleWalletCapExceeded
+        );
+        require!(
+            self.presale_vault
+                .presale_terms
+                .is_within_cap(self.bonding_curve.presale_sol_raised, sol_amount),
+            ContractError::PresaleCapExceeded
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<ContributePresale>, sol_amount: u64) -> Result<()> {
+        // sender is signer, must go through system program
+        let transfer_instruction = system_instruction::transfer(
+            ctx.accounts.contributor.key,
+            &ctx.accounts.presale_vault.key(),
+            sol_amount,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &transfer_instruction,
+            &[
+                ctx.accounts.contributor.to_account_info(),
+                ctx.accounts.presale_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[],
+        )?;
+        msg!("ContributePresale::handler: SOL to presale_vault transfer complete");
+
+        let position = &mut ctx.accounts.presale_position;
+        position.contributor = ctx.accounts.contributor.key();
+        position.mint = ctx.accounts.mint.key();
+        position.contributed += sol_amount;
+        position.bump = ctx.bumps.presale_position;
+
+        let bonding_curve = &mut ctx.accounts.bonding_curve;
+        bonding_curve.presale_sol_raised += sol_amount;
+
+        emit_cpi!(PresaleContributionEvent {
+            mint: position.mint,
+            contributor: position.contributor,
+            sol_amount,
+            contributed: position.contributed,
+            presale_sol_raised: bonding_curve.presale_sol_raised,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("ContributePresale::handler: done");
+        Ok(())
+    }
+}
@@ -1,22 +1,26 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_instruction;
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, AssociatedToken},
     metadata::{
         create_metadata_accounts_v3, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3,
         Metadata as Metaplex,
     },
-    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+    token_interface::{mint_to, MintTo, TokenInterface},
 };
 
 use crate::state::{
     allocation::AllocationData,
     bonding_curve::*,
     global::*,
-    vaults::{BrandVault, CreatorVault, PlatformVault, PresaleVault},
+    vaults::{BrandVault, CexVault, CreatorVault, PlatformVault, PresaleVault},
 };
 
-use crate::{errors::ContractError, events::CreateEvent};
+use crate::{
+    errors::ContractError,
+    events::{CreateEvent, LaunchScheduledEvent},
+    mint::{is_token_2022, validate_mint_extensions, CreateMint},
+};
 
 use crate::state::bonding_curve::locker::{BondingCurveLockerCtx, IntoBondingCurveLockerCtx};
 
@@ -24,14 +28,9 @@ use crate::state::bonding_curve::locker::{BondingCurveLockerCtx, IntoBondingCurv
 #[derive(Accounts)]
 #[instruction(params: CreateBondingCurveParams)]
 pub struct CreateBondingCurve<'info> {
-    #[account(
-        init,
-        payer = creator,
-        mint::decimals = global.created_mint_decimals,
-        mint::authority = bonding_curve,
-        mint::freeze_authority = bonding_curve
-    )]
-    mint: Box<Account<'info, Mint>>,
+    // created in the handler, with the metadata extensions when launched on Token-2022
+    #[account(mut)]
+    mint: Signer<'info>,
 
     #[account(mut)]
     creator: Signer<'info>,
@@ -43,13 +42,9 @@ pub struct CreateBondingCurve<'info> {
         bump,
     )]
     creator_vault: Box<Account<'info, CreatorVault>>,
-    #[account(
-        init_if_needed,
-        payer = creator,
-        associated_token::mint = mint,
-        associated_token::authority = creator_vault,
-    )]
-    creator_vault_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: associated token account of creator_vault, created in the handler
+    #[account(mut)]
+    creator_vault_token_account: UncheckedAccount<'info>,
 
     #[account(
         init,
@@ -59,13 +54,9 @@ pub struct CreateBondingCurve<'info> {
         bump,
     )]
     presale_vault: Box<Account<'info, PresaleVault>>,
-    #[account(
-        init_if_needed,
-        payer = creator,
-        associated_token::mint = mint,
-        associated_token::authority = presale_vault,
-    )]
-    presale_vault_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: associated token account of presale_vault, created in the handler
+    #[account(mut)]
+    presale_vault_token_account: UncheckedAccount<'info>,
 
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account()]
@@ -78,13 +69,9 @@ pub struct CreateBondingCurve<'info> {
         bump,
     )]
     brand_vault: Box<Account<'info, BrandVault>>,
-    #[account(
-        init_if_needed,
-        payer = creator,
-        associated_token::mint = mint,
-        associated_token::authority = brand_vault,
-    )]
-    brand_vault_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: associated token account of brand_vault, created in the handler
+    #[account(mut)]
+    brand_vault_token_account: UncheckedAccount<'info>,
 
     #[account(
         init,
@@ -94,13 +81,21 @@ pub struct CreateBondingCurve<'info> {
         bump,
     )]
     platform_vault: Box<Account<'info, PlatformVault>>,
+    /// CHECK: associated token account of platform_vault, created in the handler
+    #[account(mut)]
+    platform_vault_token_account: UncheckedAccount<'info>,
+
     #[account(
-        init_if_needed,
+        init,
         payer = creator,
-        associated_token::mint = mint,
-        associated_token::authority = platform_vault,
+        seeds = [CexVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        space = 8 + CexVault::INIT_SPACE,
+        bump,
     )]
-    platform_vault_token_account: Box<Account<'info, TokenAccount>>,
+    cex_vault: Box<Account<'info, CexVault>>,
+    /// CHECK: associated token account of cex_vault, created in the handler
+    #[account(mut)]
+    cex_vault_token_account: UncheckedAccount<'info>,
 
     #[account(
         init,
@@ -111,13 +106,9 @@ pub struct CreateBondingCurve<'info> {
     )]
     bonding_curve: Box<Account<'info, BondingCurve>>,
 
-    #[account(
-        init_if_needed,
-        payer = creator,
-        associated_token::mint = mint,
-        associated_token::authority = bonding_curve,
-    )]
-    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: associated token account of bonding_curve, created in the handler
+    #[account(mut)]
+    bonding_curve_token_account: UncheckedAccount<'info>,
 
     // #[account(
     //     init,
@@ -130,31 +121,23 @@ pub struct CreateBondingCurve<'info> {
     #[account(
         seeds = [Global::SEED_PREFIX.as_bytes()],
         constraint = global.initialized == true @ ContractError::NotInitialized,
-        constraint = global.status == ProgramStatus::Running @ ContractError::ProgramNotRunning,
+        constraint = global.status.can_create() @ ContractError::CreateDisabled,
         bump,
     )]
     global: Box<Account<'info, Global>>,
 
-    ///CHECK: Using seed to validate metadata account
-    #[account(
-        mut,
-        seeds = [
-            b"metadata",
-            token_metadata_program.key.as_ref(),
-            mint.to_account_info().key.as_ref()
-        ],
-        seeds::program = token_metadata_program.key(),
-        bump,
-    )]
-    metadata: AccountInfo<'info>,
+    ///CHECK: Using seed to validate metadata account, only used for SPL Token mints
+    #[account(mut)]
+    metadata: Option<UncheckedAccount<'info>>,
 
     system_program: Program<'info, System>,
 
-    token_program: Program<'info, Token>,
+    // SPL Token mints get Metaplex metadata, Token-2022 mints the metadata extensions
+    token_program: Interface<'info, TokenInterface>,
 
     associated_token_program: Program<'info, AssociatedToken>,
 
-    token_metadata_program: Program<'info, Metaplex>,
+    token_metadata_program: Option<Program<'info, Metaplex>>,
 
     rent: Sysvar<'info, Rent>,
 
@@ -168,32 +151,122 @@ impl<'info> IntoBondingCurveLockerCtx<'info> for CreateBondingCurve<'info> {
     ) -> BondingCurveLockerCtx<'info> {
         BondingCurveLockerCtx {
             bonding_curve_bump,
-            mint: self.mint.clone(),
+            mint: self.mint.to_account_info(),
             bonding_curve: self.bonding_curve.clone(),
-            bonding_curve_token_account: self.bonding_curve_token_account.clone(),
-            token_program: self.token_program.clone(),
+            bonding_curve_token_account: self.bonding_curve_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
         }
     }
 }
-impl CreateBondingCurve<'_> {
+impl<'info> CreateBondingCurve<'info> {
     pub fn validate(&self, params: &CreateBondingCurveParams) -> Result<()> {
         let clock = Clock::get()?;
         msg!("allocation: {:#?}", params.allocation);
         // todo complete validation for params,allocations and start time
-        require!(
-            AllocationData::from(params.allocation).is_valid(),
-            ContractError::InvalidAllocation
-        );
+        let allocation = AllocationData::from(params.allocation);
+        require!(allocation.is_valid(), ContractError::InvalidAllocation);
+
+        // SPL Token mints keep their metadata in Metaplex
+        if !is_token_2022(self.token_program.key) {
+            let (Some(metadata), Some(token_metadata_program)) =
+                (&self.metadata, &self.token_metadata_program)
+            else {
+                return Err(ContractError::MissingMetadataAccounts.into());
+            };
+            let (metadata_pda, _) = Pubkey::find_program_address(
+                &[
+                    b"metadata",
+                    token_metadata_program.key.as_ref(),
+                    self.mint.key.as_ref(),
+                ],
+                token_metadata_program.key,
+            );
+            require_keys_eq!(
+                metadata.key(),
+                metadata_pda,
+                ContractError::MissingMetadataAccounts
+            );
+        }
 
         msg!("not_allc");
 
+        // a presale allocation needs presale terms and vice versa
+        require!(
+            (allocation.presale > 0) == params.presale_terms.is_some(),
+            ContractError::InvalidPresaleTerms
+        );
+
         // validate start time
+        if let Some(presale_terms) = &params.presale_terms {
+            require!(
+                presale_terms.open_time < presale_terms.close_time
+                    && presale_terms.close_time > clock.unix_timestamp
+                    && presale_terms.max_contribution_per_wallet > 0
+                    && presale_terms.presale_sol_cap > 0
+                    && presale_terms.presale_sol_cap < params.sol_launch_threshold,
+                ContractError::InvalidPresaleTerms
+            );
+            // the curve opens once the presale is over
+            let start_time = params.start_time.ok_or(ContractError::InvalidStartTime)?;
+            require!(
+                start_time >= presale_terms.close_time,
+                ContractError::InvalidStartTime
+            )
+        }
+        // launches can be scheduled up to the global lead time ahead
         if let Some(start_time) = params.start_time {
             require!(
-                start_time <= clock.unix_timestamp,
+                BondingCurve::is_valid_start_time(
+                    start_time,
+                    clock.unix_timestamp,
+                    self.global.max_launch_lead_time
+                ),
                 ContractError::InvalidStartTime
             )
         }
+        let start_time = params.start_time.unwrap_or(clock.unix_timestamp);
+        if let Some(graduation_deadline) = params.graduation_deadline {
+            require!(
+                graduation_deadline > start_time,
+                ContractError::InvalidGraduationDeadline
+            );
+        }
+
+        if let Some(launch_protection) = &params.launch_protection {
+            require!(
+                launch_protection.is_valid(),
+                ContractError::InvalidLaunchProtection
+            );
+        }
+
+        if let Some(fee_schedule) = &params.fee_schedule {
+            require!(
+                fee_schedule.is_valid(self.global.caps.max_trade_fee_bps),
+                ContractError::InvalidFeeSchedule
+            );
+        }
+
+        if let Some(curve_kind) = &params.curve_kind {
+            require!(curve_kind.is_valid(), ContractError::InvalidCurveKind);
+        }
+
+        if let Some(allowlist) = &params.allowlist {
+            require!(
+                allowlist.end_time > start_time,
+                ContractError::InvalidAllowlist
+            );
+        }
+
+        // explicit vesting anchors can't precede the curve start
+        for terms in [&params.vesting_terms, &params.brandkit_vesting_terms]
+            .into_iter()
+            .flatten()
+        {
+            if let VestingAnchor::Timestamp(timestamp) = terms.anchor {
+                require!(timestamp >= start_time, ContractError::InvalidVestingTerms);
+            }
+        }
+
         // validate sol_launch_threshold
         let mut d = BondingCurve::default();
         let bc = d.update_from_params(
@@ -235,13 +308,22 @@ impl CreateBondingCurve<'_> {
             ctx.bumps.bonding_curve,
         );
         msg!("CreateBondingCurve::update_from_params: created bonding_curve");
+        ctx.accounts.presale_vault.presale_terms = params.presale_terms.clone().unwrap_or_default();
+        ctx.accounts.brand_vault.lifetime_vesting_terms =
+            params.brandkit_vesting_terms.clone().unwrap_or_default();
+        ctx.accounts.platform_vault.vesting_terms =
+            ctx.accounts.global.platform_vesting_terms.clone();
+        ctx.accounts.cex_vault.vesting_terms = ctx.accounts.global.cex_vesting_terms.clone();
 
         let mint_k = ctx.accounts.mint.key();
         let mint_authority_signer = BondingCurve::get_signer(&ctx.bumps.bonding_curve, &mint_k);
         let mint_auth_signer_seeds = &[&mint_authority_signer[..]];
 
+        ctx.accounts.create_mint(&params)?;
         ctx.accounts
             .intialize_meta(mint_auth_signer_seeds, &params)?;
+        validate_mint_extensions(&ctx.accounts.mint.to_account_info())?;
+        ctx.accounts.create_token_accounts()?;
         ctx.accounts.mint_allocations(mint_auth_signer_seeds)?;
         ctx.accounts.pay_launch_fee()?;
 
@@ -272,17 +354,104 @@ impl CreateBondingCurve<'_> {
 
             start_time: bonding_curve.start_time,
         });
+        if !bonding_curve.is_started(&clock) {
+            emit_cpi!(LaunchScheduledEvent {
+                mint: bonding_curve.mint,
+                creator: bonding_curve.creator,
+                start_time: bonding_curve.start_time,
+                previous_start_time: None,
+                timestamp: clock.unix_timestamp,
+            });
+        }
         msg!("CreateBondingCurve::handler: success");
         Ok(())
     }
+    pub fn get_create_mint(&self) -> CreateMint<'info> {
+        CreateMint {
+            payer: self.creator.to_account_info(),
+            mint: self.mint.to_account_info(),
+            authority: self.bonding_curve.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+        }
+    }
+
+    pub fn create_mint(&self, params: &CreateBondingCurveParams) -> Result<()> {
+        self.get_create_mint().create(
+            self.global.created_mint_decimals,
+            &params.name,
+            &params.symbol,
+            &params.uri,
+        )?;
+        msg!("CreateBondingCurve::create_mint: done");
+        Ok(())
+    }
+
+    pub fn create_token_accounts(&self) -> Result<()> {
+        let token_accounts = [
+            (
+                &self.creator_vault_token_account,
+                self.creator_vault.to_account_info(),
+            ),
+            (
+                &self.presale_vault_token_account,
+                self.presale_vault.to_account_info(),
+            ),
+            (
+                &self.brand_vault_token_account,
+                self.brand_vault.to_account_info(),
+            ),
+            (
+                &self.platform_vault_token_account,
+                self.platform_vault.to_account_info(),
+            ),
+            (
+                &self.cex_vault_token_account,
+                self.cex_vault.to_account_info(),
+            ),
+            (
+                &self.bonding_curve_token_account,
+                self.bonding_curve.to_account_info(),
+            ),
+        ];
+        for (token_account, authority) in token_accounts {
+            associated_token::create(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: self.creator.to_account_info(),
+                    associated_token: token_account.to_account_info(),
+                    authority,
+                    mint: self.mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+            ))?;
+        }
+        msg!("CreateBondingCurve::create_token_accounts: done");
+        Ok(())
+    }
+
     pub fn intialize_meta(
         &mut self,
         mint_auth_signer_seeds: &[&[&[u8]]; 1],
         params: &CreateBondingCurveParams,
     ) -> Result<()> {
+        if is_token_2022(self.token_program.key) {
+            return self.get_create_mint().initialize_token_metadata(
+                mint_auth_signer_seeds,
+                params.name.clone(),
+                params.symbol.clone(),
+                params.uri.clone(),
+            );
+        }
+        let (Some(metadata), Some(token_metadata_program)) =
+            (&self.metadata, &self.token_metadata_program)
+        else {
+            return Err(ContractError::MissingMetadataAccounts.into());
+        };
         let mint_info = self.mint.to_account_info();
         let mint_authority_info = self.bonding_curve.to_account_info();
-        let metadata_info = self.metadata.to_account_info();
+        let metadata_info = metadata.to_account_info();
         let token_data: DataV2 = DataV2 {
             name: params.name.clone(),
             symbol: params.symbol.clone(),
@@ -293,7 +462,7 @@ impl CreateBondingCurve<'_> {
             uses: None,
         };
         let metadata_ctx = CpiContext::new_with_signer(
-            self.token_metadata_program.to_account_info(),
+            token_metadata_program.to_account_info(),
             CreateMetadataAccountsV3 {
                 payer: self.creator.to_account_info(),
                 mint: mint_info.clone(),
@@ -366,8 +535,7 @@ impl CreateBondingCurve<'_> {
                 amount,
             )?;
             self.brand_vault.launch_brandkit_supply = bonding_curve.launch_brandkit_supply;
-            self.brand_vault.lifetime_brandkit_supply =
-                bonding_curve.lifetime_brandkit_supply;
+            self.brand_vault.lifetime_brandkit_supply = bonding_curve.lifetime_brandkit_supply;
             self.brand_vault.initial_vested_supply = amount;
             msg!("CreateBondingCurve::mint_allocations:bonding_curve.launch_brandkit_supply + bonding_curve.lifetime_brandkit_supply minted");
         }
@@ -388,6 +556,23 @@ impl CreateBondingCurve<'_> {
             self.platform_vault.initial_vested_supply = bonding_curve.platform_supply;
             msg!("CreateBondingCurve::mint_allocations:bonding_curve.platform_supply minted");
         }
+        if bonding_curve.cex_supply > 0 {
+            // mint cex share to cex_vault_token_account
+            mint_to(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    MintTo {
+                        authority: mint_authority_info.clone(),
+                        to: self.cex_vault_token_account.to_account_info(),
+                        mint: mint_info.clone(),
+                    },
+                    mint_auth_signer_seeds,
+                ),
+                bonding_curve.cex_supply,
+            )?;
+            self.cex_vault.initial_vested_supply = bonding_curve.cex_supply;
+            msg!("CreateBondingCurve::mint_allocations:bonding_curve.cex_supply minted");
+        }
         // mint tokens to bonding_curve_token_account
         mint_to(
             CpiContext::new_with_signer(
@@ -425,6 +610,7 @@ impl CreateBondingCurve<'_> {
             ],
             &[],
         )?;
+        self.bonding_curve.launch_fee_lamports = fee_amount;
         msg!("CreateBondingCurve::pay_launch_fee: done");
         Ok(())
     }
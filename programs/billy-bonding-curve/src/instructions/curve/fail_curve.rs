@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ContractError,
+    events::CurveFailedEvent,
+    state::{
+        bonding_curve::*,
+        global::*,
+        vaults::{BrandVault, CexVault, CreatorVault, PlatformVault, PresaleVault},
+    },
+};
+
+use crate::state::bonding_curve::locker::{BondingCurveLockerCtx, IntoBondingCurveLockerCtx};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FailCurve<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_sell() @ ContractError::SellDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(mut)]
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        constraint = !bonding_curve.complete @ ContractError::BondingCurveComplete,
+        constraint = !bonding_curve.failed @ ContractError::BondingCurveFailed,
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve,
+        associated_token::token_program = token_program,
+    )]
+    bonding_curve_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [PresaleVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    presale_vault: Box<Account<'info, PresaleVault>>,
+
+    #[account(
+        seeds = [CreatorVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    creator_vault: Box<Account<'info, CreatorVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator_vault,
+        associated_token::token_program = token_program,
+    )]
+    creator_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [CexVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    cex_vault: Box<Account<'info, CexVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = cex_vault,
+        associated_token::token_program = token_program,
+    )]
+    cex_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [BrandVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    brand_vault: Box<Account<'info, BrandVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = brand_vault,
+        associated_token::token_program = token_program,
+    )]
+    brand_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [PlatformVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    platform_vault: Box<Account<'info, PlatformVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = platform_vault,
+        associated_token::token_program = token_program,
+    )]
+    platform_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> IntoBondingCurveLockerCtx<'info> for FailCurve<'info> {
+    fn into_bonding_curve_locker_ctx(
+        &self,
+        bonding_curve_bump: u8,
+    ) -> BondingCurveLockerCtx<'info> {
+        BondingCurveLockerCtx {
+            bonding_curve_bump,
+            mint: self.mint.to_account_info(),
+            bonding_curve: self.bonding_curve.clone(),
+            bonding_curve_token_account: self.bonding_curve_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        }
+    }
+}
+
+impl<'info> FailCurve<'info> {
+    pub fn validate(&self) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            self.bonding_curve.is_past_graduation_deadline(&clock),
+            ContractError::GraduationDeadlineNotReached
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<FailCurve>) -> Result<()> {
+        let clock = Clock::get()?;
+        let mint_k = ctx.accounts.mint.key();
+
+        let locker: &mut BondingCurveLockerCtx = &mut ctx
+            .accounts
+            .into_bonding_curve_locker_ctx(ctx.bumps.bonding_curve);
+        // leave the account thawed, nothing trades on a failed curve
+        locker.unlock_ata()?;
+        locker.revoke_freeze_authority()?;
+
+        // unsold tokens and the unclaimed vault allocations are burned,
+        // the presale vault is kept so contributors can still claim and redeem
+        let mut tokens_burned = 0;
+        let curve_signer = BondingCurve::get_signer(&ctx.bumps.bonding_curve, &mint_k);
+        tokens_burned += ctx.accounts.burn_all(
+            &ctx.accounts.bonding_curve_token_account,
+            ctx.accounts.bonding_curve.to_account_info(),
+            &[&curve_signer[..]],
+        )?;
+        let creator_signer = CreatorVault::get_signer(&ctx.bumps.creator_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_all(
+            &ctx.accounts.creator_vault_token_account,
+            ctx.accounts.creator_vault.to_account_info(),
+            &[&creator_signer[..]],
+        )?;
+        let cex_signer = CexVault::get_signer(&ctx.bumps.cex_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_all(
+            &ctx.accounts.cex_vault_token_account,
+            ctx.accounts.cex_vault.to_account_info(),
+            &[&cex_signer[..]],
+        )?;
+        let brand_signer = BrandVault::get_signer(&ctx.bumps.brand_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_all(
+            &ctx.accounts.brand_vault_token_account,
+            ctx.accounts.brand_vault.to_account_info(),
+            &[&brand_signer[..]],
+        )?;
+        let platform_signer = PlatformVault::get_signer(&ctx.bumps.platform_vault, &mint_k);
+        tokens_burned += ctx.accounts.burn_all(
+            &ctx.accounts.platform_vault_token_account,
+            ctx.accounts.platform_vault.to_account_info(),
+            &[&platform_signer[..]],
+        )?;
+        msg!("FailCurve::handler: tokens_burned: {}", tokens_burned);
+
+        // presale SOL joins the curve reserves and is refunded the same way
+        let presale_sol_raised = ctx.accounts.bonding_curve.presale_sol_raised;
+        ctx.accounts
+            .presale_vault
+            .sub_lamports(presale_sol_raised)?;
+        ctx.accounts
+            .bonding_curve
+            .add_lamports(presale_sol_raised)?;
+
+        let bonding_curve = &mut ctx.accounts.bonding_curve;
+        // only buyers share the reserves
+        bonding_curve.refund_token_supply = bonding_curve
+            .get_buyer_token_supply()
+            .ok_or(ContractError::BondingCurveInvariant)?;
+        bonding_curve.real_sol_reserves += presale_sol_raised;
+        bonding_curve.real_token_reserves = 0;
+        bonding_curve.failed = true;
+
+        BondingCurve::invariant(
+            &mut ctx
+                .accounts
+                .into_bonding_curve_locker_ctx(ctx.bumps.bonding_curve),
+        )?;
+
+        let bonding_curve = &ctx.accounts.bonding_curve;
+        emit_cpi!(CurveFailedEvent {
+            mint: mint_k,
+            payer: ctx.accounts.payer.key(),
+            graduation_deadline: bonding_curve.graduation_deadline.unwrap_or_default(),
+            real_sol_reserves: bonding_curve.real_sol_reserves,
+            presale_sol_raised,
+            tokens_burned,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("FailCurve::handler: done");
+        Ok(())
+    }
+
+    fn burn_all(
+        &self,
+        token_account: &InterfaceAccount<'info, TokenAccount>,
+        authority: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64> {
+        let amount = token_account.amount;
+        if amount == 0 {
+            return Ok(0);
+        }
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.mint.to_account_info(),
+                    from: token_account.to_account_info(),
+                    authority,
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        Ok(amount)
+    }
+}
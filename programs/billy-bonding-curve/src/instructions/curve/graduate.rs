@@ -0,0 +1,280 @@
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Burn, Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    amm::{self, InitializePool, LIQUIDITY_PROVIDER_SEED_PREFIX},
+    errors::ContractError,
+    events::GraduationEvent,
+    state::{bonding_curve::*, global::*, vaults::PresaleVault},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Graduate<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_launch() @ ContractError::LaunchDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        constraint = bonding_curve.complete @ ContractError::BondingCurveNotComplete,
+        constraint = !bonding_curve.graduated @ ContractError::BondingCurveGraduated,
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve,
+        associated_token::token_program = token_program,
+    )]
+    bonding_curve_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [PresaleVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    presale_vault: Box<Account<'info, PresaleVault>>,
+
+    /// CHECK: data-less PDA that provides the liquidity to the AMM
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_PROVIDER_SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        bump,
+    )]
+    liquidity_provider: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = liquidity_provider,
+        associated_token::token_program = token_program,
+    )]
+    liquidity_provider_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: created and validated by the AMM program
+    #[account(mut)]
+    pool: UncheckedAccount<'info>,
+    /// CHECK: created and validated by the AMM program
+    #[account(mut)]
+    lp_mint: UncheckedAccount<'info>,
+    /// CHECK: created by the AMM program, deserialized after the CPI
+    #[account(mut)]
+    liquidity_provider_lp_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: validated against global.amm_program
+    #[account(executable)]
+    amm_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> Graduate<'info> {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.global.amm_program != Pubkey::default(),
+            ContractError::AmmProgramNotSet
+        );
+        require_keys_eq!(
+            self.amm_program.key(),
+            self.global.amm_program,
+            ContractError::InvalidAmmProgram
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, Graduate<'info>>) -> Result<()> {
+        let (sol_amount, token_amount) = ctx
+            .accounts
+            .bonding_curve
+            .get_graduation_liquidity()
+            .ok_or(ContractError::GraduationFailed)?;
+        msg!(
+            "Graduate::handler: sol_amount: {}, token_amount: {}",
+            sol_amount,
+            token_amount
+        );
+        require!(
+            sol_amount > 0 && token_amount > 0,
+            ContractError::GraduationFailed
+        );
+
+        let mint_k = ctx.accounts.mint.key();
+        let curve_signer = BondingCurve::get_signer(&ctx.bumps.bonding_curve, &mint_k);
+        let curve_signer_seeds = &[&curve_signer[..]];
+        let provider_signer =
+            amm::get_liquidity_provider_signer(&ctx.bumps.liquidity_provider, &mint_k);
+        let provider_signer_seeds = &[&provider_signer[..]];
+
+        // presale SOL is held by the presale vault, the rest by the curve
+        let presale_sol_amount = ctx.accounts.bonding_curve.presale_sol_raised;
+        let curve_sol_amount = sol_amount - presale_sol_amount;
+
+        // senders are PDAs, can use lamport utilities
+        ctx.accounts.bonding_curve.sub_lamports(curve_sol_amount)?;
+        ctx.accounts
+            .presale_vault
+            .sub_lamports(presale_sol_amount)?;
+        ctx.accounts.liquidity_provider.add_lamports(sol_amount)?;
+        let provider_lamports = ctx.accounts.liquidity_provider.lamports();
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .liquidity_provider_token_account
+                        .to_account_info(),
+                    authority: ctx.accounts.bonding_curve.to_account_info(),
+                },
+                curve_signer_seeds,
+            ),
+            token_amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        msg!("Graduate::handler: liquidity moved to liquidity_provider");
+
+        amm::initialize_pool(
+            CpiContext::new_with_signer(
+                ctx.accounts.amm_program.to_account_info(),
+                InitializePool {
+                    payer: ctx.accounts.payer.to_account_info(),
+                    liquidity_provider: ctx.accounts.liquidity_provider.to_account_info(),
+                    liquidity_provider_token_account: ctx
+                        .accounts
+                        .liquidity_provider_token_account
+                        .to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    pool: ctx.accounts.pool.to_account_info(),
+                    lp_mint: ctx.accounts.lp_mint.to_account_info(),
+                    liquidity_provider_lp_token_account: ctx
+                        .accounts
+                        .liquidity_provider_lp_token_account
+                        .to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    associated_token_program: ctx
+                        .accounts
+                        .associated_token_program
+                        .to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                provider_signer_seeds,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            sol_amount,
+            token_amount,
+        )?;
+        msg!("Graduate::handler: pool initialized");
+
+        // the AMM must have taken the full liquidity
+        ctx.accounts.liquidity_provider_token_account.reload()?;
+        require!(
+            ctx.accounts.liquidity_provider_token_account.amount == 0,
+            ContractError::GraduationFailed
+        );
+        let provider_lamports_spent = provider_lamports
+            .checked_sub(ctx.accounts.liquidity_provider.lamports())
+            .ok_or(ContractError::GraduationFailed)?;
+        require!(
+            provider_lamports_spent >= sol_amount,
+            ContractError::GraduationFailed
+        );
+
+        ctx.accounts.validate_pool()?;
+        let lp_amount = ctx.accounts.burn_lp_tokens(provider_signer_seeds)?;
+
+        let bonding_curve = &mut ctx.accounts.bonding_curve;
+        bonding_curve.real_sol_reserves -= curve_sol_amount;
+        bonding_curve.real_token_reserves -= token_amount;
+        bonding_curve.graduated = true;
+
+        emit_cpi!(GraduationEvent {
+            mint: mint_k,
+            payer: ctx.accounts.payer.key(),
+            amm_program: ctx.accounts.amm_program.key(),
+            pool: ctx.accounts.pool.key(),
+            lp_mint: ctx.accounts.lp_mint.key(),
+            sol_amount,
+            token_amount,
+            lp_amount_burned: lp_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Graduate::handler: success");
+        Ok(())
+    }
+
+    // the pool and its LP mint must come from the AMM, not from the caller
+    pub fn validate_pool(&self) -> Result<()> {
+        require_keys_eq!(
+            *self.pool.owner,
+            self.amm_program.key(),
+            ContractError::InvalidAmmPool
+        );
+        require_keys_eq!(
+            *self.lp_mint.owner,
+            self.token_program.key(),
+            ContractError::InvalidAmmPool
+        );
+        let lp_mint = Mint::try_deserialize(&mut &self.lp_mint.data.borrow()[..])?;
+        require!(
+            lp_mint.mint_authority == COption::Some(self.pool.key()),
+            ContractError::InvalidAmmPool
+        );
+        Ok(())
+    }
+
+    pub fn burn_lp_tokens(&self, provider_signer_seeds: &[&[&[u8]]; 1]) -> Result<u64> {
+        let lp_token_account = TokenAccount::try_deserialize(
+            &mut &self.liquidity_provider_lp_token_account.data.borrow()[..],
+        )?;
+        require_keys_eq!(
+            lp_token_account.owner,
+            self.liquidity_provider.key(),
+            ContractError::InvalidLpTokenAccount
+        );
+        require_keys_eq!(
+            lp_token_account.mint,
+            self.lp_mint.key(),
+            ContractError::InvalidLpTokenAccount
+        );
+        require_gt!(
+            lp_token_account.amount,
+            0,
+            ContractError::InvalidLpTokenAccount
+        );
+
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.lp_mint.to_account_info(),
+                    from: self.liquidity_provider_lp_token_account.to_account_info(),
+                    authority: self.liquidity_provider.to_account_info(),
+                },
+                provider_signer_seeds,
+            ),
+            lp_token_account.amount,
+        )?;
+        msg!("Graduate::burn_lp_tokens: done");
+        Ok(lp_token_account.amount)
+    }
+}
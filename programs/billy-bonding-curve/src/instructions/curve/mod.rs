@@ -1,3 +1,12 @@
+pub mod cancel_launch;
+pub mod claim_brandkit;
 pub mod claim_creator_vesting;
+pub mod claim_presale;
+pub mod contribute_presale;
 pub mod create_bonding_curve;
+pub mod fail_curve;
+pub mod graduate;
+pub mod quote;
+pub mod refund;
+pub mod reschedule_launch;
 pub mod swap;
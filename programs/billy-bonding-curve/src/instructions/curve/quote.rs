@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ContractError,
+    instructions::swap::SwapParams,
+    state::{
+        bonding_curve::*,
+        fee::{calculate_amount_before_fee, calculate_fee},
+        global::*,
+    },
+};
+
+// what a swap with the same params would do right now, returned Borsh encoded
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SwapQuote {
+    // SOL including the fee for buys, tokens for sells, a buy reaching the
+    // launch threshold is only partially filled
+    pub amount_in: u64,
+    // tokens for buys, SOL after the fee for sells
+    pub amount_out: u64,
+    pub fee_lamports: u64,
+    pub fee_bps: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u128,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    // lamports per token base unit, scaled by PRICE_SCALE
+    pub spot_price_before: u128,
+    pub spot_price_after: u128,
+    pub completes_curve: bool,
+}
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    /// CHECK: only used as a seed
+    mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.key.as_ref()],
+        constraint = !bonding_curve.complete @ ContractError::BondingCurveComplete,
+        constraint = !bonding_curve.failed @ ContractError::BondingCurveFailed,
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+}
+
+impl Quote<'_> {
+    pub fn validate(&self, params: &SwapParams) -> Result<()> {
+        if params.exact_out_amount == 0 {
+            require!(params.exact_in_amount > 0, ContractError::MinSwap);
+        } else {
+            require!(params.exact_in_amount == 0, ContractError::InvalidSwapMode);
+        }
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<Quote>, params: SwapParams) -> Result<SwapQuote> {
+        let clock = Clock::get()?;
+        let fee_bps = ctx
+            .accounts
+            .bonding_curve
+            .get_fee_bps(ctx.accounts.global.trade_fee_bps, clock.unix_timestamp);
+
+        // the trade runs against a copy, the account is never written
+        let mut bonding_curve = ctx.accounts.bonding_curve.as_ref().clone();
+        let spot_price_before = bonding_curve
+            .get_spot_price()
+            .ok_or(ContractError::InvalidArgument)?;
+        let exact_out = params.exact_out_amount > 0;
+
+        let (amount_in, amount_out, fee_lamports) = if params.base_in {
+            // same steps as a sell in Swap::handler
+            let sell_result = if exact_out {
+                let gross_sol_amount =
+                    calculate_amount_before_fee(fee_bps, params.exact_out_amount)
+                        .ok_or(ContractError::SellFailed)?;
+                bonding_curve.apply_sell_exact_out(gross_sol_amount)
+            } else {
+                bonding_curve.apply_sell(params.exact_in_amount)
+            }
+            .ok_or(ContractError::SellFailed)?;
+            let fee_lamports = calculate_fee(fee_bps, sell_result.sol_amount);
+            (
+                sell_result.token_amount,
+                sell_result.sol_amount - fee_lamports,
+                fee_lamports,
+            )
+        } else {
+            // same steps as a buy in Swap::handler
+            let buy_result = if exact_out {
+                bonding_curve.apply_buy_exact_out_capped(params.exact_out_amount)
+            } else {
+                bonding_curve.apply_buy_capped(params.exact_in_amount)
+            }
+            .ok_or(ContractError::BuyFailed)?;
+            let fee_lamports = calculate_fee(fee_bps, buy_result.sol_amount);
+            (
+                buy_result.sol_amount + fee_lamports,
+                buy_result.token_amount,
+                fee_lamports,
+            )
+        };
+
+        // presale SOL counts toward the launch threshold
+        let completes_curve = !params.base_in
+            && bonding_curve.real_sol_reserves + bonding_curve.presale_sol_raised
+                >= bonding_curve.sol_launch_threshold;
+        // the reserves left after the unsold tokens are settled
+        if completes_curve {
+            bonding_curve
+                .apply_completion_policy()
+                .ok_or(ContractError::BuyFailed)?;
+        }
+
+        let quote = SwapQuote {
+            amount_in,
+            amount_out,
+            fee_lamports,
+            fee_bps,
+            virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+            virtual_token_reserves: bonding_curve.virtual_token_reserves,
+            real_sol_reserves: bonding_curve.real_sol_reserves,
+            real_token_reserves: bonding_curve.real_token_reserves,
+            spot_price_before,
+            spot_price_after: bonding_curve
+                .get_spot_price()
+                .ok_or(ContractError::InvalidArgument)?,
+            completes_curve,
+        };
+        msg!("Quote::handler: {:#?}", quote);
+        Ok(quote)
+    }
+}
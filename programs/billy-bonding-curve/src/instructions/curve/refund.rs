@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ContractError,
+    events::RefundEvent,
+    state::{bonding_curve::BondingCurve, global::*},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    #[account(mut)]
+    user: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_sell() @ ContractError::SellDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    #[account(mut)]
+    mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
+        constraint = bonding_curve.failed @ ContractError::BondingCurveNotFailed,
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl Refund<'_> {
+    pub fn validate(&self, token_amount: u64) -> Result<()> {
+        require_gt!(token_amount, 0, ContractError::InvalidArgument);
+        require_gte!(
+            self.user_token_account.amount,
+            token_amount,
+            ContractError::InsufficientUserTokens
+        );
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<Refund>, token_amount: u64) -> Result<()> {
+        // every buyer token redeems the same share of the reserves,
+        // the last tokens burned take whatever is left
+        let refund_token_supply = ctx.accounts.bonding_curve.refund_token_supply;
+        let sol_amount = ctx
+            .accounts
+            .bonding_curve
+            .apply_refund(token_amount)
+            .ok_or(ContractError::InvalidArgument)?;
+        msg!(
+            "Refund::handler: token_amount: {}, refund_token_supply: {}, sol_amount: {}",
+            token_amount,
+            refund_token_supply,
+            sol_amount
+        );
+
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            token_amount,
+        )?;
+
+        // sender is a PDA, can use lamport utilities
+        ctx.accounts.bonding_curve.sub_lamports(sol_amount)?;
+        ctx.accounts.user.add_lamports(sol_amount)?;
+
+        emit_cpi!(RefundEvent {
+            mint: ctx.accounts.mint.key(),
+            user: ctx.accounts.user.key(),
+            token_amount,
+            sol_amount,
+            real_sol_reserves: ctx.accounts.bonding_curve.real_sol_reserves,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("Refund::handler: done");
+        Ok(())
+    }
+}
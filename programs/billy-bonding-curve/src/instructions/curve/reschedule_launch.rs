@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ContractError,
+    events::LaunchScheduledEvent,
+    state::{bonding_curve::BondingCurve, global::*, vaults::PresaleVault},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RescheduleLaunch<'info> {
+    #[account(
+        constraint = creator.key() == bonding_curve.creator.key() @ ContractError::InvalidCreatorAuthority
+    )]
+    creator: Signer<'info>,
+
+    #[account(
+        seeds = [Global::SEED_PREFIX.as_bytes()],
+        constraint = global.initialized @ ContractError::NotInitialized,
+        constraint = global.status.can_reschedule() @ ContractError::RescheduleDisabled,
+        bump,
+    )]
+    global: Box<Account<'info, Global>>,
+
+    /// CHECK: only used as a seed
+    mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.key.as_ref()],
+        bump,
+    )]
+    bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        seeds = [PresaleVault::SEED_PREFIX.as_bytes(), mint.key.as_ref()],
+        bump,
+    )]
+    presale_vault: Box<Account<'info, PresaleVault>>,
+}
+
+impl RescheduleLaunch<'_> {
+    pub fn validate(&self, start_time: i64) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            !self.bonding_curve.is_started(&clock),
+            ContractError::LaunchAlreadyStarted
+        );
+
+        // same bounds as on creation, but a launch can't be moved into the past
+        require!(
+            start_time >= clock.unix_timestamp
+                && BondingCurve::is_valid_start_time(
+                    start_time,
+                    clock.unix_timestamp,
+                    self.global.max_launch_lead_time
+                ),
+            ContractError::InvalidStartTime
+        );
+        if self.bonding_curve.presale_supply > 0 {
+            require!(
+                start_time >= self.presale_vault.presale_terms.close_time,
+                ContractError::InvalidStartTime
+            );
+        }
+        if let Some(graduation_deadline) = self.bonding_curve.graduation_deadline {
+            require!(
+                graduation_deadline > start_time,
+                ContractError::InvalidGraduationDeadline
+            );
+        }
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<RescheduleLaunch>, start_time: i64) -> Result<()> {
+        let bonding_curve = &mut ctx.accounts.bonding_curve;
+        let previous_start_time = bonding_curve.start_time;
+        bonding_curve.start_time = start_time;
+        msg!(
+            "RescheduleLaunch::handler: start_time: {} -> {}",
+            previous_start_time,
+            start_time
+        );
+
+        emit_cpi!(LaunchScheduledEvent {
+            mint: bonding_curve.mint,
+            creator: bonding_curve.creator,
+            start_time,
+            previous_start_time: Some(previous_start_time),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+}
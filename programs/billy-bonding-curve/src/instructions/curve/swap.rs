@@ -3,13 +3,21 @@ use std::ops::Div;
 use anchor_lang::{prelude::*, solana_program::system_instruction};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
+    token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
 use crate::{
     errors::ContractError,
     events::*,
-    state::{bonding_curve::*, global::*, vaults::PlatformVault},
+    mint::validate_mint_extensions,
+    state::{
+        allowlist::AllowlistProof,
+        bonding_curve::*,
+        fee::{calculate_amount_before_fee, calculate_fee},
+        global::*,
+        position::UserPosition,
+        vaults::PlatformVault,
+    },
 };
 
 use crate::state::bonding_curve::locker::{BondingCurveLockerCtx, IntoBondingCurveLockerCtx};
@@ -19,6 +27,11 @@ pub struct SwapParams {
     pub base_in: bool,
     pub exact_in_amount: u64,
     pub min_out_amount: u64,
+    // exact-out mode, used instead of exact_in_amount/min_out_amount when non zero
+    pub exact_out_amount: u64,
+    pub max_in_amount: u64,
+    // required for buys during the allowlist phase
+    pub allowlist_proof: Option<AllowlistProof>,
 }
 
 #[event_cpi]
@@ -35,12 +48,15 @@ pub struct Swap<'info> {
     )]
     global: Box<Account<'info, Global>>,
 
-    mint: Box<Account<'info, Mint>>,
+    // burning unsold tokens on completion changes the supply
+    #[account(mut)]
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
         seeds = [BondingCurve::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
         constraint = bonding_curve.complete == false @ ContractError::BondingCurveComplete,
+        constraint = !bonding_curve.failed @ ContractError::BondingCurveFailed,
         bump,
     )]
     bonding_curve: Box<Account<'info, BondingCurve>>,
@@ -49,25 +65,47 @@ pub struct Swap<'info> {
         mut,
         associated_token::mint = mint,
         associated_token::authority = bonding_curve,
+        associated_token::token_program = token_program,
     )]
-    bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
+    bonding_curve_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         seeds = [PlatformVault::SEED_PREFIX.as_bytes(), mint.to_account_info().key.as_ref()],
         bump,
     )]
     platform_vault: Box<Account<'info, PlatformVault>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = platform_vault,
+        associated_token::token_program = token_program,
+    )]
+    platform_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         init_if_needed,
         payer = user,
         associated_token::mint = mint,
         associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    user_token_account: Box<Account<'info, TokenAccount>>,
+    user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    // only needed for buys while the curve tracks them, see BondingCurve::tracks_buys
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserPosition::INIT_SPACE,
+        seeds = [
+            UserPosition::SEED_PREFIX.as_bytes(),
+            mint.to_account_info().key.as_ref(),
+            user.to_account_info().key.as_ref()
+        ],
+        bump,
+    )]
+    user_position: Option<Box<Account<'info, UserPosition>>>,
 
     system_program: Program<'info, System>,
 
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
 
     clock: Sysvar<'info, Clock>,
@@ -79,42 +117,102 @@ impl<'info> IntoBondingCurveLockerCtx<'info> for Swap<'info> {
     ) -> BondingCurveLockerCtx<'info> {
         BondingCurveLockerCtx {
             bonding_curve_bump,
-            mint: self.mint.clone(),
+            mint: self.mint.to_account_info(),
             bonding_curve: self.bonding_curve.clone(),
-            bonding_curve_token_account: self.bonding_curve_token_account.clone(),
-            token_program: self.token_program.clone(),
+            bonding_curve_token_account: self.bonding_curve_token_account.to_account_info(),
+            token_program: self.token_program.to_account_info(),
         }
     }
 }
 impl Swap<'_> {
     pub fn validate(&self, params: &SwapParams) -> Result<()> {
         let SwapParams {
-            base_in: _,
+            base_in,
             exact_in_amount,
             min_out_amount: _,
+            exact_out_amount,
+            max_in_amount: _,
+            allowlist_proof,
         } = params;
         let clock = Clock::get()?;
 
+        validate_mint_extensions(&self.mint.to_account_info())?;
+        if *base_in {
+            require!(self.global.status.can_sell(), ContractError::SellDisabled);
+        } else {
+            require!(self.global.status.can_buy(), ContractError::BuyDisabled);
+        }
         require!(
             self.bonding_curve.is_started(&clock),
             ContractError::CurveNotStarted
         );
-        require!(exact_in_amount > &0, ContractError::MinSwap);
+        require!(
+            !self.bonding_curve.is_past_graduation_deadline(&clock),
+            ContractError::GraduationDeadlinePassed
+        );
+        // only allowlisted wallets can buy during the early-access phase
+        if let Some(allowlist) = &self.bonding_curve.allowlist {
+            if !*base_in && allowlist.is_active(clock.unix_timestamp) {
+                require!(
+                    allowlist_proof
+                        .as_ref()
+                        .is_some_and(|proof| allowlist.verify(self.user.key, proof)),
+                    ContractError::NotAllowlisted
+                );
+            }
+        }
+        if !*base_in && self.bonding_curve.tracks_buys(clock.unix_timestamp) {
+            require!(
+                self.user_position.is_some(),
+                ContractError::MissingUserPosition
+            );
+        }
+        if exact_out_amount == &0 {
+            require!(exact_in_amount > &0, ContractError::MinSwap);
+        } else {
+            require!(exact_in_amount == &0, ContractError::InvalidSwapMode);
+        }
         Ok(())
     }
-    pub fn handler(ctx: Context<Swap>, params: SwapParams) -> Result<()> {
+    pub fn handler(mut ctx: Context<Swap>, params: SwapParams) -> Result<()> {
+        let clock = Clock::get()?;
         let SwapParams {
             base_in,
             exact_in_amount,
             min_out_amount,
+            exact_out_amount,
+            max_in_amount,
+            allowlist_proof,
         } = params;
 
-        msg!(
-            "Swap started. BaseIn: {}, AmountIn: {}, MinOutAmount: {}",
-            base_in,
-            exact_in_amount,
+        let exact_out = exact_out_amount > 0;
+        if exact_out {
+            msg!(
+                "Swap started. BaseIn: {}, AmountOut: {}, MaxInAmount: {}",
+                base_in,
+                exact_out_amount,
+                max_in_amount
+            );
+        } else {
+            msg!(
+                "Swap started. BaseIn: {}, AmountIn: {}, MinOutAmount: {}",
+                base_in,
+                exact_in_amount,
+                min_out_amount
+            );
+        }
+        // an exact-out swap receives exactly exact_out_amount
+        let min_out_amount = if exact_out {
+            exact_out_amount
+        } else {
             min_out_amount
-        );
+        };
+
+        if let Some(position) = ctx.accounts.user_position.as_mut() {
+            position.user = ctx.accounts.user.key();
+            position.mint = ctx.accounts.mint.key();
+            position.bump = ctx.bumps.user_position;
+        }
 
         let global_state = &ctx.accounts.global;
         let locker: &mut BondingCurveLockerCtx = &mut ctx
@@ -125,55 +223,156 @@ impl Swap<'_> {
         let sol_amount: u64;
         let token_amount: u64;
         let fee_lamports: u64;
+        // SOL for exact-in buys, tokens for exact-out buys, only buys are partially filled
+        let requested_amount = if exact_out {
+            exact_out_amount
+        } else {
+            exact_in_amount
+        };
+        let filled_amount: u64;
+        let fee_bps = ctx
+            .accounts
+            .bonding_curve
+            .get_fee_bps(global_state.trade_fee_bps, clock.unix_timestamp);
+        msg!("Fee rate: {} bps", fee_bps);
 
         if base_in {
             // Sell tokens
+            let sell_result = if exact_out {
+                // the seller receives exact_out_amount once the fee is taken out
+                let gross_sol_amount = calculate_amount_before_fee(fee_bps, exact_out_amount)
+                    .ok_or(ContractError::SellFailed)?;
+                ctx.accounts
+                    .bonding_curve
+                    .apply_sell_exact_out(gross_sol_amount)
+            } else {
+                ctx.accounts.bonding_curve.apply_sell(exact_in_amount)
+            }
+            .ok_or(ContractError::SellFailed)?;
+
             require!(
-                ctx.accounts.user_token_account.amount >= exact_in_amount,
+                ctx.accounts.user_token_account.amount >= sell_result.token_amount,
                 ContractError::InsufficientUserTokens,
             );
-
-            let sell_result = ctx
-                .accounts
-                .bonding_curve
-                .apply_sell(exact_in_amount)
-                .ok_or(ContractError::SellFailed)?;
+            if exact_out {
+                require!(
+                    sell_result.token_amount <= max_in_amount,
+                    ContractError::SlippageExceeded,
+                );
+            }
 
             sol_amount = sell_result.sol_amount;
             token_amount = sell_result.token_amount;
-            fee_lamports = global_state.calculate_fee(sol_amount);
+            filled_amount = requested_amount;
+            fee_lamports = calculate_fee(fee_bps, sol_amount);
 
             msg!("SellResult: {:#?}", sell_result);
             msg!("Fee: {} SOL", fee_lamports.div(10u64.pow(9))); // lamports to SOL
             Swap::complete_sell(&ctx, sell_result.clone(), min_out_amount, fee_lamports)?;
         } else {
-            // Buy tokens
-            let buy_result = ctx
-                .accounts
-                .bonding_curve
-                .apply_buy(exact_in_amount)
-                .ok_or(ContractError::BuyFailed)?;
+            // Buy tokens, the buy reaching the launch threshold is only filled up to it
+            // and the SOL and fee for the rest are never taken from the user
+            let buy_result = if exact_out {
+                ctx.accounts
+                    .bonding_curve
+                    .apply_buy_exact_out_capped(exact_out_amount)
+            } else {
+                ctx.accounts.bonding_curve.apply_buy_capped(exact_in_amount)
+            }
+            .ok_or(ContractError::BuyFailed)?;
 
             sol_amount = buy_result.sol_amount;
             token_amount = buy_result.token_amount;
-            fee_lamports = global_state.calculate_fee(exact_in_amount);
+            fee_lamports = calculate_fee(fee_bps, sol_amount);
             msg!("Fee: {} lamports", fee_lamports);
+            filled_amount = if exact_out { token_amount } else { sol_amount };
+            if filled_amount < requested_amount {
+                msg!(
+                    "Partial fill: requested: {}, filled: {}",
+                    requested_amount,
+                    filled_amount
+                );
+            }
+            if exact_out {
+                // a partial fill pays at most the requested price per token
+                require!(
+                    ((sol_amount + fee_lamports) as u128) * (exact_out_amount as u128)
+                        <= (max_in_amount as u128) * (token_amount as u128),
+                    ContractError::SlippageExceeded,
+                );
+            }
+            // the minimum out scales with the fill
+            let min_out_amount = if exact_out {
+                token_amount
+            } else {
+                ((min_out_amount as u128) * (sol_amount as u128) / (exact_in_amount as u128)) as u64
+            };
 
             msg!("BuyResult: {:#?}", buy_result);
 
+            // buys are capped per transaction and per wallet for a while after the start
+            let launch_protection = &ctx.accounts.bonding_curve.launch_protection;
+            if launch_protection
+                .is_active(ctx.accounts.bonding_curve.start_time, clock.unix_timestamp)
+            {
+                let position = ctx
+                    .accounts
+                    .user_position
+                    .as_mut()
+                    .ok_or(ContractError::MissingUserPosition)?;
+                require!(
+                    launch_protection.allows_buy(position.protected_buy_lamports, sol_amount),
+                    ContractError::LaunchProtectionLimitExceeded
+                );
+                position.protected_buy_lamports += sol_amount;
+            }
+
+            // the proof was verified in validate, what is left is the allowance
+            if let Some(allowlist) = &ctx.accounts.bonding_curve.allowlist {
+                if allowlist.is_active(clock.unix_timestamp) {
+                    let allowance = allowlist_proof
+                        .as_ref()
+                        .ok_or(ContractError::NotAllowlisted)?
+                        .allowance;
+                    let position = ctx
+                        .accounts
+                        .user_position
+                        .as_mut()
+                        .ok_or(ContractError::MissingUserPosition)?;
+                    require!(
+                        position
+                            .allowlist_buy_lamports
+                            .checked_add(sol_amount)
+                            .is_some_and(|total| total <= allowance),
+                        ContractError::AllowlistAllowanceExceeded
+                    );
+                    position.allowlist_buy_lamports += sol_amount;
+                }
+            }
+
             Swap::complete_buy(&ctx, buy_result.clone(), min_out_amount, fee_lamports)?;
 
-            let bonding_curve_total_lamports = ctx.accounts.bonding_curve.get_lamports();
-            let min_balance = Rent::get()?.minimum_balance(8 + BondingCurve::INIT_SPACE as usize);
-            let bonding_curve_pool_lamports = bonding_curve_total_lamports - min_balance;
+            // only tracked reserves count, lamports sent straight to the curve can't complete it
+            // presale SOL counts toward the launch threshold
+            let raised_lamports = ctx.accounts.bonding_curve.real_sol_reserves
+                + ctx.accounts.bonding_curve.presale_sol_raised;
 
             // can be completed only after a buy
-            if bonding_curve_pool_lamports >= ctx.accounts.bonding_curve.sol_launch_threshold {
+            if raised_lamports >= ctx.accounts.bonding_curve.sol_launch_threshold {
+                require!(
+                    global_state.status.can_launch(),
+                    ContractError::LaunchDisabled
+                );
                 // has been completed
-                ctx.accounts.bonding_curve.complete = true;
+                ctx.accounts.bonding_curve.mark_complete(&clock);
+                // leave the account thawed so graduation can move the reserves
+                locker.unlock_ata()?;
                 locker.revoke_freeze_authority()?;
+                // unsold tokens are settled in the same transaction
+                Swap::settle_unsold_tokens(&mut ctx, &clock)?;
             }
         }
+        ctx.accounts.bonding_curve.traded = true;
 
         BondingCurve::invariant(
             &mut ctx
@@ -186,9 +385,12 @@ impl Swap<'_> {
             sol_amount: sol_amount,
             token_amount: token_amount,
             fee_lamports: fee_lamports,
+            fee_bps,
+            requested_amount,
+            filled_amount,
             is_buy: !base_in,
             user: *ctx.accounts.user.to_account_info().key,
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: clock.unix_timestamp,
             virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
             virtual_token_reserves: bonding_curve.virtual_token_reserves,
             real_sol_reserves: bonding_curve.real_sol_reserves,
@@ -202,7 +404,7 @@ impl Swap<'_> {
                 virtual_token_reserves: bonding_curve.virtual_token_reserves,
                 real_sol_reserves: bonding_curve.real_sol_reserves,
                 real_token_reserves: bonding_curve.real_token_reserves,
-                timestamp: Clock::get()?.unix_timestamp,
+                timestamp: clock.unix_timestamp,
             });
         }
 
@@ -211,6 +413,68 @@ impl Swap<'_> {
         Ok(())
     }
 
+    // burns the unsold tokens, returns them to the platform vault or leaves them for graduation
+    pub fn settle_unsold_tokens(ctx: &mut Context<Swap>, clock: &Clock) -> Result<()> {
+        let policy = ctx.accounts.bonding_curve.completion_policy;
+        let unsold_token_amount = ctx
+            .accounts
+            .bonding_curve
+            .apply_completion_policy()
+            .ok_or(ContractError::BuyFailed)?;
+        msg!(
+            "Swap::settle_unsold_tokens: policy: {:?}, unsold_token_amount: {}",
+            policy,
+            unsold_token_amount
+        );
+
+        let mint_k = ctx.accounts.mint.key();
+        let signer = BondingCurve::get_signer(&ctx.bumps.bonding_curve, &mint_k);
+        let signer_seeds = &[&signer[..]];
+        match policy {
+            CompletionPolicy::Burn if unsold_token_amount > 0 => {
+                token_interface::burn(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Burn {
+                            mint: ctx.accounts.mint.to_account_info(),
+                            from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                            authority: ctx.accounts.bonding_curve.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    unsold_token_amount,
+                )?;
+            }
+            CompletionPolicy::PlatformVault if unsold_token_amount > 0 => {
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                            mint: ctx.accounts.mint.to_account_info(),
+                            to: ctx.accounts.platform_vault_token_account.to_account_info(),
+                            authority: ctx.accounts.bonding_curve.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    unsold_token_amount,
+                    ctx.accounts.mint.decimals,
+                )?;
+                ctx.accounts.platform_vault.unsold_token_amount += unsold_token_amount;
+            }
+            _ => {}
+        }
+
+        emit_cpi!(UnsoldTokensEvent {
+            mint: mint_k,
+            policy,
+            unsold_token_amount,
+            graduation_token_amount: ctx.accounts.bonding_curve.real_token_reserves,
+            timestamp: clock.unix_timestamp,
+        });
+        Ok(())
+    }
+
     pub fn complete_buy(
         ctx: &Context<Swap>,
         buy_result: BuyResult,
@@ -235,8 +499,9 @@ impl Swap<'_> {
         );
 
         // Transfer tokens to user
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.user_token_account.to_account_info(),
             authority: bonding_curve.to_account_info(),
         };
@@ -246,13 +511,14 @@ impl Swap<'_> {
             ctx.accounts.mint.to_account_info().key,
         );
         let signer_seeds = &[&signer[..]];
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 cpi_accounts,
                 signer_seeds,
             ),
             buy_result.token_amount,
+            ctx.accounts.mint.decimals,
         )?;
         let locker = &mut ctx
             .accounts
@@ -315,15 +581,17 @@ impl Swap<'_> {
         );
 
         // Transfer tokens to bonding curve
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.bonding_curve_token_account.to_account_info(),
             authority: ctx.accounts.user.to_account_info(),
         };
 
-        token::transfer(
+        token_interface::transfer_checked(
             CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
             sell_result.token_amount,
+            ctx.accounts.mint.decimals,
         )?;
         let locker = &mut ctx
             .accounts
@@ -1,12 +1,25 @@
 use anchor_lang::prelude::*;
+pub mod amm;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod mint;
 pub mod state;
 pub mod util;
+use instructions::cancel_launch::*;
+use instructions::claim_brandkit::*;
 use instructions::claim_creator_vesting::*;
+use instructions::claim_presale::*;
+use instructions::contribute_presale::*;
+use instructions::fail_curve::*;
+use instructions::graduate::*;
+use instructions::quote::*;
+use instructions::refund::*;
+use instructions::reschedule_launch::*;
 use instructions::{
-    create_bonding_curve::*, initialize::*, set_params::*, swap::*, withdraw_fees::*,
+    accept_authority::*, cancel_authority_transfer::*, cancel_settings::*,
+    claim_platform_tokens::*, create_bonding_curve::*, execute_settings::*, initialize::*,
+    release_cex_tokens::*, schedule_settings::*, set_params::*, skim::*, swap::*, withdraw_fees::*,
 };
 use state::bonding_curve::CreateBondingCurveParams;
 use state::global::*;
@@ -17,13 +30,47 @@ pub mod billy_bonding_curve {
 
     use super::*;
 
+    #[access_control(ctx.accounts.validate(&params))]
     pub fn initialize(ctx: Context<Initialize>, params: GlobalSettingsInput) -> Result<()> {
         Initialize::handler(ctx, params)
     }
+    #[access_control(ctx.accounts.validate(&params))]
     pub fn set_params(ctx: Context<SetParams>, params: GlobalSettingsInput) -> Result<()> {
         SetParams::handler(ctx, params)
     }
 
+    #[access_control(ctx.accounts.validate(&params, effective_time))]
+    pub fn schedule_settings(
+        ctx: Context<ScheduleSettings>,
+        params: GlobalSettingsInput,
+        effective_time: i64,
+    ) -> Result<()> {
+        ScheduleSettings::handler(ctx, params, effective_time)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_settings(ctx: Context<ExecuteSettings>) -> Result<()> {
+        ExecuteSettings::handler(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_settings(ctx: Context<CancelSettings>) -> Result<()> {
+        CancelSettings::handler(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(role))]
+    pub fn accept_authority(ctx: Context<AcceptAuthority>, role: AuthorityRole) -> Result<()> {
+        AcceptAuthority::handler(ctx, role)
+    }
+
+    #[access_control(ctx.accounts.validate(role))]
+    pub fn cancel_authority_transfer(
+        ctx: Context<CancelAuthorityTransfer>,
+        role: AuthorityRole,
+    ) -> Result<()> {
+        CancelAuthorityTransfer::handler(ctx, role)
+    }
+
     #[access_control(ctx.accounts.validate(&params))]
     pub fn create_bonding_curve(
         ctx: Context<CreateBondingCurve>,
@@ -37,12 +84,71 @@ pub mod billy_bonding_curve {
         Swap::handler(ctx, params)
     }
 
+    #[access_control(ctx.accounts.validate(&params))]
+    pub fn quote(ctx: Context<Quote>, params: SwapParams) -> Result<SwapQuote> {
+        Quote::handler(ctx, params)
+    }
+
     #[access_control(ctx.accounts.validate())]
     pub fn claim_creator_vesting(ctx: Context<ClaimCreatorVesting>) -> Result<()> {
         ClaimCreatorVesting::handler(ctx)
     }
 
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_brandkit(ctx: Context<ClaimBrandkit>) -> Result<()> {
+        ClaimBrandkit::handler(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(sol_amount))]
+    pub fn contribute_presale(ctx: Context<ContributePresale>, sol_amount: u64) -> Result<()> {
+        ContributePresale::handler(ctx, sol_amount)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_presale(ctx: Context<ClaimPresale>) -> Result<()> {
+        ClaimPresale::handler(ctx)
+    }
+
     pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
         WithdrawFees::handler(ctx)
     }
+
+    pub fn skim(ctx: Context<Skim>) -> Result<()> {
+        Skim::handler(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_platform_tokens(ctx: Context<ClaimPlatformTokens>) -> Result<()> {
+        ClaimPlatformTokens::handler(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(amount))]
+    pub fn release_cex_tokens(ctx: Context<ReleaseCexTokens>, amount: u64) -> Result<()> {
+        ReleaseCexTokens::handler(ctx, amount)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn graduate<'info>(ctx: Context<'_, '_, '_, 'info, Graduate<'info>>) -> Result<()> {
+        Graduate::handler(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn fail_curve(ctx: Context<FailCurve>) -> Result<()> {
+        FailCurve::handler(ctx)
+    }
+
+    #[access_control(ctx.accounts.validate(token_amount))]
+    pub fn refund(ctx: Context<Refund>, token_amount: u64) -> Result<()> {
+        Refund::handler(ctx, token_amount)
+    }
+
+    #[access_control(ctx.accounts.validate(start_time))]
+    pub fn reschedule_launch(ctx: Context<RescheduleLaunch>, start_time: i64) -> Result<()> {
+        RescheduleLaunch::handler(ctx, start_time)
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+        CancelLaunch::handler(ctx)
+    }
 }
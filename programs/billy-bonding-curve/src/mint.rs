@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        metadata_pointer::{self, MetadataPointer},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+};
+use anchor_spl::token_interface::{initialize_mint2, InitializeMint2};
+use spl_token_metadata_interface::state::TokenMetadata;
+
+use crate::errors::ContractError;
+
+// Launched mints are either classic SPL mints with Metaplex metadata, or
+// Token-2022 mints that keep their metadata on the mint itself through the
+// metadata-pointer and token-metadata extensions.
+
+// the only extensions a launched Token-2022 mint may carry
+pub const ALLOWED_MINT_EXTENSIONS: [ExtensionType; 2] =
+    [ExtensionType::MetadataPointer, ExtensionType::TokenMetadata];
+
+pub fn is_token_2022(token_program: &Pubkey) -> bool {
+    *token_program == spl_token_2022::ID
+}
+
+pub struct CreateMint<'info> {
+    pub payer: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    // mint and freeze authority
+    pub authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> CreateMint<'info> {
+    // allocates and initializes the mint, Token-2022 mints get a metadata pointer to themselves
+    // and are funded upfront for the token-metadata written by `initialize_token_metadata`
+    pub fn create(&self, decimals: u8, name: &str, symbol: &str, uri: &str) -> Result<()> {
+        let token_2022 = is_token_2022(self.token_program.key);
+        let (space, metadata_space) = if token_2022 {
+            let space =
+                ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+                    ExtensionType::MetadataPointer,
+                ])?;
+            let metadata = TokenMetadata {
+                name: name.to_string(),
+                symbol: symbol.to_string(),
+                uri: uri.to_string(),
+                ..Default::default()
+            };
+            (space, metadata.tlv_size_of()?)
+        } else {
+            (spl_token_2022::state::Mint::LEN, 0)
+        };
+        let lamports = Rent::get()?.minimum_balance(space + metadata_space);
+
+        create_account(
+            CpiContext::new(
+                self.system_program.clone(),
+                CreateAccount {
+                    from: self.payer.clone(),
+                    to: self.mint.clone(),
+                },
+            ),
+            lamports,
+            space as u64,
+            self.token_program.key,
+        )?;
+
+        if token_2022 {
+            invoke(
+                &metadata_pointer::instruction::initialize(
+                    self.token_program.key,
+                    self.mint.key,
+                    Some(*self.authority.key),
+                    Some(*self.mint.key),
+                )?,
+                &[self.mint.clone(), self.token_program.clone()],
+            )?;
+        }
+
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.clone(),
+                InitializeMint2 {
+                    mint: self.mint.clone(),
+                },
+            ),
+            decimals,
+            self.authority.key,
+            Some(self.authority.key),
+        )?;
+        msg!("CreateMint::create: done");
+        Ok(())
+    }
+
+    // writes name, symbol and uri into the Token-2022 mint, the authority must still hold the mint authority
+    pub fn initialize_token_metadata(
+        &self,
+        authority_signer_seeds: &[&[&[u8]]],
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::initialize(
+                self.token_program.key,
+                self.mint.key,
+                self.authority.key,
+                self.mint.key,
+                self.authority.key,
+                name,
+                symbol,
+                uri,
+            ),
+            &[
+                self.mint.clone(),
+                self.authority.clone(),
+                self.mint.clone(),
+                self.authority.clone(),
+                self.token_program.clone(),
+            ],
+            authority_signer_seeds,
+        )?;
+        msg!("CreateMint::initialize_token_metadata: done");
+        Ok(())
+    }
+}
+
+// rejects Token-2022 mints carrying anything but the metadata extensions,
+// and metadata pointers that don't point at the mint itself
+pub fn validate_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if !is_token_2022(mint.owner) {
+        return Ok(());
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let extensions = state.get_extension_types()?;
+    require!(
+        extensions
+            .iter()
+            .all(|extension| ALLOWED_MINT_EXTENSIONS.contains(extension)),
+        ContractError::InvalidMintExtensions
+    );
+    let metadata_pointer = state
+        .get_extension::<MetadataPointer>()
+        .map_err(|_| ContractError::InvalidMintExtensions)?;
+    require!(
+        Option::<Pubkey>::from(metadata_pointer.metadata_address) == Some(mint.key()),
+        ContractError::InvalidMintExtensions
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        extension::{transfer_fee::TransferFeeConfig, StateWithExtensionsMut},
+        state::Mint,
+    };
+
+    fn mint_data(extensions: &[ExtensionType], metadata_address: Pubkey) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+        let mut data = vec![0u8; len];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        for extension in extensions {
+            match extension {
+                ExtensionType::MetadataPointer => {
+                    let pointer = state.init_extension::<MetadataPointer>(true).unwrap();
+                    pointer.metadata_address = Some(metadata_address).try_into().unwrap();
+                }
+                ExtensionType::TransferFeeConfig => {
+                    state.init_extension::<TransferFeeConfig>(true).unwrap();
+                }
+                _ => unimplemented!(),
+            }
+        }
+        state.base = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    fn validate(owner: &Pubkey, key: &Pubkey, data: &mut [u8]) -> Result<()> {
+        let mut lamports = 0;
+        let info = AccountInfo::new(key, false, false, &mut lamports, data, owner, false, 0);
+        validate_mint_extensions(&info)
+    }
+
+    #[test]
+    fn test_validate_mint_extensions() {
+        let mint = Pubkey::new_unique();
+
+        // metadata pointing at the mint itself
+        let mut data = mint_data(&[ExtensionType::MetadataPointer], mint);
+        assert!(validate(&spl_token_2022::ID, &mint, &mut data).is_ok());
+
+        // metadata living elsewhere
+        let mut data = mint_data(&[ExtensionType::MetadataPointer], Pubkey::new_unique());
+        assert_eq!(
+            validate(&spl_token_2022::ID, &mint, &mut data),
+            Err(ContractError::InvalidMintExtensions.into())
+        );
+
+        // no metadata pointer at all
+        let mut data = mint_data(&[], mint);
+        assert_eq!(
+            validate(&spl_token_2022::ID, &mint, &mut data),
+            Err(ContractError::InvalidMintExtensions.into())
+        );
+
+        // extensions other than the metadata ones
+        let mut data = mint_data(
+            &[
+                ExtensionType::MetadataPointer,
+                ExtensionType::TransferFeeConfig,
+            ],
+            mint,
+        );
+        assert_eq!(
+            validate(&spl_token_2022::ID, &mint, &mut data),
+            Err(ContractError::InvalidMintExtensions.into())
+        );
+
+        // SPL Token mints have no extensions to check
+        let mut data = vec![0u8; Mint::LEN];
+        assert!(validate(&anchor_spl::token::ID, &mint, &mut data).is_ok());
+    }
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+// leaves and inner nodes are hashed with different prefixes so a node can't pass as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+// early-access phase where only wallets in the merkle tree may buy, up to their allowance
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct Allowlist {
+    pub merkle_root: [u8; 32],
+    pub end_time: i64,
+}
+
+// membership proof for a wallet, supplied with the swap
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AllowlistProof {
+    // lamports the wallet may spend on buys during the phase
+    pub allowance: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl Allowlist {
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.end_time
+    }
+
+    pub fn get_leaf(user: &Pubkey, allowance: u64) -> [u8; 32] {
+        hashv(&[LEAF_PREFIX, user.as_ref(), &allowance.to_le_bytes()]).to_bytes()
+    }
+
+    // pairs are hashed in sorted order, so proofs don't need to encode left/right
+    pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[NODE_PREFIX, first, second]).to_bytes()
+    }
+
+    pub fn verify(&self, user: &Pubkey, proof: &AllowlistProof) -> bool {
+        let root = proof
+            .proof
+            .iter()
+            .fold(Self::get_leaf(user, proof.allowance), |node, sibling| {
+                Self::hash_pair(&node, sibling)
+            });
+        root == self.merkle_root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // builds the tree bottom up, returns the root and the proof for every leaf
+    fn build_tree(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let mut proofs = vec![vec![]; leaves.len()];
+        let mut positions: Vec<usize> = (0..leaves.len()).collect();
+        let mut level = leaves.to_vec();
+        while level.len() > 1 {
+            let mut next = vec![];
+            for pair in level.chunks(2) {
+                next.push(match pair {
+                    [a, b] => Allowlist::hash_pair(a, b),
+                    // odd nodes are carried up as is
+                    [a] => *a,
+                    _ => unreachable!(),
+                });
+            }
+            for (leaf, position) in positions.iter_mut().enumerate() {
+                let sibling = *position ^ 1;
+                if sibling < level.len() {
+                    proofs[leaf].push(level[sibling]);
+                }
+                *position /= 2;
+            }
+            level = next;
+        }
+        (level[0], proofs)
+    }
+
+    #[test]
+    fn test_verify() {
+        let users: Vec<(Pubkey, u64)> = (0..5)
+            .map(|i| (Pubkey::new_unique(), (i + 1) * 1_000_000_000))
+            .collect();
+        let leaves: Vec<[u8; 32]> = users
+            .iter()
+            .map(|(user, allowance)| Allowlist::get_leaf(user, *allowance))
+            .collect();
+        let (merkle_root, proofs) = build_tree(&leaves);
+        let allowlist = Allowlist {
+            merkle_root,
+            end_time: 100,
+        };
+
+        for ((user, allowance), proof) in users.iter().zip(proofs.iter()) {
+            let proof = AllowlistProof {
+                allowance: *allowance,
+                proof: proof.clone(),
+            };
+            assert!(allowlist.verify(user, &proof));
+
+            // a different allowance doesn't verify
+            assert!(!allowlist.verify(
+                user,
+                &AllowlistProof {
+                    allowance: allowance + 1,
+                    ..proof.clone()
+                }
+            ));
+            // nor does someone else's proof
+            assert!(!allowlist.verify(&Pubkey::new_unique(), &proof));
+        }
+
+        // a tampered proof doesn't verify
+        let (user, allowance) = users[0];
+        let mut proof = proofs[0].clone();
+        proof[0][0] ^= 1;
+        assert!(!allowlist.verify(&user, &AllowlistProof { allowance, proof }));
+
+        // an inner node can't be passed off as a leaf
+        let inner = Allowlist::hash_pair(&leaves[0], &leaves[1]);
+        assert_ne!(inner, Allowlist::get_leaf(&user, allowance));
+
+        // a single wallet list has an empty proof
+        let single = Allowlist {
+            merkle_root: leaves[0],
+            end_time: 100,
+        };
+        assert!(single.verify(
+            &user,
+            &AllowlistProof {
+                allowance,
+                proof: vec![]
+            }
+        ));
+    }
+
+    #[test]
+    fn test_is_active() {
+        let allowlist = Allowlist {
+            merkle_root: [0; 32],
+            end_time: 100,
+        };
+        assert!(allowlist.is_active(99));
+        assert!(!allowlist.is_active(100));
+    }
+}
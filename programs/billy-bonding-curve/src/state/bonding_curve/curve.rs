@@ -1,9 +1,11 @@
 use crate::errors::ContractError;
 use crate::state::allocation::AllocationData;
 use crate::state::bonding_curve::locker::BondingCurveLockerCtx;
+use crate::state::bonding_curve::pricing::{CurveKind, PRICE_SCALE};
 use crate::state::bonding_curve::*;
 use crate::util::{bps_mul, bps_mul_raw};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 use std::fmt::{self};
 use structs::BondingCurve;
 
@@ -60,6 +62,7 @@ impl BondingCurve {
         let sol_launch_threshold = params.sol_launch_threshold;
         let creator = creator;
         let complete = false;
+        let graduated = false;
 
         let vesting_terms = params.vesting_terms.clone().unwrap_or_default();
 
@@ -86,8 +89,21 @@ impl BondingCurve {
             platform_supply,
 
             sol_launch_threshold,
+            presale_sol_raised: 0,
             start_time,
             complete,
+            graduated,
+            traded: false,
+            completion_time: 0,
+            graduation_deadline: params.graduation_deadline,
+            failed: false,
+            refund_token_supply: 0,
+            launch_fee_lamports: 0,
+            launch_protection: params.launch_protection.clone().unwrap_or_default(),
+            fee_schedule: params.fee_schedule.clone(),
+            allowlist: params.allowlist.clone(),
+            curve_kind: params.curve_kind.clone().unwrap_or_default(),
+            completion_policy: params.completion_policy.unwrap_or_default(),
             allocation,
 
             bump,
@@ -96,71 +112,91 @@ impl BondingCurve {
         self
     }
 
-    pub fn get_max_attainable_sol(&self) -> Option<u64> {
-        // Calculate the number of tokens available for purchase
-        let tokens_available = self.real_token_reserves;
-
-        // If no tokens are available, return the current real SOL reserves
-        if tokens_available == 0 {
-            return Some(self.real_sol_reserves);
-        }
+    pub fn mark_complete(&mut self, clock: &Clock) {
+        self.complete = true;
+        self.completion_time = clock.unix_timestamp;
+    }
 
-        // Calculate the product of reserves (constant in the bonding curve equation)
-        let product_of_reserves =
-            (self.virtual_sol_reserves as u128).checked_mul(self.virtual_token_reserves as u128)?;
+    // swaps stop once the deadline passes without the curve completing
+    pub fn is_past_graduation_deadline(&self, clock: &Clock) -> bool {
+        !self.complete
+            && self
+                .graduation_deadline
+                .is_some_and(|deadline| clock.unix_timestamp >= deadline)
+    }
 
-        // Calculate the new virtual token reserves after all tokens are bought
-        let new_virtual_token_reserves = self
-            .virtual_token_reserves
-            .checked_sub(tokens_available as u128)?;
+    // nobody has traded on the launch or contributed to its presale yet
+    pub fn is_cancellable(&self) -> bool {
+        !self.traded && self.presale_sol_raised == 0
+    }
 
-        // Calculate the new virtual SOL reserves using the constant product formula
-        let new_virtual_sol_reserves = product_of_reserves
-            .checked_div(new_virtual_token_reserves)?
-            .checked_add(1)?;
+    // buys are recorded per wallet only while launch protection or the allowlist phase caps them
+    pub fn tracks_buys(&self, now: i64) -> bool {
+        self.launch_protection.is_active(self.start_time, now)
+            || self
+                .allowlist
+                .as_ref()
+                .is_some_and(|allowlist| allowlist.is_active(now))
+    }
 
-        // Calculate the difference in virtual SOL reserves
-        let sol_increase =
-            new_virtual_sol_reserves.checked_sub(self.virtual_sol_reserves as u128)?;
+    // allocations stay in their vaults while the curve may still fail,
+    // so a refund only ever splits the reserves over the buyer tokens
+    pub fn can_release_allocations(&self) -> bool {
+        self.complete || self.graduation_deadline.is_none()
+    }
 
-        // Add the increase to the current real SOL reserves
-        let max_attainable_sol = (self.real_sol_reserves as u128).checked_add(sol_increase)?;
+    // tokens bought through the curve or the presale, the only ones out of the vaults when a curve fails
+    pub fn get_buyer_token_supply(&self) -> Option<u64> {
+        let sold = self.bonding_supply.checked_sub(self.real_token_reserves)?;
+        let presale = if self.presale_sol_raised > 0 {
+            self.presale_supply
+        } else {
+            0
+        };
+        sold.checked_add(presale)
+    }
 
-        // Convert to u64 and return
-        max_attainable_sol.try_into().ok()
+    // pro-rata share of the remaining SOL reserves for `token_amount` out of the buyer tokens not refunded yet
+    pub fn get_refund_amount(&self, token_amount: u64) -> Option<u64> {
+        if token_amount > self.refund_token_supply {
+            return None;
+        }
+        (self.real_sol_reserves as u128)
+            .checked_mul(token_amount as u128)?
+            .checked_div(self.refund_token_supply as u128)?
+            .try_into()
+            .ok()
+    }
 
-        // TODO CALCULATE PRESALE SOL VALUE
+    pub fn apply_refund(&mut self, token_amount: u64) -> Option<u64> {
+        let sol_amount = self.get_refund_amount(token_amount)?;
+        self.real_sol_reserves = self.real_sol_reserves.checked_sub(sol_amount)?;
+        self.refund_token_supply -= token_amount;
+        Some(sol_amount)
     }
-    pub fn get_buy_price(&self, tokens: u64) -> Option<u64> {
-        msg!("get_buy_price: tokens: {}", tokens);
-        if tokens == 0 || tokens > self.virtual_token_reserves as u64 {
-            return None;
+
+    // the time a vesting schedule is measured from, None until a completion anchor is reached
+    pub fn get_vesting_anchor_time(&self, anchor: &VestingAnchor) -> Option<i64> {
+        match anchor {
+            VestingAnchor::StartTime => Some(self.start_time),
+            VestingAnchor::CompletionTime => self.complete.then_some(self.completion_time),
+            VestingAnchor::Timestamp(timestamp) => Some(*timestamp),
         }
+    }
 
-        let product_of_reserves =
-            (self.virtual_sol_reserves as u128).checked_mul(self.virtual_token_reserves as u128)?;
-        msg!(
-            "get_buy_price: product_of_reserves: {}",
-            product_of_reserves
-        );
-        let new_virtual_token_reserves =
-            (self.virtual_token_reserves as u128).checked_sub(tokens as u128)?;
-        msg!(
-            "get_buy_price: new_virtual_token_reserves: {}",
-            new_virtual_token_reserves
-        );
-        let new_virtual_sol_reserves = product_of_reserves
-            .checked_div(new_virtual_token_reserves)?
-            .checked_add(1)?;
-        msg!(
-            "get_buy_price: new_virtual_sol_reserves: {}",
-            new_virtual_sol_reserves
-        );
-        let amount_needed =
-            new_virtual_sol_reserves.checked_sub(self.virtual_sol_reserves as u128)?;
-        msg!("get_buy_price: amount_needed: {}", amount_needed);
+    // amount of `total` vested under `terms` at `now`, nothing before the anchor is reached
+    pub fn get_vested_amount(&self, terms: &VestingTerms, total: u64, now: i64) -> Option<u64> {
+        match self.get_vesting_anchor_time(&terms.anchor) {
+            Some(anchor_time) => terms.get_vested_amount(total, anchor_time, now),
+            None => Some(0),
+        }
+    }
 
-        amount_needed.try_into().ok()
+    pub fn get_max_attainable_sol(&self) -> Option<u64> {
+        self.curve_kind.pricing().max_attainable_sol(self)
+    }
+    pub fn get_buy_price(&self, tokens: u64) -> Option<u64> {
+        self.curve_kind.pricing().cost_for_tokens(self, tokens)
     }
 
     pub fn apply_buy(&mut self, sol_amount: u64) -> Option<BuyResult> {
@@ -168,14 +204,61 @@ impl BondingCurve {
 
         let final_token_amount = self.get_tokens_for_buy_sol(sol_amount)?;
         msg!("ApplyBuy: final_token_amount: {}", final_token_amount);
+        self.commit_buy(final_token_amount, sol_amount)
+    }
+
+    pub fn apply_buy_exact_out(&mut self, token_amount: u64) -> Option<BuyResult> {
+        msg!("ApplyBuyExactOut: token_amount: {}", token_amount);
+        if token_amount > self.real_token_reserves {
+            return None;
+        }
+
+        let sol_amount = self.get_buy_price(token_amount)?;
+        msg!("ApplyBuyExactOut: sol_amount: {}", sol_amount);
+        self.commit_buy(token_amount, sol_amount)
+    }
+
+    // SOL still needed to reach the launch threshold, presale included
+    pub fn get_sol_to_threshold(&self) -> u64 {
+        self.sol_launch_threshold.saturating_sub(
+            self.real_sol_reserves
+                .saturating_add(self.presale_sol_raised),
+        )
+    }
+
+    // exact-in buy filled up to the launch threshold, paying only for the tokens that are left
+    pub fn apply_buy_capped(&mut self, sol_amount: u64) -> Option<BuyResult> {
+        let sol_amount = sol_amount.min(self.get_sol_to_threshold());
+        msg!("ApplyBuyCapped: sol_amount: {}", sol_amount);
+        let token_amount = self.get_tokens_for_buy_sol(sol_amount)?;
+        let sol_amount = if token_amount > 0 && token_amount == self.real_token_reserves {
+            self.get_buy_price(token_amount)?.min(sol_amount)
+        } else {
+            sol_amount
+        };
+        msg!("ApplyBuyCapped: token_amount: {}", token_amount);
+        self.commit_buy(token_amount, sol_amount)
+    }
+
+    // exact-out buy filled up to the launch threshold or the remaining tokens
+    pub fn apply_buy_exact_out_capped(&mut self, token_amount: u64) -> Option<BuyResult> {
+        let token_amount = token_amount.min(self.real_token_reserves);
+        let sol_amount = self.get_buy_price(token_amount)?;
+        if sol_amount > self.get_sol_to_threshold() {
+            return self.apply_buy_capped(sol_amount);
+        }
+        self.commit_buy(token_amount, sol_amount)
+    }
+
+    fn commit_buy(&mut self, token_amount: u64, sol_amount: u64) -> Option<BuyResult> {
         let new_virtual_token_reserves =
-            (self.virtual_token_reserves as u128).checked_sub(final_token_amount as u128)?;
+            (self.virtual_token_reserves as u128).checked_sub(token_amount as u128)?;
         msg!(
             "ApplyBuy: new_virtual_token_reserves: {}",
             new_virtual_token_reserves
         );
         let new_real_token_reserves =
-            (self.real_token_reserves as u128).checked_sub(final_token_amount as u128)?;
+            (self.real_token_reserves as u128).checked_sub(token_amount as u128)?;
         msg!(
             "ApplyBuy: new_real_token_reserves: {}",
             new_real_token_reserves
@@ -212,42 +295,36 @@ impl BondingCurve {
         );
         self.msg();
         Some(BuyResult {
-            token_amount: final_token_amount,
+            token_amount,
             sol_amount,
         })
     }
 
     pub fn get_sell_price(&self, tokens: u64) -> Option<u64> {
-        msg!("get_sell_price: tokens: {}", tokens);
-        if tokens == 0 || tokens > self.virtual_token_reserves as u64 {
-            return None;
-        }
+        self.curve_kind.pricing().sell_out(self, tokens)
+    }
 
-        let scaling_factor = self.initial_virtual_token_reserves as u128;
-        msg!("get_sell_price: scaling_factor: {}", scaling_factor);
+    pub fn apply_sell(&mut self, token_amount: u64) -> Option<SellResult> {
+        msg!("apply_sell: token_amount: {}", token_amount);
+        let sol_amount = self.get_sell_price(token_amount)?;
+        msg!("apply_sell: sol_amount: {}", sol_amount);
 
-        let scaled_tokens = (tokens as u128).checked_mul(scaling_factor)?;
-        msg!("get_sell_price: scaled_tokens: {}", scaled_tokens);
-        let token_sell_proportion =
-            scaled_tokens.checked_div(self.virtual_token_reserves as u128)?;
-        msg!(
-            "get_sell_price: token_sell_proportion: {}",
-            token_sell_proportion
-        );
-        let sol_received = ((self.virtual_sol_reserves as u128)
-            .checked_mul(token_sell_proportion)?)
-        .checked_div(scaling_factor)?;
-        msg!("get_sell_price: sol_received: {}", sol_received);
-        let recv = <u128 as std::convert::TryInto<u64>>::try_into(sol_received)
-            .ok()?
-            .min(self.real_sol_reserves);
+        self.commit_sell(token_amount, sol_amount)
+    }
 
-        msg!("get_sell_price: recv: {}", recv);
-        Some(recv)
+    pub fn apply_sell_exact_out(&mut self, sol_amount: u64) -> Option<SellResult> {
+        msg!("apply_sell_exact_out: sol_amount: {}", sol_amount);
+
+        let token_amount = self.get_tokens_for_sell_sol(sol_amount)?;
+        msg!("apply_sell_exact_out: token_amount: {}", token_amount);
+        // the tokens must be sellable for at least sol_amount on the same curve
+        if self.get_sell_price(token_amount)? < sol_amount {
+            return None;
+        }
+        self.commit_sell(token_amount, sol_amount)
     }
 
-    pub fn apply_sell(&mut self, token_amount: u64) -> Option<SellResult> {
-        msg!("apply_sell: token_amount: {}", token_amount);
+    fn commit_sell(&mut self, token_amount: u64, sol_amount: u64) -> Option<SellResult> {
         let new_virtual_token_reserves =
             (self.virtual_token_reserves as u128).checked_add(token_amount as u128)?;
         msg!(
@@ -261,9 +338,6 @@ impl BondingCurve {
             new_real_token_reserves
         );
 
-        let sol_amount = self.get_sell_price(token_amount)?;
-        msg!("apply_sell: sol_amount: {}", sol_amount);
-
         let new_virtual_sol_reserves =
             (self.virtual_sol_reserves as u128).checked_sub(sol_amount as u128)?;
         msg!(
@@ -304,63 +378,77 @@ impl BondingCurve {
     }
 
     pub fn get_tokens_for_buy_sol(&self, sol_amount: u64) -> Option<u64> {
-        msg!("GetTokensForBuySol: sol_amount: {}", sol_amount);
-        if sol_amount == 0 {
-            return None;
-        }
-        msg!("GetTokensForBuySol: sol_amount: {}", sol_amount);
-
-        let product_of_reserves =
-            (self.virtual_sol_reserves as u128).checked_mul(self.virtual_token_reserves as u128)?;
-        msg!(
-            "GetTokensForBuySol: product_of_reserves: {}",
-            product_of_reserves
-        );
-        let new_virtual_sol_reserves =
-            (self.virtual_sol_reserves as u128).checked_add(sol_amount as u128)?;
-        msg!(
-            "GetTokensForBuySol: new_virtual_sol_reserves: {}",
-            new_virtual_sol_reserves
-        );
-        let new_virtual_token_reserves = product_of_reserves
-            .checked_div(new_virtual_sol_reserves)?
-            .checked_add(1)?;
-        msg!(
-            "GetTokensForBuySol: new_virtual_token_reserves: {}",
-            new_virtual_token_reserves
-        );
-        let tokens_received =
-            (self.virtual_token_reserves as u128).checked_sub(new_virtual_token_reserves)?;
-        msg!("GetTokensForBuySol: tokens_received: {}", tokens_received);
-        Some(
-            <u128 as std::convert::TryInto<u64>>::try_into(tokens_received)
-                .ok()?
-                .min(self.real_token_reserves),
-        )
+        self.curve_kind.pricing().buy_out(self, sol_amount)
     }
 
     pub fn get_tokens_for_sell_sol(&self, sol_amount: u64) -> Option<u64> {
-        msg!("GetTokensForSellSol: sol_amount: {}", sol_amount);
-        if sol_amount == 0 || sol_amount > self.real_sol_reserves {
-            msg!("GetTokensForSellSol: sol_amount is invalid");
-            return None;
-        }
+        self.curve_kind
+            .pricing()
+            .tokens_for_sol_out(self, sol_amount)
+    }
 
-        let scaling_factor = self.initial_virtual_token_reserves as u128;
+    // tokens left on the curve beyond its graduation liquidity
+    pub fn get_unsold_token_amount(&self) -> Option<u64> {
+        let (_, token_amount) = self.get_graduation_liquidity()?;
+        self.real_token_reserves.checked_sub(token_amount)
+    }
 
-        let scaled_sol = (sol_amount as u128).checked_mul(scaling_factor)?;
-        msg!("GetTokensForSellSol: scaled_sol: {}", scaled_sol);
-        let sol_sell_proportion = scaled_sol.checked_div(self.virtual_sol_reserves as u128)?;
-        msg!(
-            "GetTokensForSellSol: sol_sell_proportion: {}",
-            sol_sell_proportion
-        );
-        let tokens_received = ((self.virtual_token_reserves as u128)
-            .checked_mul(sol_sell_proportion)?)
-        .checked_div(scaling_factor)?;
-        msg!("GetTokensForSellSol: tokens_received: {}", tokens_received);
+    // takes the unsold tokens out of the reserves once the curve completes, the caller moves them
+    pub fn apply_completion_policy(&mut self) -> Option<u64> {
+        let unsold_token_amount = self.get_unsold_token_amount()?;
+        self.real_token_reserves -= unsold_token_amount;
+        Some(unsold_token_amount)
+    }
+
+    // lamports above the rent and the tracked reserves, e.g. sent straight to the PDA
+    pub fn get_surplus_lamports(&self, lamports: u64, rent_exempt_balance: u64) -> u64 {
+        lamports
+            .saturating_sub(rent_exempt_balance)
+            .saturating_sub(self.real_sol_reserves)
+    }
 
-        tokens_received.try_into().ok()
+    // price of the next token, see PRICE_SCALE
+    pub fn get_spot_price(&self) -> Option<u128> {
+        self.curve_kind.pricing().spot_price(self)
+    }
+
+    // SOL and tokens handed to the AMM at graduation, priced at the final curve price
+    pub fn get_graduation_liquidity(&self) -> Option<(u64, u64)> {
+        let sol_amount = self
+            .real_sol_reserves
+            .checked_add(self.presale_sol_raised)?;
+        // unsold tokens routed to graduation go into the pool as well
+        if self.completion_policy == CompletionPolicy::GraduationLiquidity {
+            return Some((sol_amount, self.real_token_reserves));
+        }
+        let token_amount = match self.curve_kind {
+            // exact from the virtual reserves
+            CurveKind::ConstantProduct => (sol_amount as u128)
+                .checked_mul(self.virtual_token_reserves)?
+                .checked_div(self.virtual_sol_reserves as u128)?,
+            _ => (sol_amount as u128)
+                .checked_mul(PRICE_SCALE)?
+                .checked_div(self.get_spot_price()?)?,
+        };
+        let token_amount = <u128 as std::convert::TryInto<u64>>::try_into(token_amount)
+            .ok()?
+            .min(self.real_token_reserves);
+        Some((sol_amount, token_amount))
+    }
+
+    // the curve's fee schedule never undercuts the global trade fee
+    pub fn get_fee_bps(&self, global_fee_bps: u64, now: i64) -> u64 {
+        match &self.fee_schedule {
+            Some(fee_schedule) => fee_schedule
+                .get_fee_bps(self.start_time, now)
+                .max(global_fee_bps),
+            None => global_fee_bps,
+        }
+    }
+
+    // launches may open right away, be backdated or be scheduled up to `max_lead_time` ahead
+    pub fn is_valid_start_time(start_time: i64, now: i64, max_lead_time: i64) -> bool {
+        start_time <= now.saturating_add(max_lead_time)
     }
 
     pub fn is_started(&self, clock: &Clock) -> bool {
@@ -374,28 +462,33 @@ impl BondingCurve {
 
     pub fn invariant<'info>(ctx: &mut BondingCurveLockerCtx<'info>) -> Result<()> {
         let bonding_curve = &mut ctx.bonding_curve;
-        let tkn_account = &mut ctx.bonding_curve_token_account;
+        if ctx.bonding_curve_token_account.owner != ctx.token_program.key {
+            msg!("Invariant failed: invalid token acc supplied");
+            return Err(ContractError::BondingCurveInvariant.into());
+        }
+        let tkn_account = TokenAccount::try_deserialize(
+            &mut &ctx.bonding_curve_token_account.try_borrow_data()?[..],
+        )?;
         if tkn_account.owner != bonding_curve.key() {
             msg!("Invariant failed: invalid token acc supplied");
             return Err(ContractError::BondingCurveInvariant.into());
         }
-        tkn_account.reload()?;
 
         let lamports = bonding_curve.get_lamports();
         let tkn_balance = tkn_account.amount;
 
         let rent_exemption_balance: u64 =
             Rent::get()?.minimum_balance(8 + BondingCurve::INIT_SPACE as usize);
-        let bonding_curve_pool_lamports: u64 = lamports - rent_exemption_balance;
+        let bonding_curve_pool_lamports: u64 = lamports.saturating_sub(rent_exemption_balance);
 
-        // Ensure real sol reserves are equal to bonding curve pool lamports
-        if bonding_curve_pool_lamports != bonding_curve.real_sol_reserves {
+        // Ensure the pool lamports cover the real sol reserves, anything above is skimmable
+        if bonding_curve_pool_lamports < bonding_curve.real_sol_reserves {
             msg!(
                 "real_sol_r:{}, bonding_lamps:{}",
                 bonding_curve.real_sol_reserves,
                 bonding_curve_pool_lamports
             );
-            msg!("Invariant failed: real_sol_reserves != bonding_curve_pool_lamports");
+            msg!("Invariant failed: real_sol_reserves > bonding_curve_pool_lamports");
             return Err(ContractError::BondingCurveInvariant.into());
         }
 
@@ -417,13 +510,14 @@ impl BondingCurve {
             return Err(ContractError::BondingCurveInvariant.into());
         }
 
-        // Ensure the bonding curve is complete only if real token reserves are zero
-        if bonding_curve.complete && bonding_curve.real_token_reserves != 0 {
-            msg!("Invariant failed: bonding curve marked as complete but real_token_reserves != 0");
+        // A complete curve only holds its graduation liquidity, the rest is settled on completion
+        if bonding_curve.complete && bonding_curve.get_unsold_token_amount() != Some(0) {
+            msg!("Invariant failed: complete curve holds unsold tokens");
             return Err(ContractError::BondingCurveInvariant.into());
         }
 
-        if !bonding_curve.complete && !tkn_account.is_frozen() {
+        // a failed curve is left thawed with its freeze authority revoked
+        if !bonding_curve.complete && !bonding_curve.failed && !tkn_account.is_frozen() {
             msg!("Active BondingCurve TokenAccount must always be frozen at the end");
             return Err(ContractError::BondingCurveInvariant.into());
         }
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 // use anchor_lang::{prelude::AccountInfo, Accounts};
-use anchor_spl::token::spl_token::instruction::AuthorityType;
-use anchor_spl::token::{self, FreezeAccount, Mint, ThawAccount, Token, TokenAccount};
+use anchor_spl::token_interface::{
+    self, spl_token_2022::instruction::AuthorityType, FreezeAccount, ThawAccount,
+};
 
 use crate::state::bonding_curve::BondingCurve;
 
@@ -9,7 +10,7 @@ use crate::state::bonding_curve::BondingCurve;
 pub struct BondingCurveLockerCtx<'info> {
     pub bonding_curve_bump: u8,
     // #[account()]
-    pub mint: Box<Account<'info, Mint>>,
+    pub mint: AccountInfo<'info>,
 
     // // #[account(
     // //     mut,
@@ -28,13 +29,14 @@ pub struct BondingCurveLockerCtx<'info> {
     //     associated_token::mint = mint,
     //     associated_token::authority = bonding_curve,
     // )]
-    pub bonding_curve_token_account: Box<Account<'info, TokenAccount>>,
-    pub token_program: Program<'info, Token>,
+    // plain account infos so the locker works for SPL Token and Token-2022 mints,
+    // including ones created within the same instruction
+    pub bonding_curve_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
 }
 impl BondingCurveLockerCtx<'_> {
     fn get_signer<'a>(&self) -> [&[u8]; 3] {
-        let signer: [&[u8]; 3] =
-            BondingCurve::get_signer(&self.bonding_curve_bump, self.mint.to_account_info().key);
+        let signer: [&[u8]; 3] = BondingCurve::get_signer(&self.bonding_curve_bump, self.mint.key);
         signer
     }
     pub fn lock_ata<'a>(&self) -> Result<()> {
@@ -42,12 +44,12 @@ impl BondingCurveLockerCtx<'_> {
         let signer_seeds: &[&[&[u8]]; 1] = &[&signer[..]];
 
         let accs = FreezeAccount {
-            account: self.bonding_curve_token_account.to_account_info(),
-            mint: self.mint.to_account_info(),
+            account: self.bonding_curve_token_account.clone(),
+            mint: self.mint.clone(),
             authority: self.bonding_curve.to_account_info(),
         };
-        token::freeze_account(CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
             accs,
             signer_seeds,
         ))?;
@@ -60,12 +62,12 @@ impl BondingCurveLockerCtx<'_> {
         let signer_seeds: &[&[&[u8]]; 1] = &[&signer[..]];
 
         let accs = ThawAccount {
-            account: self.bonding_curve_token_account.to_account_info(),
-            mint: self.mint.to_account_info(),
+            account: self.bonding_curve_token_account.clone(),
+            mint: self.mint.clone(),
             authority: self.bonding_curve.to_account_info(),
         };
-        token::thaw_account(CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
             accs,
             signer_seeds,
         ))?;
@@ -75,16 +77,16 @@ impl BondingCurveLockerCtx<'_> {
     }
 
     pub fn revoke_mint_authority(&self) -> Result<()> {
-        let mint_info = self.mint.to_account_info();
+        let mint_info = self.mint.clone();
         let mint_authority_info = self.bonding_curve.to_account_info();
         let signer = self.get_signer();
         let signer_seeds: &[&[&[u8]]; 1] = &[&signer[..]];
 
         //remove mint_authority
-        token::set_authority(
+        token_interface::set_authority(
             CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::SetAuthority {
+                self.token_program.clone(),
+                token_interface::SetAuthority {
                     current_authority: mint_authority_info.clone(),
                     account_or_mint: mint_info.clone(),
                 },
@@ -99,16 +101,16 @@ impl BondingCurveLockerCtx<'_> {
     }
 
     pub fn revoke_freeze_authority(&self) -> Result<()> {
-        let mint_info = self.mint.to_account_info();
+        let mint_info = self.mint.clone();
         let mint_authority_info = self.bonding_curve.to_account_info();
         let signer = self.get_signer();
         let signer_seeds: &[&[&[u8]]; 1] = &[&signer[..]];
 
         // revoke freeze authority
-        token::set_authority(
+        token_interface::set_authority(
             CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::SetAuthority {
+                self.token_program.clone(),
+                token_interface::SetAuthority {
                     current_authority: mint_authority_info.clone(),
                     account_or_mint: mint_info.clone(),
                 },
@@ -3,3 +3,4 @@ pub mod structs;
 pub mod tests;
 pub use structs::*;
 pub mod locker;
+pub mod pricing;
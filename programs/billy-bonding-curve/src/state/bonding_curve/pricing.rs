@@ -0,0 +1,881 @@
+use anchor_lang::prelude::*;
+
+use crate::state::bonding_curve::structs::BondingCurve;
+use crate::util::{bps_mul, ceil_div, BASIS_POINTS_DIVISOR};
+
+// spot prices are lamports per token base unit, scaled by PRICE_SCALE
+pub const PRICE_SCALE: u128 = 1_000_000_000_000;
+// ln(2) scaled by PRICE_SCALE, rounded down
+const LN_2: u128 = 693_147_180_559;
+
+// common pricing for every curve shape, amounts are always rounded in favour of the pool
+pub trait CurvePricing {
+    // tokens received for `sol_amount`, capped at the real token reserves
+    fn buy_out(&self, curve: &BondingCurve, sol_amount: u64) -> Option<u64>;
+    // SOL received for selling `tokens`, capped at the real SOL reserves
+    fn sell_out(&self, curve: &BondingCurve, tokens: u64) -> Option<u64>;
+    // SOL needed to buy exactly `tokens`
+    fn cost_for_tokens(&self, curve: &BondingCurve, tokens: u64) -> Option<u64>;
+    // fewest tokens that sell for at least `sol_amount`
+    fn tokens_for_sol_out(&self, curve: &BondingCurve, sol_amount: u64) -> Option<u64>;
+    // SOL raised once every remaining token is bought, presale included
+    fn max_attainable_sol(&self, curve: &BondingCurve) -> Option<u64>;
+    // price of the next token
+    fn spot_price(&self, curve: &BondingCurve) -> Option<u128>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LinearCurve {
+    pub start_price: u64,
+    // price once the whole bonding supply is sold
+    pub end_price: u64,
+}
+
+// price = start_price * e^(growth * sold / bonding_supply)
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ExponentialCurve {
+    pub start_price: u64,
+    pub growth_bps: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct CurveSegment {
+    // share of the bonding supply sold along this segment
+    pub supply_bps: u64,
+    pub end_price: u64,
+}
+
+// linear segments joined end to end, the price stays at the last end price past the final segment
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct PiecewiseLinearCurve {
+    pub start_price: u64,
+    #[max_len(8)]
+    pub segments: Vec<CurveSegment>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum CurveKind {
+    // priced off the virtual reserves
+    #[default]
+    ConstantProduct,
+    Linear(LinearCurve),
+    Exponential(ExponentialCurve),
+    PiecewiseLinear(PiecewiseLinearCurve),
+}
+
+impl CurveKind {
+    pub const MAX_SEGMENTS: usize = 8;
+    // e^20, keeps the exponential math within u128
+    pub const MAX_GROWTH_BPS: u64 = 200_000;
+
+    pub fn pricing(&self) -> &dyn CurvePricing {
+        match self {
+            CurveKind::ConstantProduct => &ConstantProductCurve,
+            CurveKind::Linear(curve) => curve,
+            CurveKind::Exponential(curve) => curve,
+            CurveKind::PiecewiseLinear(curve) => curve,
+        }
+    }
+
+    // every shape needs a positive, non-decreasing price
+    pub fn is_valid(&self) -> bool {
+        match self {
+            CurveKind::ConstantProduct => true,
+            CurveKind::Linear(curve) => {
+                curve.start_price > 0 && curve.end_price >= curve.start_price
+            }
+            CurveKind::Exponential(curve) => {
+                curve.start_price > 0
+                    && curve.growth_bps > 0
+                    && curve.growth_bps <= Self::MAX_GROWTH_BPS
+            }
+            CurveKind::PiecewiseLinear(curve) => {
+                let mut price = curve.start_price;
+                let mut supply_bps = 0;
+                for segment in &curve.segments {
+                    if segment.supply_bps == 0 || segment.end_price < price {
+                        return false;
+                    }
+                    price = segment.end_price;
+                    supply_bps += segment.supply_bps;
+                }
+                curve.start_price > 0
+                    && !curve.segments.is_empty()
+                    && curve.segments.len() <= Self::MAX_SEGMENTS
+                    && supply_bps == BASIS_POINTS_DIVISOR
+            }
+        }
+    }
+}
+
+pub struct ConstantProductCurve;
+
+impl CurvePricing for ConstantProductCurve {
+    fn buy_out(&self, curve: &BondingCurve, sol_amount: u64) -> Option<u64> {
+        msg!("GetTokensForBuySol: sol_amount: {}", sol_amount);
+        if sol_amount == 0 {
+            return None;
+        }
+        msg!("GetTokensForBuySol: sol_amount: {}", sol_amount);
+
+        let product_of_reserves =
+            (curve.virtual_sol_reserves as u128).checked_mul(curve.virtual_token_reserves)?;
+        msg!(
+            "GetTokensForBuySol: product_of_reserves: {}",
+            product_of_reserves
+        );
+        let new_virtual_sol_reserves =
+            (curve.virtual_sol_reserves as u128).checked_add(sol_amount as u128)?;
+        msg!(
+            "GetTokensForBuySol: new_virtual_sol_reserves: {}",
+            new_virtual_sol_reserves
+        );
+        let new_virtual_token_reserves = product_of_reserves
+            .checked_div(new_virtual_sol_reserves)?
+            .checked_add(1)?;
+        msg!(
+            "GetTokensForBuySol: new_virtual_token_reserves: {}",
+            new_virtual_token_reserves
+        );
+        let tokens_received = curve
+            .virtual_token_reserves
+            .checked_sub(new_virtual_token_reserves)?;
+        msg!("GetTokensForBuySol: tokens_received: {}", tokens_received);
+        Some(
+            <u128 as std::convert::TryInto<u64>>::try_into(tokens_received)
+                .ok()?
+                .min(curve.real_token_reserves),
+        )
+    }
+
+    fn sell_out(&self, curve: &BondingCurve, tokens: u64) -> Option<u64> {
+        msg!("get_sell_price: tokens: {}", tokens);
+        if tokens == 0 || tokens > curve.virtual_token_reserves as u64 {
+            return None;
+        }
+
+        let product_of_reserves =
+            (curve.virtual_sol_reserves as u128).checked_mul(curve.virtual_token_reserves)?;
+        msg!(
+            "get_sell_price: product_of_reserves: {}",
+            product_of_reserves
+        );
+        let new_virtual_token_reserves =
+            curve.virtual_token_reserves.checked_add(tokens as u128)?;
+        msg!(
+            "get_sell_price: new_virtual_token_reserves: {}",
+            new_virtual_token_reserves
+        );
+        // rounded up so the pool keeps any remainder
+        let new_virtual_sol_reserves = ceil_div(product_of_reserves, new_virtual_token_reserves)?;
+        msg!(
+            "get_sell_price: new_virtual_sol_reserves: {}",
+            new_virtual_sol_reserves
+        );
+        let sol_received =
+            (curve.virtual_sol_reserves as u128).checked_sub(new_virtual_sol_reserves)?;
+        msg!("get_sell_price: sol_received: {}", sol_received);
+        let recv = <u128 as std::convert::TryInto<u64>>::try_into(sol_received)
+            .ok()?
+            .min(curve.real_sol_reserves);
+
+        msg!("get_sell_price: recv: {}", recv);
+        Some(recv)
+    }
+
+    fn cost_for_tokens(&self, curve: &BondingCurve, tokens: u64) -> Option<u64> {
+        msg!("get_buy_price: tokens: {}", tokens);
+        if tokens == 0 || tokens > curve.virtual_token_reserves as u64 {
+            return None;
+        }
+
+        let product_of_reserves =
+            (curve.virtual_sol_reserves as u128).checked_mul(curve.virtual_token_reserves)?;
+        msg!(
+            "get_buy_price: product_of_reserves: {}",
+            product_of_reserves
+        );
+        let new_virtual_token_reserves =
+            curve.virtual_token_reserves.checked_sub(tokens as u128)?;
+        msg!(
+            "get_buy_price: new_virtual_token_reserves: {}",
+            new_virtual_token_reserves
+        );
+        let new_virtual_sol_reserves = product_of_reserves
+            .checked_div(new_virtual_token_reserves)?
+            .checked_add(1)?;
+        msg!(
+            "get_buy_price: new_virtual_sol_reserves: {}",
+            new_virtual_sol_reserves
+        );
+        let amount_needed =
+            new_virtual_sol_reserves.checked_sub(curve.virtual_sol_reserves as u128)?;
+        msg!("get_buy_price: amount_needed: {}", amount_needed);
+
+        amount_needed.try_into().ok()
+    }
+
+    fn tokens_for_sol_out(&self, curve: &BondingCurve, sol_amount: u64) -> Option<u64> {
+        msg!("GetTokensForSellSol: sol_amount: {}", sol_amount);
+        if sol_amount == 0 || sol_amount > curve.real_sol_reserves {
+            msg!("GetTokensForSellSol: sol_amount is invalid");
+            return None;
+        }
+
+        let product_of_reserves =
+            (curve.virtual_sol_reserves as u128).checked_mul(curve.virtual_token_reserves)?;
+        msg!(
+            "GetTokensForSellSol: product_of_reserves: {}",
+            product_of_reserves
+        );
+        let new_virtual_sol_reserves =
+            (curve.virtual_sol_reserves as u128).checked_sub(sol_amount as u128)?;
+        msg!(
+            "GetTokensForSellSol: new_virtual_sol_reserves: {}",
+            new_virtual_sol_reserves
+        );
+        // inverse of get_sell_price, rounded up so the seller never receives
+        // more SOL than the tokens are worth
+        let new_virtual_token_reserves = ceil_div(product_of_reserves, new_virtual_sol_reserves)?;
+        msg!(
+            "GetTokensForSellSol: new_virtual_token_reserves: {}",
+            new_virtual_token_reserves
+        );
+        let tokens_received =
+            new_virtual_token_reserves.checked_sub(curve.virtual_token_reserves)?;
+        msg!("GetTokensForSellSol: tokens_received: {}", tokens_received);
+
+        tokens_received.try_into().ok()
+    }
+
+    fn max_attainable_sol(&self, curve: &BondingCurve) -> Option<u64> {
+        // Calculate the number of tokens available for purchase
+        let tokens_available = curve.real_token_reserves;
+
+        // If no tokens are available, return the current real SOL reserves
+        if tokens_available == 0 {
+            return curve
+                .real_sol_reserves
+                .checked_add(curve.presale_sol_raised);
+        }
+
+        // Calculate the product of reserves (constant in the bonding curve equation)
+        let product_of_reserves =
+            (curve.virtual_sol_reserves as u128).checked_mul(curve.virtual_token_reserves)?;
+
+        // Calculate the new virtual token reserves after all tokens are bought
+        let new_virtual_token_reserves = curve
+            .virtual_token_reserves
+            .checked_sub(tokens_available as u128)?;
+
+        // Calculate the new virtual SOL reserves using the constant product formula
+        let new_virtual_sol_reserves = product_of_reserves
+            .checked_div(new_virtual_token_reserves)?
+            .checked_add(1)?;
+
+        // Calculate the difference in virtual SOL reserves
+        let sol_increase =
+            new_virtual_sol_reserves.checked_sub(curve.virtual_sol_reserves as u128)?;
+
+        // Add the increase to the current real SOL reserves
+        let max_attainable_sol = (curve.real_sol_reserves as u128).checked_add(sol_increase)?;
+
+        // Presale SOL counts toward the launch threshold
+        let max_attainable_sol =
+            max_attainable_sol.checked_add(curve.presale_sol_raised as u128)?;
+
+        // Convert to u64 and return
+        max_attainable_sol.try_into().ok()
+    }
+
+    fn spot_price(&self, curve: &BondingCurve) -> Option<u128> {
+        (curve.virtual_sol_reserves as u128)
+            .checked_mul(PRICE_SCALE)?
+            .checked_div(curve.virtual_token_reserves)
+    }
+}
+
+// shapes priced by a non-decreasing price over the tokens sold from the bonding supply
+pub trait SupplyCurve {
+    // price of the next token once `sold` tokens are out, scaled by PRICE_SCALE
+    fn price_at(&self, bonding_supply: u64, sold: u64) -> Option<u128>;
+    // SOL paid for the first `sold` tokens, scaled by PRICE_SCALE and non-decreasing in `sold`
+    fn integral(&self, bonding_supply: u64, sold: u64) -> Option<u128>;
+
+    // buying never costs less than the spot price, rounding can flatten the integral over a few tokens
+    fn buy_cost(&self, bonding_supply: u64, sold: u64, tokens: u64) -> Option<u128> {
+        let area = self
+            .integral(bonding_supply, sold.checked_add(tokens)?)?
+            .checked_sub(self.integral(bonding_supply, sold)?)?;
+        let spot_cost = (tokens as u128).checked_mul(self.price_at(bonding_supply, sold)?)?;
+        ceil_div(area.max(spot_cost), PRICE_SCALE)
+    }
+
+    // the curve only buys back along the part of it that was sold
+    fn sell_proceeds(&self, bonding_supply: u64, sold: u64, tokens: u64) -> Option<u128> {
+        let area = self
+            .integral(bonding_supply, sold)?
+            .checked_sub(self.integral(bonding_supply, sold.saturating_sub(tokens))?)?;
+        Some(area / PRICE_SCALE)
+    }
+}
+
+fn tokens_sold(curve: &BondingCurve) -> u64 {
+    curve
+        .bonding_supply
+        .saturating_sub(curve.real_token_reserves)
+}
+
+impl<T: SupplyCurve> CurvePricing for T {
+    fn buy_out(&self, curve: &BondingCurve, sol_amount: u64) -> Option<u64> {
+        if sol_amount == 0 {
+            return None;
+        }
+        let sold = tokens_sold(curve);
+        // the price never drops, so buying everything at the spot price is an upper bound
+        let max_tokens = (sol_amount as u128)
+            .checked_mul(PRICE_SCALE)?
+            .checked_div(self.price_at(curve.bonding_supply, sold)?)?;
+        let max_tokens = u64::try_from(max_tokens)
+            .unwrap_or(u64::MAX)
+            .min(curve.real_token_reserves);
+        Some(search_max(0, max_tokens, |tokens| {
+            tokens == 0
+                || self
+                    .buy_cost(curve.bonding_supply, sold, tokens)
+                    .is_some_and(|cost| cost <= sol_amount as u128)
+        }))
+    }
+
+    fn sell_out(&self, curve: &BondingCurve, tokens: u64) -> Option<u64> {
+        if tokens == 0 {
+            return None;
+        }
+        let sol_amount = self.sell_proceeds(curve.bonding_supply, tokens_sold(curve), tokens)?;
+        Some(u64::try_from(sol_amount).ok()?.min(curve.real_sol_reserves))
+    }
+
+    fn cost_for_tokens(&self, curve: &BondingCurve, tokens: u64) -> Option<u64> {
+        if tokens == 0 || tokens > curve.real_token_reserves {
+            return None;
+        }
+        self.buy_cost(curve.bonding_supply, tokens_sold(curve), tokens)?
+            .try_into()
+            .ok()
+    }
+
+    fn tokens_for_sol_out(&self, curve: &BondingCurve, sol_amount: u64) -> Option<u64> {
+        if sol_amount == 0 || sol_amount > curve.real_sol_reserves {
+            return None;
+        }
+        let sold = tokens_sold(curve);
+        let sells_for = |tokens: u64| {
+            self.sell_proceeds(curve.bonding_supply, sold, tokens)
+                .is_some_and(|proceeds| proceeds >= sol_amount as u128)
+        };
+        if sold == 0 || !sells_for(sold) {
+            return None;
+        }
+        Some(search_min(1, sold, sells_for))
+    }
+
+    fn max_attainable_sol(&self, curve: &BondingCurve) -> Option<u64> {
+        let remaining_sol = match curve.real_token_reserves {
+            0 => 0,
+            tokens => self.cost_for_tokens(curve, tokens)?,
+        };
+        curve
+            .real_sol_reserves
+            .checked_add(remaining_sol)?
+            .checked_add(curve.presale_sol_raised)
+    }
+
+    fn spot_price(&self, curve: &BondingCurve) -> Option<u128> {
+        self.price_at(curve.bonding_supply, tokens_sold(curve))
+    }
+}
+
+// price `offset` tokens into a segment of `len` tokens rising from `start_price` to `end_price`
+fn segment_price(start_price: u64, end_price: u64, len: u64, offset: u64) -> Option<u128> {
+    ((end_price as u128).checked_sub(start_price as u128)?)
+        .checked_mul(offset as u128)?
+        .checked_div(len as u128)?
+        .checked_add(start_price as u128)
+}
+
+// area under the first `offset` tokens of the same segment, `offset` tokens at their average price
+fn segment_area(start_price: u64, end_price: u64, len: u64, offset: u64) -> Option<u128> {
+    if offset == 0 {
+        return Some(0);
+    }
+    let average_rise = ((end_price as u128).checked_sub(start_price as u128)?)
+        .checked_mul(offset as u128)?
+        .checked_div((len as u128).checked_mul(2)?)?;
+    (offset as u128).checked_mul(average_rise.checked_add(start_price as u128)?)
+}
+
+impl SupplyCurve for LinearCurve {
+    fn price_at(&self, bonding_supply: u64, sold: u64) -> Option<u128> {
+        segment_price(self.start_price, self.end_price, bonding_supply, sold)
+    }
+
+    fn integral(&self, bonding_supply: u64, sold: u64) -> Option<u128> {
+        segment_area(self.start_price, self.end_price, bonding_supply, sold)
+    }
+}
+
+impl ExponentialCurve {
+    // growth * sold / bonding_supply, scaled by PRICE_SCALE
+    fn exponent(&self, bonding_supply: u64, sold: u64) -> Option<u128> {
+        (self.growth_bps as u128)
+            .checked_mul(sold as u128)?
+            .checked_mul(PRICE_SCALE)?
+            .checked_div((bonding_supply as u128).checked_mul(BASIS_POINTS_DIVISOR as u128)?)
+    }
+}
+
+impl SupplyCurve for ExponentialCurve {
+    fn price_at(&self, bonding_supply: u64, sold: u64) -> Option<u128> {
+        let growth = exp(self.exponent(bonding_supply, sold)?)?;
+        (self.start_price as u128)
+            .checked_mul(growth)?
+            .checked_div(PRICE_SCALE)
+    }
+
+    // start_price * bonding_supply / growth * (e^exponent - 1)
+    fn integral(&self, bonding_supply: u64, sold: u64) -> Option<u128> {
+        let scale = (self.start_price as u128)
+            .checked_mul(bonding_supply as u128)?
+            .checked_mul(BASIS_POINTS_DIVISOR as u128)?
+            .checked_div(self.growth_bps as u128)?;
+        let growth = exp(self.exponent(bonding_supply, sold)?)?.checked_sub(PRICE_SCALE)?;
+        // split to keep the product within u128
+        let whole = (growth / PRICE_SCALE).checked_mul(scale)?;
+        let fraction = (growth % PRICE_SCALE)
+            .checked_mul(scale)?
+            .checked_div(PRICE_SCALE)?;
+        whole.checked_add(fraction)
+    }
+}
+
+impl SupplyCurve for PiecewiseLinearCurve {
+    fn price_at(&self, bonding_supply: u64, sold: u64) -> Option<u128> {
+        let mut start = 0u64;
+        let mut start_price = self.start_price;
+        for segment in &self.segments {
+            let len = bps_mul(segment.supply_bps, bonding_supply)?;
+            if sold < start.checked_add(len)? {
+                return segment_price(start_price, segment.end_price, len, sold - start);
+            }
+            start += len;
+            start_price = segment.end_price;
+        }
+        Some(start_price as u128)
+    }
+
+    fn integral(&self, bonding_supply: u64, sold: u64) -> Option<u128> {
+        let mut area = 0u128;
+        let mut start = 0u64;
+        let mut start_price = self.start_price;
+        for segment in &self.segments {
+            let len = bps_mul(segment.supply_bps, bonding_supply)?;
+            if sold < start.checked_add(len)? {
+                return area.checked_add(segment_area(
+                    start_price,
+                    segment.end_price,
+                    len,
+                    sold - start,
+                )?);
+            }
+            // same formula as a partial segment so the integral has no jumps
+            area = area.checked_add(segment_area(start_price, segment.end_price, len, len)?)?;
+            start += len;
+            start_price = segment.end_price;
+        }
+        area.checked_add(((sold - start) as u128).checked_mul(start_price as u128)?)
+    }
+}
+
+// e^x with x and the result scaled by PRICE_SCALE, rounded down and non-decreasing in x
+pub fn exp(x: u128) -> Option<u128> {
+    // e^x = 2^n * e^r with r < ln(2)
+    let doublings = u32::try_from(x / LN_2).ok()?;
+    let r = x % LN_2;
+    let mut sum = PRICE_SCALE;
+    let mut term = PRICE_SCALE;
+    let mut i = 1u128;
+    while term > 0 {
+        term = term
+            .checked_mul(r)?
+            .checked_div(i.checked_mul(PRICE_SCALE)?)?;
+        sum += term;
+        i += 1;
+    }
+    sum.checked_mul(1u128.checked_shl(doublings)?)
+}
+
+// largest value in [lo, hi] passing a check that holds at lo and flips at most once
+fn search_max(mut lo: u64, mut hi: u64, check: impl Fn(u64) -> bool) -> u64 {
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        if check(mid) {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+    lo
+}
+
+// smallest value in [lo, hi] passing a check that holds at hi and flips at most once
+fn search_min(mut lo: u64, mut hi: u64, check: impl Fn(u64) -> bool) -> u64 {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if check(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    hi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn curve_with(curve_kind: CurveKind, bonding_supply: u64) -> BondingCurve {
+        let virtual_token_reserves = bonding_supply as u128 * 2;
+        BondingCurve {
+            curve_kind,
+            bonding_supply,
+            real_token_reserves: bonding_supply,
+            virtual_sol_reserves: 30_000_000_000,
+            virtual_token_reserves,
+            initial_virtual_token_reserves: virtual_token_reserves,
+            ..Default::default()
+        }
+    }
+
+    fn piecewise(start_price: u64, segments: &[(u64, u64)]) -> CurveKind {
+        // weights are spread over the whole supply, the last segment takes the rounding
+        let total: u64 = segments.iter().map(|(weight, _)| weight).sum();
+        let mut supply_bps = 0;
+        let mut end_price = start_price;
+        let segments = segments
+            .iter()
+            .enumerate()
+            .map(|(i, (weight, rise))| {
+                let bps = if i + 1 == segments.len() {
+                    BASIS_POINTS_DIVISOR - supply_bps
+                } else {
+                    weight * BASIS_POINTS_DIVISOR / total
+                };
+                supply_bps += bps;
+                end_price += rise;
+                CurveSegment {
+                    supply_bps: bps,
+                    end_price,
+                }
+            })
+            .collect();
+        CurveKind::PiecewiseLinear(PiecewiseLinearCurve {
+            start_price,
+            segments,
+        })
+    }
+
+    // more SOL never buys fewer tokens, more tokens never cost less and buys never lower the price
+    fn check_monotonic(
+        mut curve: BondingCurve,
+        sol_amounts: (u64, u64),
+        token_amounts: (u64, u64),
+    ) -> std::result::Result<(), TestCaseError> {
+        let (low_sol, high_sol) = (
+            sol_amounts.0.min(sol_amounts.1),
+            sol_amounts.0.max(sol_amounts.1),
+        );
+        if let (Some(low), Some(high)) = (
+            curve.get_tokens_for_buy_sol(low_sol),
+            curve.get_tokens_for_buy_sol(high_sol),
+        ) {
+            prop_assert!(low <= high, "More SOL should not buy fewer tokens");
+        }
+        let (low_tokens, high_tokens) = (
+            token_amounts.0.min(token_amounts.1),
+            token_amounts.0.max(token_amounts.1),
+        );
+        if let (Some(low), Some(high)) = (
+            curve.get_buy_price(low_tokens),
+            curve.get_buy_price(high_tokens),
+        ) {
+            prop_assert!(low <= high, "More tokens should not cost less");
+        }
+
+        let spot_before = curve.curve_kind.pricing().spot_price(&curve);
+        if let Some(result) = curve.apply_buy(high_sol) {
+            prop_assert!(result.token_amount <= curve.bonding_supply);
+            let spot_after = curve.curve_kind.pricing().spot_price(&curve);
+            if let (Some(before), Some(after)) = (spot_before, spot_after) {
+                prop_assert!(after >= before, "Buys should not lower the spot price");
+            }
+            // selling them straight back returns no more than was paid
+            if result.token_amount > 0 {
+                if let Some(sell) = curve.apply_sell(result.token_amount) {
+                    prop_assert!(
+                        sell.sol_amount <= result.sol_amount,
+                        "Round trips should not profit"
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // (kind, amount): 0 buy exact-in, 1 sell exact-in, 2 buy exact-out, 3 sell exact-out
+    fn check_no_arbitrage(
+        mut curve: BondingCurve,
+        swaps: Vec<(u8, u64)>,
+    ) -> std::result::Result<(), TestCaseError> {
+        let mut sol_in: u128 = 0;
+        let mut sol_out: u128 = 0;
+        let mut tokens_held: u64 = 0;
+        for (kind, amount) in swaps {
+            let mut quote = curve.clone();
+            let result = match kind {
+                0 => quote
+                    .apply_buy(amount)
+                    .map(|r| (true, r.token_amount, r.sol_amount)),
+                2 => quote
+                    .apply_buy_exact_out(amount)
+                    .map(|r| (true, r.token_amount, r.sol_amount)),
+                1 => quote
+                    .apply_sell(amount.min(tokens_held).max(1))
+                    .map(|r| (false, r.token_amount, r.sol_amount)),
+                _ => quote
+                    .apply_sell_exact_out(amount)
+                    .map(|r| (false, r.token_amount, r.sol_amount)),
+            };
+            match result {
+                Some((true, token_amount, sol_amount)) => {
+                    sol_in += sol_amount as u128;
+                    tokens_held += token_amount;
+                }
+                // only tokens bought from the curve can be sold back
+                Some((false, token_amount, sol_amount)) if token_amount <= tokens_held => {
+                    sol_out += sol_amount as u128;
+                    tokens_held -= token_amount;
+                }
+                _ => continue,
+            }
+            curve.clone_from(&quote);
+            prop_assert_eq!(
+                curve.real_sol_reserves as u128,
+                sol_in - sol_out,
+                "Real SOL reserves should track the SOL in the curve"
+            );
+        }
+
+        // dump everything that is left
+        if tokens_held > 0 {
+            if let Some(result) = curve.apply_sell(tokens_held) {
+                sol_out += result.sol_amount as u128;
+            }
+        }
+        prop_assert!(
+            sol_out <= sol_in,
+            "No buy/sell sequence should extract more SOL than was put in"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(exp(0), Some(PRICE_SCALE));
+        // within a millionth of the real values
+        for (x, expected) in [
+            (1.0f64, 1.0f64.exp()),
+            (0.5, 0.5f64.exp()),
+            (20.0, 20.0f64.exp()),
+        ] {
+            let result = exp((x * PRICE_SCALE as f64) as u128).unwrap() as f64 / PRICE_SCALE as f64;
+            assert!(
+                (result - expected).abs() / expected < 1e-6,
+                "{} {}",
+                result,
+                expected
+            );
+        }
+        // no dip when crossing a doubling
+        assert!(exp(LN_2 - 1).unwrap() < exp(LN_2).unwrap());
+        assert_eq!(exp(LN_2), Some(2 * PRICE_SCALE));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(CurveKind::ConstantProduct.is_valid());
+        assert!(CurveKind::Linear(LinearCurve {
+            start_price: 1,
+            end_price: 1
+        })
+        .is_valid());
+        assert!(!CurveKind::Linear(LinearCurve {
+            start_price: 0,
+            end_price: 1
+        })
+        .is_valid());
+        assert!(!CurveKind::Linear(LinearCurve {
+            start_price: 2,
+            end_price: 1
+        })
+        .is_valid());
+
+        let exponential = |growth_bps| {
+            CurveKind::Exponential(ExponentialCurve {
+                start_price: 1,
+                growth_bps,
+            })
+        };
+        assert!(exponential(CurveKind::MAX_GROWTH_BPS).is_valid());
+        assert!(!exponential(0).is_valid());
+        assert!(!exponential(CurveKind::MAX_GROWTH_BPS + 1).is_valid());
+
+        assert!(piecewise(1, &[(1, 1), (3, 0)]).is_valid());
+        assert!(!piecewise(0, &[(1, 1)]).is_valid());
+        assert!(!piecewise(1, &[]).is_valid());
+        assert!(!piecewise(1, &[(1, 1); CurveKind::MAX_SEGMENTS + 1]).is_valid());
+        // segments must cover the supply with non-decreasing prices
+        let CurveKind::PiecewiseLinear(mut curve) = piecewise(1, &[(1, 1), (1, 1)]) else {
+            unreachable!()
+        };
+        curve.segments[1].supply_bps -= 1;
+        assert!(!CurveKind::PiecewiseLinear(curve.clone()).is_valid());
+        curve.segments[1].supply_bps += 1;
+        curve.segments[1].end_price = 1;
+        assert!(!CurveKind::PiecewiseLinear(curve).is_valid());
+    }
+
+    #[test]
+    fn test_linear_pricing() {
+        // price rises from 1 to 3 lamports per token over 1000 tokens
+        let kind = CurveKind::Linear(LinearCurve {
+            start_price: PRICE_SCALE as u64,
+            end_price: 3 * PRICE_SCALE as u64,
+        });
+        let mut curve = curve_with(kind, 1000);
+        assert_eq!(curve.get_max_attainable_sol(), Some(2000));
+        assert_eq!(curve.get_buy_price(500), Some(750));
+        assert_eq!(curve.get_tokens_for_buy_sol(750), Some(500));
+        assert_eq!(curve.get_tokens_for_buy_sol(749), Some(499));
+
+        curve.apply_buy(750).unwrap();
+        assert_eq!(
+            curve.curve_kind.pricing().spot_price(&curve),
+            Some(2 * PRICE_SCALE)
+        );
+        assert_eq!(curve.get_sell_price(500), Some(750));
+        assert_eq!(curve.get_tokens_for_sell_sol(750), Some(500));
+        // graduation liquidity is priced at the spot price
+        assert_eq!(curve.get_graduation_liquidity(), Some((750, 375)));
+    }
+
+    #[test]
+    fn test_piecewise_pricing() {
+        // flat at 1 lamport for the first half, then rising to 3
+        let kind = piecewise(PRICE_SCALE as u64, &[(1, 0), (1, 2 * PRICE_SCALE as u64)]);
+        let mut curve = curve_with(kind, 1000);
+        assert_eq!(curve.get_max_attainable_sol(), Some(1500));
+        assert_eq!(curve.get_buy_price(500), Some(500));
+        assert_eq!(curve.get_buy_price(750), Some(875));
+
+        curve.apply_buy(875).unwrap();
+        assert_eq!(curve.real_token_reserves, 250);
+        assert_eq!(
+            curve.curve_kind.pricing().spot_price(&curve),
+            Some(2 * PRICE_SCALE)
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn fuzz_test_constant_product_monotonic(
+            bonding_supply in 1..1_000_000_000_000_000_000u64,
+            sol_amounts in (1..1_000_000_000_000u64, 1..1_000_000_000_000u64),
+            token_amounts in (1..1_000_000_000_000_000_000u64, 1..1_000_000_000_000_000_000u64),
+        ) {
+            check_monotonic(curve_with(CurveKind::ConstantProduct, bonding_supply), sol_amounts, token_amounts)?;
+        }
+
+        #[test]
+        fn fuzz_test_linear_monotonic(
+            start_price in 1..1_000_000_000u64,
+            rise in 0..1_000_000_000_000u64,
+            bonding_supply in 1..1_000_000_000_000_000_000u64,
+            sol_amounts in (1..1_000_000_000_000u64, 1..1_000_000_000_000u64),
+            token_amounts in (1..1_000_000_000_000_000_000u64, 1..1_000_000_000_000_000_000u64),
+        ) {
+            let kind = CurveKind::Linear(LinearCurve { start_price, end_price: start_price + rise });
+            check_monotonic(curve_with(kind, bonding_supply), sol_amounts, token_amounts)?;
+        }
+
+        #[test]
+        fn fuzz_test_linear_no_arbitrage(
+            start_price in 1..1_000_000_000u64,
+            rise in 0..1_000_000_000_000u64,
+            bonding_supply in 1..1_000_000_000_000_000_000u64,
+            swaps in proptest::collection::vec((0..4u8, 1..1_000_000_000_000u64), 1..20),
+        ) {
+            let kind = CurveKind::Linear(LinearCurve { start_price, end_price: start_price + rise });
+            check_no_arbitrage(curve_with(kind, bonding_supply), swaps)?;
+        }
+
+        #[test]
+        fn fuzz_test_exponential_monotonic(
+            start_price in 1..1_000_000_000u64,
+            growth_bps in 1..=CurveKind::MAX_GROWTH_BPS,
+            bonding_supply in 1..1_000_000_000_000_000_000u64,
+            sol_amounts in (1..1_000_000_000_000u64, 1..1_000_000_000_000u64),
+            token_amounts in (1..1_000_000_000_000_000_000u64, 1..1_000_000_000_000_000_000u64),
+        ) {
+            let kind = CurveKind::Exponential(ExponentialCurve { start_price, growth_bps });
+            check_monotonic(curve_with(kind, bonding_supply), sol_amounts, token_amounts)?;
+        }
+
+        #[test]
+        fn fuzz_test_exponential_no_arbitrage(
+            start_price in 1..1_000_000_000u64,
+            growth_bps in 1..=CurveKind::MAX_GROWTH_BPS,
+            bonding_supply in 1..1_000_000_000_000_000_000u64,
+            swaps in proptest::collection::vec((0..4u8, 1..1_000_000_000_000u64), 1..20),
+        ) {
+            let kind = CurveKind::Exponential(ExponentialCurve { start_price, growth_bps });
+            check_no_arbitrage(curve_with(kind, bonding_supply), swaps)?;
+        }
+
+        #[test]
+        fn fuzz_test_piecewise_linear_monotonic(
+            start_price in 1..1_000_000_000u64,
+            segments in proptest::collection::vec((1..100u64, 0..1_000_000_000_000u64), 1..=CurveKind::MAX_SEGMENTS),
+            bonding_supply in 1..1_000_000_000_000_000_000u64,
+            sol_amounts in (1..1_000_000_000_000u64, 1..1_000_000_000_000u64),
+            token_amounts in (1..1_000_000_000_000_000_000u64, 1..1_000_000_000_000_000_000u64),
+        ) {
+            let kind = piecewise(start_price, &segments);
+            prop_assert!(kind.is_valid());
+            check_monotonic(curve_with(kind, bonding_supply), sol_amounts, token_amounts)?;
+        }
+
+        #[test]
+        fn fuzz_test_piecewise_linear_no_arbitrage(
+            start_price in 1..1_000_000_000u64,
+            segments in proptest::collection::vec((1..100u64, 0..1_000_000_000_000u64), 1..=CurveKind::MAX_SEGMENTS),
+            bonding_supply in 1..1_000_000_000_000_000_000u64,
+            swaps in proptest::collection::vec((0..4u8, 1..1_000_000_000_000u64), 1..20),
+        ) {
+            check_no_arbitrage(curve_with(piecewise(start_price, &segments), bonding_supply), swaps)?;
+        }
+    }
+}
@@ -2,11 +2,29 @@ use crate::state::allocation::AllocationData;
 use anchor_lang::prelude::*;
 
 use crate::state::allocation::AllocationDataParams;
+use crate::state::allowlist::Allowlist;
+use crate::state::fee::FeeSchedule;
+use crate::state::presale::PresaleTerms;
+
+use super::pricing::CurveKind;
+
+// what the cliff and duration of a vesting schedule are measured from
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, InitSpace, AnchorDeserialize, Default,
+)]
+pub enum VestingAnchor {
+    #[default]
+    StartTime,
+    // nothing vests until the curve completes
+    CompletionTime,
+    Timestamp(i64),
+}
 
 #[derive(Debug, Clone, AnchorSerialize, InitSpace, AnchorDeserialize)]
 pub struct VestingTerms {
     pub cliff: i64,
     pub duration: i64,
+    pub anchor: VestingAnchor,
 }
 
 impl Default for VestingTerms {
@@ -14,10 +32,72 @@ impl Default for VestingTerms {
         VestingTerms {
             cliff: 7 * 24 * 60 * 60,     // 7 days
             duration: 31 * 24 * 60 * 60, // 31 days
+            anchor: VestingAnchor::StartTime,
+        }
+    }
+}
+
+impl VestingTerms {
+    // linear release of `total` over `duration` once the cliff after `anchor_time` is reached
+    pub fn get_vested_amount(&self, total: u64, anchor_time: i64, now: i64) -> Option<u64> {
+        let vesting_start = anchor_time.checked_add(self.cliff)?;
+        if now < vesting_start {
+            return Some(0);
+        }
+        let elapsed = now.checked_sub(vesting_start)?;
+        if self.duration <= 0 || elapsed >= self.duration {
+            return Some(total);
         }
+        (total as u128)
+            .checked_mul(elapsed as u128)?
+            .checked_div(self.duration as u128)?
+            .try_into()
+            .ok()
+    }
+}
+
+// buy limits for the first `window` seconds after the start, disabled with a zero window
+#[derive(Debug, Clone, Default, AnchorSerialize, InitSpace, AnchorDeserialize)]
+pub struct LaunchProtection {
+    pub window: i64,
+    pub max_buy_lamports: u64,
+    pub max_wallet_lamports: u64,
+}
+
+impl LaunchProtection {
+    pub fn is_valid(&self) -> bool {
+        self.window == 0
+            || (self.window > 0
+                && self.max_buy_lamports > 0
+                && self.max_wallet_lamports >= self.max_buy_lamports)
+    }
+
+    pub fn is_active(&self, start_time: i64, now: i64) -> bool {
+        self.window > 0 && now < start_time.saturating_add(self.window)
+    }
+
+    // a buy of `sol_amount` on top of what the wallet already bought during the window
+    pub fn allows_buy(&self, bought_lamports: u64, sol_amount: u64) -> bool {
+        sol_amount <= self.max_buy_lamports
+            && bought_lamports
+                .checked_add(sol_amount)
+                .is_some_and(|total| total <= self.max_wallet_lamports)
     }
 }
 
+// what happens to the tokens left on the curve beyond its graduation liquidity once it completes
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, InitSpace, AnchorDeserialize, Default,
+)]
+pub enum CompletionPolicy {
+    #[default]
+    Burn,
+    // added to the AMM pool on graduation, at a lower price than the curve's final price
+    GraduationLiquidity,
+    // vested to the platform along with its allocation
+    PlatformVault,
+}
+
 #[derive(Debug, Clone)]
 pub struct BuyResult {
     pub token_amount: u64,
@@ -61,8 +141,27 @@ pub struct BondingCurve {
     pub platform_supply: u64,
 
     pub sol_launch_threshold: u64,
+    pub presale_sol_raised: u64,
     pub start_time: i64,
     pub complete: bool,
+    pub graduated: bool,
+    // set by the first swap, a traded launch can no longer be cancelled
+    pub traded: bool,
+    // set when `complete` flips
+    pub completion_time: i64,
+    // swaps stop once it passes without completion, the curve can then be failed and refunded
+    pub graduation_deadline: Option<i64>,
+    pub failed: bool,
+    // buyer tokens still redeemable against the reserves, set when the curve fails
+    pub refund_token_supply: u64,
+    // paid into the platform vault at creation, the refund on cancel is a share of it
+    pub launch_fee_lamports: u64,
+
+    pub launch_protection: LaunchProtection,
+    pub fee_schedule: Option<FeeSchedule>,
+    pub allowlist: Option<Allowlist>,
+    pub curve_kind: CurveKind,
+    pub completion_policy: CompletionPolicy,
 
     pub vesting_terms: VestingTerms,
 
@@ -76,6 +175,7 @@ pub struct CreateBondingCurveParams {
     pub symbol: String,
     pub uri: String,
     pub start_time: Option<i64>,
+    pub graduation_deadline: Option<i64>,
 
     pub token_total_supply: u64,
     pub sol_launch_threshold: u64,
@@ -86,4 +186,18 @@ pub struct CreateBondingCurveParams {
     pub allocation: AllocationDataParams,
 
     pub vesting_terms: Option<VestingTerms>,
+
+    pub presale_terms: Option<PresaleTerms>,
+
+    pub brandkit_vesting_terms: Option<VestingTerms>,
+
+    pub launch_protection: Option<LaunchProtection>,
+
+    pub fee_schedule: Option<FeeSchedule>,
+
+    pub allowlist: Option<Allowlist>,
+
+    pub curve_kind: Option<CurveKind>,
+
+    pub completion_policy: Option<CompletionPolicy>,
 }
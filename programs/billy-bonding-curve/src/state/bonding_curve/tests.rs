@@ -2,10 +2,14 @@
 mod tests {
     use anchor_lang::prelude::{Clock, Pubkey};
     use once_cell::sync::Lazy;
-    use structs::{BondingCurve, CreateBondingCurveParams};
+    use structs::{BondingCurve, CompletionPolicy, CreateBondingCurveParams};
 
     use crate::{
-        state::{allocation::AllocationDataParams, bonding_curve::*},
+        state::{
+            allocation::AllocationDataParams,
+            bonding_curve::*,
+            fee::{FeeDecay, FeeSchedule},
+        },
         util::BASIS_POINTS_DIVISOR,
     };
     use std::{
@@ -23,17 +27,15 @@ mod tests {
         unix_timestamp: START_TIME.clone(),
         ..Clock::default()
     });
-    #[test]
-    fn test_buy_and_sell_too_much() {
-        let creator = Pubkey::default();
-        let mint = Pubkey::default();
-        let allocation = AllocationDataParams::default();
 
-        let params = CreateBondingCurveParams {
+    // the curve most tests start from, override only the fields under test
+    fn default_params() -> CreateBondingCurveParams {
+        CreateBondingCurveParams {
             name: "test".to_string(),
             symbol: "test".to_string(),
             uri: "test".to_string(),
             start_time: Some(*START_TIME),
+            graduation_deadline: None,
 
             token_total_supply: 2000,
             sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
@@ -41,9 +43,24 @@ mod tests {
             virtual_token_multiplier_bps: 730,
             virtual_sol_reserves: 600,
 
-            allocation,
+            allocation: AllocationDataParams::default(),
             vesting_terms: None,
-        };
+            presale_terms: None,
+            brandkit_vesting_terms: None,
+            launch_protection: None,
+            fee_schedule: None,
+            allowlist: None,
+            curve_kind: None,
+            completion_policy: None,
+        }
+    }
+
+    #[test]
+    fn test_buy_and_sell_too_much() {
+        let creator = Pubkey::default();
+        let mint = Pubkey::default();
+
+        let params = default_params();
         let mut bc = BondingCurve::default();
         let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
         let curve_initial = curve.clone();
@@ -82,23 +99,8 @@ mod tests {
     fn test_apply_sell() {
         let creator = Pubkey::default();
         let mint = Pubkey::default();
-        let allocation = AllocationDataParams::default();
-
-        let params = CreateBondingCurveParams {
-            name: "test".to_string(),
-            symbol: "test".to_string(),
-            uri: "test".to_string(),
-            start_time: Some(*START_TIME),
-
-            token_total_supply: 2000,
-            sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
 
-            virtual_token_multiplier_bps: 730,
-            virtual_sol_reserves: 600,
-
-            allocation,
-            vesting_terms: None,
-        };
+        let params = default_params();
         let mut bc = BondingCurve::default();
         let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
         // first apply buy
@@ -108,34 +110,20 @@ mod tests {
         let result = curve.apply_sell(200).unwrap();
         println!("{:?} \n", result);
         assert_eq!(result.token_amount, 200);
-        assert_eq!(result.sol_amount, 793);
+        // 1600 - ceil(1600 * 403 / 603)
+        assert_eq!(result.sol_amount, 530);
         assert_eq!(curve.virtual_token_reserves, 603);
         assert_eq!(curve.real_token_reserves, 530);
-        assert_eq!(curve.virtual_sol_reserves, 807);
-        assert_eq!(curve.real_sol_reserves, 207);
+        assert_eq!(curve.virtual_sol_reserves, 1070);
+        assert_eq!(curve.real_sol_reserves, 470);
     }
 
     #[test]
     fn test_get_sell_price() {
         let creator = Pubkey::default();
         let mint = Pubkey::default();
-        let allocation = AllocationDataParams::default();
-
-        let params = CreateBondingCurveParams {
-            name: "test".to_string(),
-            symbol: "test".to_string(),
-            uri: "test".to_string(),
-            start_time: Some(*START_TIME),
-
-            token_total_supply: 2000,
-            sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
 
-            virtual_token_multiplier_bps: 730,
-            virtual_sol_reserves: 600,
-
-            allocation,
-            vesting_terms: None,
-        };
+        let params = default_params();
         let mut bc = BondingCurve::default();
         let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
         // first apply buy
@@ -146,7 +134,7 @@ mod tests {
         assert_eq!(curve.get_sell_price(0), None);
 
         // Normal case
-        assert_eq!(curve.get_sell_price(396), Some(1000));
+        assert_eq!(curve.get_sell_price(396), Some(792));
 
         // Should not exceed real sol reserves
         assert_eq!(curve.get_sell_price(5000), None);
@@ -156,23 +144,8 @@ mod tests {
     fn test_apply_buy() {
         let creator = Pubkey::default();
         let mint = Pubkey::default();
-        let allocation = AllocationDataParams::default();
-
-        let params = CreateBondingCurveParams {
-            name: "test".to_string(),
-            symbol: "test".to_string(),
-            uri: "test".to_string(),
-            start_time: Some(*START_TIME),
-
-            token_total_supply: 2000,
-            sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
-
-            virtual_token_multiplier_bps: 730,
-            virtual_sol_reserves: 600,
 
-            allocation,
-            vesting_terms: None,
-        };
+        let params = default_params();
         let mut bc = BondingCurve::default();
         let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
         let curve_initial = curve.clone();
@@ -196,28 +169,100 @@ mod tests {
     }
 
     #[test]
-    fn test_get_buy_price() {
+    fn test_apply_buy_capped() {
         let creator = Pubkey::default();
         let mint = Pubkey::default();
-        let allocation = AllocationDataParams::default();
 
         let params = CreateBondingCurveParams {
-            name: "test".to_string(),
-            symbol: "test".to_string(),
-            uri: "test".to_string(),
-            start_time: Some(*START_TIME),
-
-            token_total_supply: 2000,
-            sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
+            sol_launch_threshold: 1000,
+            ..default_params()
+        };
+        let mut bc = BondingCurve::default();
+        let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
+        let curve_initial = curve.clone();
+        assert_eq!(curve.get_sol_to_threshold(), 1000);
+
+        // below the threshold the buy is filled in full
+        let result = curve.clone().apply_buy_capped(400).unwrap();
+        assert_eq!(result.sol_amount, 400);
+
+        // landing exactly on the threshold
+        let expected = curve.clone().apply_buy(1000).unwrap();
+        let result = curve.clone().apply_buy_capped(1000).unwrap();
+        assert_eq!(result.sol_amount, 1000);
+        assert_eq!(result.token_amount, expected.token_amount);
+
+        // crossing it, only the SOL up to the threshold is taken
+        let result = curve.apply_buy_capped(1500).unwrap();
+        assert_eq!(result.sol_amount, 1000);
+        assert_eq!(result.token_amount, expected.token_amount);
+        assert_eq!(curve.get_sol_to_threshold(), 0);
+        assert_eq!(curve.real_sol_reserves, 1000);
+        // nothing more fills once it is reached
+        assert!(curve.clone().apply_buy_capped(1).is_none());
+
+        // presale SOL counts toward the threshold
+        let mut curve = curve_initial.clone();
+        curve.presale_sol_raised = 600;
+        assert_eq!(curve.apply_buy_capped(1500).unwrap().sol_amount, 400);
+
+        // the remaining tokens are cheaper than the SOL offered
+        let mut curve = curve_initial.clone();
+        curve.sol_launch_threshold = u64::MAX;
+        let cost = curve.get_buy_price(curve.real_token_reserves).unwrap();
+        let result = curve.apply_buy_capped(cost * 10).unwrap();
+        assert_eq!(result.token_amount, curve_initial.real_token_reserves);
+        assert_eq!(result.sol_amount, cost);
+        assert_eq!(curve.real_token_reserves, 0);
+    }
 
-            virtual_token_multiplier_bps: 730,
-            virtual_sol_reserves: 600,
+    #[test]
+    fn test_apply_buy_exact_out_capped() {
+        let creator = Pubkey::default();
+        let mint = Pubkey::default();
 
-            allocation,
-            vesting_terms: None,
+        let params = CreateBondingCurveParams {
+            sol_launch_threshold: 1000,
+            ..default_params()
         };
         let mut bc = BondingCurve::default();
         let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
+        let curve_initial = curve.clone();
+
+        // landing exactly on the threshold fills every token asked for
+        let cost = curve.get_buy_price(500).unwrap();
+        let mut exact = curve_initial.clone();
+        exact.sol_launch_threshold = cost;
+        let result = exact.apply_buy_exact_out_capped(500).unwrap();
+        assert_eq!(result.token_amount, 500);
+        assert_eq!(result.sol_amount, cost);
+        assert_eq!(exact.get_sol_to_threshold(), 0);
+
+        // crossing it fills what the SOL up to the threshold buys
+        let expected = curve.clone().apply_buy(1000).unwrap();
+        assert!(curve.get_buy_price(expected.token_amount + 1).unwrap() > 1000);
+        let result = curve
+            .apply_buy_exact_out_capped(expected.token_amount + 1)
+            .unwrap();
+        assert_eq!(result.sol_amount, 1000);
+        assert_eq!(result.token_amount, expected.token_amount);
+
+        // asking for more than the curve holds fills the rest
+        let mut curve = curve_initial.clone();
+        curve.sol_launch_threshold = u64::MAX;
+        let result = curve.apply_buy_exact_out_capped(u64::MAX).unwrap();
+        assert_eq!(result.token_amount, curve_initial.real_token_reserves);
+        assert_eq!(curve.real_token_reserves, 0);
+    }
+
+    #[test]
+    fn test_get_buy_price() {
+        let creator = Pubkey::default();
+        let mint = Pubkey::default();
+
+        let params = default_params();
+        let mut bc = BondingCurve::default();
+        let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
         // let _curve_initial = curve.clone();
         assert_eq!(curve.get_buy_price(0), None);
 
@@ -232,23 +277,8 @@ mod tests {
     fn test_get_tokens_for_buy_sol() {
         let creator = Pubkey::default();
         let mint = Pubkey::default();
-        let allocation = AllocationDataParams::default();
-
-        let params = CreateBondingCurveParams {
-            name: "test".to_string(),
-            symbol: "test".to_string(),
-            uri: "test".to_string(),
-            start_time: Some(*START_TIME),
-
-            token_total_supply: 2000,
-            sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
-
-            virtual_token_multiplier_bps: 730,
-            virtual_sol_reserves: 600,
 
-            allocation,
-            vesting_terms: None,
-        };
+        let params = default_params();
         let mut bc = BondingCurve::default();
         let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
         // let _curve_initial = curve.clone();
@@ -273,31 +303,18 @@ mod tests {
     fn test_get_tokens_for_sell_sol() {
         let creator = Pubkey::default();
         let mint = Pubkey::default();
-        let allocation = AllocationDataParams::default();
-
-        let params = CreateBondingCurveParams {
-            name: "test".to_string(),
-            symbol: "test".to_string(),
-            uri: "test".to_string(),
-            start_time: Some(*START_TIME),
-
-            token_total_supply: 2000,
-            sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
-
-            virtual_token_multiplier_bps: 730,
-            virtual_sol_reserves: 600,
 
-            allocation,
-            vesting_terms: None,
-        };
+        let params = default_params();
         let mut bc = BondingCurve::default();
         let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
         // let _curve_initial = curve.clone();
         // first apply buy
         curve.apply_buy(1000).unwrap();
 
-        // Test case 1: Normal case
-        assert_eq!(curve.get_tokens_for_sell_sol(100), Some(25));
+        // Test case 1: Normal case, rounded up in favour of the curve
+        assert_eq!(curve.get_tokens_for_sell_sol(100), Some(27));
+        assert!(curve.get_sell_price(27).unwrap() >= 100);
+        assert!(curve.get_sell_price(26).unwrap() < 100);
 
         // Test case 2: Edge case - zero SOL
         assert_eq!(curve.get_tokens_for_sell_sol(0), None);
@@ -306,7 +323,271 @@ mod tests {
         assert_eq!(curve.get_tokens_for_sell_sol(1001), None);
 
         // Test case 4: Large SOL amount (but within limits)
-        assert_eq!(curve.get_tokens_for_sell_sol(500), Some(125));
+        assert_eq!(curve.get_tokens_for_sell_sol(500), Some(184));
+        assert!(curve.get_sell_price(184).unwrap() >= 500);
+        assert!(curve.get_sell_price(183).unwrap() < 500);
+    }
+
+    #[test]
+    fn test_get_graduation_liquidity() {
+        let creator = Pubkey::default();
+        let mint = Pubkey::default();
+
+        let params = default_params();
+        let mut bc = BondingCurve::default();
+        let curve = bc.update_from_params(mint, creator, creator, creator, &params, &CLOCK, 0);
+        // nothing raised yet
+        assert_eq!(curve.get_graduation_liquidity(), Some((0, 0)));
+
+        curve.apply_buy(1000).unwrap();
+        // tokens are priced at the final curve price: 1000 * 403 / 1600
+        assert_eq!(curve.get_graduation_liquidity(), Some((1000, 251)));
+
+        // never more tokens than the curve holds
+        curve.apply_buy(100000).unwrap();
+        let (sol_amount, token_amount) = curve.get_graduation_liquidity().unwrap();
+        assert_eq!(sol_amount, curve.real_sol_reserves);
+        assert_eq!(token_amount, curve.real_token_reserves);
+    }
+
+    #[test]
+    fn test_get_vested_amount() {
+        let terms = VestingTerms {
+            cliff: 10,
+            duration: 100,
+            anchor: VestingAnchor::StartTime,
+        };
+        let start = *START_TIME;
+        // nothing before the cliff
+        assert_eq!(terms.get_vested_amount(1000, start, start), Some(0));
+        assert_eq!(terms.get_vested_amount(1000, start, start + 9), Some(0));
+        // linear over the duration once the cliff is reached
+        assert_eq!(terms.get_vested_amount(1000, start, start + 10), Some(0));
+        assert_eq!(terms.get_vested_amount(1000, start, start + 35), Some(250));
+        assert_eq!(terms.get_vested_amount(1000, start, start + 109), Some(990));
+        // fully vested afterwards
+        assert_eq!(
+            terms.get_vested_amount(1000, start, start + 110),
+            Some(1000)
+        );
+        assert_eq!(terms.get_vested_amount(1000, start, i64::MAX), Some(1000));
+
+        let no_duration = VestingTerms {
+            cliff: 0,
+            duration: 0,
+            anchor: VestingAnchor::StartTime,
+        };
+        assert_eq!(
+            no_duration.get_vested_amount(1000, start, start),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_graduation_deadline() {
+        let mut curve = BondingCurve {
+            start_time: *START_TIME,
+            ..Default::default()
+        };
+        let at = |offset: i64| Clock {
+            unix_timestamp: *START_TIME + offset,
+            ..Clock::default()
+        };
+        // no deadline, never past it
+        assert!(!curve.is_past_graduation_deadline(&at(i64::MAX / 2)));
+
+        curve.graduation_deadline = Some(*START_TIME + 100);
+        assert!(!curve.is_past_graduation_deadline(&at(99)));
+        assert!(curve.is_past_graduation_deadline(&at(100)));
+
+        // a completed curve can't miss its deadline
+        curve.mark_complete(&at(50));
+        assert!(!curve.is_past_graduation_deadline(&at(100)));
+    }
+
+    #[test]
+    fn test_is_valid_start_time() {
+        let now = *START_TIME;
+        let lead_time = 7 * 24 * 60 * 60;
+        // right away or backdated
+        assert!(BondingCurve::is_valid_start_time(now, now, lead_time));
+        assert!(BondingCurve::is_valid_start_time(now - 100, now, lead_time));
+        // scheduled within the lead time
+        assert!(BondingCurve::is_valid_start_time(
+            now + lead_time,
+            now,
+            lead_time
+        ));
+        assert!(!BondingCurve::is_valid_start_time(
+            now + lead_time + 1,
+            now,
+            lead_time
+        ));
+        // no lead time, no scheduling
+        assert!(!BondingCurve::is_valid_start_time(now + 1, now, 0));
+    }
+
+    #[test]
+    fn test_launch_protection() {
+        let start = *START_TIME;
+        let protection = LaunchProtection {
+            window: 60,
+            max_buy_lamports: 1_000,
+            max_wallet_lamports: 2_500,
+        };
+        assert!(protection.is_valid());
+        assert!(protection.is_active(start, start));
+        assert!(protection.is_active(start, start + 59));
+        // lifts after the window
+        assert!(!protection.is_active(start, start + 60));
+
+        assert!(protection.allows_buy(0, 1_000));
+        assert!(!protection.allows_buy(0, 1_001));
+        assert!(protection.allows_buy(1_500, 1_000));
+        assert!(!protection.allows_buy(2_000, 1_000));
+        assert!(!protection.allows_buy(u64::MAX, 1));
+
+        // disabled by default
+        let disabled = LaunchProtection::default();
+        assert!(disabled.is_valid());
+        assert!(!disabled.is_active(start, start));
+
+        // a wallet cap below the per-transaction cap
+        let invalid = LaunchProtection {
+            max_wallet_lamports: 999,
+            ..protection
+        };
+        assert!(!invalid.is_valid());
+    }
+
+    #[test]
+    fn test_get_fee_bps() {
+        let start = *START_TIME;
+        let mut curve = BondingCurve {
+            start_time: start,
+            ..Default::default()
+        };
+        // flat global fee without a schedule
+        assert_eq!(curve.get_fee_bps(100, start), 100);
+
+        curve.fee_schedule = Some(FeeSchedule {
+            initial_fee_bps: 1_000,
+            floor_fee_bps: 0,
+            duration: 100,
+            decay: FeeDecay::Linear,
+        });
+        assert_eq!(curve.get_fee_bps(100, start), 1_000);
+        assert_eq!(curve.get_fee_bps(100, start + 50), 500);
+        // the schedule can't go below the global fee
+        assert_eq!(curve.get_fee_bps(100, start + 95), 100);
+        assert_eq!(curve.get_fee_bps(100, start + 100), 100);
+    }
+
+    #[test]
+    fn test_apply_refund() {
+        let mut curve = BondingCurve {
+            real_sol_reserves: 1000,
+            refund_token_supply: 300,
+            ..Default::default()
+        };
+        // 1/3 of the supply
+        assert_eq!(curve.apply_refund(100), Some(333));
+        assert_eq!(curve.real_sol_reserves, 667);
+        assert_eq!(curve.refund_token_supply, 200);
+        // 1/2 of what is left
+        assert_eq!(curve.apply_refund(100), Some(333));
+        // more than the supply
+        assert_eq!(curve.apply_refund(101), None);
+        // the last tokens take the remainder
+        assert_eq!(curve.apply_refund(100), Some(334));
+        assert_eq!(curve.real_sol_reserves, 0);
+        assert_eq!(curve.refund_token_supply, 0);
+    }
+
+    #[test]
+    fn test_refund_buyers_only() {
+        let mut curve = BondingCurve::default();
+        curve.update_from_params(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            &default_params(),
+            &CLOCK,
+            0,
+        );
+        let buy = curve.apply_buy(1000).unwrap();
+        assert_eq!(curve.get_buyer_token_supply(), Some(buy.token_amount));
+
+        // a presale adds its allocation to the buyer supply
+        let mut presale = curve.clone();
+        presale.presale_supply = 100;
+        presale.presale_sol_raised = 50;
+        assert_eq!(
+            presale.get_buyer_token_supply(),
+            Some(buy.token_amount + 100)
+        );
+
+        // the allocations outside the curve don't dilute the buyers
+        curve.refund_token_supply = curve.get_buyer_token_supply().unwrap();
+        assert!(curve.token_total_supply > curve.refund_token_supply);
+        assert_eq!(curve.get_refund_amount(buy.token_amount), Some(1000));
+        assert_eq!(
+            curve.get_refund_amount(buy.token_amount / 2),
+            Some(1000 * (buy.token_amount / 2) / buy.token_amount)
+        );
+    }
+
+    #[test]
+    fn test_can_release_allocations() {
+        // a curve without a deadline never refunds
+        let mut curve = BondingCurve::default();
+        assert!(curve.can_release_allocations());
+
+        // one that may still fail holds the allocations until it completes
+        curve.graduation_deadline = Some(*START_TIME + 1000);
+        assert!(!curve.can_release_allocations());
+        curve.complete = true;
+        assert!(curve.can_release_allocations());
+    }
+
+    #[test]
+    fn test_get_surplus_lamports() {
+        let curve = BondingCurve {
+            real_sol_reserves: 1000,
+            ..Default::default()
+        };
+        // rent and reserves only
+        assert_eq!(curve.get_surplus_lamports(1100, 100), 0);
+        // a donation on top
+        assert_eq!(curve.get_surplus_lamports(1150, 100), 50);
+        // never negative
+        assert_eq!(curve.get_surplus_lamports(900, 100), 0);
+    }
+
+    #[test]
+    fn test_apply_completion_policy() {
+        let mut curve = BondingCurve {
+            virtual_sol_reserves: 100,
+            virtual_token_reserves: 1000,
+            real_sol_reserves: 50,
+            real_token_reserves: 800,
+            ..Default::default()
+        };
+        // 500 tokens match the raised SOL at the final price
+        assert_eq!(curve.get_graduation_liquidity(), Some((50, 500)));
+        assert_eq!(curve.get_unsold_token_amount(), Some(300));
+
+        let mut graduating = curve.clone();
+        graduating.completion_policy = CompletionPolicy::GraduationLiquidity;
+        assert_eq!(graduating.get_graduation_liquidity(), Some((50, 800)));
+        assert_eq!(graduating.apply_completion_policy(), Some(0));
+        assert_eq!(graduating.real_token_reserves, 800);
+
+        assert_eq!(curve.apply_completion_policy(), Some(300));
+        assert_eq!(curve.real_token_reserves, 500);
+        // settled once
+        assert_eq!(curve.get_unsold_token_amount(), Some(0));
     }
 
     // FUZZ TESTS
@@ -327,22 +608,12 @@ mod tests {
         ) {
             let creator = Pubkey::default();
             let mint = Pubkey::default();
-            let allocation = AllocationDataParams::default();
 
             let params = CreateBondingCurveParams {
-                name: "test".to_string(),
-                symbol: "test".to_string(),
-                uri: "test".to_string(),
-                start_time: Some(*START_TIME),
-
                 token_total_supply,
-                sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
-
                 virtual_token_multiplier_bps,
                 virtual_sol_reserves,
-
-                allocation,
-                vesting_terms:None,
+                ..default_params()
             };
             let mut bc = BondingCurve::default();
             let curve = bc.update_from_params(mint,creator,creator, creator, &params, &CLOCK, 0);
@@ -367,22 +638,72 @@ mod tests {
         ) {
             let creator = Pubkey::default();
             let mint = Pubkey::default();
-            let allocation = AllocationDataParams::default();
 
             let params = CreateBondingCurveParams {
-                name: "test".to_string(),
-                symbol: "test".to_string(),
-                uri: "test".to_string(),
-                start_time: Some(*START_TIME),
-
                 token_total_supply,
-                sol_launch_threshold: *SOL_LAUNCH_THRESHOLD,
+                virtual_token_multiplier_bps,
+                virtual_sol_reserves,
+                ..default_params()
+            };
+            let mut bc = BondingCurve::default();
+            let curve = bc.update_from_params(mint,creator,creator, creator, &params, &CLOCK, 0);
+            let buy_result = curve.apply_buy(buy_sol_amount);
+            if buy_result.is_none() {
+                return Ok(())
+            }
+            let _curve_after_buy = curve.clone();
+            if let Some(result) = curve.apply_sell(token_amount) {
+                prop_assert!(result.sol_amount <= _curve_after_buy.real_sol_reserves, "SOL amount to send to seller should not exceed real SOL reserves");
+            }
+        }
 
+        #[test]
+        fn fuzz_test_default_alloc_simple_curve_apply_buy_exact_out(
+            virtual_sol_reserves in 1..u64::MAX,
+            token_total_supply in 1..u64::MAX,
+            token_amount in 1..u64::MAX,
+            virtual_token_multiplier_bps in 1..BASIS_POINTS_DIVISOR,
+        ) {
+            let creator = Pubkey::default();
+            let mint = Pubkey::default();
+
+            let params = CreateBondingCurveParams {
+                token_total_supply,
                 virtual_token_multiplier_bps,
                 virtual_sol_reserves,
+                ..default_params()
+            };
+            let mut bc = BondingCurve::default();
+            let curve = bc.update_from_params(mint,creator,creator, creator, &params, &CLOCK, 0);
+            let _curve_initial = curve.clone();
+
+            if let Some(result) = curve.apply_buy_exact_out(token_amount) {
+                prop_assert_eq!(result.token_amount, token_amount, "Exactly the requested tokens should be bought");
+                prop_assert!(result.token_amount <= _curve_initial.real_token_reserves, "Token amount bought should not exceed real token reserves");
+                // the same SOL spent exact-in buys at least as many tokens
+                if let Some(exact_in) = _curve_initial.clone().apply_buy(result.sol_amount) {
+                    prop_assert!(exact_in.token_amount >= token_amount, "Exact-out buy should not be cheaper than exact-in");
+                }
+            }
+        }
+
+        #[test]
+        fn fuzz_test_default_alloc_simple_curve_apply_sell_exact_out(
+            virtual_sol_reserves in 1..u64::MAX,
+            token_total_supply in 1..u64::MAX,
+
+            sol_amount in 1..u64::MAX,
+            buy_sol_amount in 1..u64::MAX,
+            virtual_token_multiplier_bps in 1..BASIS_POINTS_DIVISOR,
+        ) {
+            let creator = Pubkey::default();
+            let mint = Pubkey::default();
 
-                allocation,
-                vesting_terms:None,
+            let params = CreateBondingCurveParams {
+                token_total_supply,
+                virtual_token_multiplier_bps,
+                virtual_sol_reserves,
+                ..default_params()
             };
             let mut bc = BondingCurve::default();
             let curve = bc.update_from_params(mint,creator,creator, creator, &params, &CLOCK, 0);
@@ -391,10 +712,169 @@ mod tests {
                 return Ok(())
             }
             let _curve_after_buy = curve.clone();
-            if let Some(result) = curve.apply_sell(token_amount) {
+            if let Some(result) = curve.apply_sell_exact_out(sol_amount) {
+                prop_assert_eq!(result.sol_amount, sol_amount, "Exactly the requested SOL should be paid out");
                 prop_assert!(result.sol_amount <= _curve_after_buy.real_sol_reserves, "SOL amount to send to seller should not exceed real SOL reserves");
+                // the same tokens sold exact-in are worth at least as much, and one token less is not enough
+                let sell_price = _curve_after_buy.get_sell_price(result.token_amount);
+                prop_assert!(sell_price.is_some_and(|price| price >= sol_amount), "Exact-out sell should not pay more than exact-in");
+                if result.token_amount > 1 {
+                    let lower_price = _curve_after_buy.get_sell_price(result.token_amount - 1).unwrap_or(0);
+                    prop_assert!(lower_price < sol_amount, "Exact-out sell should not take more tokens than needed");
+                }
+            }
+        }
+
+        #[test]
+        fn fuzz_test_default_alloc_simple_curve_buy_sell_round_trip(
+            virtual_sol_reserves in 1..u64::MAX,
+            token_total_supply in 1..u64::MAX,
+            buy_sol_amount in 1..u64::MAX,
+            virtual_token_multiplier_bps in 1..BASIS_POINTS_DIVISOR,
+        ) {
+            let creator = Pubkey::default();
+            let mint = Pubkey::default();
+
+            let params = CreateBondingCurveParams {
+                token_total_supply,
+                virtual_token_multiplier_bps,
+                virtual_sol_reserves,
+                ..default_params()
+            };
+            let mut bc = BondingCurve::default();
+            let curve = bc.update_from_params(mint,creator,creator, creator, &params, &CLOCK, 0);
+            let buy_result = match curve.apply_buy(buy_sol_amount) {
+                Some(result) if result.token_amount > 0 => result,
+                _ => return Ok(()),
+            };
+            if let Some(sell_result) = curve.apply_sell(buy_result.token_amount) {
+                prop_assert!(sell_result.sol_amount <= buy_result.sol_amount, "Selling bought tokens should not return more SOL than was paid");
+            }
+        }
+
+        #[test]
+        fn fuzz_test_default_alloc_simple_curve_no_arbitrage(
+            virtual_sol_reserves in 1..1_000_000_000_000u64,
+            token_total_supply in 1..1_000_000_000_000_000_000u64,
+            virtual_token_multiplier_bps in 1..BASIS_POINTS_DIVISOR,
+            // (kind, amount): 0 buy exact-in, 1 sell exact-in, 2 buy exact-out, 3 sell exact-out
+            swaps in proptest::collection::vec((0..4u8, 1..1_000_000_000_000u64), 1..20),
+        ) {
+            let creator = Pubkey::default();
+            let mint = Pubkey::default();
+
+            let params = CreateBondingCurveParams {
+                token_total_supply,
+                virtual_token_multiplier_bps,
+                virtual_sol_reserves,
+                ..default_params()
+            };
+            let mut bc = BondingCurve::default();
+            let curve = bc.update_from_params(mint,creator,creator, creator, &params, &CLOCK, 0);
+
+            let mut sol_in: u128 = 0;
+            let mut sol_out: u128 = 0;
+            let mut tokens_held: u64 = 0;
+            for (kind, amount) in swaps {
+                let product_before = (curve.virtual_sol_reserves as u128).checked_mul(curve.virtual_token_reserves);
+                match kind {
+                    0 | 2 => {
+                        let result = if kind == 0 { curve.apply_buy(amount) } else { curve.apply_buy_exact_out(amount) };
+                        if let Some(result) = result {
+                            sol_in += result.sol_amount as u128;
+                            tokens_held += result.token_amount;
+                        }
+                    }
+                    _ => {
+                        let mut quote = curve.clone();
+                        let result = if kind == 1 { quote.apply_sell(amount.min(tokens_held)) } else { quote.apply_sell_exact_out(amount) };
+                        // only tokens bought from the curve can be sold back
+                        if let Some(result) = result.filter(|result| result.token_amount <= tokens_held) {
+                            curve.clone_from(&quote);
+                            sol_out += result.sol_amount as u128;
+                            tokens_held -= result.token_amount;
+                        }
+                    }
+                }
+                if let (Some(before), Some(after)) = (product_before, (curve.virtual_sol_reserves as u128).checked_mul(curve.virtual_token_reserves)) {
+                    prop_assert!(after >= before, "Constant product should never decrease");
+                }
+                prop_assert_eq!(curve.real_sol_reserves as u128, sol_in - sol_out, "Real SOL reserves should track the SOL in the curve");
+            }
+
+            // dump everything that is left
+            if tokens_held > 0 {
+                if let Some(result) = curve.apply_sell(tokens_held) {
+                    sol_out += result.sol_amount as u128;
+                }
+            }
+            prop_assert!(sol_out <= sol_in, "No buy/sell sequence should extract more SOL than was put in");
+        }
+
+        #[test]
+        fn fuzz_test_buy_capped_at_threshold(
+            virtual_sol_reserves in 1..1_000_000_000_000u64,
+            token_total_supply in 1..1_000_000_000_000_000_000u64,
+            virtual_token_multiplier_bps in 1..BASIS_POINTS_DIVISOR,
+            sol_launch_threshold in 1..1_000_000_000_000u64,
+            // (exact_out, amount)
+            buys in proptest::collection::vec((any::<bool>(), 1..1_000_000_000_000u64), 1..10),
+        ) {
+            let mut curve = BondingCurve::default();
+            curve.update_from_params(Pubkey::default(), Pubkey::default(), Pubkey::default(), Pubkey::default(), &CreateBondingCurveParams {
+                token_total_supply,
+                sol_launch_threshold,
+                virtual_token_multiplier_bps,
+                virtual_sol_reserves,
+                ..default_params()
+            }, &CLOCK, 0);
+            for (exact_out, amount) in buys {
+                let before = curve.clone();
+                let result = if exact_out { curve.apply_buy_exact_out_capped(amount) } else { curve.apply_buy_capped(amount) };
+                if let Some(result) = result {
+                    prop_assert!(result.sol_amount <= before.get_sol_to_threshold(), "A buy should never overshoot the launch threshold");
+                    prop_assert!(result.token_amount <= before.real_token_reserves);
+                    if exact_out {
+                        prop_assert!(result.token_amount <= amount);
+                    } else {
+                        prop_assert!(result.sol_amount <= amount);
+                    }
+                }
+                prop_assert!(curve.real_sol_reserves <= sol_launch_threshold);
             }
         }
 
+        #[test]
+        fn fuzz_test_refunds_drain_reserves(
+            real_sol_reserves in 0..u64::MAX,
+            token_supply in 1..u64::MAX,
+            mut burns in prop::collection::vec(1..u64::MAX, 0..20),
+        ) {
+            let mut curve = BondingCurve {
+                real_sol_reserves,
+                refund_token_supply: token_supply,
+                ..Default::default()
+            };
+            let mut supply = token_supply;
+            let mut refunded: u128 = 0;
+            for burn in burns.iter_mut() {
+                let amount = (*burn % supply).max(1);
+                let share = (real_sol_reserves as u128 * amount as u128) / token_supply as u128;
+                let sol_amount = curve.apply_refund(amount).unwrap();
+                prop_assert!(sol_amount as u128 + 1 >= share, "Refunds should not drop below the pro-rata share");
+                refunded += sol_amount as u128;
+                supply -= amount;
+                if supply == 0 {
+                    break;
+                }
+            }
+
+            // the last holders redeem the rest
+            if supply > 0 {
+                refunded += curve.apply_refund(supply).unwrap() as u128;
+            }
+            prop_assert_eq!(refunded, real_sol_reserves as u128, "Refunds should pay out exactly the reserves");
+            prop_assert_eq!(curve.real_sol_reserves, 0);
+        }
     }
 }
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::util::{bps_mul, BASIS_POINTS_DIVISOR};
+
+pub fn calculate_fee(fee_bps: u64, amount: u64) -> u64 {
+    bps_mul(fee_bps, amount).unwrap()
+}
+
+// smallest amount that is still worth `net_amount` after calculate_fee is taken out
+pub fn calculate_amount_before_fee(fee_bps: u64, net_amount: u64) -> Option<u64> {
+    if net_amount == 0 {
+        return Some(0);
+    }
+    let net_bps = BASIS_POINTS_DIVISOR.checked_sub(fee_bps)?;
+    ((net_amount as u128 - 1)
+        .checked_mul(BASIS_POINTS_DIVISOR as u128)?
+        .checked_div(net_bps as u128)?
+        + 1)
+    .try_into()
+    .ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum FeeDecay {
+    Linear,
+    // drops in `steps` equal steps over the duration
+    Stepwise { steps: u32 },
+}
+
+// trade fee that decays from `initial_fee_bps` to `floor_fee_bps` over `duration` seconds from the curve start
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct FeeSchedule {
+    pub initial_fee_bps: u64,
+    pub floor_fee_bps: u64,
+    pub duration: i64,
+    pub decay: FeeDecay,
+}
+
+impl FeeSchedule {
+    // a schedule can't charge more than the platform could through the global trade fee
+    pub fn is_valid(&self, max_fee_bps: u64) -> bool {
+        self.initial_fee_bps <= max_fee_bps
+            && self.floor_fee_bps <= self.initial_fee_bps
+            && self.duration > 0
+            && !matches!(self.decay, FeeDecay::Stepwise { steps: 0 })
+    }
+
+    pub fn get_fee_bps(&self, start_time: i64, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(start_time).max(0);
+        if self.duration <= 0 || elapsed >= self.duration {
+            return self.floor_fee_bps;
+        }
+        let range = self.initial_fee_bps.saturating_sub(self.floor_fee_bps) as u128;
+        let decayed = match self.decay {
+            FeeDecay::Linear => range * elapsed as u128 / self.duration as u128,
+            FeeDecay::Stepwise { steps } => {
+                let step = elapsed as u128 * steps as u128 / self.duration as u128;
+                range * step / steps.max(1) as u128
+            }
+        };
+        self.initial_fee_bps - decayed as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::global::SettingsCaps;
+
+    #[test]
+    fn test_calculate_fee() {
+        assert_eq!(calculate_fee(100, 100), 1); //1% fee
+        assert_eq!(calculate_fee(1000, 100), 10); //10% fee
+        assert_eq!(calculate_fee(5000, 100), 50); //50% fee
+        assert_eq!(calculate_fee(50000, 100), 500); //500% fee
+        assert_eq!(calculate_fee(50, 100), 0); //0.5% fee
+        assert_eq!(calculate_fee(50, 1000), 5); //0.5% fee
+        assert_eq!(calculate_fee(0, 100), 0); //0% fee
+    }
+
+    #[test]
+    fn test_calculate_amount_before_fee() {
+        for fee_bps in [0, 1, 50, 100, 1000, 5000, 9999] {
+            for net_amount in [1, 2, 99, 100, 1000, 123_456_789, u32::MAX as u64] {
+                let amount = calculate_amount_before_fee(fee_bps, net_amount).unwrap();
+                // exactly net_amount is left after the fee
+                assert_eq!(amount - calculate_fee(fee_bps, amount), net_amount);
+                // and no smaller amount would do
+                assert!(amount - 1 - calculate_fee(fee_bps, amount - 1) < net_amount);
+            }
+        }
+
+        assert_eq!(calculate_amount_before_fee(100, 0), Some(0));
+        assert_eq!(calculate_amount_before_fee(100, 198), Some(199)); //1% fee
+        assert_eq!(calculate_amount_before_fee(10000, 1), None);
+    }
+
+    #[test]
+    fn test_fee_schedule() {
+        let max_fee_bps = SettingsCaps::default().max_trade_fee_bps;
+        let start = 1_700_000_000;
+        let linear = FeeSchedule {
+            initial_fee_bps: 1_000,
+            floor_fee_bps: 100,
+            duration: 100,
+            decay: FeeDecay::Linear,
+        };
+        assert!(linear.is_valid(max_fee_bps));
+        assert_eq!(linear.get_fee_bps(start, start - 10), 1_000);
+        assert_eq!(linear.get_fee_bps(start, start), 1_000);
+        assert_eq!(linear.get_fee_bps(start, start + 50), 550);
+        assert_eq!(linear.get_fee_bps(start, start + 99), 109);
+        assert_eq!(linear.get_fee_bps(start, start + 100), 100);
+        assert_eq!(linear.get_fee_bps(start, i64::MAX), 100);
+
+        let stepwise = FeeSchedule {
+            decay: FeeDecay::Stepwise { steps: 4 },
+            ..linear.clone()
+        };
+        assert!(stepwise.is_valid(max_fee_bps));
+        assert_eq!(stepwise.get_fee_bps(start, start + 24), 1_000);
+        assert_eq!(stepwise.get_fee_bps(start, start + 25), 775);
+        assert_eq!(stepwise.get_fee_bps(start, start + 74), 550);
+        assert_eq!(stepwise.get_fee_bps(start, start + 75), 325);
+        assert_eq!(stepwise.get_fee_bps(start, start + 100), 100);
+
+        // never rises over time
+        for schedule in [&linear, &stepwise] {
+            let mut previous = schedule.initial_fee_bps;
+            for offset in 0..=110 {
+                let fee_bps = schedule.get_fee_bps(start, start + offset);
+                assert!(fee_bps <= previous);
+                assert!(fee_bps >= schedule.floor_fee_bps);
+                previous = fee_bps;
+            }
+        }
+
+        assert!(!FeeSchedule {
+            floor_fee_bps: 1_001,
+            ..linear.clone()
+        }
+        .is_valid(max_fee_bps));
+        // capped like the global trade fee
+        assert!(FeeSchedule {
+            initial_fee_bps: max_fee_bps,
+            ..linear.clone()
+        }
+        .is_valid(max_fee_bps));
+        assert!(!FeeSchedule {
+            initial_fee_bps: max_fee_bps + 1,
+            ..linear.clone()
+        }
+        .is_valid(max_fee_bps));
+        assert!(!FeeSchedule {
+            duration: 0,
+            ..linear.clone()
+        }
+        .is_valid(max_fee_bps));
+        assert!(!FeeSchedule {
+            decay: FeeDecay::Stepwise { steps: 0 },
+            ..linear
+        }
+        .is_valid(max_fee_bps));
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
-    events::{GlobalUpdateEvent, IntoEvent},
-    util::bps_mul,
+    errors::ContractError,
+    events::GlobalUpdateEvent,
+    state::bonding_curve::{VestingAnchor, VestingTerms},
+    util::{bps_mul, BASIS_POINTS_DIVISOR},
 };
 use anchor_lang::prelude::*;
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -9,6 +11,12 @@ pub struct GlobalAuthorityInput {
     pub withdraw_authority: Option<Pubkey>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq)]
+pub enum AuthorityRole {
+    Global,
+    Withdraw,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, Debug, PartialEq)]
 pub enum ProgramStatus {
     Running,
@@ -17,6 +25,52 @@ pub enum ProgramStatus {
     Paused,
 }
 
+// what each status allows:
+//
+// | action         | Running | SwapOnly | SwapOnlyNoLaunch | Paused |
+// |----------------|---------|----------|------------------|--------|
+// | create         | yes     | no       | no               | no     |
+// | buy            | yes     | yes      | yes              | no     |
+// | sell           | yes     | yes      | yes              | no     |
+// | launch         | yes     | yes      | no               | no     |
+// | claim          | yes     | yes      | yes              | no     |
+// | withdraw fees  | yes     | yes      | yes              | no     |
+// | reschedule     | yes     | yes      | yes              | no     |
+//
+// reschedule covers moving upcoming launches and cancelling untraded ones,
+// launch covers the buy that completes a curve and its graduation,
+// failing a curve past its graduation deadline and refunds count as sells
+impl ProgramStatus {
+    pub fn can_create(&self) -> bool {
+        matches!(self, ProgramStatus::Running)
+    }
+
+    pub fn can_buy(&self) -> bool {
+        !matches!(self, ProgramStatus::Paused)
+    }
+
+    pub fn can_sell(&self) -> bool {
+        !matches!(self, ProgramStatus::Paused)
+    }
+
+    pub fn can_launch(&self) -> bool {
+        matches!(self, ProgramStatus::Running | ProgramStatus::SwapOnly)
+    }
+
+    pub fn can_claim(&self) -> bool {
+        !matches!(self, ProgramStatus::Paused)
+    }
+
+    pub fn can_withdraw_fees(&self) -> bool {
+        !matches!(self, ProgramStatus::Paused)
+    }
+
+    // covers rescheduling and cancelling upcoming launches
+    pub fn can_reschedule(&self) -> bool {
+        !matches!(self, ProgramStatus::Paused)
+    }
+}
+
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct Global {
@@ -26,33 +80,118 @@ pub struct Global {
     pub global_authority: Pubkey,
     pub withdraw_authority: Pubkey,
 
+    // nominated authorities, set by set_params and taken over by accept_authority
+    pub pending_global_authority: Option<Pubkey>,
+    pub pending_withdraw_authority: Option<Pubkey>,
+
     pub trade_fee_bps: u64,
     pub launch_fee_lamports: u64,
+    // share of the launch fee returned to a creator cancelling their launch
+    pub launch_fee_refund_bps: u64,
 
     pub created_mint_decimals: u8,
+
+    // how far ahead of creation a launch may be scheduled
+    pub max_launch_lead_time: i64,
+
+    pub amm_program: Pubkey,
+
+    pub platform_vesting_terms: VestingTerms,
+    pub cex_vesting_terms: VestingTerms,
+
+    // upper bounds for the settings above
+    pub caps: SettingsCaps,
+
+    // settings queued by schedule_settings, executable from the effective time
+    pub pending_settings: Option<GlobalSettingsInput>,
+    pub pending_settings_effective_time: i64,
 }
-#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, InitSpace)]
 pub struct GlobalSettingsInput {
     pub trade_fee_bps: Option<u64>,
     pub created_mint_decimals: Option<u8>,
     pub launch_fee_lamports: Option<u64>,
+    pub launch_fee_refund_bps: Option<u64>,
+    pub max_launch_lead_time: Option<i64>,
 
     pub status: Option<ProgramStatus>,
+
+    pub amm_program: Option<Pubkey>,
+
+    pub platform_vesting_terms: Option<VestingTerms>,
+    pub cex_vesting_terms: Option<VestingTerms>,
+
+    pub caps: Option<SettingsCaps>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, InitSpace, PartialEq)]
+pub struct SettingsCaps {
+    pub max_trade_fee_bps: u64,
+    pub max_launch_fee_lamports: u64,
+    pub max_created_mint_decimals: u8,
+    pub max_launch_lead_time: i64,
+}
+
+impl Default for SettingsCaps {
+    fn default() -> Self {
+        Self {
+            max_trade_fee_bps: 1_000,                    // 10%
+            max_launch_fee_lamports: 10 * 1_000_000_000, // 10 SOL
+            max_created_mint_decimals: 9,
+            max_launch_lead_time: 30 * 24 * 60 * 60, // 30 days
+        }
+    }
+}
+
+impl SettingsCaps {
+    // a trade fee of the whole amount leaves nothing to trade
+    pub fn is_valid(&self) -> bool {
+        self.max_trade_fee_bps < BASIS_POINTS_DIVISOR
+            && self.max_created_mint_decimals > 0
+            && self.max_launch_lead_time >= 0
+    }
+}
+
+impl GlobalSettingsInput {
+    // checks that don't depend on the caps, see Global::validate_settings for the rest
+    pub fn validate(&self) -> Result<()> {
+        if let Some(launch_fee_refund_bps) = self.launch_fee_refund_bps {
+            require!(
+                launch_fee_refund_bps <= BASIS_POINTS_DIVISOR,
+                ContractError::InvalidLaunchFeeRefund
+            );
+        }
+        if let Some(caps) = &self.caps {
+            require!(caps.is_valid(), ContractError::InvalidSettingsCaps);
+        }
+        // global terms apply to every curve, so they can't name a fixed timestamp
+        for terms in [&self.platform_vesting_terms, &self.cex_vesting_terms]
+            .into_iter()
+            .flatten()
+        {
+            require!(
+                !matches!(terms.anchor, VestingAnchor::Timestamp(_)),
+                ContractError::InvalidVestingTerms
+            );
+        }
+        Ok(())
+    }
 }
 
 impl Global {
     pub const SEED_PREFIX: &'static str = "global";
 
+    pub const DEFAULT_LAUNCH_LEAD_TIME: i64 = 7 * 24 * 60 * 60; // 7 days
+
+    // minimum notice before a scheduled fee, cap, amm or status change can be executed
+    pub const SETTINGS_TIMELOCK_SECONDS: i64 = 24 * 60 * 60; // 1 day
+
     pub fn get_signer<'a>(bump: &'a u8) -> [&'a [u8]; 2] {
         let prefix_bytes = Self::SEED_PREFIX.as_bytes();
         let bump_slice: &'a [u8] = std::slice::from_ref(bump);
         [prefix_bytes, bump_slice]
     }
 
-    pub fn calculate_fee(&self, amount: u64) -> u64 {
-        bps_mul(self.trade_fee_bps, amount).unwrap()
-    }
-
     pub fn update_settings(&mut self, params: GlobalSettingsInput) {
         if let Some(trade_fee_bps) = params.trade_fee_bps {
             self.trade_fee_bps = trade_fee_bps;
@@ -60,12 +199,78 @@ impl Global {
         if let Some(launch_fee_lamports) = params.launch_fee_lamports {
             self.launch_fee_lamports = launch_fee_lamports;
         }
+        if let Some(launch_fee_refund_bps) = params.launch_fee_refund_bps {
+            self.launch_fee_refund_bps = launch_fee_refund_bps;
+        }
         if let Some(created_mint_decimals) = params.created_mint_decimals {
             self.created_mint_decimals = created_mint_decimals;
         }
+        if let Some(max_launch_lead_time) = params.max_launch_lead_time {
+            self.max_launch_lead_time = max_launch_lead_time;
+        }
         if let Some(status) = params.status {
             self.status = status;
         }
+        if let Some(amm_program) = params.amm_program {
+            self.amm_program = amm_program;
+        }
+        if let Some(platform_vesting_terms) = params.platform_vesting_terms {
+            self.platform_vesting_terms = platform_vesting_terms;
+        }
+        if let Some(cex_vesting_terms) = params.cex_vesting_terms {
+            self.cex_vesting_terms = cex_vesting_terms;
+        }
+        if let Some(caps) = params.caps {
+            self.caps = caps;
+        }
+    }
+
+    // the settings have to stay within the caps, so lowering a cap below
+    // a current value needs that value lowered in the same change
+    pub fn validate_settings(&self, params: &GlobalSettingsInput) -> Result<()> {
+        params.validate()?;
+        let mut updated = self.clone();
+        updated.update_settings(params.clone());
+        updated.validate_caps()
+    }
+
+    pub fn validate_caps(&self) -> Result<()> {
+        require!(
+            self.trade_fee_bps <= self.caps.max_trade_fee_bps,
+            ContractError::TradeFeeTooHigh
+        );
+        require!(
+            self.launch_fee_lamports <= self.caps.max_launch_fee_lamports,
+            ContractError::LaunchFeeTooHigh
+        );
+        require!(
+            self.created_mint_decimals > 0
+                && self.created_mint_decimals <= self.caps.max_created_mint_decimals,
+            ContractError::InvalidMintDecimals
+        );
+        require!(
+            (0..=self.caps.max_launch_lead_time).contains(&self.max_launch_lead_time),
+            ContractError::InvalidLaunchLeadTime
+        );
+        Ok(())
+    }
+
+    // fee changes, cap changes, amm changes and status changes outside of pausing have to be
+    // scheduled, graduation sends every completed curve's liquidity to the amm program.
+    // resuming from a pause is a status change like any other, whatever the current status
+    pub fn requires_timelock(params: &GlobalSettingsInput) -> bool {
+        if params.trade_fee_bps.is_some()
+            || params.launch_fee_lamports.is_some()
+            || params.launch_fee_refund_bps.is_some()
+            || params.amm_program.is_some()
+            || params.caps.is_some()
+        {
+            return true;
+        }
+        match params.status {
+            Some(status) => status != ProgramStatus::Paused,
+            None => false,
+        }
     }
 
     pub fn update_authority(&mut self, params: GlobalAuthorityInput) {
@@ -76,17 +281,96 @@ impl Global {
             self.withdraw_authority = withdraw_authority;
         }
     }
+
+    pub fn propose_authority(&mut self, params: GlobalAuthorityInput) {
+        if let Some(global_authority) = params.global_authority {
+            self.pending_global_authority = Some(global_authority);
+        }
+        if let Some(withdraw_authority) = params.withdraw_authority {
+            self.pending_withdraw_authority = Some(withdraw_authority);
+        }
+    }
+
+    pub fn get_pending_authority(&self, role: AuthorityRole) -> Option<Pubkey> {
+        match role {
+            AuthorityRole::Global => self.pending_global_authority,
+            AuthorityRole::Withdraw => self.pending_withdraw_authority,
+        }
+    }
+
+    // hands the role over to the pending authority, returns the previous authority
+    pub fn accept_authority(
+        &mut self,
+        role: AuthorityRole,
+        new_authority: Pubkey,
+    ) -> Result<Pubkey> {
+        require!(
+            self.get_pending_authority(role) == Some(new_authority),
+            ContractError::InvalidPendingAuthority
+        );
+        let previous_authority = match role {
+            AuthorityRole::Global => {
+                self.pending_global_authority = None;
+                std::mem::replace(&mut self.global_authority, new_authority)
+            }
+            AuthorityRole::Withdraw => {
+                self.pending_withdraw_authority = None;
+                std::mem::replace(&mut self.withdraw_authority, new_authority)
+            }
+        };
+        Ok(previous_authority)
+    }
+
+    // splits the launch fee of a cancelled launch into the creator refund and the platform's share
+    pub fn get_launch_fee_refund(&self, launch_fee_lamports: u64) -> Option<(u64, u64)> {
+        let refund_lamports = bps_mul(self.launch_fee_refund_bps, launch_fee_lamports)?;
+        Some((
+            refund_lamports,
+            launch_fee_lamports.checked_sub(refund_lamports)?,
+        ))
+    }
+
+    // drops the pending authority for the role, returns the cancelled nominee
+    pub fn cancel_authority_transfer(&mut self, role: AuthorityRole) -> Result<Pubkey> {
+        let pending_authority = match role {
+            AuthorityRole::Global => self.pending_global_authority.take(),
+            AuthorityRole::Withdraw => self.pending_withdraw_authority.take(),
+        };
+        pending_authority.ok_or(ContractError::NoPendingAuthority.into())
+    }
 }
 
-impl IntoEvent<GlobalUpdateEvent> for Global {
-    fn into_event(&self) -> GlobalUpdateEvent {
+impl Global {
+    // the settings after an update alongside the ones they replaced
+    pub fn into_update_event(&self, previous: &Global) -> GlobalUpdateEvent {
         GlobalUpdateEvent {
             global_authority: self.global_authority,
             withdraw_authority: self.withdraw_authority,
+            status: self.status,
 
             launch_fee_lamports: self.launch_fee_lamports,
+            launch_fee_refund_bps: self.launch_fee_refund_bps,
             trade_fee_bps: self.trade_fee_bps,
             created_mint_decimals: self.created_mint_decimals,
+            max_launch_lead_time: self.max_launch_lead_time,
+            amm_program: self.amm_program,
+            platform_vesting_terms: self.platform_vesting_terms.clone(),
+            cex_vesting_terms: self.cex_vesting_terms.clone(),
+            caps: self.caps,
+
+            previous_global_authority: previous.global_authority,
+            previous_withdraw_authority: previous.withdraw_authority,
+            previous_status: previous.status,
+
+            previous_launch_fee_lamports: previous.launch_fee_lamports,
+            previous_launch_fee_refund_bps: previous.launch_fee_refund_bps,
+            previous_trade_fee_bps: previous.trade_fee_bps,
+            previous_created_mint_decimals: previous.created_mint_decimals,
+            previous_max_launch_lead_time: previous.max_launch_lead_time,
+            previous_amm_program: previous.amm_program,
+            previous_platform_vesting_terms: previous.platform_vesting_terms.clone(),
+            previous_cex_vesting_terms: previous.cex_vesting_terms.clone(),
+            previous_caps: previous.caps,
         }
     }
 }
@@ -95,37 +379,409 @@ impl IntoEvent<GlobalUpdateEvent> for Global {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_calculate_fee() {
-        let mut fixture = Global {
+    fn test_global() -> Global {
+        Global {
             status: ProgramStatus::Running,
             initialized: true,
             global_authority: Pubkey::default(),
             withdraw_authority: Pubkey::default(),
-            trade_fee_bps: 0,
+            pending_global_authority: None,
+            pending_withdraw_authority: None,
+            trade_fee_bps: 100,
             launch_fee_lamports: 1000,
-            created_mint_decimals: 0,
+            launch_fee_refund_bps: 0,
+            created_mint_decimals: 6,
+            max_launch_lead_time: 0,
+            amm_program: Pubkey::default(),
+            platform_vesting_terms: VestingTerms::default(),
+            cex_vesting_terms: VestingTerms::default(),
+            caps: SettingsCaps::default(),
+            pending_settings: None,
+            pending_settings_effective_time: 0,
+        }
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        let global = test_global();
+        let caps = SettingsCaps::default();
+        let settings =
+            |trade_fee_bps, launch_fee_lamports, created_mint_decimals| GlobalSettingsInput {
+                trade_fee_bps,
+                created_mint_decimals,
+                launch_fee_lamports,
+                launch_fee_refund_bps: None,
+                max_launch_lead_time: None,
+                status: None,
+                amm_program: None,
+                platform_vesting_terms: None,
+                cex_vesting_terms: None,
+                caps: None,
+            };
+
+        assert!(global
+            .validate_settings(&settings(None, None, None))
+            .is_ok());
+        assert!(global
+            .validate_settings(&settings(Some(0), Some(0), Some(1)))
+            .is_ok());
+        assert!(global
+            .validate_settings(&settings(
+                Some(caps.max_trade_fee_bps),
+                Some(caps.max_launch_fee_lamports),
+                Some(caps.max_created_mint_decimals)
+            ))
+            .is_ok());
+
+        assert_eq!(
+            global.validate_settings(&settings(Some(caps.max_trade_fee_bps + 1), None, None)),
+            Err(ContractError::TradeFeeTooHigh.into())
+        );
+        assert_eq!(
+            global.validate_settings(&settings(Some(50000), None, None)),
+            Err(ContractError::TradeFeeTooHigh.into())
+        );
+        assert_eq!(
+            global.validate_settings(&settings(
+                None,
+                Some(caps.max_launch_fee_lamports + 1),
+                None
+            )),
+            Err(ContractError::LaunchFeeTooHigh.into())
+        );
+        assert_eq!(
+            global.validate_settings(&settings(None, None, Some(0))),
+            Err(ContractError::InvalidMintDecimals.into())
+        );
+        assert_eq!(
+            global.validate_settings(&settings(
+                None,
+                None,
+                Some(caps.max_created_mint_decimals + 1)
+            )),
+            Err(ContractError::InvalidMintDecimals.into())
+        );
+
+        let lead_time = |max_launch_lead_time| GlobalSettingsInput {
+            max_launch_lead_time: Some(max_launch_lead_time),
+            ..settings(None, None, None)
+        };
+        assert!(global.validate_settings(&lead_time(0)).is_ok());
+        assert!(global
+            .validate_settings(&lead_time(caps.max_launch_lead_time))
+            .is_ok());
+        assert_eq!(
+            global.validate_settings(&lead_time(caps.max_launch_lead_time + 1)),
+            Err(ContractError::InvalidLaunchLeadTime.into())
+        );
+        assert_eq!(
+            global.validate_settings(&lead_time(-1)),
+            Err(ContractError::InvalidLaunchLeadTime.into())
+        );
+
+        let refund = |launch_fee_refund_bps| GlobalSettingsInput {
+            launch_fee_refund_bps: Some(launch_fee_refund_bps),
+            ..settings(None, None, None)
+        };
+        assert!(global.validate_settings(&refund(0)).is_ok());
+        assert!(global
+            .validate_settings(&refund(BASIS_POINTS_DIVISOR))
+            .is_ok());
+        assert_eq!(
+            global.validate_settings(&refund(BASIS_POINTS_DIVISOR + 1)),
+            Err(ContractError::InvalidLaunchFeeRefund.into())
+        );
+
+        let anchored = |anchor| GlobalSettingsInput {
+            cex_vesting_terms: Some(VestingTerms {
+                anchor,
+                ..Default::default()
+            }),
+            ..settings(None, None, None)
+        };
+        assert!(global
+            .validate_settings(&anchored(VestingAnchor::CompletionTime))
+            .is_ok());
+        assert_eq!(
+            global.validate_settings(&anchored(VestingAnchor::Timestamp(0))),
+            Err(ContractError::InvalidVestingTerms.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_settings_caps() {
+        let global = test_global();
+        let capped = |trade_fee_bps, max_trade_fee_bps| GlobalSettingsInput {
+            trade_fee_bps,
+            created_mint_decimals: None,
+            launch_fee_lamports: None,
+            launch_fee_refund_bps: None,
+            max_launch_lead_time: None,
+            status: None,
+            amm_program: None,
+            platform_vesting_terms: None,
+            cex_vesting_terms: None,
+            caps: Some(SettingsCaps {
+                max_trade_fee_bps,
+                ..SettingsCaps::default()
+            }),
+        };
+
+        // raising a cap lets the setting follow in the same change
+        assert!(global
+            .validate_settings(&capped(Some(2_000), 2_000))
+            .is_ok());
+        assert!(global.validate_settings(&capped(None, 100)).is_ok());
+        // the current trade fee of 100 bps no longer fits
+        assert_eq!(
+            global.validate_settings(&capped(None, 99)),
+            Err(ContractError::TradeFeeTooHigh.into())
+        );
+        assert!(global.validate_settings(&capped(Some(50), 99)).is_ok());
+
+        // new settings are checked against the caps in force
+        let raised = Global {
+            caps: SettingsCaps {
+                max_trade_fee_bps: 2_000,
+                ..SettingsCaps::default()
+            },
+            ..test_global()
+        };
+        let trade_fee = GlobalSettingsInput {
+            trade_fee_bps: Some(2_000),
+            caps: None,
+            ..capped(None, 0)
         };
+        assert!(raised.validate_settings(&trade_fee).is_ok());
+        assert_eq!(
+            global.validate_settings(&trade_fee),
+            Err(ContractError::TradeFeeTooHigh.into())
+        );
 
-        fixture.trade_fee_bps = 100;
-        assert_eq!(fixture.calculate_fee(100), 1); //1% fee
+        // the caps themselves have to leave something to trade and mint
+        assert_eq!(
+            global.validate_settings(&capped(Some(0), BASIS_POINTS_DIVISOR)),
+            Err(ContractError::InvalidSettingsCaps.into())
+        );
+        let invalid = |caps| GlobalSettingsInput {
+            caps: Some(caps),
+            ..capped(None, 0)
+        };
+        assert_eq!(
+            global.validate_settings(&invalid(SettingsCaps {
+                max_created_mint_decimals: 0,
+                ..SettingsCaps::default()
+            })),
+            Err(ContractError::InvalidSettingsCaps.into())
+        );
+        assert_eq!(
+            global.validate_settings(&invalid(SettingsCaps {
+                max_launch_lead_time: -1,
+                ..SettingsCaps::default()
+            })),
+            Err(ContractError::InvalidSettingsCaps.into())
+        );
+    }
 
-        fixture.trade_fee_bps = 1000;
-        assert_eq!(fixture.calculate_fee(100), 10); //10% fee
+    #[test]
+    fn test_requires_timelock() {
+        let settings = |trade_fee_bps, launch_fee_lamports, status| GlobalSettingsInput {
+            trade_fee_bps,
+            created_mint_decimals: None,
+            launch_fee_lamports,
+            launch_fee_refund_bps: None,
+            max_launch_lead_time: None,
+            status,
+            amm_program: None,
+            platform_vesting_terms: None,
+            cex_vesting_terms: None,
+            caps: None,
+        };
+        assert!(!Global::requires_timelock(&settings(None, None, None)));
+        assert!(Global::requires_timelock(&settings(Some(200), None, None)));
+        assert!(Global::requires_timelock(&settings(None, Some(2000), None)));
+        assert!(Global::requires_timelock(&settings(
+            None,
+            None,
+            Some(ProgramStatus::SwapOnly)
+        )));
+        assert!(Global::requires_timelock(&GlobalSettingsInput {
+            amm_program: Some(Pubkey::new_unique()),
+            ..settings(None, None, None)
+        }));
+        assert!(Global::requires_timelock(&GlobalSettingsInput {
+            caps: Some(SettingsCaps::default()),
+            ..settings(None, None, None)
+        }));
+        // pausing is immediate
+        assert!(!Global::requires_timelock(&settings(
+            None,
+            None,
+            Some(ProgramStatus::Paused)
+        )));
+        assert!(Global::requires_timelock(&settings(
+            Some(200),
+            None,
+            Some(ProgramStatus::Paused)
+        )));
+    }
 
-        fixture.trade_fee_bps = 5000;
-        assert_eq!(fixture.calculate_fee(100), 50); //50% fee
+    #[test]
+    fn test_into_update_event() {
+        let previous = test_global();
+        let amm_program = Pubkey::new_unique();
+        let caps = SettingsCaps {
+            max_trade_fee_bps: 2_000,
+            ..SettingsCaps::default()
+        };
+        let updated = Global {
+            status: ProgramStatus::SwapOnly,
+            trade_fee_bps: 200,
+            launch_fee_lamports: 2000,
+            launch_fee_refund_bps: 5000,
+            max_launch_lead_time: 60,
+            amm_program,
+            caps,
+            ..test_global()
+        };
 
-        fixture.trade_fee_bps = 50000;
-        assert_eq!(fixture.calculate_fee(100), 500); //500% fee
+        let event = updated.into_update_event(&previous);
+        assert_eq!(event.status, ProgramStatus::SwapOnly);
+        assert_eq!(event.previous_status, ProgramStatus::Running);
+        assert_eq!(event.trade_fee_bps, 200);
+        assert_eq!(event.previous_trade_fee_bps, 100);
+        assert_eq!(event.launch_fee_lamports, 2000);
+        assert_eq!(event.previous_launch_fee_lamports, 1000);
+        assert_eq!(event.launch_fee_refund_bps, 5000);
+        assert_eq!(event.previous_launch_fee_refund_bps, 0);
+        assert_eq!(event.max_launch_lead_time, 60);
+        assert_eq!(event.previous_max_launch_lead_time, 0);
+        assert_eq!(event.amm_program, amm_program);
+        assert_eq!(event.previous_amm_program, Pubkey::default());
+        assert_eq!(event.caps, caps);
+        assert_eq!(event.previous_caps, SettingsCaps::default());
+        assert_eq!(event.previous_created_mint_decimals, 6);
+    }
 
-        fixture.trade_fee_bps = 50;
-        assert_eq!(fixture.calculate_fee(100), 0); //0.5% fee
+    #[test]
+    fn test_get_launch_fee_refund() {
+        let fixture = |launch_fee_refund_bps| Global {
+            launch_fee_refund_bps,
+            ..test_global()
+        };
 
-        fixture.trade_fee_bps = 50;
-        assert_eq!(fixture.calculate_fee(1000), 5); //0.5% fee
+        // kept by the platform by default
+        assert_eq!(fixture(0).get_launch_fee_refund(1000), Some((0, 1000)));
+        assert_eq!(fixture(2500).get_launch_fee_refund(1000), Some((250, 750)));
+        assert_eq!(
+            fixture(BASIS_POINTS_DIVISOR).get_launch_fee_refund(1000),
+            Some((1000, 0))
+        );
+        // rounded in the platform's favour
+        assert_eq!(fixture(3333).get_launch_fee_refund(10), Some((3, 7)));
+    }
 
-        fixture.trade_fee_bps = 0;
-        assert_eq!(fixture.calculate_fee(100), 0); //0% fee
+    #[test]
+    fn test_authority_transfer() {
+        let admin = Pubkey::new_unique();
+        let nominee = Pubkey::new_unique();
+        let mut fixture = Global {
+            global_authority: admin,
+            withdraw_authority: admin,
+            ..test_global()
+        };
+
+        // nothing to accept or cancel yet
+        assert!(fixture
+            .accept_authority(AuthorityRole::Global, nominee)
+            .is_err());
+        assert!(fixture
+            .cancel_authority_transfer(AuthorityRole::Global)
+            .is_err());
+
+        fixture.propose_authority(GlobalAuthorityInput {
+            global_authority: Some(nominee),
+            withdraw_authority: Some(nominee),
+        });
+        // proposing does not hand anything over
+        assert_eq!(fixture.global_authority, admin);
+        assert_eq!(fixture.withdraw_authority, admin);
+        assert_eq!(fixture.pending_global_authority, Some(nominee));
+        assert_eq!(fixture.pending_withdraw_authority, Some(nominee));
+
+        // only the nominee can accept
+        assert!(fixture
+            .accept_authority(AuthorityRole::Global, admin)
+            .is_err());
+        assert_eq!(
+            fixture
+                .accept_authority(AuthorityRole::Global, nominee)
+                .unwrap(),
+            admin
+        );
+        assert_eq!(fixture.global_authority, nominee);
+        assert_eq!(fixture.pending_global_authority, None);
+        // the withdraw role is untouched until accepted on its own
+        assert_eq!(fixture.withdraw_authority, admin);
+
+        assert_eq!(
+            fixture
+                .cancel_authority_transfer(AuthorityRole::Withdraw)
+                .unwrap(),
+            nominee
+        );
+        assert_eq!(fixture.pending_withdraw_authority, None);
+        assert!(fixture
+            .accept_authority(AuthorityRole::Withdraw, nominee)
+            .is_err());
+        assert_eq!(fixture.withdraw_authority, admin);
+    }
+
+    #[test]
+    fn test_program_status_running() {
+        let status = ProgramStatus::Running;
+        assert!(status.can_create());
+        assert!(status.can_buy());
+        assert!(status.can_sell());
+        assert!(status.can_launch());
+        assert!(status.can_claim());
+        assert!(status.can_withdraw_fees());
+        assert!(status.can_reschedule());
+    }
+
+    #[test]
+    fn test_program_status_swap_only() {
+        let status = ProgramStatus::SwapOnly;
+        assert!(!status.can_create());
+        assert!(status.can_buy());
+        assert!(status.can_sell());
+        assert!(status.can_launch());
+        assert!(status.can_claim());
+        assert!(status.can_withdraw_fees());
+        assert!(status.can_reschedule());
+    }
+
+    #[test]
+    fn test_program_status_swap_only_no_launch() {
+        let status = ProgramStatus::SwapOnlyNoLaunch;
+        assert!(!status.can_create());
+        assert!(status.can_buy());
+        assert!(status.can_sell());
+        assert!(!status.can_launch());
+        assert!(status.can_claim());
+        assert!(status.can_withdraw_fees());
+        assert!(status.can_reschedule());
+    }
+
+    #[test]
+    fn test_program_status_paused() {
+        let status = ProgramStatus::Paused;
+        assert!(!status.can_create());
+        assert!(!status.can_buy());
+        assert!(!status.can_sell());
+        assert!(!status.can_launch());
+        assert!(!status.can_claim());
+        assert!(!status.can_withdraw_fees());
+        assert!(!status.can_reschedule());
     }
 }
@@ -1,4 +1,8 @@
 pub mod allocation;
+pub mod allowlist;
 pub mod bonding_curve;
+pub mod fee;
 pub mod global;
+pub mod position;
+pub mod presale;
 pub mod vaults;
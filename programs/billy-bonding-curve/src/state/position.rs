@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+// per-wallet trading record on a curve, used to enforce the launch protection caps
+#[account]
+#[derive(InitSpace, Debug, Default)]
+pub struct UserPosition {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    // lamports spent on buys during the launch protection window
+    pub protected_buy_lamports: u64,
+    // lamports spent on buys during the allowlist phase
+    pub allowlist_buy_lamports: u64,
+    pub bump: u8,
+}
+
+impl UserPosition {
+    pub const SEED_PREFIX: &'static str = "user-position";
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Default, AnchorSerialize, InitSpace, AnchorDeserialize)]
+pub struct PresaleTerms {
+    pub open_time: i64,
+    pub close_time: i64,
+    pub max_contribution_per_wallet: u64,
+    // total SOL the presale accepts, kept below the launch threshold
+    pub presale_sol_cap: u64,
+}
+
+impl PresaleTerms {
+    pub fn is_open(&self, clock: &Clock) -> bool {
+        let now = clock.unix_timestamp;
+        now >= self.open_time && now < self.close_time
+    }
+
+    pub fn is_within_cap(&self, sol_raised: u64, sol_amount: u64) -> bool {
+        sol_raised
+            .checked_add(sol_amount)
+            .is_some_and(|total| total <= self.presale_sol_cap)
+    }
+}
+
+#[account]
+#[derive(InitSpace, Debug, Default)]
+pub struct PresalePosition {
+    pub contributor: Pubkey,
+    pub mint: Pubkey,
+    pub contributed: u64,
+    pub claimed: bool,
+    pub bump: u8,
+}
+
+impl PresalePosition {
+    pub const SEED_PREFIX: &'static str = "presale-position";
+
+    // pro-rata share of the presale supply for this position
+    pub fn get_claimable_tokens(&self, presale_supply: u64, sol_raised: u64) -> Option<u64> {
+        if sol_raised == 0 {
+            return None;
+        }
+        (presale_supply as u128)
+            .checked_mul(self.contributed as u128)?
+            .checked_div(sol_raised as u128)?
+            .try_into()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_claimable_tokens() {
+        let mut position = PresalePosition {
+            contributed: 250,
+            ..Default::default()
+        };
+        assert_eq!(position.get_claimable_tokens(1000, 1000), Some(250));
+        // rounds down, dust stays in the vault
+        assert_eq!(position.get_claimable_tokens(1000, 3000), Some(83));
+        assert_eq!(position.get_claimable_tokens(1000, 0), None);
+
+        position.contributed = u64::MAX;
+        assert_eq!(
+            position.get_claimable_tokens(u64::MAX, u64::MAX),
+            Some(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_presale_is_within_cap() {
+        let terms = PresaleTerms {
+            presale_sol_cap: 1000,
+            ..Default::default()
+        };
+        assert!(terms.is_within_cap(0, 1000));
+        assert!(terms.is_within_cap(600, 400));
+        assert!(!terms.is_within_cap(600, 401));
+        assert!(!terms.is_within_cap(u64::MAX, 1));
+    }
+
+    #[test]
+    fn test_presale_is_open() {
+        let terms = PresaleTerms {
+            open_time: 100,
+            close_time: 200,
+            max_contribution_per_wallet: 1,
+            presale_sol_cap: 1,
+        };
+        let at = |unix_timestamp| Clock {
+            unix_timestamp,
+            ..Clock::default()
+        };
+        assert!(!terms.is_open(&at(99)));
+        assert!(terms.is_open(&at(100)));
+        assert!(terms.is_open(&at(199)));
+        assert!(!terms.is_open(&at(200)));
+    }
+}
@@ -1,13 +1,34 @@
 use anchor_lang::prelude::*;
+
+use crate::state::{
+    bonding_curve::{BondingCurve, VestingTerms},
+    presale::PresaleTerms,
+};
 #[account]
 #[derive(InitSpace, Debug, Default)]
 pub struct CreatorVault {
     pub initial_vested_supply: u64,
     pub last_distribution: i64,
+    pub claimed_amount: u64,
 }
 impl CreatorVault {
     pub const SEED_PREFIX: &'static str = "creator-vault";
 
+    // cumulative amount vested at `now`, reaches `initial_vested_supply` exactly at the end of the duration
+    pub fn vested_at(&self, bonding_curve: &BondingCurve, now: i64) -> Option<u64> {
+        bonding_curve.get_vested_amount(
+            &bonding_curve.vesting_terms,
+            self.initial_vested_supply,
+            now,
+        )
+    }
+
+    // vested but not yet claimed at `now`
+    pub fn claimable_at(&self, bonding_curve: &BondingCurve, now: i64) -> Option<u64> {
+        self.vested_at(bonding_curve, now)?
+            .checked_sub(self.claimed_amount)
+    }
+
     pub fn get_signer<'a>(bump: &'a u8, mint: &'a Pubkey) -> [&'a [u8]; 3] {
         [
             Self::SEED_PREFIX.as_bytes(),
@@ -21,6 +42,8 @@ impl CreatorVault {
 #[derive(InitSpace, Debug, Default)]
 pub struct PresaleVault {
     pub initial_vested_supply: u64,
+    pub presale_terms: PresaleTerms,
+    pub total_claimed: u64,
 }
 impl PresaleVault {
     pub const SEED_PREFIX: &'static str = "presale-vault";
@@ -34,6 +57,26 @@ impl PresaleVault {
     }
 }
 
+#[account]
+#[derive(InitSpace, Debug, Default)]
+pub struct CexVault {
+    pub initial_vested_supply: u64,
+    pub vesting_terms: VestingTerms,
+    pub tokens_released: u64,
+    pub last_release: i64,
+}
+impl CexVault {
+    pub const SEED_PREFIX: &'static str = "cex-vault";
+
+    pub fn get_signer<'a>(bump: &'a u8, mint: &'a Pubkey) -> [&'a [u8]; 3] {
+        [
+            Self::SEED_PREFIX.as_bytes(),
+            mint.as_ref(),
+            std::slice::from_ref(bump),
+        ]
+    }
+}
+
 #[account]
 #[derive(InitSpace, Debug, Default)]
 pub struct PlatformVault {
@@ -41,10 +84,22 @@ pub struct PlatformVault {
     pub last_distribution: i64,
     pub last_fee_withdrawal: i64,
     pub fees_withdrawn: u64,
+    pub vesting_terms: VestingTerms,
+    pub tokens_claimed: u64,
+    // returned by the curve on completion, outside the vesting schedule
+    pub unsold_token_amount: u64,
 }
 impl PlatformVault {
     pub const SEED_PREFIX: &'static str = "platform-vault";
 
+    // the platform allocation vests, unsold tokens are claimable as soon as they arrive
+    pub fn claimable_at(&self, bonding_curve: &BondingCurve, now: i64) -> Option<u64> {
+        bonding_curve
+            .get_vested_amount(&self.vesting_terms, self.initial_vested_supply, now)?
+            .checked_add(self.unsold_token_amount)?
+            .checked_sub(self.tokens_claimed)
+    }
+
     pub fn get_signer<'a>(bump: &'a u8, mint: &'a Pubkey) -> [&'a [u8]; 3] {
         [
             Self::SEED_PREFIX.as_bytes(),
@@ -60,6 +115,9 @@ pub struct BrandVault {
     pub launch_brandkit_supply: u64,
     pub lifetime_brandkit_supply: u64,
     pub initial_vested_supply: u64,
+    pub lifetime_vesting_terms: VestingTerms,
+    pub launch_brandkit_claimed: u64,
+    pub lifetime_brandkit_claimed: u64,
 }
 impl BrandVault {
     pub const SEED_PREFIX: &'static str = "brand-vault";
@@ -72,3 +130,153 @@ impl BrandVault {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::bonding_curve::VestingAnchor;
+    use proptest::prelude::*;
+
+    const START_TIME: i64 = 1_700_000_000;
+
+    fn curve_with(anchor: VestingAnchor) -> BondingCurve {
+        BondingCurve {
+            start_time: START_TIME,
+            vesting_terms: VestingTerms {
+                anchor,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_creator_vesting_schedule() {
+        let curve = curve_with(VestingAnchor::StartTime);
+        let terms = &curve.vesting_terms;
+        let vault = CreatorVault {
+            initial_vested_supply: 1_000_000_007,
+            ..Default::default()
+        };
+        let vesting_start = START_TIME + terms.cliff;
+
+        assert_eq!(vault.vested_at(&curve, START_TIME), Some(0));
+        assert_eq!(vault.vested_at(&curve, vesting_start), Some(0));
+        assert_eq!(
+            vault.vested_at(&curve, vesting_start + terms.duration / 2),
+            Some(500_000_003)
+        );
+        // the remainder lands at the end of the duration, and nothing more after it
+        assert_eq!(
+            vault.vested_at(&curve, vesting_start + terms.duration),
+            Some(1_000_000_007)
+        );
+        assert_eq!(
+            vault.vested_at(&curve, vesting_start + 10 * terms.duration),
+            Some(1_000_000_007)
+        );
+    }
+
+    #[test]
+    fn test_creator_vesting_anchor() {
+        let vault = CreatorVault {
+            initial_vested_supply: 1_000,
+            ..Default::default()
+        };
+        let terms = VestingTerms::default();
+        let end = terms.cliff + terms.duration;
+
+        // nothing vests before the curve completes, however long it has been open
+        let mut curve = curve_with(VestingAnchor::CompletionTime);
+        assert_eq!(vault.vested_at(&curve, START_TIME + 10 * end), Some(0));
+
+        // the schedule runs from the completion time once it does
+        let completion_time = START_TIME + end;
+        curve.mark_complete(&Clock {
+            unix_timestamp: completion_time,
+            ..Default::default()
+        });
+        assert_eq!(
+            vault.vested_at(&curve, completion_time + terms.cliff),
+            Some(0)
+        );
+        assert_eq!(
+            vault.vested_at(&curve, completion_time + terms.cliff + terms.duration / 2),
+            Some(500)
+        );
+        assert_eq!(vault.vested_at(&curve, completion_time + end), Some(1_000));
+
+        // or from an explicit timestamp
+        let curve = curve_with(VestingAnchor::Timestamp(START_TIME + 100));
+        assert_eq!(
+            vault.vested_at(&curve, START_TIME + 100 + terms.cliff),
+            Some(0)
+        );
+        assert_eq!(vault.vested_at(&curve, START_TIME + 100 + end), Some(1_000));
+    }
+
+    #[test]
+    fn test_platform_unsold_tokens() {
+        let curve = curve_with(VestingAnchor::StartTime);
+        let terms = VestingTerms::default();
+        let vesting_start = START_TIME + terms.cliff;
+        let mut vault = PlatformVault {
+            initial_vested_supply: 1_000,
+            vesting_terms: terms.clone(),
+            unsold_token_amount: 400,
+            ..Default::default()
+        };
+
+        // unsold tokens don't wait for the cliff
+        assert_eq!(vault.claimable_at(&curve, START_TIME), Some(400));
+        vault.tokens_claimed += 400;
+        // nor change the schedule of the allocation
+        assert_eq!(
+            vault.claimable_at(&curve, vesting_start + terms.duration / 2),
+            Some(500)
+        );
+        vault.tokens_claimed += 500;
+        assert_eq!(
+            vault.claimable_at(&curve, vesting_start + terms.duration),
+            Some(500)
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(10000))]
+
+        #[test]
+        fn fuzz_test_creator_vesting_claims(
+            supply in 0..u64::MAX,
+            mut offsets in prop::collection::vec(0..2 * 38 * 24 * 60 * 60i64, 0..20),
+        ) {
+            let curve = curve_with(VestingAnchor::StartTime);
+            let terms = &curve.vesting_terms;
+            let mut vault = CreatorVault {
+                initial_vested_supply: supply,
+                ..Default::default()
+            };
+            offsets.sort();
+
+            let mut previous_vested = 0;
+            for offset in offsets {
+                let now = START_TIME + offset;
+                let vested = vault.vested_at(&curve, now).unwrap();
+                prop_assert!(vested >= previous_vested, "Vested amount should never decrease");
+                prop_assert!(vested <= supply, "Vested amount should not exceed the vested supply");
+                previous_vested = vested;
+
+                let claimable = vault.claimable_at(&curve, now).unwrap();
+                vault.claimed_amount += claimable;
+                prop_assert_eq!(vault.claimed_amount, vested, "Claims should add up to the vested amount");
+            }
+
+            // a final claim after the end sweeps exactly the rest
+            let end = START_TIME + terms.cliff + terms.duration;
+            let claimable = vault.claimable_at(&curve, end).unwrap();
+            vault.claimed_amount += claimable;
+            prop_assert_eq!(vault.claimed_amount, supply, "All vested supply should be claimable by the end");
+            prop_assert_eq!(vault.claimable_at(&curve, end + 1), Some(0));
+        }
+    }
+}
@@ -19,3 +19,9 @@ pub fn bps_div_raw(bps: u64, value: u64) -> Option<u128> {
         .checked_mul(BASIS_POINTS_DIVISOR as u128)?
         .checked_div(bps as u128)
 }
+
+pub fn ceil_div(value: u128, divisor: u128) -> Option<u128> {
+    value
+        .checked_add(divisor.checked_sub(1)?)?
+        .checked_div(divisor)
+}
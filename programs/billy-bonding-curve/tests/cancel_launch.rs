@@ -0,0 +1,160 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use billy_bonding_curve::{
+    errors::ContractError,
+    state::{
+        bonding_curve::{BondingCurve, CreateBondingCurveParams},
+        global::GlobalSettingsInput,
+    },
+};
+use common::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+
+const LAUNCH_FEE_REFUND_BPS: u64 = 5_000;
+
+async fn cancellable_launch(test: &mut TestContext) -> Pubkey {
+    test.initialize(GlobalSettingsInput {
+        launch_fee_refund_bps: Some(LAUNCH_FEE_REFUND_BPS),
+        ..global_settings()
+    })
+    .await;
+    test.create_curve(curve_params()).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_cancel_launch_refunds_the_launch_fee_paid() {
+    let mut test = TestContext::start().await;
+    let mint = cancellable_launch(&mut test).await;
+    let curve = CurveAccounts::new(mint);
+    // a donation to the platform vault is not part of the launch fee
+    let donation = LAMPORTS_PER_SOL;
+    test.donate(curve.platform_vault, donation).await;
+
+    let withdraw_authority = test.authority.pubkey();
+    let before = test.lamports(withdraw_authority).await;
+    let creator = test.creator.insecure_clone();
+    test.cancel_launch(&creator, mint).await.unwrap();
+
+    let refund = LAUNCH_FEE_LAMPORTS * LAUNCH_FEE_REFUND_BPS / 10_000;
+    assert_eq!(
+        test.lamports(withdraw_authority).await - before,
+        LAUNCH_FEE_LAMPORTS - refund + donation
+    );
+    for closed in [curve.bonding_curve, curve.platform_vault] {
+        assert_eq!(test.lamports(closed).await, 0);
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_launch_after_withdraw_fees() {
+    let mut test = TestContext::start().await;
+    let mint = cancellable_launch(&mut test).await;
+
+    // the platform can only take its own share of the launch fee ahead of a cancel
+    let authority = test.authority.insecure_clone();
+    let before = test.lamports(authority.pubkey()).await;
+    test.withdraw_fees(&authority, mint).await.unwrap();
+    let refund = LAUNCH_FEE_LAMPORTS * LAUNCH_FEE_REFUND_BPS / 10_000;
+    assert_eq!(
+        test.lamports(authority.pubkey()).await - before,
+        LAUNCH_FEE_LAMPORTS - refund
+    );
+    assert_contract_error(
+        test.withdraw_fees(&authority, mint).await,
+        ContractError::NoFeesToWithdraw,
+    );
+
+    let before = test.lamports(authority.pubkey()).await;
+    let creator = test.creator.insecure_clone();
+    test.cancel_launch(&creator, mint).await.unwrap();
+    assert_eq!(test.lamports(authority.pubkey()).await, before);
+}
+
+#[tokio::test]
+async fn test_cancel_launch_rejected_once_traded() {
+    let mut test = TestContext::start().await;
+    let mint = cancellable_launch(&mut test).await;
+    let buyer = test.new_wallet();
+    test.buy(&buyer, mint, LAMPORTS_PER_SOL).await.unwrap();
+    // selling everything back does not undo the trade
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    test.swap(&buyer, mint, sell_params(tokens)).await.unwrap();
+    let curve: BondingCurve = test.fetch(bonding_curve_pda(mint)).await;
+    assert!(curve.traded);
+
+    let creator = test.creator.insecure_clone();
+    assert_contract_error(
+        test.cancel_launch(&creator, mint).await,
+        ContractError::LaunchAlreadyTraded,
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_launch_rejected_for_other_creator() {
+    let mut test = TestContext::start().await;
+    let mint = cancellable_launch(&mut test).await;
+    let other = test.new_wallet();
+    assert_contract_error(
+        test.cancel_launch(&other, mint).await,
+        ContractError::InvalidCreatorAuthority,
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_launch_rejected_with_presale_contributions() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    let mint = test
+        .create_curve(presale_params(now, 1_000, 10 * LAMPORTS_PER_SOL))
+        .await
+        .unwrap();
+    let creator = test.creator.insecure_clone();
+    let contributor = test.new_wallet();
+    test.contribute_presale(&contributor, mint, LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+    assert_contract_error(
+        test.cancel_launch(&creator, mint).await,
+        ContractError::LaunchHasContributions,
+    );
+}
+
+#[tokio::test]
+async fn test_reschedule_launch() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    let mint = test
+        .create_curve(CreateBondingCurveParams {
+            start_time: Some(now + 100),
+            ..curve_params()
+        })
+        .await
+        .unwrap();
+    let buyer = test.new_wallet();
+    assert_contract_error(
+        test.buy(&buyer, mint, LAMPORTS_PER_SOL).await,
+        ContractError::CurveNotStarted,
+    );
+
+    let creator = test.creator.insecure_clone();
+    test.reschedule_launch(&creator, mint, now + 200)
+        .await
+        .unwrap();
+    let curve: BondingCurve = test.fetch(bonding_curve_pda(mint)).await;
+    assert_eq!(curve.start_time, now + 200);
+    assert_contract_error(
+        test.reschedule_launch(&buyer, mint, now + 300).await,
+        ContractError::InvalidCreatorAuthority,
+    );
+
+    // an open launch keeps its start time
+    test.warp_to(now + 200).await;
+    test.buy(&buyer, mint, LAMPORTS_PER_SOL).await.unwrap();
+    assert_contract_error(
+        test.reschedule_launch(&creator, mint, now + 300).await,
+        ContractError::LaunchAlreadyStarted,
+    );
+}
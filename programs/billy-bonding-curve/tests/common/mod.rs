@@ -0,0 +1,842 @@
+#![allow(dead_code)]
+// program-test harness, the program runs natively and Metaplex from programs/.bin
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::ProgramResult,
+        instruction::{Instruction, InstructionError},
+        sysvar,
+    },
+    AccountDeserialize, InstructionData,
+};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id},
+    metadata::mpl_token_metadata,
+    token::{self, spl_token},
+    token_interface::TokenAccount,
+};
+use billy_bonding_curve::{
+    instructions::{quote::SwapQuote, swap::SwapParams},
+    state::{
+        allocation::AllocationDataParams,
+        bonding_curve::{BondingCurve, CreateBondingCurveParams},
+        global::{AuthorityRole, Global, GlobalSettingsInput, ProgramStatus},
+        position::UserPosition,
+        presale::{PresalePosition, PresaleTerms},
+        vaults::{BrandVault, CexVault, CreatorVault, PlatformVault, PresaleVault},
+    },
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader,
+    compute_budget::ComputeBudgetInstruction,
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+pub const TOKEN_DECIMALS: u8 = 6;
+pub const TOKEN_TOTAL_SUPPLY: u64 = 2_000_000_000 * 10u64.pow(TOKEN_DECIMALS as u32);
+pub const SOL_LAUNCH_THRESHOLD: u64 = 300 * LAMPORTS_PER_SOL;
+pub const LAUNCH_FEE_LAMPORTS: u64 = LAMPORTS_PER_SOL / 2;
+pub const INITIAL_SOL: u64 = 1_000 * LAMPORTS_PER_SOL;
+
+// anchor's entry wants the accounts to outlive the instruction
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    billy_bonding_curve::entry(program_id, accounts, data)
+}
+
+pub struct TestContext {
+    pub context: ProgramTestContext,
+    pub authority: Keypair,
+    pub creator: Keypair,
+}
+
+impl TestContext {
+    pub async fn start() -> Self {
+        let authority = Keypair::new();
+        let creator = Keypair::new();
+
+        let mut program_test = ProgramTest::new(
+            "billy_bonding_curve",
+            billy_bonding_curve::ID,
+            processor!(process_instruction),
+        );
+        program_test.add_account(
+            mpl_token_metadata::ID,
+            Account {
+                lamports: INITIAL_SOL,
+                data: std::fs::read(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../.bin/mpl_token_metadata.so"
+                ))
+                .unwrap(),
+                owner: bpf_loader::ID,
+                executable: true,
+                rent_epoch: 0,
+            },
+        );
+        for wallet in [&authority, &creator] {
+            program_test.add_account(wallet.pubkey(), system_account(INITIAL_SOL));
+        }
+
+        Self {
+            context: program_test.start_with_context().await,
+            authority,
+            creator,
+        }
+    }
+
+    pub fn new_wallet(&mut self) -> Keypair {
+        let wallet = Keypair::new();
+        self.context
+            .set_account(&wallet.pubkey(), &system_account(INITIAL_SOL).into());
+        wallet
+    }
+
+    pub async fn process(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&Keypair],
+    ) -> std::result::Result<(), BanksClientError> {
+        let transaction = self.transaction(instruction, signers).await;
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    // runs the instruction without committing it and returns its return data
+    pub async fn simulate(
+        &mut self,
+        instruction: Instruction,
+    ) -> std::result::Result<Vec<u8>, BanksClientError> {
+        let transaction = self.transaction(instruction, &[]).await;
+        let simulation = self
+            .context
+            .banks_client
+            .simulate_transaction(transaction)
+            .await?;
+        if let Some(Err(error)) = simulation.result {
+            return Err(BanksClientError::TransactionError(error));
+        }
+        Ok(simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .map(|return_data| return_data.data)
+            .unwrap_or_default())
+    }
+
+    async fn transaction(&mut self, instruction: Instruction, signers: &[&Keypair]) -> Transaction {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        Transaction::new_signed_with_payer(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(600_000),
+                instruction,
+            ],
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        )
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        let clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    pub async fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn lamports(&mut self, address: Pubkey) -> u64 {
+        self.context
+            .banks_client
+            .get_balance(address)
+            .await
+            .unwrap()
+    }
+
+    pub async fn fetch<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub async fn token_balance(&mut self, address: Pubkey) -> u64 {
+        self.fetch::<TokenAccount>(address).await.amount
+    }
+
+    pub async fn initialize(&mut self, params: GlobalSettingsInput) {
+        let accounts = billy_bonding_curve::accounts::Initialize {
+            authority: self.authority.pubkey(),
+            global: global_pda(),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::Initialize { params },
+        );
+        let authority = self.authority.insecure_clone();
+        self.process(instruction, &[&authority]).await.unwrap();
+    }
+
+    // a fresh curve with the default allocations, returns its mint
+    pub async fn create_curve(
+        &mut self,
+        params: CreateBondingCurveParams,
+    ) -> std::result::Result<Pubkey, BanksClientError> {
+        let mint = Keypair::new();
+        let creator = self.creator.insecure_clone();
+        let curve = CurveAccounts::new(mint.pubkey());
+        let accounts = billy_bonding_curve::accounts::CreateBondingCurve {
+            mint: curve.mint,
+            creator: creator.pubkey(),
+            creator_vault: curve.creator_vault,
+            creator_vault_token_account: ata(curve.creator_vault, curve.mint),
+            presale_vault: curve.presale_vault,
+            presale_vault_token_account: ata(curve.presale_vault, curve.mint),
+            brand_authority: creator.pubkey(),
+            brand_vault: curve.brand_vault,
+            brand_vault_token_account: ata(curve.brand_vault, curve.mint),
+            platform_vault: curve.platform_vault,
+            platform_vault_token_account: ata(curve.platform_vault, curve.mint),
+            cex_vault: curve.cex_vault,
+            cex_vault_token_account: ata(curve.cex_vault, curve.mint),
+            bonding_curve: curve.bonding_curve,
+            bonding_curve_token_account: ata(curve.bonding_curve, curve.mint),
+            global: global_pda(),
+            metadata: Some(metadata_pda(curve.mint)),
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            token_metadata_program: Some(mpl_token_metadata::ID),
+            rent: sysvar::rent::ID,
+            clock: sysvar::clock::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::CreateBondingCurve { params },
+        );
+        self.process(instruction, &[&creator, &mint]).await?;
+        Ok(curve.mint)
+    }
+
+    // passes the user position only when the curve tracks the buy, like the SDK
+    pub async fn swap(
+        &mut self,
+        user: &Keypair,
+        mint: Pubkey,
+        params: SwapParams,
+    ) -> std::result::Result<(), BanksClientError> {
+        let bonding_curve: BondingCurve = self.fetch(bonding_curve_pda(mint)).await;
+        let now = self.now().await;
+        let user_position = (!params.base_in && bonding_curve.tracks_buys(now))
+            .then(|| user_position_pda(mint, user.pubkey()));
+        self.swap_with_position(user, mint, params, user_position)
+            .await
+    }
+
+    pub async fn swap_with_position(
+        &mut self,
+        user: &Keypair,
+        mint: Pubkey,
+        params: SwapParams,
+        user_position: Option<Pubkey>,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::Swap {
+            user: user.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            bonding_curve_token_account: ata(curve.bonding_curve, mint),
+            platform_vault: curve.platform_vault,
+            platform_vault_token_account: ata(curve.platform_vault, mint),
+            user_token_account: ata(user.pubkey(), mint),
+            user_position,
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            clock: sysvar::clock::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(accounts, billy_bonding_curve::instruction::Swap { params });
+        self.process(instruction, &[user]).await
+    }
+
+    pub async fn buy(
+        &mut self,
+        user: &Keypair,
+        mint: Pubkey,
+        sol_amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        self.swap(user, mint, buy_params(sol_amount)).await
+    }
+
+    pub async fn fail_curve(&mut self, mint: Pubkey) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::FailCurve {
+            payer: self.context.payer.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            bonding_curve_token_account: ata(curve.bonding_curve, mint),
+            presale_vault: curve.presale_vault,
+            creator_vault: curve.creator_vault,
+            creator_vault_token_account: ata(curve.creator_vault, mint),
+            cex_vault: curve.cex_vault,
+            cex_vault_token_account: ata(curve.cex_vault, mint),
+            brand_vault: curve.brand_vault,
+            brand_vault_token_account: ata(curve.brand_vault, mint),
+            platform_vault: curve.platform_vault,
+            platform_vault_token_account: ata(curve.platform_vault, mint),
+            token_program: spl_token::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(accounts, billy_bonding_curve::instruction::FailCurve {});
+        self.process(instruction, &[]).await
+    }
+
+    pub async fn refund(
+        &mut self,
+        user: &Keypair,
+        mint: Pubkey,
+        token_amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let accounts = billy_bonding_curve::accounts::Refund {
+            user: user.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: bonding_curve_pda(mint),
+            user_token_account: ata(user.pubkey(), mint),
+            token_program: spl_token::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::Refund { token_amount },
+        );
+        self.process(instruction, &[user]).await
+    }
+
+    pub async fn cancel_launch(
+        &mut self,
+        creator: &Keypair,
+        mint: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let global: Global = self.fetch(global_pda()).await;
+        let accounts = billy_bonding_curve::accounts::CancelLaunch {
+            creator: creator.pubkey(),
+            global: global_pda(),
+            withdraw_authority: global.withdraw_authority,
+            mint,
+            bonding_curve: curve.bonding_curve,
+            bonding_curve_token_account: ata(curve.bonding_curve, mint),
+            creator_vault: curve.creator_vault,
+            creator_vault_token_account: ata(curve.creator_vault, mint),
+            presale_vault: curve.presale_vault,
+            presale_vault_token_account: ata(curve.presale_vault, mint),
+            cex_vault: curve.cex_vault,
+            cex_vault_token_account: ata(curve.cex_vault, mint),
+            brand_vault: curve.brand_vault,
+            brand_vault_token_account: ata(curve.brand_vault, mint),
+            platform_vault: curve.platform_vault,
+            platform_vault_token_account: ata(curve.platform_vault, mint),
+            token_program: spl_token::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(accounts, billy_bonding_curve::instruction::CancelLaunch {});
+        self.process(instruction, &[creator]).await
+    }
+
+    pub async fn contribute_presale(
+        &mut self,
+        contributor: &Keypair,
+        mint: Pubkey,
+        sol_amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::ContributePresale {
+            contributor: contributor.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            presale_vault: curve.presale_vault,
+            presale_position: presale_position_pda(mint, contributor.pubkey()),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::ContributePresale { sol_amount },
+        );
+        self.process(instruction, &[contributor]).await
+    }
+
+    pub async fn reschedule_launch(
+        &mut self,
+        creator: &Keypair,
+        mint: Pubkey,
+        start_time: i64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::RescheduleLaunch {
+            creator: creator.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            presale_vault: curve.presale_vault,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::RescheduleLaunch { start_time },
+        );
+        self.process(instruction, &[creator]).await
+    }
+
+    pub async fn withdraw_fees(
+        &mut self,
+        authority: &Keypair,
+        mint: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let accounts = billy_bonding_curve::accounts::WithdrawFees {
+            authority: authority.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: bonding_curve_pda(mint),
+            platform_vault: CurveAccounts::new(mint).platform_vault,
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            clock: sysvar::clock::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(accounts, billy_bonding_curve::instruction::WithdrawFees {});
+        self.process(instruction, &[authority]).await
+    }
+
+    pub async fn claim_platform_tokens(
+        &mut self,
+        authority: &Keypair,
+        mint: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::ClaimPlatformTokens {
+            authority: authority.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            platform_vault: curve.platform_vault,
+            platform_vault_token_account: ata(curve.platform_vault, mint),
+            user_token_account: ata(authority.pubkey(), mint),
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: spl_token::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::ClaimPlatformTokens {},
+        );
+        self.process(instruction, &[authority]).await
+    }
+
+    pub async fn claim_creator_vesting(
+        &mut self,
+        creator: &Keypair,
+        mint: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::ClaimCreatorVesting {
+            creator: creator.pubkey(),
+            creator_vault: curve.creator_vault,
+            creator_vault_token_account: ata(curve.creator_vault, mint),
+            bonding_curve: curve.bonding_curve,
+            user_token_account: ata(creator.pubkey(), mint),
+            global: global_pda(),
+            mint,
+            system_program: system_program::ID,
+            clock: sysvar::clock::ID,
+            rent: sysvar::rent::ID,
+            associated_token_program: associated_token::ID,
+            token_program: spl_token::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::ClaimCreatorVesting {},
+        );
+        self.process(instruction, &[creator]).await
+    }
+
+    pub async fn release_cex_tokens(
+        &mut self,
+        authority: &Keypair,
+        mint: Pubkey,
+        exchange_wallet: Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::ReleaseCexTokens {
+            authority: authority.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            cex_vault: curve.cex_vault,
+            cex_vault_token_account: ata(curve.cex_vault, mint),
+            exchange_wallet,
+            exchange_token_account: ata(exchange_wallet, mint),
+            system_program: system_program::ID,
+            associated_token_program: associated_token::ID,
+            token_program: spl_token::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(
+            accounts,
+            billy_bonding_curve::instruction::ReleaseCexTokens { amount },
+        );
+        self.process(instruction, &[authority]).await
+    }
+
+    // nominates the new withdraw authority and accepts the role with it
+    pub async fn transfer_withdraw_authority(&mut self, new_authority: &Keypair) {
+        let accounts = billy_bonding_curve::accounts::SetParams {
+            authority: self.authority.pubkey(),
+            global: global_pda(),
+            new_authority: None,
+            new_withdraw_authority: Some(new_authority.pubkey()),
+            system_program: system_program::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        // no settings change, so nothing has to be scheduled
+        let params = GlobalSettingsInput {
+            trade_fee_bps: None,
+            created_mint_decimals: None,
+            launch_fee_lamports: None,
+            launch_fee_refund_bps: None,
+            max_launch_lead_time: None,
+            status: None,
+            amm_program: None,
+            platform_vesting_terms: None,
+            cex_vesting_terms: None,
+            caps: None,
+        };
+        let nominate = instruction(
+            accounts,
+            billy_bonding_curve::instruction::SetParams { params },
+        );
+        let authority = self.authority.insecure_clone();
+        self.process(nominate, &[&authority]).await.unwrap();
+
+        let accounts = billy_bonding_curve::accounts::AcceptAuthority {
+            new_authority: new_authority.pubkey(),
+            global: global_pda(),
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let accept = instruction(
+            accounts,
+            billy_bonding_curve::instruction::AcceptAuthority {
+                role: AuthorityRole::Withdraw,
+            },
+        );
+        self.process(accept, &[new_authority]).await.unwrap();
+    }
+
+    // writes the status straight into the global account, skipping the timelock
+    pub async fn set_status(&mut self, status: ProgramStatus) {
+        let mut global: Global = self.fetch(global_pda()).await;
+        global.status = status;
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(global_pda())
+            .await
+            .unwrap()
+            .unwrap();
+        let mut data = Vec::new();
+        global.try_serialize(&mut data).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+        self.context.set_account(&global_pda(), &account.into());
+    }
+
+    pub async fn quote(
+        &mut self,
+        mint: Pubkey,
+        params: SwapParams,
+    ) -> std::result::Result<SwapQuote, BanksClientError> {
+        let accounts = billy_bonding_curve::accounts::Quote {
+            global: global_pda(),
+            mint,
+            bonding_curve: bonding_curve_pda(mint),
+        };
+        let instruction = instruction(accounts, billy_bonding_curve::instruction::Quote { params });
+        let data = self.simulate(instruction).await?;
+        Ok(SwapQuote::try_from_slice(&data).unwrap())
+    }
+
+    pub async fn skim(
+        &mut self,
+        authority: &Keypair,
+        mint: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::Skim {
+            authority: authority.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            platform_vault: curve.platform_vault,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(accounts, billy_bonding_curve::instruction::Skim {});
+        self.process(instruction, &[authority]).await
+    }
+
+    pub async fn claim_presale(
+        &mut self,
+        contributor: &Keypair,
+        mint: Pubkey,
+    ) -> std::result::Result<(), BanksClientError> {
+        let curve = CurveAccounts::new(mint);
+        let accounts = billy_bonding_curve::accounts::ClaimPresale {
+            contributor: contributor.pubkey(),
+            global: global_pda(),
+            mint,
+            bonding_curve: curve.bonding_curve,
+            presale_vault: curve.presale_vault,
+            presale_vault_token_account: ata(curve.presale_vault, mint),
+            presale_position: presale_position_pda(mint, contributor.pubkey()),
+            user_token_account: ata(contributor.pubkey(), mint),
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            associated_token_program: associated_token::ID,
+            event_authority: event_authority(),
+            program: billy_bonding_curve::ID,
+        };
+        let instruction = instruction(accounts, billy_bonding_curve::instruction::ClaimPresale {});
+        self.process(instruction, &[contributor]).await
+    }
+
+    // lamports sent straight to an account, outside any instruction of the program
+    pub async fn donate(&mut self, to: Pubkey, lamports: u64) {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(to)
+            .await
+            .unwrap()
+            .unwrap();
+        account.lamports += lamports;
+        self.context.set_account(&to, &account.into());
+    }
+}
+
+pub struct CurveAccounts {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator_vault: Pubkey,
+    pub presale_vault: Pubkey,
+    pub brand_vault: Pubkey,
+    pub platform_vault: Pubkey,
+    pub cex_vault: Pubkey,
+}
+
+impl CurveAccounts {
+    pub fn new(mint: Pubkey) -> Self {
+        let vault = |prefix: &str| pda(&[prefix.as_bytes(), mint.as_ref()]);
+        Self {
+            mint,
+            bonding_curve: vault(BondingCurve::SEED_PREFIX),
+            creator_vault: vault(CreatorVault::SEED_PREFIX),
+            presale_vault: vault(PresaleVault::SEED_PREFIX),
+            brand_vault: vault(BrandVault::SEED_PREFIX),
+            platform_vault: vault(PlatformVault::SEED_PREFIX),
+            cex_vault: vault(CexVault::SEED_PREFIX),
+        }
+    }
+}
+
+pub fn global_settings() -> GlobalSettingsInput {
+    GlobalSettingsInput {
+        trade_fee_bps: Some(100),
+        created_mint_decimals: Some(TOKEN_DECIMALS),
+        launch_fee_lamports: Some(LAUNCH_FEE_LAMPORTS),
+        launch_fee_refund_bps: None,
+        max_launch_lead_time: None,
+        status: Some(ProgramStatus::Running),
+        amm_program: None,
+        platform_vesting_terms: None,
+        cex_vesting_terms: None,
+        caps: None,
+    }
+}
+
+pub fn curve_params() -> CreateBondingCurveParams {
+    CreateBondingCurveParams {
+        name: "testCurve".to_string(),
+        symbol: "TEST".to_string(),
+        uri: "https://www.testCurve.com".to_string(),
+        start_time: None,
+        graduation_deadline: None,
+        token_total_supply: TOKEN_TOTAL_SUPPLY,
+        sol_launch_threshold: SOL_LAUNCH_THRESHOLD,
+        virtual_token_multiplier_bps: 730,
+        virtual_sol_reserves: 30 * LAMPORTS_PER_SOL,
+        allocation: AllocationDataParams::default(),
+        vesting_terms: None,
+        presale_terms: None,
+        brandkit_vesting_terms: None,
+        launch_protection: None,
+        fee_schedule: None,
+        allowlist: None,
+        curve_kind: None,
+        completion_policy: None,
+    }
+}
+
+// a presale over the next `duration` seconds, the curve opens when it closes
+pub fn presale_params(now: i64, duration: i64, presale_sol_cap: u64) -> CreateBondingCurveParams {
+    CreateBondingCurveParams {
+        start_time: Some(now + duration),
+        allocation: AllocationDataParams {
+            presale: Some(1000),
+            pool_reserve: Some(4000),
+            ..AllocationDataParams::default()
+        },
+        presale_terms: Some(PresaleTerms {
+            open_time: now,
+            close_time: now + duration,
+            max_contribution_per_wallet: presale_sol_cap,
+            presale_sol_cap,
+        }),
+        ..curve_params()
+    }
+}
+
+pub fn buy_params(sol_amount: u64) -> SwapParams {
+    SwapParams {
+        base_in: false,
+        exact_in_amount: sol_amount,
+        min_out_amount: 0,
+        exact_out_amount: 0,
+        max_in_amount: 0,
+        allowlist_proof: None,
+    }
+}
+
+pub fn sell_params(token_amount: u64) -> SwapParams {
+    SwapParams {
+        base_in: true,
+        ..buy_params(token_amount)
+    }
+}
+
+// program errors and anchor's own, both convert to their custom error code
+pub fn assert_contract_error<T: std::fmt::Debug>(
+    result: std::result::Result<T, BanksClientError>,
+    error: impl Into<u32>,
+) {
+    let code: u32 = error.into();
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(actual)) => {
+            assert_eq!(actual, code)
+        }
+        other => panic!("expected custom error {}, got {:?}", code, other),
+    }
+}
+
+pub fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: billy_bonding_curve::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn system_account(lamports: u64) -> Account {
+    Account {
+        lamports,
+        owner: system_program::ID,
+        ..Account::default()
+    }
+}
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &billy_bonding_curve::ID).0
+}
+
+pub fn global_pda() -> Pubkey {
+    pda(&[Global::SEED_PREFIX.as_bytes()])
+}
+
+pub fn bonding_curve_pda(mint: Pubkey) -> Pubkey {
+    pda(&[BondingCurve::SEED_PREFIX.as_bytes(), mint.as_ref()])
+}
+
+pub fn user_position_pda(mint: Pubkey, user: Pubkey) -> Pubkey {
+    pda(&[
+        UserPosition::SEED_PREFIX.as_bytes(),
+        mint.as_ref(),
+        user.as_ref(),
+    ])
+}
+
+pub fn presale_position_pda(mint: Pubkey, contributor: Pubkey) -> Pubkey {
+    pda(&[
+        PresalePosition::SEED_PREFIX.as_bytes(),
+        mint.as_ref(),
+        contributor.as_ref(),
+    ])
+}
+
+pub fn event_authority() -> Pubkey {
+    pda(&[b"__event_authority"])
+}
+
+pub fn metadata_pda(mint: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()],
+        &mpl_token_metadata::ID,
+    )
+    .0
+}
+
+pub fn ata(owner: Pubkey, mint: Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(&owner, &mint, &token::ID)
+}
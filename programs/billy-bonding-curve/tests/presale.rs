@@ -0,0 +1,55 @@
+mod common;
+
+use billy_bonding_curve::{errors::ContractError, state::bonding_curve::BondingCurve};
+use common::*;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+
+const PRESALE_DURATION: i64 = 1_000;
+const PRESALE_SOL_CAP: u64 = 10 * LAMPORTS_PER_SOL;
+
+#[tokio::test]
+async fn test_presale_sol_cap() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    let mint = test
+        .create_curve(presale_params(now, PRESALE_DURATION, PRESALE_SOL_CAP))
+        .await
+        .unwrap();
+    let contributor = test.new_wallet();
+    let other_contributor = test.new_wallet();
+
+    test.contribute_presale(&contributor, mint, 6 * LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+    // the cap is shared by every contributor
+    assert_contract_error(
+        test.contribute_presale(&other_contributor, mint, 5 * LAMPORTS_PER_SOL)
+            .await,
+        ContractError::PresaleCapExceeded,
+    );
+    test.contribute_presale(&other_contributor, mint, 4 * LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+
+    let curve: BondingCurve = test.fetch(bonding_curve_pda(mint)).await;
+    assert_eq!(curve.presale_sol_raised, PRESALE_SOL_CAP);
+}
+
+#[tokio::test]
+async fn test_presale_sol_cap_below_threshold() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    // a presale reaching the threshold would complete the curve before anyone trades
+    assert_contract_error(
+        test.create_curve(presale_params(now, PRESALE_DURATION, SOL_LAUNCH_THRESHOLD))
+            .await,
+        ContractError::InvalidPresaleTerms,
+    );
+    assert_contract_error(
+        test.create_curve(presale_params(now, PRESALE_DURATION, 0))
+            .await,
+        ContractError::InvalidPresaleTerms,
+    );
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use billy_bonding_curve::{
+    errors::ContractError,
+    state::{bonding_curve::CreateBondingCurveParams, global::ProgramStatus},
+};
+use common::*;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+
+async fn traded_curve(test: &mut TestContext) -> (Pubkey, Keypair) {
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    let mint = test
+        .create_curve(CreateBondingCurveParams {
+            graduation_deadline: Some(now + 1_000),
+            ..curve_params()
+        })
+        .await
+        .unwrap();
+    let buyer = test.new_wallet();
+    test.buy(&buyer, mint, LAMPORTS_PER_SOL).await.unwrap();
+    (mint, buyer)
+}
+
+#[tokio::test]
+async fn test_paused() {
+    let mut test = TestContext::start().await;
+    let (mint, buyer) = traded_curve(&mut test).await;
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    test.set_status(ProgramStatus::Paused).await;
+
+    assert_contract_error(
+        test.create_curve(curve_params()).await,
+        ContractError::CreateDisabled,
+    );
+    assert_contract_error(
+        test.buy(&buyer, mint, LAMPORTS_PER_SOL).await,
+        ContractError::BuyDisabled,
+    );
+    assert_contract_error(
+        test.swap(&buyer, mint, sell_params(tokens)).await,
+        ContractError::SellDisabled,
+    );
+    assert_contract_error(
+        test.contribute_presale(&buyer, mint, LAMPORTS_PER_SOL)
+            .await,
+        ContractError::BuyDisabled,
+    );
+
+    let authority = test.authority.insecure_clone();
+    assert_contract_error(
+        test.claim_platform_tokens(&authority, mint).await,
+        ContractError::ClaimDisabled,
+    );
+    assert_contract_error(
+        test.withdraw_fees(&authority, mint).await,
+        ContractError::FeeWithdrawalDisabled,
+    );
+
+    let creator = test.creator.insecure_clone();
+    let now = test.now().await;
+    assert_contract_error(
+        test.reschedule_launch(&creator, mint, now + 100).await,
+        ContractError::RescheduleDisabled,
+    );
+    assert_contract_error(
+        test.cancel_launch(&creator, mint).await,
+        ContractError::RescheduleDisabled,
+    );
+
+    // a failing curve waits for the program to resume
+    test.warp_to(now + 1_000).await;
+    assert_contract_error(test.fail_curve(mint).await, ContractError::SellDisabled);
+    test.set_status(ProgramStatus::Running).await;
+    test.fail_curve(mint).await.unwrap();
+    test.set_status(ProgramStatus::Paused).await;
+    assert_contract_error(
+        test.refund(&buyer, mint, tokens).await,
+        ContractError::SellDisabled,
+    );
+}
+
+#[tokio::test]
+async fn test_swap_only() {
+    let mut test = TestContext::start().await;
+    let (mint, buyer) = traded_curve(&mut test).await;
+    test.set_status(ProgramStatus::SwapOnly).await;
+
+    assert_contract_error(
+        test.create_curve(curve_params()).await,
+        ContractError::CreateDisabled,
+    );
+    test.buy(&buyer, mint, LAMPORTS_PER_SOL).await.unwrap();
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    test.swap(&buyer, mint, sell_params(tokens)).await.unwrap();
+    let authority = test.authority.insecure_clone();
+    test.withdraw_fees(&authority, mint).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_swap_only_no_launch() {
+    let mut test = TestContext::start().await;
+    let (mint, buyer) = traded_curve(&mut test).await;
+    test.set_status(ProgramStatus::SwapOnlyNoLaunch).await;
+
+    assert_contract_error(
+        test.create_curve(curve_params()).await,
+        ContractError::CreateDisabled,
+    );
+    test.buy(&buyer, mint, LAMPORTS_PER_SOL).await.unwrap();
+    // trading goes on but nothing completes
+    let whale = test.new_wallet();
+    assert_contract_error(
+        test.buy(&whale, mint, SOL_LAUNCH_THRESHOLD).await,
+        ContractError::LaunchDisabled,
+    );
+    test.set_status(ProgramStatus::Running).await;
+    test.buy(&whale, mint, SOL_LAUNCH_THRESHOLD).await.unwrap();
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use billy_bonding_curve::{
+    errors::ContractError,
+    state::bonding_curve::{BondingCurve, CreateBondingCurveParams},
+};
+use common::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+
+#[tokio::test]
+async fn test_quote_matches_swap() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let mint = test.create_curve(curve_params()).await.unwrap();
+    let curve = CurveAccounts::new(mint);
+    let buyer = test.new_wallet();
+
+    let quote = test
+        .quote(mint, buy_params(10 * LAMPORTS_PER_SOL))
+        .await
+        .unwrap();
+    // quoting leaves the curve untouched
+    let before: BondingCurve = test.fetch(curve.bonding_curve).await;
+    assert_eq!(before.real_sol_reserves, 0);
+    assert!(quote.spot_price_after > quote.spot_price_before);
+    assert!(!quote.completes_curve);
+
+    let vault_lamports = test.lamports(curve.platform_vault).await;
+    test.buy(&buyer, mint, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    let after: BondingCurve = test.fetch(curve.bonding_curve).await;
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    assert_eq!(tokens, quote.amount_out);
+    assert_eq!(after.real_sol_reserves, quote.real_sol_reserves);
+    assert_eq!(after.real_token_reserves, quote.real_token_reserves);
+    assert_eq!(after.virtual_token_reserves, quote.virtual_token_reserves);
+    assert_eq!(
+        test.lamports(curve.platform_vault).await - vault_lamports,
+        quote.fee_lamports
+    );
+
+    let quote = test.quote(mint, sell_params(tokens)).await.unwrap();
+    assert_eq!(quote.amount_in, tokens);
+    let before = test.lamports(buyer.pubkey()).await;
+    test.swap(&buyer, mint, sell_params(tokens)).await.unwrap();
+    assert_eq!(
+        test.lamports(buyer.pubkey()).await - before,
+        quote.amount_out
+    );
+}
+
+#[tokio::test]
+async fn test_quote_completes_curve() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let mint = test.create_curve(curve_params()).await.unwrap();
+
+    let quote = test
+        .quote(mint, buy_params(2 * SOL_LAUNCH_THRESHOLD))
+        .await
+        .unwrap();
+    assert!(quote.completes_curve);
+    // the buy is only filled up to the threshold
+    assert!(quote.amount_in < 2 * SOL_LAUNCH_THRESHOLD);
+
+    let whale = test.new_wallet();
+    test.buy(&whale, mint, 2 * SOL_LAUNCH_THRESHOLD)
+        .await
+        .unwrap();
+    assert_contract_error(
+        test.quote(mint, buy_params(LAMPORTS_PER_SOL)).await,
+        ContractError::BondingCurveComplete,
+    );
+}
+
+#[tokio::test]
+async fn test_quote_rejected() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    let mint = test
+        .create_curve(CreateBondingCurveParams {
+            graduation_deadline: Some(now + 1_000),
+            ..curve_params()
+        })
+        .await
+        .unwrap();
+    assert_contract_error(
+        test.quote(mint, buy_params(0)).await,
+        ContractError::MinSwap,
+    );
+
+    test.warp_to(now + 1_000).await;
+    test.fail_curve(mint).await.unwrap();
+    assert_contract_error(
+        test.quote(mint, buy_params(LAMPORTS_PER_SOL)).await,
+        ContractError::BondingCurveFailed,
+    );
+}
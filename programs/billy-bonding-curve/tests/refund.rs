@@ -0,0 +1,227 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use billy_bonding_curve::{
+    errors::ContractError,
+    state::bonding_curve::{BondingCurve, CreateBondingCurveParams, VestingAnchor, VestingTerms},
+};
+use common::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+
+const GRADUATION_WINDOW: i64 = 1_000;
+
+async fn failing_curve(test: &mut TestContext) -> Pubkey {
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    test.create_curve(CreateBondingCurveParams {
+        graduation_deadline: Some(now + GRADUATION_WINDOW),
+        ..curve_params()
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_fail_curve_then_refund() {
+    let mut test = TestContext::start().await;
+    let mint = failing_curve(&mut test).await;
+    let buyer = test.new_wallet();
+    let other_buyer = test.new_wallet();
+    test.buy(&buyer, mint, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    test.buy(&other_buyer, mint, 30 * LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+
+    // nothing fails before the deadline
+    assert_contract_error(
+        test.fail_curve(mint).await,
+        ContractError::GraduationDeadlineNotReached,
+    );
+    let now = test.now().await;
+    test.warp_to(now + GRADUATION_WINDOW).await;
+    test.fail_curve(mint).await.unwrap();
+
+    let curve: BondingCurve = test.fetch(bonding_curve_pda(mint)).await;
+    assert!(curve.failed);
+    assert_eq!(curve.real_token_reserves, 0);
+    let reserves = curve.real_sol_reserves;
+    let buyer_tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    let other_tokens = test.token_balance(ata(other_buyer.pubkey(), mint)).await;
+    // claimable allocations never share the reserves
+    assert_eq!(curve.refund_token_supply, buyer_tokens + other_tokens);
+
+    // buyers split the reserves by the tokens they hold
+    let before = test.lamports(buyer.pubkey()).await;
+    test.refund(&buyer, mint, buyer_tokens).await.unwrap();
+    let refunded = test.lamports(buyer.pubkey()).await - before;
+    assert_eq!(
+        refunded as u128,
+        reserves as u128 * buyer_tokens as u128 / curve.refund_token_supply as u128
+    );
+    assert_eq!(test.token_balance(ata(buyer.pubkey(), mint)).await, 0);
+
+    // the last buyer takes whatever is left
+    let before = test.lamports(other_buyer.pubkey()).await;
+    test.refund(&other_buyer, mint, other_tokens).await.unwrap();
+    let other_refunded = test.lamports(other_buyer.pubkey()).await - before;
+    assert_eq!(refunded + other_refunded, reserves);
+
+    let curve: BondingCurve = test.fetch(bonding_curve_pda(mint)).await;
+    assert_eq!(curve.real_sol_reserves, 0);
+    assert_eq!(curve.refund_token_supply, 0);
+}
+
+#[tokio::test]
+async fn test_refund_rejected() {
+    let mut test = TestContext::start().await;
+    let mint = failing_curve(&mut test).await;
+    let buyer = test.new_wallet();
+    test.buy(&buyer, mint, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+
+    // only failed curves refund
+    assert_contract_error(
+        test.refund(&buyer, mint, tokens).await,
+        ContractError::BondingCurveNotFailed,
+    );
+
+    let now = test.now().await;
+    test.warp_to(now + GRADUATION_WINDOW).await;
+    test.fail_curve(mint).await.unwrap();
+    assert_contract_error(
+        test.fail_curve(mint).await,
+        ContractError::BondingCurveFailed,
+    );
+    assert_contract_error(
+        test.refund(&buyer, mint, tokens + 1).await,
+        ContractError::InsufficientUserTokens,
+    );
+    assert_contract_error(
+        test.refund(&buyer, mint, 0).await,
+        ContractError::InvalidArgument,
+    );
+    // a failed curve no longer trades
+    assert_contract_error(
+        test.buy(&buyer, mint, LAMPORTS_PER_SOL).await,
+        ContractError::BondingCurveFailed,
+    );
+}
+
+#[tokio::test]
+async fn test_refund_with_presale() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    let presale_duration = 1_000;
+    let mint = test
+        .create_curve(CreateBondingCurveParams {
+            graduation_deadline: Some(now + presale_duration + GRADUATION_WINDOW),
+            ..presale_params(now, presale_duration, 10 * LAMPORTS_PER_SOL)
+        })
+        .await
+        .unwrap();
+    let contributor = test.new_wallet();
+    test.contribute_presale(&contributor, mint, 5 * LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+
+    test.warp_to(now + presale_duration).await;
+    let buyer = test.new_wallet();
+    test.buy(&buyer, mint, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    test.warp_to(now + presale_duration + GRADUATION_WINDOW)
+        .await;
+    test.fail_curve(mint).await.unwrap();
+
+    // presale tokens redeem alongside the curve buyers, against the presale SOL as well
+    test.claim_presale(&contributor, mint).await.unwrap();
+    let curve: BondingCurve = test.fetch(bonding_curve_pda(mint)).await;
+    let reserves = curve.real_sol_reserves;
+    assert_eq!(curve.presale_sol_raised, 5 * LAMPORTS_PER_SOL);
+    let presale_tokens = test.token_balance(ata(contributor.pubkey(), mint)).await;
+    let buyer_tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    assert_eq!(curve.refund_token_supply, presale_tokens + buyer_tokens);
+
+    let before = test.lamports(contributor.pubkey()).await;
+    test.refund(&contributor, mint, presale_tokens)
+        .await
+        .unwrap();
+    let presale_refund = test.lamports(contributor.pubkey()).await - before;
+    assert_eq!(
+        presale_refund as u128,
+        reserves as u128 * presale_tokens as u128 / curve.refund_token_supply as u128
+    );
+    let before = test.lamports(buyer.pubkey()).await;
+    test.refund(&buyer, mint, buyer_tokens).await.unwrap();
+    assert_eq!(
+        test.lamports(buyer.pubkey()).await - before + presale_refund,
+        reserves
+    );
+}
+
+#[tokio::test]
+async fn test_refund_rejected_without_tokens() {
+    let mut test = TestContext::start().await;
+    let mint = failing_curve(&mut test).await;
+    let buyer = test.new_wallet();
+    test.buy(&buyer, mint, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    let now = test.now().await;
+    test.warp_to(now + GRADUATION_WINDOW).await;
+    test.fail_curve(mint).await.unwrap();
+
+    // someone who never bought has no token account to burn from
+    let other = test.new_wallet();
+    assert_contract_error(
+        test.refund(&other, mint, 1).await,
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+}
+
+#[tokio::test]
+async fn test_refund_rejected_for_vested_allocations() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let now = test.now().await;
+    // the creator allocation vests in full as soon as the curve starts
+    let mint = test
+        .create_curve(CreateBondingCurveParams {
+            graduation_deadline: Some(now + GRADUATION_WINDOW),
+            vesting_terms: Some(VestingTerms {
+                cliff: 0,
+                duration: 0,
+                anchor: VestingAnchor::StartTime,
+            }),
+            ..curve_params()
+        })
+        .await
+        .unwrap();
+    let buyer = test.new_wallet();
+    test.buy(&buyer, mint, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    test.warp_to(now + 100).await;
+
+    // allocations stay in their vaults while the curve may still fail
+    let creator = test.creator.insecure_clone();
+    assert_contract_error(
+        test.claim_creator_vesting(&creator, mint).await,
+        ContractError::BondingCurveNotComplete,
+    );
+    test.warp_to(now + GRADUATION_WINDOW).await;
+    test.fail_curve(mint).await.unwrap();
+    assert_contract_error(
+        test.claim_creator_vesting(&creator, mint).await,
+        ContractError::BondingCurveFailed,
+    );
+    assert_contract_error(
+        test.refund(&creator, mint, 1).await,
+        anchor_lang::error::ErrorCode::AccountNotInitialized,
+    );
+
+    // so the buyer still gets the whole reserves back
+    let curve: BondingCurve = test.fetch(bonding_curve_pda(mint)).await;
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    let before = test.lamports(buyer.pubkey()).await;
+    test.refund(&buyer, mint, tokens).await.unwrap();
+    assert_eq!(
+        test.lamports(buyer.pubkey()).await - before,
+        curve.real_sol_reserves
+    );
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use billy_bonding_curve::{errors::ContractError, state::vaults::CexVault};
+use common::*;
+
+// past the cliff and duration of the default cex vesting terms
+const VESTED: i64 = 60 * 24 * 60 * 60;
+
+#[tokio::test]
+async fn test_release_cex_tokens_after_withdraw_authority_rotation() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let mint = test.create_curve(curve_params()).await.unwrap();
+    let now = test.now().await;
+    test.warp_to(now + VESTED).await;
+
+    let exchange_wallet = Pubkey::new_unique();
+    let previous_authority = test.authority.insecure_clone();
+    let new_authority = test.new_wallet();
+    test.transfer_withdraw_authority(&new_authority).await;
+
+    // the rotation covers curves created before it
+    assert_contract_error(
+        test.release_cex_tokens(&previous_authority, mint, exchange_wallet, 1_000)
+            .await,
+        ContractError::InvalidWithdrawAuthority,
+    );
+    test.release_cex_tokens(&new_authority, mint, exchange_wallet, 1_000)
+        .await
+        .unwrap();
+    assert_eq!(test.token_balance(ata(exchange_wallet, mint)).await, 1_000);
+    let cex_vault: CexVault = test.fetch(CurveAccounts::new(mint).cex_vault).await;
+    assert_eq!(cex_vault.tokens_released, 1_000);
+}
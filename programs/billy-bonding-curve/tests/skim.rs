@@ -0,0 +1,67 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use billy_bonding_curve::{errors::ContractError, state::bonding_curve::BondingCurve};
+use common::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+
+async fn traded_curve(test: &mut TestContext) -> Pubkey {
+    test.initialize(global_settings()).await;
+    let mint = test.create_curve(curve_params()).await.unwrap();
+    let buyer = test.new_wallet();
+    test.buy(&buyer, mint, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    mint
+}
+
+#[tokio::test]
+async fn test_skim() {
+    let mut test = TestContext::start().await;
+    let mint = traded_curve(&mut test).await;
+    let curve = CurveAccounts::new(mint);
+    let reserves = test
+        .fetch::<BondingCurve>(curve.bonding_curve)
+        .await
+        .real_sol_reserves;
+
+    // only lamports above the reserves are skimmed
+    let authority = test.authority.insecure_clone();
+    assert_contract_error(
+        test.skim(&authority, mint).await,
+        ContractError::NoSurplusLamports,
+    );
+    let donation = LAMPORTS_PER_SOL;
+    test.donate(curve.bonding_curve, donation).await;
+    let curve_lamports = test.lamports(curve.bonding_curve).await;
+    let vault_lamports = test.lamports(curve.platform_vault).await;
+    test.skim(&authority, mint).await.unwrap();
+
+    assert_eq!(
+        test.lamports(curve.bonding_curve).await,
+        curve_lamports - donation
+    );
+    assert_eq!(
+        test.lamports(curve.platform_vault).await,
+        vault_lamports + donation
+    );
+    let bonding_curve: BondingCurve = test.fetch(curve.bonding_curve).await;
+    assert_eq!(bonding_curve.real_sol_reserves, reserves);
+}
+
+#[tokio::test]
+async fn test_skim_rejected_for_other_authority() {
+    let mut test = TestContext::start().await;
+    let mint = traded_curve(&mut test).await;
+    test.donate(bonding_curve_pda(mint), LAMPORTS_PER_SOL).await;
+    let other = test.new_wallet();
+    assert_contract_error(
+        test.skim(&other, mint).await,
+        ContractError::InvalidWithdrawAuthority,
+    );
+    // the creator doesn't own the surplus either
+    let creator = test.creator.insecure_clone();
+    assert_ne!(creator.pubkey(), test.authority.pubkey());
+    assert_contract_error(
+        test.skim(&creator, mint).await,
+        ContractError::InvalidWithdrawAuthority,
+    );
+}
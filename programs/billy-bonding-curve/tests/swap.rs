@@ -0,0 +1,91 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use billy_bonding_curve::{
+    errors::ContractError,
+    state::{
+        bonding_curve::{CreateBondingCurveParams, LaunchProtection},
+        position::UserPosition,
+    },
+};
+use common::*;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Signer};
+
+async fn account_exists(test: &mut TestContext, address: Pubkey) -> bool {
+    test.context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .is_some()
+}
+
+#[tokio::test]
+async fn test_swap_without_user_position() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let mint = test.create_curve(curve_params()).await.unwrap();
+    let buyer = test.new_wallet();
+    let position = user_position_pda(mint, buyer.pubkey());
+
+    // nothing caps the buys, so nothing is recorded for the wallet
+    test.buy(&buyer, mint, LAMPORTS_PER_SOL).await.unwrap();
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    test.swap(&buyer, mint, sell_params(tokens / 2))
+        .await
+        .unwrap();
+    assert!(!account_exists(&mut test, position).await);
+}
+
+#[tokio::test]
+async fn test_protected_buy_requires_user_position() {
+    let mut test = TestContext::start().await;
+    test.initialize(global_settings()).await;
+    let window = 60 * 60;
+    let mint = test
+        .create_curve(CreateBondingCurveParams {
+            launch_protection: Some(LaunchProtection {
+                window,
+                max_buy_lamports: LAMPORTS_PER_SOL,
+                max_wallet_lamports: 2 * LAMPORTS_PER_SOL,
+            }),
+            ..curve_params()
+        })
+        .await
+        .unwrap();
+    let start = test.now().await;
+    let buyer = test.new_wallet();
+    let position = user_position_pda(mint, buyer.pubkey());
+
+    // the wallet cap can't be skipped by leaving the position out
+    assert_contract_error(
+        test.swap_with_position(&buyer, mint, buy_params(LAMPORTS_PER_SOL), None)
+            .await,
+        ContractError::MissingUserPosition,
+    );
+    test.buy(&buyer, mint, LAMPORTS_PER_SOL).await.unwrap();
+    assert_eq!(
+        test.fetch::<UserPosition>(position)
+            .await
+            .protected_buy_lamports,
+        LAMPORTS_PER_SOL
+    );
+
+    // sells never need it
+    let tokens = test.token_balance(ata(buyer.pubkey(), mint)).await;
+    test.swap_with_position(&buyer, mint, sell_params(tokens / 2), None)
+        .await
+        .unwrap();
+
+    // nor do buys once the window is over
+    test.warp_to(start + window).await;
+    test.swap_with_position(&buyer, mint, buy_params(LAMPORTS_PER_SOL), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        test.fetch::<UserPosition>(position)
+            .await
+            .protected_buy_lamports,
+        LAMPORTS_PER_SOL
+    );
+}
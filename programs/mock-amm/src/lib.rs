@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, MintTo, Token, TokenAccount, Transfer},
+};
+declare_id!("CQja8FXJWb3ioMdwCRuWiXQsYoDexh5RAXV1amDMMrwa");
+
+// Minimal constant-product pool implementing the graduation interface of
+// billy-bonding-curve (see `billy_bonding_curve::amm`). Only used in tests.
+#[program]
+pub mod mock_amm {
+
+    use super::*;
+
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        sol_amount: u64,
+        token_amount: u64,
+    ) -> Result<()> {
+        require!(
+            sol_amount > 0 && token_amount > 0,
+            MockAmmError::ZeroLiquidity
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.liquidity_provider.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            sol_amount,
+        )?;
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx
+                        .accounts
+                        .liquidity_provider_token_account
+                        .to_account_info(),
+                    to: ctx.accounts.pool_token_account.to_account_info(),
+                    authority: ctx.accounts.liquidity_provider.to_account_info(),
+                },
+            ),
+            token_amount,
+        )?;
+
+        let lp_amount = Pool::initial_lp_amount(sol_amount, token_amount);
+        let mint_k = ctx.accounts.mint.key();
+        let signer = Pool::get_signer(&ctx.bumps.pool, &mint_k);
+        let signer_seeds = &[&signer[..]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .liquidity_provider_lp_token_account
+                        .to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            lp_amount,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.mint = mint_k;
+        pool.lp_mint = ctx.accounts.lp_mint.key();
+        pool.sol_reserves = sol_amount;
+        pool.token_reserves = token_amount;
+        pool.lp_supply = lp_amount;
+        pool.bump = ctx.bumps.pool;
+        msg!("MockAmm::initialize_pool: {:#?}", pool);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(mut)]
+    liquidity_provider: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = liquidity_provider,
+    )]
+    liquidity_provider_token_account: Box<Account<'info, TokenAccount>>,
+
+    mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [Pool::SEED_PREFIX.as_bytes(), mint.key().as_ref()],
+        space = 8 + Pool::INIT_SPACE,
+        bump,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [Pool::LP_MINT_SEED_PREFIX.as_bytes(), mint.key().as_ref()],
+        mint::decimals = mint.decimals,
+        mint::authority = pool,
+        bump,
+    )]
+    lp_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = liquidity_provider,
+    )]
+    liquidity_provider_lp_token_account: Box<Account<'info, TokenAccount>>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+
+    // pool specific accounts, passed as remaining accounts by the caller
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+    )]
+    pool_token_account: Box<Account<'info, TokenAccount>>,
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Pool {
+    pub mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    pub lp_supply: u64,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const SEED_PREFIX: &'static str = "pool";
+    pub const LP_MINT_SEED_PREFIX: &'static str = "lp-mint";
+
+    pub fn get_signer<'a>(bump: &'a u8, mint: &'a Pubkey) -> [&'a [u8]; 3] {
+        [
+            Self::SEED_PREFIX.as_bytes(),
+            mint.as_ref(),
+            std::slice::from_ref(bump),
+        ]
+    }
+
+    // sqrt(x * y), same as uniswap v2
+    pub fn initial_lp_amount(sol_amount: u64, token_amount: u64) -> u64 {
+        let product = (sol_amount as u128) * (token_amount as u128);
+        let mut x = product;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + product / x) / 2;
+        }
+        x as u64
+    }
+}
+
+#[error_code]
+pub enum MockAmmError {
+    #[msg("Liquidity amounts must be non zero")]
+    ZeroLiquidity,
+}
@@ -0,0 +1,198 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { BankrunProvider } from "anchor-bankrun";
+import { ProgramTestContext, startAnchor } from "solana-bankrun";
+import assert from "assert";
+import { BillyBondingCurve } from "../target/types/billy_bonding_curve";
+
+// Two-step global/withdraw authority transfer, runs fully in bankrun.
+
+const INITIAL_SOL = 100 * LAMPORTS_PER_SOL;
+
+const systemAccount = (lamports: number) => ({
+  lamports,
+  executable: false,
+  data: Buffer.from([]),
+  owner: SystemProgram.programId,
+});
+
+const nominee = Keypair.generate();
+const stranger = Keypair.generate();
+
+let context: ProgramTestContext;
+let program: Program<BillyBondingCurve>;
+
+describe("authority transfer", () => {
+  let globalPda: PublicKey;
+  let eventAuthority: PublicKey;
+  let admin: PublicKey;
+
+  const setParams = (accounts: {
+    newAuthority?: PublicKey | null;
+    newWithdrawAuthority?: PublicKey | null;
+  }) =>
+    program.methods
+      .setParams({
+        tradeFeeBps: null,
+        createdMintDecimals: null,
+        launchFeeLamports: null,
+        launchFeeRefundBps: null,
+        maxLaunchLeadTime: null,
+        status: null,
+        ammProgram: null,
+        platformVestingTerms: null,
+        cexVestingTerms: null,
+        caps: null,
+      })
+      .accounts({
+        authority: admin,
+        global: globalPda,
+        newAuthority: accounts.newAuthority ?? null,
+        newWithdrawAuthority: accounts.newWithdrawAuthority ?? null,
+        systemProgram: SystemProgram.programId,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+  const acceptAuthority = (role: any, signer: Keypair) =>
+    program.methods
+      .acceptAuthority(role)
+      .accounts({
+        newAuthority: signer.publicKey,
+        global: globalPda,
+        eventAuthority,
+        program: program.programId,
+      })
+      .signers([signer])
+      .rpc();
+
+  const cancelAuthorityTransfer = (role: any) =>
+    program.methods
+      .cancelAuthorityTransfer(role)
+      .accounts({
+        authority: admin,
+        global: globalPda,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+  before(async () => {
+    process.env.ANCHOR_WALLET = "./keys/test-kp.json";
+    context = await startAnchor(
+      "./",
+      [],
+      [
+        { address: nominee.publicKey, info: systemAccount(INITIAL_SOL) },
+        { address: stranger.publicKey, info: systemAccount(INITIAL_SOL) },
+      ]
+    );
+    const provider = new BankrunProvider(context);
+    anchor.setProvider(provider);
+    program = anchor.workspace.BillyBondingCurve as Program<BillyBondingCurve>;
+    admin = context.payer.publicKey;
+
+    globalPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("global")],
+      program.programId
+    )[0];
+    eventAuthority = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority")],
+      program.programId
+    )[0];
+
+    await program.methods
+      .initialize({
+        tradeFeeBps: new BN(100),
+        createdMintDecimals: 6,
+        launchFeeLamports: new BN(0.5 * LAMPORTS_PER_SOL),
+        launchFeeRefundBps: null,
+        maxLaunchLeadTime: null,
+        status: { running: {} },
+        ammProgram: null,
+        platformVestingTerms: null,
+        cexVestingTerms: null,
+        caps: null,
+      })
+      .accounts({
+        authority: admin,
+        global: globalPda,
+        systemProgram: SystemProgram.programId,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  });
+
+  it("set_params only nominates the new authorities", async () => {
+    await setParams({
+      newAuthority: nominee.publicKey,
+      newWithdrawAuthority: nominee.publicKey,
+    });
+
+    const global = await program.account.global.fetch(globalPda);
+    assert(global.globalAuthority.equals(admin));
+    assert(global.withdrawAuthority.equals(admin));
+    assert(global.pendingGlobalAuthority.equals(nominee.publicKey));
+    assert(global.pendingWithdrawAuthority.equals(nominee.publicKey));
+  });
+
+  it("cant be accepted by anyone but the nominee", async () => {
+    try {
+      await acceptAuthority({ global: {} }, stranger);
+      assert(false);
+    } catch (e) {
+      assert(e.toString().includes("InvalidPendingAuthority"));
+    }
+  });
+
+  it("accepts the global authority", async () => {
+    await acceptAuthority({ global: {} }, nominee);
+
+    const global = await program.account.global.fetch(globalPda);
+    assert(global.globalAuthority.equals(nominee.publicKey));
+    assert(global.pendingGlobalAuthority === null);
+    // the withdraw role is still pending
+    assert(global.withdrawAuthority.equals(admin));
+  });
+
+  it("cant be cancelled by the previous authority", async () => {
+    try {
+      await cancelAuthorityTransfer({ withdraw: {} });
+      assert(false);
+    } catch (e) {
+      assert(e.toString().includes("InvalidGlobalAuthority"));
+    }
+  });
+
+  it("cancels the withdraw authority transfer", async () => {
+    await program.methods
+      .cancelAuthorityTransfer({ withdraw: {} })
+      .accounts({
+        authority: nominee.publicKey,
+        global: globalPda,
+        eventAuthority,
+        program: program.programId,
+      })
+      .signers([nominee])
+      .rpc();
+
+    const global = await program.account.global.fetch(globalPda);
+    assert(global.pendingWithdrawAuthority === null);
+    assert(global.withdrawAuthority.equals(admin));
+
+    try {
+      await acceptAuthority({ withdraw: {} }, nominee);
+      assert(false);
+    } catch (e) {
+      assert(e.toString().includes("InvalidPendingAuthority"));
+    }
+  });
+});
@@ -36,6 +36,8 @@ import {
   initialize,
   BILLY_BONDING_CURVE_PROGRAM_ID,
   ProgramStatus,
+  AuthorityRole,
+  GlobalSettingsInputArgs,
   createBondingCurve,
   safeFetchBondingCurve,
   fetchBondingCurve,
@@ -49,6 +51,7 @@ import {
   claimCreatorVesting,
   fetchCreatorVault,
   BillySDK,
+  AdminSDK,
 } from "../clients/js/src";
 import {
   fromWeb3JsKeypair,
@@ -65,6 +68,7 @@ import assert from "assert";
 import * as anchor from "@coral-xyz/anchor";
 import {
   INIT_DEFAULTS,
+  SETTINGS_TIMELOCK_SECONDS,
   SIMPLE_DEFAULT_BONDING_CURVE_PRESET,
 } from "../clients/js/src/constants";
 import { Program } from "@coral-xyz/anchor";
@@ -332,6 +336,29 @@ const getBalance = async (umi: Umi, pubkey: PublicKey) => {
     return umiBalance.basisPoints;
   }
 };
+// schedules the settings, jumps past the timelock and executes them
+const executeScheduledSettings = async (
+  adminSdk: AdminSDK,
+  settings: Partial<GlobalSettingsInputArgs>
+) => {
+  const currentClock = await bankrunClient.getClock();
+  const effectiveTime =
+    currentClock.unixTimestamp + BigInt(SETTINGS_TIMELOCK_SECONDS);
+  await processTransaction(
+    umi,
+    adminSdk.scheduleSettings(settings, effectiveTime)
+  );
+  bankrunContext.setClock(
+    new Clock(
+      currentClock.slot,
+      currentClock.epochStartTimestamp,
+      currentClock.epoch,
+      currentClock.leaderScheduleEpoch,
+      effectiveTime
+    )
+  );
+  await processTransaction(umi, adminSdk.executeSettings());
+};
 const getTknAmount = async (umi: Umi, pubkey: PublicKey) => {
   // cannot use umi helpers and some rpc methods in bankrun
   if (USE_BANKRUN) {
@@ -508,27 +535,46 @@ describe("billy-bonding", () => {
     assert(traderAtaBalancePost == traderAtaBalancePre - sellTokenAmount);
   });
 
-  it("set_params: status:SwapOnly, withdrawAuthority", async () => {
+  it("set_params: withdrawAuthority", async () => {
     const adminSdk = new BillySDK(
       // admin signer
       umi.use(keypairIdentity(fromWeb3JsKeypair(bankrunContext.payer)))
     ).getAdminSDK();
 
     const txBuilder = adminSdk.setParams({
-      status: ProgramStatus.SwapOnly,
       newWithdrawAuthority: withdrawAuthority.publicKey,
     });
 
-    // const txRes = await txBuilder.sendAndConfirm(umi);
     await processTransaction(umi, txBuilder);
-    // const events = await getTxEventsFromTxBuilderResponse(connection, program, txRes);
-    // events.forEach(logEvent)
+
+    // the nominee has to accept the role
+    const nomineeSdk = new BillySDK(
+      // withdrawAuthority signer
+      umi.use(keypairIdentity(withdrawAuthority))
+    ).getAdminSDK();
+    await processTransaction(
+      umi,
+      nomineeSdk.acceptAuthority(AuthorityRole.Withdraw)
+    );
+
+    const global = await adminSdk.Billy.fetchGlobalData();
+    assert.equal(global.withdrawAuthority, withdrawAuthority.publicKey);
+  });
+
+  it("schedule_settings: status:SwapOnly", async () => {
+    const adminSdk = new BillySDK(
+      // admin signer
+      umi.use(keypairIdentity(fromWeb3JsKeypair(bankrunContext.payer)))
+    ).getAdminSDK();
+
+    await executeScheduledSettings(adminSdk, {
+      status: ProgramStatus.SwapOnly,
+    });
     const global = await adminSdk.Billy.fetchGlobalData();
 
     assertGlobal(global, {
       ...INIT_DEFAULTS,
       status: ProgramStatus.SwapOnly,
-      withdrawAuthority: withdrawAuthority.publicKey,
     });
   });
 
@@ -578,19 +624,15 @@ describe("billy-bonding", () => {
     assert(feeBalancePost_int == startingBalance);
   });
 
-  it("set_params: status:Running", async () => {
+  it("schedule_settings: status:Running", async () => {
     const adminSdk = new BillySDK(
       // admin signer
       umi.use(keypairIdentity(fromWeb3JsKeypair(bankrunContext.payer)))
     ).getAdminSDK();
 
-    const txBuilder = adminSdk.setParams({
+    await executeScheduledSettings(adminSdk, {
       status: INIT_DEFAULTS.status,
     });
-
-    await processTransaction(umi, txBuilder);
-    //   const events = await getTxEventsFromTxBuilderResponse(connection, program, txRes);
-    //   events.forEach(logEvent)
     const global = await adminSdk.Billy.fetchGlobalData();
     console.log("global", global);
     assertGlobal(global, {
@@ -38,6 +38,7 @@ import {
   initialize,
   BILLY_BONDING_CURVE_PROGRAM_ID,
   ProgramStatus,
+  AuthorityRole,
   createBondingCurve,
   safeFetchBondingCurve,
   fetchBondingCurve,
@@ -67,6 +68,7 @@ import assert from "assert";
 import * as anchor from "@coral-xyz/anchor";
 import {
   INIT_DEFAULTS,
+  SETTINGS_TIMELOCK_SECONDS,
   SIMPLE_DEFAULT_BONDING_CURVE_PRESET,
 } from "../clients/js/src/constants";
 import { Program } from "@coral-xyz/anchor";
@@ -380,28 +382,43 @@ describe("billy-bonding", () => {
     assert(traderAtaBalancePost == traderAtaBalancePre - sellTokenAmount);
   });
 
-  it("set_params: status:SwapOnly, withdrawAuthority", async () => {
+  it("set_params: nominates withdrawAuthority", async () => {
     const adminSdk = new BillySDK(
       // admin signer
       umi.use(keypairIdentity(masterKp))
     ).getAdminSDK();
 
     const txBuilder = adminSdk.setParams({
-      status: ProgramStatus.SwapOnly,
       newWithdrawAuthority: withdrawAuthority.publicKey,
     });
 
-    // const txRes = await txBuilder.sendAndConfirm(umi);
     await processTransaction(umi, txBuilder);
-    // const events = await getTxEventsFromTxBuilderResponse(connection, program, txRes);
-    // events.forEach(logEvent)
     const global = await adminSdk.Billy.fetchGlobalData();
 
     assertGlobal(global, {
       ...INIT_DEFAULTS,
-      status: ProgramStatus.SwapOnly,
-      withdrawAuthority: withdrawAuthority.publicKey,
     });
+    // the withdraw authority only changes once the nominee accepts
+    assert.equal(global.withdrawAuthority, masterKp.publicKey);
+    assert.equal(
+      unwrapOption(global.pendingWithdrawAuthority),
+      withdrawAuthority.publicKey
+    );
+  });
+
+  it("accept_authority: withdrawAuthority", async () => {
+    const adminSdk = new BillySDK(
+      // nominee signer
+      umi.use(keypairIdentity(withdrawAuthority))
+    ).getAdminSDK();
+
+    const txBuilder = adminSdk.acceptAuthority(AuthorityRole.Withdraw);
+
+    await processTransaction(umi, txBuilder);
+    const global = await adminSdk.Billy.fetchGlobalData();
+
+    assert.equal(global.withdrawAuthority, withdrawAuthority.publicKey);
+    assert.equal(unwrapOption(global.pendingWithdrawAuthority), null);
   });
 
   it("withdraw_fees using withdraw_authority", async () => {
@@ -441,8 +458,6 @@ describe("billy-bonding", () => {
 
     assertGlobal(global, {
       ...INIT_DEFAULTS,
-      status: ProgramStatus.SwapOnly,
-      withdrawAuthority: withdrawAuthority.publicKey,
     });
 
     const feeBalancePost = await getBalance(umi, platformVault[0]);
@@ -452,24 +467,100 @@ describe("billy-bonding", () => {
     assert(feeBalancePost_int == startingBalance);
   });
 
-  it("set_params: status:Running", async () => {
+  it("schedule_settings: status:SwapOnly", async () => {
     const adminSdk = new BillySDK(
       // admin signer
       umi.use(keypairIdentity(masterKp))
     ).getAdminSDK();
 
-    const txBuilder = adminSdk.setParams({
-      status: INIT_DEFAULTS.status,
-    });
+    // status changes other than pausing cant skip the timelock
+    try {
+      await processTransaction(
+        umi,
+        adminSdk.setParams({ status: ProgramStatus.SwapOnly })
+      );
+      assert(false);
+    } catch (e) {
+      assert(e.toString().includes("SettingsChangeRequiresTimelock"));
+    }
+
+    const now = await connection.getBlockTime(await connection.getSlot());
+    const effectiveTime = now + SETTINGS_TIMELOCK_SECONDS + 60;
+    const txBuilder = adminSdk.scheduleSettings(
+      { status: ProgramStatus.SwapOnly },
+      effectiveTime
+    );
 
     await processTransaction(umi, txBuilder);
-    //   const events = await getTxEventsFromTxBuilderResponse(connection, program, txRes);
-    //   events.forEach(logEvent)
     const global = await adminSdk.Billy.fetchGlobalData();
-    console.log("global", global);
+
+    // nothing changes until the settings are executed
     assertGlobal(global, {
       ...INIT_DEFAULTS,
     });
+    assert.equal(
+      unwrapOption(unwrapOption(global.pendingSettings).status),
+      ProgramStatus.SwapOnly
+    );
+    assert.equal(global.pendingSettingsEffectiveTime, BigInt(effectiveTime));
+  });
+
+  it("execute_settings: cant execute before the effective time", async () => {
+    const adminSdk = new BillySDK(
+      // anyone can execute
+      umi.use(keypairIdentity(trader))
+    ).getAdminSDK();
+
+    try {
+      await processTransaction(umi, adminSdk.executeSettings());
+      assert(false);
+    } catch (e) {
+      assert(e.toString().includes("SettingsNotYetEffective"));
+    }
+  });
+
+  it("cancel_settings", async () => {
+    const adminSdk = new BillySDK(
+      // admin signer
+      umi.use(keypairIdentity(masterKp))
+    ).getAdminSDK();
+
+    await processTransaction(umi, adminSdk.cancelSettings());
+    const global = await adminSdk.Billy.fetchGlobalData();
+
+    assertGlobal(global, {
+      ...INIT_DEFAULTS,
+    });
+    assert.equal(unwrapOption(global.pendingSettings), null);
+  });
+
+  it("set_params: status:Paused", async () => {
+    const adminSdk = new BillySDK(
+      // admin signer
+      umi.use(keypairIdentity(masterKp))
+    ).getAdminSDK();
+
+    // pausing applies right away
+    await processTransaction(
+      umi,
+      adminSdk.setParams({ status: ProgramStatus.Paused })
+    );
+    const global = await adminSdk.Billy.fetchGlobalData();
+    assertGlobal(global, {
+      ...INIT_DEFAULTS,
+      status: ProgramStatus.Paused,
+    });
+
+    // unpausing has to be scheduled
+    try {
+      await processTransaction(
+        umi,
+        adminSdk.setParams({ status: INIT_DEFAULTS.status })
+      );
+      assert(false);
+    } catch (e) {
+      assert(e.toString().includes("SettingsChangeRequiresTimelock"));
+    }
   });
 
   it("cant claim creator vesting before cliff", async () => {
@@ -0,0 +1,429 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  AccountLayout,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  MintLayout,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { BankrunProvider } from "anchor-bankrun";
+import { BanksClient, ProgramTestContext, startAnchor } from "solana-bankrun";
+import { readFileSync } from "fs";
+import path from "path";
+import assert from "assert";
+import { toWeb3JsPublicKey } from "@metaplex-foundation/umi-web3js-adapters";
+import { MPL_TOKEN_METADATA_PROGRAM_ID } from "@metaplex-foundation/mpl-token-metadata";
+import { MPL_SYSTEM_EXTRAS_PROGRAM_ID } from "@metaplex-foundation/mpl-toolbox";
+import { BillyBondingCurve } from "../target/types/billy_bonding_curve";
+import { MockAmm } from "../target/types/mock_amm";
+import { AMM } from "../clients/js/src/amm";
+
+// Graduation end to end against the local mock AMM, runs fully in bankrun.
+
+const INITIAL_SOL = 1000 * LAMPORTS_PER_SOL;
+const TOKEN_DECIMALS = 6;
+const TOKEN_TOTAL_SUPPLY = new BN(2_000_000_000).mul(new BN(10 ** TOKEN_DECIMALS));
+const SOL_LAUNCH_THRESHOLD = new BN(300 * LAMPORTS_PER_SOL);
+
+const programBinDir = path.join(__dirname, "..", ".programsBin");
+
+const loadBin = (binary: string) => ({
+  lamports: INITIAL_SOL,
+  executable: true,
+  owner: new PublicKey("BPFLoader2111111111111111111111111111111111"),
+  data: readFileSync(path.join(programBinDir, binary)),
+});
+
+const systemAccount = (lamports: number) => ({
+  lamports,
+  executable: false,
+  data: Buffer.from([]),
+  owner: SystemProgram.programId,
+});
+
+const creator = Keypair.generate();
+const trader = Keypair.generate();
+const mintKp = Keypair.generate();
+const mint = mintKp.publicKey;
+
+let context: ProgramTestContext;
+let client: BanksClient;
+let program: Program<BillyBondingCurve>;
+let ammProgram: Program<MockAmm>;
+
+const pda = (seeds: (Buffer | Uint8Array)[], programId: PublicKey) =>
+  PublicKey.findProgramAddressSync(seeds, programId)[0];
+
+const getTknAmount = async (pubkey: PublicKey) => {
+  const accInfo = await client.getAccount(pubkey);
+  return AccountLayout.decode(accInfo?.data || Buffer.from([])).amount;
+};
+
+describe("graduate", () => {
+  let globalPda: PublicKey;
+  let eventAuthority: PublicKey;
+  let bondingCurvePda: PublicKey;
+  let bondingCurveTokenAccount: PublicKey;
+  let platformVaultPda: PublicKey;
+
+  before(async () => {
+    process.env.ANCHOR_WALLET = "./keys/test-kp.json";
+    context = await startAnchor(
+      "./",
+      [],
+      [
+        { address: creator.publicKey, info: systemAccount(INITIAL_SOL) },
+        { address: trader.publicKey, info: systemAccount(INITIAL_SOL) },
+        {
+          address: toWeb3JsPublicKey(MPL_TOKEN_METADATA_PROGRAM_ID),
+          info: loadBin("mpl_token_metadata.so"),
+        },
+        {
+          address: toWeb3JsPublicKey(MPL_SYSTEM_EXTRAS_PROGRAM_ID),
+          info: loadBin("mpl_system_extras.so"),
+        },
+      ]
+    );
+    client = context.banksClient;
+    const provider = new BankrunProvider(context);
+    anchor.setProvider(provider);
+    program = anchor.workspace.BillyBondingCurve as Program<BillyBondingCurve>;
+    ammProgram = anchor.workspace.MockAmm as Program<MockAmm>;
+
+    globalPda = pda([Buffer.from("global")], program.programId);
+    eventAuthority = pda([Buffer.from("__event_authority")], program.programId);
+    bondingCurvePda = pda(
+      [Buffer.from("bonding-curve"), mint.toBuffer()],
+      program.programId
+    );
+    bondingCurveTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      bondingCurvePda,
+      true
+    );
+    platformVaultPda = pda(
+      [Buffer.from("platform-vault"), mint.toBuffer()],
+      program.programId
+    );
+  });
+
+  it("is initialized with the mock AMM", async () => {
+    await program.methods
+      .initialize({
+        tradeFeeBps: new BN(100),
+        createdMintDecimals: TOKEN_DECIMALS,
+        launchFeeLamports: new BN(0.5 * LAMPORTS_PER_SOL),
+        launchFeeRefundBps: null,
+        maxLaunchLeadTime: null,
+        status: { running: {} },
+        ammProgram: ammProgram.programId,
+        platformVestingTerms: null,
+        cexVestingTerms: null,
+        caps: null,
+      })
+      .accounts({
+        authority: context.payer.publicKey,
+        global: globalPda,
+        systemProgram: SystemProgram.programId,
+        eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    const global = await program.account.global.fetch(globalPda);
+    assert(global.ammProgram.equals(ammProgram.programId));
+  });
+
+  it("completes a bonding curve", async () => {
+    const vault = (prefix: string) =>
+      pda([Buffer.from(prefix), mint.toBuffer()], program.programId);
+    const ata = (owner: PublicKey) =>
+      getAssociatedTokenAddressSync(mint, owner, true);
+    const creatorVault = vault("creator-vault");
+    const presaleVault = vault("presale-vault");
+    const brandVault = vault("brand-vault");
+    const cexVault = vault("cex-vault");
+    const metadata = pda(
+      [
+        Buffer.from("metadata"),
+        toWeb3JsPublicKey(MPL_TOKEN_METADATA_PROGRAM_ID).toBuffer(),
+        mint.toBuffer(),
+      ],
+      toWeb3JsPublicKey(MPL_TOKEN_METADATA_PROGRAM_ID)
+    );
+
+    await program.methods
+      .createBondingCurve({
+        name: "graduatingCurve",
+        symbol: "GRAD",
+        uri: "https://www.graduatingCurve.com",
+        startTime: null,
+        graduationDeadline: null,
+        tokenTotalSupply: TOKEN_TOTAL_SUPPLY,
+        solLaunchThreshold: SOL_LAUNCH_THRESHOLD,
+        virtualTokenMultiplierBps: new BN(730),
+        virtualSolReserves: new BN(30 * LAMPORTS_PER_SOL),
+        allocation: {
+          creator: null,
+          cex: null,
+          launchBrandkit: null,
+          lifetimeBrandkit: null,
+          platform: null,
+          presale: null,
+          poolReserve: null,
+        },
+        vestingTerms: null,
+        presaleTerms: null,
+        brandkitVestingTerms: null,
+        launchProtection: null,
+        feeSchedule: null,
+        allowlist: null,
+        curveKind: null,
+        completionPolicy: null,
+      })
+      .accounts({
+        mint,
+        creator: creator.publicKey,
+        creatorVault,
+        creatorVaultTokenAccount: ata(creatorVault),
+        presaleVault,
+        presaleVaultTokenAccount: ata(presaleVault),
+        brandAuthority: creator.publicKey,
+        brandVault,
+        brandVaultTokenAccount: ata(brandVault),
+        platformVault: platformVaultPda,
+        platformVaultTokenAccount: ata(platformVaultPda),
+        cexVault,
+        cexVaultTokenAccount: ata(cexVault),
+        bondingCurve: bondingCurvePda,
+        bondingCurveTokenAccount,
+        global: globalPda,
+        metadata,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: toWeb3JsPublicKey(MPL_TOKEN_METADATA_PROGRAM_ID),
+        rent: SYSVAR_RENT_PUBKEY,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        eventAuthority,
+        program: program.programId,
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 600_000 }),
+      ])
+      .signers([creator, mintKp])
+      .rpc();
+
+    const curve = await program.account.bondingCurve.fetch(bondingCurvePda);
+    const amm = new AMM(
+      BigInt(curve.virtualSolReserves.toString()),
+      BigInt(curve.virtualTokenReserves.toString()),
+      BigInt(curve.realSolReserves.toString()),
+      BigInt(curve.realTokenReserves.toString()),
+      BigInt(curve.initialVirtualTokenReserves.toString())
+    );
+    // buy past the threshold, the buy is only filled up to it and leaves tokens on the curve
+    const buyAmount = SOL_LAUNCH_THRESHOLD.add(new BN(LAMPORTS_PER_SOL));
+    assert(
+      amm.getBuyPrice(amm.realTokenReserves) > BigInt(buyAmount.toString())
+    );
+
+    const swapParams = {
+      baseIn: false,
+      exactInAmount: buyAmount,
+      minOutAmount: new BN(0),
+      exactOutAmount: new BN(0),
+      maxInAmount: new BN(0),
+      allowlistProof: null,
+    };
+    const quote = await program.methods
+      .quote(swapParams)
+      .accounts({ global: globalPda, mint, bondingCurve: bondingCurvePda })
+      .view();
+    assert(quote.completesCurve);
+
+    await program.methods
+      .swap(swapParams)
+      .accounts({
+        user: trader.publicKey,
+        global: globalPda,
+        mint,
+        bondingCurve: bondingCurvePda,
+        bondingCurveTokenAccount,
+        platformVault: platformVaultPda,
+        platformVaultTokenAccount: ata(platformVaultPda),
+        userTokenAccount: ata(trader.publicKey),
+        // no launch protection or allowlist to track the buy for
+        userPosition: null,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        eventAuthority,
+        program: program.programId,
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 600_000 }),
+      ])
+      .signers([trader])
+      .rpc();
+
+    const curvePost = await program.account.bondingCurve.fetch(bondingCurvePda);
+    assert(curvePost.complete);
+    assert(!curvePost.graduated);
+    assert(curvePost.realTokenReserves.gtn(0));
+    assert(curvePost.realSolReserves.eq(SOL_LAUNCH_THRESHOLD));
+    // unsold tokens beyond the graduation liquidity are burned by default
+    assert(
+      curvePost.realTokenReserves.eq(
+        curvePost.realSolReserves
+          .mul(curvePost.virtualTokenReserves)
+          .div(curvePost.virtualSolReserves)
+      )
+    );
+    // the quote matches the executed trade
+    assert(curvePost.realTokenReserves.eq(quote.realTokenReserves));
+    assert(curvePost.realSolReserves.eq(quote.realSolReserves));
+  });
+
+  it("graduates into the mock AMM and burns the LP tokens", async () => {
+    const curve = await program.account.bondingCurve.fetch(bondingCurvePda);
+    const solAmount = curve.realSolReserves;
+    const tokenAmount = solAmount
+      .mul(curve.virtualTokenReserves)
+      .div(curve.virtualSolReserves);
+
+    const liquidityProvider = pda(
+      [Buffer.from("liquidity-provider"), mint.toBuffer()],
+      program.programId
+    );
+    const pool = pda(
+      [Buffer.from("pool"), mint.toBuffer()],
+      ammProgram.programId
+    );
+    const lpMint = pda(
+      [Buffer.from("lp-mint"), mint.toBuffer()],
+      ammProgram.programId
+    );
+    const liquidityProviderLpTokenAccount = getAssociatedTokenAddressSync(
+      lpMint,
+      liquidityProvider,
+      true
+    );
+    const poolTokenAccount = getAssociatedTokenAddressSync(mint, pool, true);
+
+    await program.methods
+      .graduate()
+      .accounts({
+        payer: trader.publicKey,
+        global: globalPda,
+        mint,
+        bondingCurve: bondingCurvePda,
+        bondingCurveTokenAccount,
+        presaleVault: pda(
+          [Buffer.from("presale-vault"), mint.toBuffer()],
+          program.programId
+        ),
+        liquidityProvider,
+        liquidityProviderTokenAccount: getAssociatedTokenAddressSync(
+          mint,
+          liquidityProvider,
+          true
+        ),
+        pool,
+        lpMint,
+        liquidityProviderLpTokenAccount,
+        ammProgram: ammProgram.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: poolTokenAccount, isSigner: false, isWritable: true },
+      ])
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 600_000 }),
+      ])
+      .signers([trader])
+      .rpc();
+
+    const curvePost = await program.account.bondingCurve.fetch(bondingCurvePda);
+    assert(curvePost.graduated);
+    assert(curvePost.realSolReserves.eqn(0));
+    assert(
+      curvePost.realTokenReserves.eq(curve.realTokenReserves.sub(tokenAmount))
+    );
+
+    const poolData = await ammProgram.account.pool.fetch(pool);
+    assert(poolData.solReserves.eq(solAmount));
+    assert(poolData.tokenReserves.eq(tokenAmount));
+    assert.equal(
+      (await getTknAmount(poolTokenAccount)).toString(),
+      tokenAmount.toString()
+    );
+
+    // every LP token minted by the pool has been burned
+    assert.equal(await getTknAmount(liquidityProviderLpTokenAccount), 0n);
+    const lpMintInfo = MintLayout.decode((await client.getAccount(lpMint))!.data);
+    assert.equal(lpMintInfo.supply, 0n);
+  });
+
+  it("cannot graduate twice", async () => {
+    try {
+      await program.methods
+        .graduate()
+        .accounts({
+          payer: trader.publicKey,
+          global: globalPda,
+          mint,
+          bondingCurve: bondingCurvePda,
+          bondingCurveTokenAccount,
+          presaleVault: pda(
+            [Buffer.from("presale-vault"), mint.toBuffer()],
+            program.programId
+          ),
+          liquidityProvider: pda(
+            [Buffer.from("liquidity-provider"), mint.toBuffer()],
+            program.programId
+          ),
+          liquidityProviderTokenAccount: getAssociatedTokenAddressSync(
+            mint,
+            pda(
+              [Buffer.from("liquidity-provider"), mint.toBuffer()],
+              program.programId
+            ),
+            true
+          ),
+          pool: pda([Buffer.from("pool"), mint.toBuffer()], ammProgram.programId),
+          lpMint: pda(
+            [Buffer.from("lp-mint"), mint.toBuffer()],
+            ammProgram.programId
+          ),
+          liquidityProviderLpTokenAccount: PublicKey.default,
+          ammProgram: ammProgram.programId,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          eventAuthority,
+          program: program.programId,
+        })
+        .signers([trader])
+        .rpc();
+      assert(false);
+    } catch (e) {
+      assert(e.toString().includes("BondingCurveGraduated"));
+    }
+  });
+});